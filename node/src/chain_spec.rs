@@ -23,10 +23,10 @@ use node_runtime::{
     versioned_store::InputValidationLengthConstraint as VsInputValidation,
     AuthorityDiscoveryConfig, BabeConfig, Balance, BalancesConfig, ContentWorkingGroupConfig,
     CouncilConfig, CouncilElectionConfig, DataObjectStorageRegistryConfig,
-    DataObjectTypeRegistryConfig, ElectionParameters, GrandpaConfig, ImOnlineConfig, IndicesConfig,
-    MembersConfig, MigrationConfig, Perbill, ProposalsCodexConfig, SessionConfig, SessionKeys,
-    Signature, StakerStatus, StakingConfig, StorageWorkingGroupConfig, SudoConfig, SystemConfig,
-    VersionedStoreConfig, DAYS, WASM_BINARY,
+    DataObjectTypeRegistryConfig, ElectionParameters, ForumWorkingGroupConfig, GrandpaConfig,
+    ImOnlineConfig, IndicesConfig, MembersConfig, MigrationConfig, Perbill, ProposalsCodexConfig,
+    SessionConfig, SessionKeys, Signature, StakerStatus, StakingConfig, StorageWorkingGroupConfig,
+    SudoConfig, SystemConfig, VersionedStoreConfig, DAYS, WASM_BINARY,
 };
 pub use node_runtime::{AccountId, GenesisConfig};
 use primitives::{sr25519, Pair, Public};
@@ -269,6 +269,13 @@ pub fn testnet_genesis(
         data_object_storage_registry: Some(DataObjectStorageRegistryConfig {
             first_relationship_id: 1,
         }),
+        working_group_Instance1: Some(ForumWorkingGroupConfig {
+            phantom: Default::default(),
+            storage_working_group_mint_capacity: 0,
+            opening_human_readable_text_constraint: default_text_constraint,
+            worker_application_human_readable_text_constraint: default_text_constraint,
+            worker_exit_rationale_text_constraint: default_text_constraint,
+        }),
         working_group_Instance2: Some(StorageWorkingGroupConfig {
             phantom: Default::default(),
             storage_working_group_mint_capacity: 0,
@@ -364,6 +371,34 @@ pub fn testnet_genesis(
                 .terminate_working_group_leader_role_proposal_voting_period,
             terminate_working_group_leader_role_proposal_grace_period: cpcp
                 .terminate_working_group_leader_role_proposal_grace_period,
+            cancel_working_group_slashing_proposal_voting_period: cpcp
+                .cancel_working_group_slashing_proposal_voting_period,
+            cancel_working_group_slashing_proposal_grace_period: cpcp
+                .cancel_working_group_slashing_proposal_grace_period,
+            resume_working_group_slashing_proposal_voting_period: cpcp
+                .resume_working_group_slashing_proposal_voting_period,
+            resume_working_group_slashing_proposal_grace_period: cpcp
+                .resume_working_group_slashing_proposal_grace_period,
+            suspend_member_proposal_voting_period: cpcp.suspend_member_proposal_voting_period,
+            suspend_member_proposal_grace_period: cpcp.suspend_member_proposal_grace_period,
+            unsuspend_member_proposal_voting_period: cpcp.unsuspend_member_proposal_voting_period,
+            unsuspend_member_proposal_grace_period: cpcp.unsuspend_member_proposal_grace_period,
+            add_working_group_opening_template_proposal_voting_period: cpcp
+                .add_working_group_opening_template_proposal_voting_period,
+            add_working_group_opening_template_proposal_grace_period: cpcp
+                .add_working_group_opening_template_proposal_grace_period,
+            update_working_group_opening_template_proposal_voting_period: cpcp
+                .update_working_group_opening_template_proposal_voting_period,
+            update_working_group_opening_template_proposal_grace_period: cpcp
+                .update_working_group_opening_template_proposal_grace_period,
+            remove_working_group_opening_template_proposal_voting_period: cpcp
+                .remove_working_group_opening_template_proposal_voting_period,
+            remove_working_group_opening_template_proposal_grace_period: cpcp
+                .remove_working_group_opening_template_proposal_grace_period,
+            add_working_group_opening_from_template_proposal_voting_period: cpcp
+                .add_working_group_opening_from_template_proposal_voting_period,
+            add_working_group_opening_from_template_proposal_grace_period: cpcp
+                .add_working_group_opening_from_template_proposal_grace_period,
         }),
     }
 }
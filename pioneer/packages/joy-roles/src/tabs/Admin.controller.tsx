@@ -35,6 +35,7 @@ import {
   Application,
   ApplicationStage,
   ActivateOpeningAt,
+  ApplicationRankingPolicy,
   ApplicationRationingPolicy,
   CurrentBlock,
   Opening,
@@ -158,7 +159,8 @@ const createRationingPolicyOpt = (maxApplicants: number) =>
   new Option<ApplicationRationingPolicy>(
     ApplicationRationingPolicy,
     new ApplicationRationingPolicy({
-      max_active_applicants: new u32(maxApplicants)
+      max_active_applicants: new u32(maxApplicants),
+      applicant_ranking: new ApplicationRankingPolicy('Stake')
     })
   );
 const createStakingPolicyOpt = (amount: number, amount_mode: StakingAmountLimitMode): Option<StakingPolicy> =>
@@ -328,7 +330,8 @@ const stockOpenings: openingDescriptor[] = [
       application_rationing_policy: new Option<ApplicationRationingPolicy>(
         ApplicationRationingPolicy,
         new ApplicationRationingPolicy({
-          max_active_applicants: new u32(10)
+          max_active_applicants: new u32(10),
+          applicant_ranking: new ApplicationRankingPolicy('Stake')
         })
       ),
       application_staking_policy: createStakingPolicyOpt(100, STAKING_MODE_EXACT),
@@ -0,0 +1,352 @@
+//! IPFS identifiers validation: multihashes, CIDv0/CIDv1 and base58 encoded IPNS peer ids.
+//! The validation works with the textual representation of the identifiers as they are passed
+//! to the runtime by the clients.
+
+use rstd::prelude::*;
+
+/// Bitcoin base58 alphabet used by IPFS.
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// RFC4648 lowercase base32 alphabet used by the CIDv1 default multibase.
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Multibase prefix for the base58btc encoding.
+const MULTIBASE_BASE58BTC: u8 = b'z';
+
+/// Multibase prefix for the lowercase base32 encoding without padding.
+const MULTIBASE_BASE32: u8 = b'b';
+
+/// Multihash code of the 'identity' hash function.
+const MULTIHASH_IDENTITY: u64 = 0x00;
+
+/// Multihash code of the 'sha2-256' hash function.
+const MULTIHASH_SHA2_256: u64 = 0x12;
+
+/// Digest length of the 'sha2-256' hash function.
+const SHA2_256_LENGTH: usize = 32;
+
+/// Maximum size of a public key inlined in the peer id with the 'identity' multihash.
+const MAX_INLINE_KEY_LENGTH: usize = 42;
+
+/// Maximum length of the textual identifier. Prevents excessive computation on the long inputs.
+const MAX_ID_LENGTH: usize = 128;
+
+/// Maximum length of the unsigned varint in bytes (up to 63-bit values).
+const MAX_VARINT_LENGTH: usize = 9;
+
+/// Defines IPFS identifier validation errors.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IdentifierError {
+    /// Identifier is empty.
+    Empty,
+
+    /// Identifier is too long.
+    TooLong,
+
+    /// Identifier contains characters outside of the encoding alphabet.
+    InvalidEncoding,
+
+    /// Multibase prefix is not supported.
+    UnsupportedMultibase,
+
+    /// CID version is not supported.
+    UnsupportedCidVersion,
+
+    /// Varint is malformed or too long.
+    InvalidVarint,
+
+    /// Multihash is malformed: digest length doesn't match.
+    InvalidMultihash,
+
+    /// Multihash hash function is not allowed for the identifier.
+    UnsupportedHashFunction,
+}
+
+impl From<IdentifierError> for &'static str {
+    fn from(error: IdentifierError) -> Self {
+        match error {
+            IdentifierError::Empty => "Identifier is empty",
+            IdentifierError::TooLong => "Identifier is too long",
+            IdentifierError::InvalidEncoding => "Identifier has invalid encoding",
+            IdentifierError::UnsupportedMultibase => "Unsupported multibase prefix",
+            IdentifierError::UnsupportedCidVersion => "Unsupported CID version",
+            IdentifierError::InvalidVarint => "Invalid varint",
+            IdentifierError::InvalidMultihash => "Invalid multihash",
+            IdentifierError::UnsupportedHashFunction => "Unsupported multihash function",
+        }
+    }
+}
+
+/// Validates binary multihash: <varint hash function code><varint digest length><digest>.
+/// Returns the hash function code.
+pub fn validate_multihash(multihash: &[u8]) -> Result<u64, IdentifierError> {
+    let (code, rest) = decode_varint(multihash)?;
+    let (length, digest) = decode_varint(rest)?;
+
+    if digest.len() as u64 != length
+        || (code == MULTIHASH_SHA2_256 && digest.len() != SHA2_256_LENGTH)
+    {
+        return Err(IdentifierError::InvalidMultihash);
+    }
+
+    Ok(code)
+}
+
+/// Validates textual IPFS content id. Supports base58 encoded CIDv0 ('Qm...') and CIDv1 with
+/// base32 ('b...') or base58btc ('z...') multibase encoding.
+pub fn validate_cid(cid: &[u8]) -> Result<(), IdentifierError> {
+    ensure_id_length(cid)?;
+
+    // CIDv0 is a base58 encoded sha2-256 multihash: always 46 characters starting with 'Qm'.
+    if cid.len() == 46 && cid.starts_with(b"Qm") {
+        let multihash = decode_base58(cid)?;
+
+        return ensure_hash_function(validate_multihash(&multihash)?, MULTIHASH_SHA2_256);
+    }
+
+    let binary_cid = match cid[0] {
+        MULTIBASE_BASE32 => decode_base32(&cid[1..])?,
+        MULTIBASE_BASE58BTC => decode_base58(&cid[1..])?,
+        _ => return Err(IdentifierError::UnsupportedMultibase),
+    };
+
+    let (version, rest) = decode_varint(&binary_cid)?;
+    if version != 1 {
+        return Err(IdentifierError::UnsupportedCidVersion);
+    }
+
+    let (_content_type, multihash) = decode_varint(rest)?;
+
+    validate_multihash(multihash).map(|_| ())
+}
+
+/// Validates base58 encoded IPNS identity (libp2p peer id). The peer id is either a sha2-256
+/// multihash of the public key ('Qm...') or the public key inlined with the 'identity' multihash
+/// ('12D3Koo...' for the ed25519 keys).
+pub fn validate_ipns_identity(id: &[u8]) -> Result<(), IdentifierError> {
+    ensure_id_length(id)?;
+
+    let multihash = decode_base58(id)?;
+
+    match validate_multihash(&multihash)? {
+        MULTIHASH_SHA2_256 => Ok(()),
+        MULTIHASH_IDENTITY if multihash.len() <= MAX_INLINE_KEY_LENGTH + 2 => Ok(()),
+        MULTIHASH_IDENTITY => Err(IdentifierError::InvalidMultihash),
+        _ => Err(IdentifierError::UnsupportedHashFunction),
+    }
+}
+
+fn ensure_id_length(id: &[u8]) -> Result<(), IdentifierError> {
+    if id.is_empty() {
+        Err(IdentifierError::Empty)
+    } else if id.len() > MAX_ID_LENGTH {
+        Err(IdentifierError::TooLong)
+    } else {
+        Ok(())
+    }
+}
+
+fn ensure_hash_function(code: u64, expected_code: u64) -> Result<(), IdentifierError> {
+    if code == expected_code {
+        Ok(())
+    } else {
+        Err(IdentifierError::UnsupportedHashFunction)
+    }
+}
+
+// Decodes unsigned LEB128 varint. Returns the value and the remaining bytes.
+fn decode_varint(input: &[u8]) -> Result<(u64, &[u8]), IdentifierError> {
+    let mut value = 0u64;
+
+    for (index, byte) in input.iter().enumerate().take(MAX_VARINT_LENGTH) {
+        value |= u64::from(byte & 0x7f) << (7 * index);
+
+        if byte & 0x80 == 0 {
+            // Minimal encoding is required: no trailing zero bytes.
+            if index > 0 && *byte == 0 {
+                return Err(IdentifierError::InvalidVarint);
+            }
+
+            return Ok((value, &input[index + 1..]));
+        }
+    }
+
+    Err(IdentifierError::InvalidVarint)
+}
+
+fn decode_base58(input: &[u8]) -> Result<Vec<u8>, IdentifierError> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+
+    for character in input.iter() {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|c| c == character)
+            .ok_or(IdentifierError::InvalidEncoding)? as u32;
+
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    // Leading '1' characters encode leading zero bytes.
+    let leading_zeros = input
+        .iter()
+        .take_while(|c| **c == BASE58_ALPHABET[0])
+        .count();
+
+    let mut result = vec![0u8; leading_zeros];
+    result.extend(bytes);
+
+    Ok(result)
+}
+
+fn decode_base32(input: &[u8]) -> Result<Vec<u8>, IdentifierError> {
+    let mut result = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+
+    for character in input.iter() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|c| c == character)
+            .ok_or(IdentifierError::InvalidEncoding)? as u32;
+
+        buffer = (buffer << 5) | value;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    // Remaining bits are padding and should be zero.
+    if buffer != 0 {
+        return Err(IdentifierError::InvalidEncoding);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_cid_succeeds_for_valid_cids() {
+        let valid_cids = vec![
+            "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+            "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o",
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            "zdj7WWeQ43G6JJvLWQWZpyHuAMq6uYWRjkBXFad11vE2LHhQ7",
+        ];
+
+        for cid in valid_cids {
+            assert_eq!(validate_cid(cid.as_bytes()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn validate_cid_fails_for_invalid_cids() {
+        let invalid_cids = vec![
+            ("", IdentifierError::Empty),
+            (
+                "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0",
+                IdentifierError::InvalidEncoding,
+            ),
+            (
+                "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdGG",
+                IdentifierError::UnsupportedMultibase,
+            ),
+            (
+                "fbafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3o",
+                IdentifierError::UnsupportedMultibase,
+            ),
+            (
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbz",
+                IdentifierError::InvalidMultihash,
+            ),
+            ("bciqa", IdentifierError::UnsupportedCidVersion),
+            ("b1234", IdentifierError::InvalidEncoding),
+        ];
+
+        for (cid, error) in invalid_cids {
+            assert_eq!(validate_cid(cid.as_bytes()), Err(error));
+        }
+
+        assert_eq!(
+            validate_cid(&[b'Q'; MAX_ID_LENGTH + 1]),
+            Err(IdentifierError::TooLong)
+        );
+        assert_eq!(
+            validate_cid(&[1, 3, 3, 7]),
+            Err(IdentifierError::UnsupportedMultibase)
+        );
+    }
+
+    #[test]
+    fn validate_ipns_identity_succeeds_for_valid_ids() {
+        let valid_ids = vec![
+            "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N",
+            "12D3KooWD3eckifWpRn9wQpMG9R9hX3sD158z7EF5AMBwa2kDhuZ",
+        ];
+
+        for id in valid_ids {
+            assert_eq!(validate_ipns_identity(id.as_bytes()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn validate_ipns_identity_fails_for_invalid_ids() {
+        let invalid_ids = vec![
+            ("", IdentifierError::Empty),
+            ("alice", IdentifierError::InvalidEncoding),
+            (
+                "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5O",
+                IdentifierError::InvalidEncoding,
+            ),
+            (
+                "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5",
+                IdentifierError::InvalidMultihash,
+            ),
+            (
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+                IdentifierError::InvalidEncoding,
+            ),
+        ];
+
+        for (id, error) in invalid_ids {
+            assert_eq!(validate_ipns_identity(id.as_bytes()), Err(error));
+        }
+    }
+
+    #[test]
+    fn validate_multihash_checks_digest_length() {
+        let mut multihash = vec![0x12, 0x20];
+        multihash.extend_from_slice(&[7u8; 32]);
+        assert_eq!(validate_multihash(&multihash), Ok(MULTIHASH_SHA2_256));
+
+        multihash.pop();
+        assert_eq!(
+            validate_multihash(&multihash),
+            Err(IdentifierError::InvalidMultihash)
+        );
+
+        assert_eq!(
+            validate_multihash(&[0x00, 0x02, 1, 2]),
+            Ok(MULTIHASH_IDENTITY)
+        );
+        assert_eq!(
+            validate_multihash(&[0x80]),
+            Err(IdentifierError::InvalidVarint)
+        );
+    }
+}
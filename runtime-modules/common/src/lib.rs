@@ -3,6 +3,7 @@
 
 pub mod constraints;
 pub mod currency;
+pub mod ipfs;
 pub mod origin;
 pub mod working_group;
 
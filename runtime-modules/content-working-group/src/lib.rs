@@ -27,7 +27,7 @@ use rstd::collections::btree_map::BTreeMap;
 use rstd::collections::btree_set::BTreeSet;
 use rstd::convert::From;
 use rstd::prelude::*;
-use sr_primitives::traits::{One, Zero}; // Member, SimpleArithmetic, MaybeSerialize
+use sr_primitives::traits::{One, Saturating, Zero}; // Member, SimpleArithmetic, MaybeSerialize
 use srml_support::traits::{Currency, ExistenceRequirement, WithdrawReasons};
 use srml_support::{
     decl_event,
@@ -237,12 +237,27 @@ pub static MSG_APPLY_ON_CURATOR_OPENING_SIGNER_NOT_CONTROLLER_ACCOUNT: &str =
     "Signer does not match controller account";
 pub static MSG_ORIGIN_IS_NIETHER_MEMBER_CONTROLLER_OR_ROOT: &str =
     "Origin must be controller or root account of member";
+pub static MSG_SUSPENDED_MEMBER_CANNOT_APPLY: &str = "Suspended member cannot apply on the opening";
 pub static MSG_MEMBER_HAS_ACTIVE_APPLICATION_ON_OPENING: &str =
     "Member already has an active application on the opening";
 pub static MSG_ADD_CURATOR_OPENING_ROLE_STAKE_CANNOT_BE_ZERO: &str =
     "Add curator opening role stake cannot be zero";
 pub static MSG_ADD_CURATOR_OPENING_APPLICATION_STAKE_CANNOT_BE_ZERO: &str =
     "Add curator opening application stake cannot be zero";
+pub static MSG_ADD_CURATOR_OPENING_SLASHING_MAX_PERCENT_PTS_TOO_HIGH: &str =
+    "Add curator opening role slashing max percentage points per time cannot exceed 100";
+
+// Errors for `slash_curator`
+pub static MSG_SLASH_CURATOR_BALANCE_CANNOT_BE_ZERO: &str = "Slashing balance cannot be zero";
+pub static MSG_SLASH_CURATOR_NO_ROLE_STAKE: &str = "Curator has no role stake";
+pub static MSG_SLASH_CURATOR_ROLE_IS_UNSLASHABLE: &str =
+    "Curator role stake cannot be slashed according to the opening slashing terms";
+pub static MSG_SLASH_CURATOR_MAX_SLASH_COUNT_REACHED: &str =
+    "Max number of slashes of the curator role stake is reached";
+pub static MSG_SLASH_CURATOR_STAKE_NOT_FOUND: &str = "Curator role stake not found";
+pub static MSG_SLASH_CURATOR_NOT_STAKED: &str = "Curator role stake is not staked";
+pub static MSG_SLASH_CURATOR_SLASH_AMOUNT_IS_ZERO: &str =
+    "Slashing amount should be greater than zero";
 
 /// The exit stage of a lead involvement in the working group.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -974,6 +989,22 @@ impl rstd::convert::From<WrappedError<hiring::AddApplicationError>> for &str {
     }
 }
 
+impl rstd::convert::From<WrappedError<stake::StakeActionError<stake::ImmediateSlashingError>>>
+    for &str
+{
+    fn from(wrapper: WrappedError<stake::StakeActionError<stake::ImmediateSlashingError>>) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => MSG_SLASH_CURATOR_STAKE_NOT_FOUND,
+            stake::StakeActionError::Error(slashing_error) => match slashing_error {
+                stake::ImmediateSlashingError::NotStaked => MSG_SLASH_CURATOR_NOT_STAKED,
+                stake::ImmediateSlashingError::SlashAmountShouldBeGreaterThanZero => {
+                    MSG_SLASH_CURATOR_SLASH_AMOUNT_IS_ZERO
+                }
+            },
+        }
+    }
+}
+
 impl rstd::convert::From<WrappedError<members::MemberControllerAccountDidNotSign>> for &str {
     fn from(wrapper: WrappedError<members::MemberControllerAccountDidNotSign>) -> Self {
         match wrapper.error {
@@ -1058,6 +1089,9 @@ decl_storage! {
         /// Recover curator by the role stake which is currently unstaking.
         pub UnstakerByStakeId get(unstaker_by_stake_id) config(): linked_map StakeId<T> => WorkingGroupUnstaker<LeadId<T>, CuratorId<T>>;
 
+        /// Number of slashes applied to the curator role stake.
+        pub CuratorSlashCountById get(curator_slash_count_by_id): map CuratorId<T> => u16;
+
         // Limits
 
         /// Limits the total number of curators which can be active.
@@ -1082,7 +1116,7 @@ decl_storage! {
         // config(mint_adjustment): minting::Adjustment<BalanceOf<T>, T::BlockNumber> (add serialize/deserialize derivation for type)
         build(|config: &GenesisConfig<T>| {
             // create mint
-            let mint_id = <minting::Module<T>>::add_mint(config.mint_capacity, None).expect("Failed to create a mint for the content working group");
+            let mint_id = <minting::Module<T>>::add_mint(config.mint_capacity, None, None).expect("Failed to create a mint for the content working group");
             Mint::<T>::put(mint_id);
         });
     }
@@ -1120,6 +1154,7 @@ decl_event! {
         ChannelCreationEnabledUpdated(bool),
         MintCapacityIncreased(MintId, MintBalanceOf, MintBalanceOf),
         MintCapacityDecreased(MintId, MintBalanceOf, MintBalanceOf),
+        CuratorStakeSlashed(CuratorId),
     }
 }
 
@@ -1373,6 +1408,9 @@ decl_module! {
             // Ensure human radable text is valid
             Self::ensure_opening_human_readable_text_is_valid(&human_readable_text)?;
 
+            // Ensure role slashing terms are valid
+            Self::ensure_role_slashing_terms_are_valid(&commitment.role_slashing_terms)?;
+
             // Add opening
             // NB: This call can in principle fail, because the staking policies
             // may not respect the minimum currency requirement.
@@ -1579,6 +1617,8 @@ decl_module! {
                         checked_policy.amount_per_payout,
                         checked_policy.next_payment_at_block,
                         checked_policy.payout_interval,
+                        None,
+                        None,
                     ).expect("Failed to create reward relationship!");
 
                     Some(relationship_id)
@@ -1650,12 +1690,57 @@ decl_module! {
         }
         */
 
-        /*
-        /// ...
-        pub fn slash_curator(_origin) {
+        /// Lead can slash the active curator role stake. Slashing is limited by the role slashing
+        /// terms committed by the curator opening: unslashable roles cannot be slashed, the number
+        /// of slashes is limited and the slashing balance is capped by the max percentage
+        /// of the current stake.
+        pub fn slash_curator(
+            origin,
+            curator_id: CuratorId<T>,
+            balance: BalanceOf<T>
+        ) {
+            // Ensure lead is set and is origin signer
+            Self::ensure_origin_is_set_lead(origin)?;
+
+            // Ensuring curator actually exists and is active
+            let curator = Self::ensure_active_curator_exists(&curator_id)?;
+
+            ensure!(balance != Zero::zero(), MSG_SLASH_CURATOR_BALANCE_CANNOT_BE_ZERO);
+
+            let stake_profile = curator.role_stake_profile.ok_or(MSG_SLASH_CURATOR_NO_ROLE_STAKE)?;
+
+            // Slashing terms are committed by the opening the curator was inducted through
+            let (_, _, curator_opening) = Self::ensure_curator_application_exists(
+                &curator.induction.curator_application_id
+            )?;
+
+            let slashing_balance = Self::ensure_slashing_terms_allow_slashing(
+                &curator_opening.policy_commitment.role_slashing_terms,
+                &curator_id,
+                &stake_profile.stake_id,
+                balance
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
 
+            // NB: Combined ensure check and mutation in stake module
+            ensure_on_wrapped_error!(
+                stake::Module::<T>::slash_immediate(
+                    &stake_profile.stake_id,
+                    slashing_balance,
+                    false
+                )
+            )?;
+
+            CuratorSlashCountById::<T>::mutate(curator_id, |slash_count| {
+                *slash_count = slash_count.saturating_add(1)
+            });
+
+            // Trigger event
+            Self::deposit_event(RawEvent::CuratorStakeSlashed(curator_id));
         }
-        */
 
         pub fn withdraw_curator_application(
             origin,
@@ -1745,6 +1830,12 @@ decl_module! {
                 MSG_ORIGIN_IS_NIETHER_MEMBER_CONTROLLER_OR_ROOT
             );
 
+            // Ensure the member is not suspended
+            ensure!(
+                !members::Module::<T>::is_member_suspended(&member_id),
+                MSG_SUSPENDED_MEMBER_CANNOT_APPLY
+            );
+
             // Ensure curator opening exists
             let (curator_opening, _opening) = Self::ensure_curator_opening_exists(&curator_opening_id)?;
 
@@ -1870,7 +1961,9 @@ decl_module! {
                 Some(new_reward_account.clone()), // new_account
                 None, // new_payout
                 None, //new_next_payment_at
-                None //new_payout_interval
+                None, //new_payout_interval
+                None, //new_ends_at_block
+                None //new_max_total_payout
             )
             .expect("Must be set, since curator has recurring reward");
 
@@ -2291,6 +2384,48 @@ impl<T: Trait> Module<T> {
         )
     }
 
+    /// Ensures the curator role stake can be slashed according to the role slashing terms.
+    /// Returns the slashing balance capped by the max percentage of the current stake.
+    fn ensure_slashing_terms_allow_slashing(
+        slashing_terms: &SlashingTerms,
+        curator_id: &CuratorId<T>,
+        stake_id: &StakeId<T>,
+        balance: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, &'static str> {
+        let slashable_terms = match slashing_terms {
+            SlashingTerms::Unslashable => return Err(MSG_SLASH_CURATOR_ROLE_IS_UNSLASHABLE),
+            SlashingTerms::Slashable(slashable_terms) => slashable_terms,
+        };
+
+        ensure!(
+            Self::curator_slash_count_by_id(curator_id) < slashable_terms.max_count,
+            MSG_SLASH_CURATOR_MAX_SLASH_COUNT_REACHED
+        );
+
+        let staked_amount = match stake::Module::<T>::stakes(stake_id).staking_status {
+            stake::StakingStatus::Staked(staked_state) => staked_state.staked_amount,
+            _ => Zero::zero(),
+        };
+
+        let max_percent_pts =
+            BalanceOf::<T>::from(u32::from(slashable_terms.max_percent_pts_per_time));
+        let max_slashing_balance =
+            staked_amount.saturating_mul(max_percent_pts) / BalanceOf::<T>::from(100u32);
+
+        Ok(balance.min(max_slashing_balance))
+    }
+
+    fn ensure_role_slashing_terms_are_valid(slashing_terms: &SlashingTerms) -> dispatch::Result {
+        if let SlashingTerms::Slashable(slashable_terms) = slashing_terms {
+            ensure!(
+                slashable_terms.max_percent_pts_per_time <= 100,
+                MSG_ADD_CURATOR_OPENING_SLASHING_MAX_PERCENT_PTS_TOO_HIGH
+            );
+        }
+
+        Ok(())
+    }
+
     fn ensure_opening_human_readable_text_is_valid(text: &[u8]) -> dispatch::Result {
         OpeningHumanReadableText::get().ensure_valid(
             text.len(),
@@ -2715,6 +2850,9 @@ impl<T: Trait> Module<T> {
 
         CuratorById::<T>::insert(curator_id, new_curator);
 
+        // Inactive curator cannot be slashed anymore
+        CuratorSlashCountById::<T>::remove(curator_id);
+
         // Unstake if directions provided
         if let Some(directions) = unstake_directions {
             // Keep track of curator unstaking
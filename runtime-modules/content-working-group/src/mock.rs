@@ -139,9 +139,14 @@ impl GovernanceCurrency for Test {
 }
 
 type TestMintId = u64;
+parameter_types! {
+    pub const MaxMintLedgerEntries: u32 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = TestMintId;
+    type MaxMintLedgerEntries = MaxMintLedgerEntries;
 }
 
 type TestRecipientId = u64;
@@ -158,6 +163,7 @@ impl stake::Trait for Test {
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type StakingBackend = stake::PoolAccountBackend;
     type StakeId = TestStakeId;
     type SlashId = TestSlashId;
 }
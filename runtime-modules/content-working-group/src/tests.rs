@@ -7,7 +7,7 @@ use hiring;
 use rstd::collections::btree_map::BTreeMap;
 use rstd::collections::btree_set::BTreeSet;
 use sr_primitives::traits::One;
-use srml_support::{assert_err, assert_ok, StorageLinkedMap, StorageValue};
+use srml_support::{assert_err, assert_ok, StorageLinkedMap, StorageMap, StorageValue};
 
 use common::constraints::InputValidationLengthConstraint;
 
@@ -1347,6 +1347,231 @@ fn unstaked_curator_success() {
         });
 }
 
+struct SlashCuratorFixture {
+    pub origin: Origin,
+    pub curator_id: CuratorId<Test>,
+    pub balance: BalanceOf<Test>,
+}
+
+impl SlashCuratorFixture {
+    fn call(&self) -> Result<(), &'static str> {
+        ContentWorkingGroup::slash_curator(self.origin.clone(), self.curator_id, self.balance)
+    }
+
+    pub fn call_and_assert_success(&self, expected_slashed_balance: BalanceOf<Test>) {
+        let curator_role_stake_id = CuratorById::<Test>::get(self.curator_id)
+            .role_stake_profile
+            .unwrap()
+            .stake_id;
+        let original_staked_amount = get_staked_amount(curator_role_stake_id);
+        let original_slash_count = CuratorSlashCountById::<Test>::get(self.curator_id);
+
+        let call_result = self.call();
+
+        assert_eq!(call_result, Ok(()));
+
+        assert_eq!(
+            get_staked_amount(curator_role_stake_id),
+            original_staked_amount - expected_slashed_balance
+        );
+
+        assert_eq!(
+            CuratorSlashCountById::<Test>::get(self.curator_id),
+            original_slash_count + 1
+        );
+
+        assert_eq!(
+            get_last_event_or_panic(),
+            crate::RawEvent::CuratorStakeSlashed(self.curator_id)
+        );
+    }
+
+    pub fn call_and_assert_failed_result(&self, error_message: &'static str) {
+        let original_slash_count = CuratorSlashCountById::<Test>::get(self.curator_id);
+
+        let call_result = self.call();
+
+        assert_eq!(call_result, Err(error_message));
+
+        assert_eq!(
+            CuratorSlashCountById::<Test>::get(self.curator_id),
+            original_slash_count
+        );
+    }
+}
+
+fn get_staked_amount(stake_id: StakeId<Test>) -> BalanceOf<Test> {
+    if let stake::StakingStatus::Staked(staked_state) =
+        stake::Module::<Test>::stakes(stake_id).staking_status
+    {
+        staked_state.staked_amount
+    } else {
+        panic!("Not staked.")
+    }
+}
+
+fn set_curator_opening_slashing_terms(
+    curator_opening_id: CuratorOpeningId<Test>,
+    role_slashing_terms: SlashingTerms,
+) {
+    CuratorOpeningById::<Test>::mutate(curator_opening_id, |curator_opening| {
+        curator_opening.policy_commitment.role_slashing_terms = role_slashing_terms;
+    });
+}
+
+fn setup_lead_and_hire_slashable_curator(
+    max_count: u16,
+    max_percent_pts_per_time: u16,
+) -> SetupLeadAndHireCuratorResult {
+    let result = setup_lead_and_hire_curator();
+
+    set_curator_opening_slashing_terms(
+        result
+            .setup_and_fill_opening_result
+            .setup_opening_in_review
+            .normal_opening_constructed
+            .curator_opening_id,
+        SlashingTerms::Slashable(SlashableTerms {
+            max_count,
+            max_percent_pts_per_time,
+        }),
+    );
+
+    result
+}
+
+#[test]
+fn slash_curator_success() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_slashable_curator(2, 10);
+
+            SlashCuratorFixture {
+                origin: Origin::signed(LEAD_ROLE_ACCOUNT),
+                curator_id: result.curator_id(),
+                balance: 1000,
+            }
+            .call_and_assert_success(1000);
+        });
+}
+
+#[test]
+fn slash_curator_is_capped_by_max_percent_of_stake() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_slashable_curator(2, 10);
+
+            let curator_role_stake_id = CuratorById::<Test>::get(result.curator_id())
+                .role_stake_profile
+                .unwrap()
+                .stake_id;
+            let max_slashed_balance = get_staked_amount(curator_role_stake_id) / 10;
+
+            SlashCuratorFixture {
+                origin: Origin::signed(LEAD_ROLE_ACCOUNT),
+                curator_id: result.curator_id(),
+                balance: max_slashed_balance * 5,
+            }
+            .call_and_assert_success(max_slashed_balance);
+        });
+}
+
+#[test]
+fn slash_curator_fails_with_unslashable_role() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            // Baseline opening policy commits to the unslashable role.
+            let result = setup_lead_and_hire_curator();
+
+            SlashCuratorFixture {
+                origin: Origin::signed(LEAD_ROLE_ACCOUNT),
+                curator_id: result.curator_id(),
+                balance: 1000,
+            }
+            .call_and_assert_failed_result(MSG_SLASH_CURATOR_ROLE_IS_UNSLASHABLE);
+        });
+}
+
+#[test]
+fn slash_curator_fails_with_max_slash_count_reached() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_slashable_curator(1, 10);
+
+            let fixture = SlashCuratorFixture {
+                origin: Origin::signed(LEAD_ROLE_ACCOUNT),
+                curator_id: result.curator_id(),
+                balance: 1000,
+            };
+
+            fixture.call_and_assert_success(1000);
+            fixture.call_and_assert_failed_result(MSG_SLASH_CURATOR_MAX_SLASH_COUNT_REACHED);
+        });
+}
+
+#[test]
+fn add_curator_opening_fails_with_too_high_slashing_max_percent_pts() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            add_member_and_set_as_lead();
+
+            let policy_commitment = OpeningPolicyCommitment {
+                role_slashing_terms: SlashingTerms::Slashable(SlashableTerms {
+                    max_count: 1,
+                    max_percent_pts_per_time: 101,
+                }),
+                ..get_baseline_opening_policy()
+            };
+
+            assert_eq!(
+                ContentWorkingGroup::add_curator_opening(
+                    Origin::signed(LEAD_ROLE_ACCOUNT),
+                    hiring::ActivateOpeningAt::ExactBlock(34),
+                    policy_commitment,
+                    generate_valid_length_buffer(&OpeningHumanReadableText::get())
+                ),
+                Err(MSG_ADD_CURATOR_OPENING_SLASHING_MAX_PERCENT_PTS_TOO_HIGH)
+            );
+        });
+}
+
+#[test]
+fn slash_curator_fails_with_zero_balance() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_slashable_curator(1, 10);
+
+            SlashCuratorFixture {
+                origin: Origin::signed(LEAD_ROLE_ACCOUNT),
+                curator_id: result.curator_id(),
+                balance: 0,
+            }
+            .call_and_assert_failed_result(MSG_SLASH_CURATOR_BALANCE_CANNOT_BE_ZERO);
+        });
+}
+
+#[test]
+fn slash_curator_fails_with_not_lead_origin() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let result = setup_lead_and_hire_slashable_curator(1, 10);
+
+            SlashCuratorFixture {
+                origin: Origin::signed(result.curator_params().curator_applicant_role_account),
+                curator_id: result.curator_id(),
+                balance: 1000,
+            }
+            .call_and_assert_failed_result(MSG_ORIGIN_IS_NOT_LEAD);
+        });
+}
+
 #[test]
 fn account_can_act_as_principal_success() {}
 
@@ -1376,6 +1601,7 @@ pub fn get_baseline_opening_policy(
     OpeningPolicyCommitment {
         application_rationing_policy: Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 5,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         }),
         max_review_period_length: 100,
         application_staking_policy: Some(hiring::StakingPolicy {
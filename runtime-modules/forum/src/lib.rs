@@ -32,6 +32,7 @@ const MAX_CATEGORY_DEPTH: u16 = 3;
 /// Error messages for dispatchables
 const ERROR_FORUM_SUDO_NOT_SET: &str = "Forum sudo not set.";
 const ERROR_ORIGIN_NOT_FORUM_SUDO: &str = "Origin not forum sudo.";
+const ERROR_ORIGIN_NOT_FORUM_MODERATOR: &str = "Origin not forum moderator.";
 const ERROR_CATEGORY_TITLE_TOO_SHORT: &str = "Category title too short.";
 const ERROR_CATEGORY_TITLE_TOO_LONG: &str = "Category title too long.";
 const ERROR_CATEGORY_DESCRIPTION_TOO_SHORT: &str = "Category description too long.";
@@ -76,6 +77,17 @@ pub trait ForumUserRegistry<AccountId> {
     fn get_forum_user(id: &AccountId) -> Option<ForumUser<AccountId>>;
 }
 
+/// Validates accounts allowed to moderate threads and posts in addition to the forum sudo.
+pub trait ForumModeratorValidator<AccountId> {
+    fn is_forum_moderator(account_id: &AccountId) -> bool;
+}
+
+impl<AccountId> ForumModeratorValidator<AccountId> for () {
+    fn is_forum_moderator(_account_id: &AccountId) -> bool {
+        false
+    }
+}
+
 /// Represents a moderation outcome applied to a post or a thread.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
@@ -83,7 +95,7 @@ pub struct ModerationAction<BlockNumber, Moment, AccountId> {
     /// When action occured.
     moderated_at: BlockAndTime<BlockNumber, Moment>,
 
-    /// Account forum sudo which acted.
+    /// Account of the forum sudo or the forum moderator which acted.
     moderator_id: AccountId,
 
     /// Moderation rationale
@@ -261,6 +273,9 @@ pub trait Trait: system::Trait + timestamp::Trait + Sized {
 
     type MembershipRegistry: ForumUserRegistry<Self::AccountId>;
 
+    /// Validates forum moderators (e.g. the forum working group lead and workers).
+    type ModeratorValidator: ForumModeratorValidator<Self::AccountId>;
+
     /// Thread Id type
     type ThreadId: Parameter
         + Member
@@ -598,8 +613,8 @@ decl_module! {
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Signed by forum SUDO or forum moderator
+            Self::ensure_is_forum_moderator(&who)?;
 
             // Get thread
             let mut thread = Self::ensure_thread_exists(thread_id)?;
@@ -733,8 +748,8 @@ decl_module! {
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Signed by forum SUDO or forum moderator
+            Self::ensure_is_forum_moderator(&who)?;
 
             // Make sure post exists and is mutable
             let post = Self::ensure_post_is_mutable(post_id)?;
@@ -889,6 +904,20 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    fn ensure_is_forum_moderator(account_id: &T::AccountId) -> dispatch::Result {
+        if T::ModeratorValidator::is_forum_moderator(account_id) {
+            return Ok(());
+        }
+
+        Self::ensure_is_forum_sudo(account_id).map_err(|err| {
+            if err == ERROR_ORIGIN_NOT_FORUM_SUDO {
+                ERROR_ORIGIN_NOT_FORUM_MODERATOR
+            } else {
+                err
+            }
+        })
+    }
+
     fn ensure_is_forum_member(
         account_id: &T::AccountId,
     ) -> Result<ForumUser<T::AccountId>, &'static str> {
@@ -98,9 +98,18 @@ impl timestamp::Trait for Runtime {
     type MinimumPeriod = MinimumPeriod;
 }
 
+pub struct TestForumModeratorValidator;
+
+impl ForumModeratorValidator<u64> for TestForumModeratorValidator {
+    fn is_forum_moderator(account_id: &u64) -> bool {
+        *account_id == FORUM_MODERATOR_ACCOUNT_ID
+    }
+}
+
 impl Trait for Runtime {
     type Event = ();
     type MembershipRegistry = registry::TestMembershipRegistryModule;
+    type ModeratorValidator = TestForumModeratorValidator;
     type ThreadId = u64;
     type PostId = u64;
 }
@@ -124,6 +133,10 @@ pub const NOT_FORUM_SUDO_ORIGIN: OriginType = OriginType::Signed(111);
 
 pub const NOT_MEMBER_ORIGIN: OriginType = OriginType::Signed(222);
 
+pub const FORUM_MODERATOR_ACCOUNT_ID: u64 = 666;
+
+pub const FORUM_MODERATOR_ORIGIN: OriginType = OriginType::Signed(FORUM_MODERATOR_ACCOUNT_ID);
+
 pub const INVLAID_CATEGORY_ID: CategoryId = 333;
 
 pub const INVLAID_THREAD_ID: RuntimeThreadId = 444;
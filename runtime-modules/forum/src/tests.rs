@@ -557,6 +557,53 @@ fn cannot_moderate_already_moderated_post() {
     });
 }
 
+#[test]
+fn forum_moderator_can_moderate_thread() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(origin);
+        assert_eq!(
+            moderate_thread(FORUM_MODERATOR_ORIGIN, thread_id, good_rationale()),
+            Ok(())
+        );
+        assert_eq!(
+            TestForumModule::thread_by_id(thread_id)
+                .moderation
+                .map(|action| action.moderator_id),
+            Some(FORUM_MODERATOR_ACCOUNT_ID)
+        );
+    });
+}
+
+#[test]
+fn forum_moderator_can_moderate_post() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin);
+        assert_eq!(
+            moderate_post(FORUM_MODERATOR_ORIGIN, post_id, good_rationale()),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn forum_moderator_cannot_create_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_create_category(
+            FORUM_MODERATOR_ORIGIN,
+            None,
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO),
+        );
+    });
+}
+
 // Not a forum sudo:
 // -----------------------------------------------------------------------------
 
@@ -617,7 +664,7 @@ fn not_forum_sudo_cannot_moderate_thread() {
         let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
         assert_eq!(
             moderate_thread(NOT_FORUM_SUDO_ORIGIN, thread_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            Err(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
         );
     });
 }
@@ -631,7 +678,7 @@ fn not_forum_sudo_cannot_moderate_post() {
         let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin.clone());
         assert_eq!(
             moderate_post(NOT_FORUM_SUDO_ORIGIN, post_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            Err(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
         );
     });
 }
@@ -98,7 +98,7 @@ impl<T: Trait> Module<T> {
     pub fn create_new_council_mint(
         capacity: minting::BalanceOf<T>,
     ) -> Result<T::MintId, &'static str> {
-        let mint_id = <minting::Module<T>>::add_mint(capacity, None)?;
+        let mint_id = <minting::Module<T>>::add_mint(capacity, None, None)?;
         CouncilMint::<T>::put(mint_id);
         Ok(mint_id)
     }
@@ -119,6 +119,8 @@ impl<T: Trait> Module<T> {
             Self::amount_per_payout(),
             next_payout_at,
             Self::payout_interval(),
+            None,
+            None,
         ) {
             RewardRelationships::<T>::insert(destination, relationship_id);
         } else {
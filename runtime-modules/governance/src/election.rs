@@ -213,7 +213,7 @@ impl<T: Trait> Module<T> {
 
     fn can_participate(sender: &T::AccountId) -> bool {
         !<T as GovernanceCurrency>::Currency::free_balance(sender).is_zero()
-            && <membership::members::Module<T>>::is_member_account(sender)
+            && <membership::members::Module<T>>::is_unsuspended_member_account(sender)
     }
 
     // PUBLIC IMMUTABLES
@@ -70,9 +70,14 @@ impl membership::members::Trait for Test {
     type ActorId = u32;
     type InitialMembersBalance = InitialMembersBalance;
 }
+parameter_types! {
+    pub const MaxMintLedgerEntries: u32 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type MaxMintLedgerEntries = MaxMintLedgerEntries;
 }
 impl recurringrewards::Trait for Test {
     type PayoutStatusHandler = ();
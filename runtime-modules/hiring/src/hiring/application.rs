@@ -152,6 +152,19 @@ pub enum ApplicationDeactivationCause {
 
     /// Opening was filled
     OpeningFilled,
+
+    /// Application was declined during the review period
+    Declined,
+}
+
+/// Review of an application made during the review period of the opening.
+#[derive(Encode, Decode, Default, Debug, Eq, PartialEq, Clone)]
+pub struct ApplicationReview<Hash> {
+    /// Application was shortlisted for the final decision.
+    pub shortlisted: bool,
+
+    /// Hash of the private score or note attached by the reviewer.
+    pub note_hash: Option<Hash>,
 }
 
 /// OpeningStage must be default constructible because it indirectly is a value in a storage map.
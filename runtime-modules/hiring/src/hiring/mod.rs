@@ -83,6 +83,26 @@ pub enum DeactivateApplicationError {
     RedundantUnstakingPeriodProvided(StakePurpose),
 }
 
+/// Error of the review API methods: shortlist_application(), decline_application()
+/// and set_application_review_note().
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum ReviewApplicationError {
+    /// Application does not exist
+    ApplicationDoesNotExist,
+
+    /// Application is not in active stage
+    ApplicationNotActive,
+
+    /// Opening is not in review period stage
+    OpeningNotInReviewPeriodStage,
+
+    /// Provided unstaking period is too short
+    UnstakingPeriodTooShort(StakePurpose),
+
+    /// Provided redundant unstaking period
+    RedundantUnstakingPeriodProvided(StakePurpose),
+}
+
 /// Error of the cancel_opening() API method.
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub enum CancelOpeningError {
@@ -401,6 +401,36 @@ pub enum ActivateOpeningAt<BlockNumber> {
 pub struct ApplicationRationingPolicy {
     /// The maximum number of applications that can be on the list at any time.
     pub max_active_applicants: u32,
-    // How applicants will be ranked, in order to respect the maximum simultaneous application limit
-    //pub applicant_ranking: ApplicationRankingPolicy
+
+    /// How applicants will be ranked, in order to respect the maximum simultaneous application limit
+    pub applicant_ranking: ApplicationRankingPolicy,
+}
+
+/// How to rank the applicants when the maximum number of active applications is reached.
+/// The application with the lowest rank is crowded out by the new application with a higher rank.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone)]
+pub enum ApplicationRankingPolicy {
+    /// Applications are ranked by their total stake.
+    Stake,
+
+    /// Applications are ranked by their arrival: new applications cannot crowd out
+    /// the existing ones.
+    FirstComeFirstServed,
+
+    /// Applications are ranked by their total stake weighted by the tenure of the application,
+    /// i.e. the number of blocks since the application was added.
+    StakeWeightedWithTenure {
+        /// Stake weight increase (in per mille of the total stake) for each block of tenure.
+        tenure_weight_per_block: u32,
+
+        /// Maximum number of tenure blocks taken into account.
+        max_tenure_blocks: u32,
+    },
+}
+
+impl Default for ApplicationRankingPolicy {
+    fn default() -> Self {
+        ApplicationRankingPolicy::Stake
+    }
 }
@@ -5,11 +5,15 @@
 //! - ensure_can_add_application
 //! - add_application
 //! - deactivate_application
+//! - shortlist_application
+//! - decline_application
+//! - set_application_review_note
 //! - cancel_opening
 //! - fill_opening
 //! - begin_review
 //! - begin_acception_application
 //! - unstaked
+//! - schedule_existing_opening_transitions (runtime migration)
 //!
 //! Dependency: Joystream stake module
 
@@ -29,7 +33,9 @@ use stake::{InitiateUnstakingError, Stake, StakeActionError, StakingError, Trait
 use codec::Codec;
 
 use runtime_primitives::traits::Zero;
-use runtime_primitives::traits::{MaybeSerialize, Member, One, SimpleArithmetic};
+use runtime_primitives::traits::{
+    MaybeSerialize, Member, One, SaturatedConversion, Saturating, SimpleArithmetic,
+};
 
 use srml_support::traits::Currency;
 use srml_support::{decl_module, decl_storage, ensure, Parameter};
@@ -98,6 +104,15 @@ decl_storage! {
 
         /// Internal purpose of given stake, i.e. fro what application, and whether for the role or for the application.
         pub ApplicationIdByStakingId get(stake_purpose_by_staking_id): linked_map T::StakeId => T::ApplicationId;
+
+        /// Reviews of the applications made during the review period.
+        pub ApplicationReviewById get(application_review_by_id): map T::ApplicationId => ApplicationReview<T::Hash>;
+
+        /// Openings waiting to begin, by the block they begin accepting applications at.
+        pub OpeningIdsByActivationBlock get(opening_ids_by_activation_block): map T::BlockNumber => Vec<T::OpeningId>;
+
+        /// Openings in the review period, by the block their review period expires at.
+        pub OpeningIdsByReviewPeriodEndBlock get(opening_ids_by_review_period_end_block): map T::BlockNumber => Vec<T::OpeningId>;
     }
 }
 
@@ -208,6 +223,9 @@ impl<T: Trait> Module<T> {
         // Get Id for new opening
         let new_opening_id = <NextOpeningId<T>>::get();
 
+        // Schedule opening activation
+        Self::schedule_opening_transition(new_opening_id, &new_opening);
+
         // Insert opening in storage
         <OpeningById<T>>::insert(new_opening_id, new_opening);
 
@@ -265,6 +283,9 @@ impl<T: Trait> Module<T> {
         // == MUTATION SAFE ==
         //
 
+        // Cancelled opening has no further transitions
+        Self::unschedule_opening_transition(opening_id, &opening);
+
         // Create and store new cancelled opening
         let new_opening = Opening {
             stage: hiring::OpeningStage::Active {
@@ -321,6 +342,9 @@ impl<T: Trait> Module<T> {
 
         let current_block_height = <system::Module<T>>::block_number();
 
+        // Opening is activated before the scheduled block
+        Self::unschedule_opening_transition(opening_id, &opening);
+
         // Update state of opening
         let new_opening = opening.clone_with_new_active_opening_stage(
             hiring::ActiveOpeningStage::AcceptingApplications {
@@ -365,6 +389,9 @@ impl<T: Trait> Module<T> {
                 started_review_period_at_block: current_block_height,
             });
 
+        // Schedule review period expiration
+        Self::schedule_opening_transition(opening_id, &new_opening);
+
         // Update to new opening
         <OpeningById<T>>::insert(opening_id, new_opening);
 
@@ -496,6 +523,9 @@ impl<T: Trait> Module<T> {
             hiring::ApplicationDeactivationCause::NotHired,
         );
 
+        // Filled opening has no further transitions
+        Self::unschedule_opening_transition(opening_id, &opening);
+
         // Grab current block height
         let current_block_height = <system::Module<T>>::block_number();
         // Get opening with updated counters
@@ -590,6 +620,40 @@ impl<T: Trait> Module<T> {
         opt_role_stake_imbalance: Option<NegativeImbalance<T>>,
         opt_application_stake_imbalance: Option<NegativeImbalance<T>>,
         human_readable_text: Vec<u8>,
+    ) -> Result<ApplicationAdded<T::ApplicationId>, AddApplicationError> {
+        Self::add_application_with_opt_sponsor(
+            opening_id,
+            opt_role_stake_imbalance,
+            opt_application_stake_imbalance,
+            human_readable_text,
+            None,
+        )
+    }
+
+    /// Same as add_application(), but the stakes are recorded as funded by the sponsor
+    /// account, which gets the staked funds back on unstaking.
+    pub fn add_sponsored_application(
+        opening_id: T::OpeningId,
+        opt_role_stake_imbalance: Option<NegativeImbalance<T>>,
+        opt_application_stake_imbalance: Option<NegativeImbalance<T>>,
+        human_readable_text: Vec<u8>,
+        sponsor: T::AccountId,
+    ) -> Result<ApplicationAdded<T::ApplicationId>, AddApplicationError> {
+        Self::add_application_with_opt_sponsor(
+            opening_id,
+            opt_role_stake_imbalance,
+            opt_application_stake_imbalance,
+            human_readable_text,
+            Some(sponsor),
+        )
+    }
+
+    fn add_application_with_opt_sponsor(
+        opening_id: T::OpeningId,
+        opt_role_stake_imbalance: Option<NegativeImbalance<T>>,
+        opt_application_stake_imbalance: Option<NegativeImbalance<T>>,
+        human_readable_text: Vec<u8>,
+        opt_sponsor: Option<T::AccountId>,
     ) -> Result<ApplicationAdded<T::ApplicationId>, AddApplicationError> {
         let opt_role_stake_balance = Self::create_stake_balance(&opt_role_stake_imbalance);
         let opt_application_stake_balance =
@@ -645,11 +709,15 @@ impl<T: Trait> Module<T> {
         let new_application_id = <NextApplicationId<T>>::get();
 
         // Possibly initiate staking
-        let active_role_staking_id =
-            Self::infallible_opt_stake_initiation(opt_role_stake_imbalance, &new_application_id);
+        let active_role_staking_id = Self::infallible_opt_stake_initiation(
+            opt_role_stake_imbalance,
+            &new_application_id,
+            &opt_sponsor,
+        );
         let active_application_staking_id = Self::infallible_opt_stake_initiation(
             opt_application_stake_imbalance,
             &new_application_id,
+            &opt_sponsor,
         );
 
         // Grab current block height
@@ -766,6 +834,88 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Shortlist an active application of the opening in the review period.
+    pub fn shortlist_application(
+        application_id: T::ApplicationId,
+    ) -> Result<(), ReviewApplicationError> {
+        Self::ensure_application_is_under_review(application_id)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        <ApplicationReviewById<T>>::mutate(application_id, |review| {
+            review.shortlisted = true;
+        });
+
+        Ok(())
+    }
+
+    /// Decline an active application of the opening in the review period
+    /// before the opening is filled. Stakes are released with provided unstaking periods.
+    pub fn decline_application(
+        application_id: T::ApplicationId,
+        application_stake_unstaking_period: Option<T::BlockNumber>,
+        role_stake_unstaking_period: Option<T::BlockNumber>,
+    ) -> Result<(), ReviewApplicationError> {
+        let (application, opening) = Self::ensure_application_is_under_review(application_id)?;
+
+        // Ensure unstaking periods are OK.
+        ensure_opt_unstaking_period_is_ok!(
+            application_stake_unstaking_period,
+            opening.application_staking_policy,
+            ReviewApplicationError::UnstakingPeriodTooShort(StakePurpose::Application),
+            ReviewApplicationError::RedundantUnstakingPeriodProvided(StakePurpose::Application)
+        )?;
+
+        ensure_opt_unstaking_period_is_ok!(
+            role_stake_unstaking_period,
+            opening.role_staking_policy,
+            ReviewApplicationError::UnstakingPeriodTooShort(StakePurpose::Role),
+            ReviewApplicationError::RedundantUnstakingPeriodProvided(StakePurpose::Role)
+        )?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        <ApplicationReviewById<T>>::mutate(application_id, |review| {
+            review.shortlisted = false;
+        });
+
+        // Deactive application
+        let result = Self::try_to_initiate_application_deactivation(
+            &application,
+            application_id,
+            application_stake_unstaking_period,
+            role_stake_unstaking_period,
+            hiring::ApplicationDeactivationCause::Declined,
+        );
+
+        assert_ne!(result, ApplicationDeactivationInitiationResult::Ignored);
+
+        Ok(())
+    }
+
+    /// Attach the hash of a private score or note to an active application
+    /// of the opening in the review period.
+    pub fn set_application_review_note(
+        application_id: T::ApplicationId,
+        note_hash: T::Hash,
+    ) -> Result<(), ReviewApplicationError> {
+        Self::ensure_application_is_under_review(application_id)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        <ApplicationReviewById<T>>::mutate(application_id, |review| {
+            review.note_hash = Some(note_hash);
+        });
+
+        Ok(())
+    }
+
     /// The stake, with the given id, was unstaked.
     pub fn unstaked(stake_id: T::StakeId) -> UnstakedResult {
         // Ignore unstaked
@@ -996,7 +1146,8 @@ impl<T: Trait> Module<T> {
             .collect::<BTreeMap<_, _>>()
     }
 
-    // Compute iterator of openings waiting to begin
+    // Compute iterator of openings waiting to begin.
+    // Takes the openings scheduled for the provided block out of the schedule.
     fn openings_waiting_to_begin_iterator(
         now: T::BlockNumber,
     ) -> impl Iterator<
@@ -1005,20 +1156,25 @@ impl<T: Trait> Module<T> {
             Opening<BalanceOf<T>, T::BlockNumber, T::ApplicationId>,
         ),
     > {
-        <OpeningById<T>>::enumerate().filter_map(move |(opening_id, opening)| {
-            if let hiring::OpeningStage::WaitingToBegin { begins_at_block } = opening.stage {
-                if begins_at_block == now {
-                    Some((opening_id, opening))
+        <OpeningIdsByActivationBlock<T>>::take(now)
+            .into_iter()
+            .filter_map(move |opening_id| {
+                let opening = <OpeningById<T>>::get(opening_id);
+
+                if let hiring::OpeningStage::WaitingToBegin { begins_at_block } = opening.stage {
+                    if begins_at_block == now {
+                        Some((opening_id, opening))
+                    } else {
+                        None
+                    }
                 } else {
                     None
                 }
-            } else {
-                None
-            }
-        })
+            })
     }
 
-    // Compute iterator of openings in expired review period
+    // Compute iterator of openings in expired review period.
+    // Takes the openings scheduled for the provided block out of the schedule.
     fn openings_expired_review_period_iterator(
         now: T::BlockNumber,
     ) -> impl Iterator<
@@ -1028,38 +1184,136 @@ impl<T: Trait> Module<T> {
             (BTreeSet<T::ApplicationId>, T::BlockNumber, T::BlockNumber),
         ),
     > {
-        <OpeningById<T>>::enumerate().filter_map(move |(opening_id, opening)| {
-            if let hiring::OpeningStage::Active {
-                ref stage,
-                ref applications_added,
-                ..
-            } = opening.stage
-            {
-                if let hiring::ActiveOpeningStage::ReviewPeriod {
-                    ref started_accepting_applicants_at_block,
-                    ref started_review_period_at_block,
-                } = stage
+        <OpeningIdsByReviewPeriodEndBlock<T>>::take(now)
+            .into_iter()
+            .filter_map(move |opening_id| {
+                let opening = <OpeningById<T>>::get(opening_id);
+
+                if let hiring::OpeningStage::Active {
+                    ref stage,
+                    ref applications_added,
+                    ..
+                } = opening.stage
                 {
-                    if now == opening.max_review_period_length + *started_review_period_at_block {
-                        Some((
-                            opening_id,
-                            opening.clone(),
-                            (
-                                applications_added.clone(),
-                                *started_accepting_applicants_at_block,
-                                *started_review_period_at_block,
-                            ),
-                        ))
+                    if let hiring::ActiveOpeningStage::ReviewPeriod {
+                        ref started_accepting_applicants_at_block,
+                        ref started_review_period_at_block,
+                    } = stage
+                    {
+                        if now == opening.max_review_period_length + *started_review_period_at_block
+                        {
+                            Some((
+                                opening_id,
+                                opening.clone(),
+                                (
+                                    applications_added.clone(),
+                                    *started_accepting_applicants_at_block,
+                                    *started_review_period_at_block,
+                                ),
+                            ))
+                        } else {
+                            None
+                        }
                     } else {
                         None
                     }
                 } else {
                     None
                 }
+            })
+    }
+}
+
+// Opening transitions scheduling
+impl<T: Trait> Module<T> {
+    /// Schedules the autonomous transitions of all existing openings: begin of the
+    /// accepting applications and expiration of the review period.
+    /// Transitions in the past are not scheduled. Should be called on the migration
+    /// to the new runtime version.
+    pub fn schedule_existing_opening_transitions() {
+        let current_block_height = <system::Module<T>>::block_number();
+
+        for (opening_id, opening) in <OpeningById<T>>::enumerate() {
+            if let Some(transition_block) = Self::opening_transition_block(&opening) {
+                if transition_block >= current_block_height {
+                    Self::schedule_opening_transition(opening_id, &opening);
+                }
+            }
+        }
+    }
+
+    // Returns the block of the next autonomous transition of the opening, if any.
+    fn opening_transition_block(
+        opening: &Opening<BalanceOf<T>, T::BlockNumber, T::ApplicationId>,
+    ) -> Option<T::BlockNumber> {
+        match opening.stage {
+            hiring::OpeningStage::WaitingToBegin { begins_at_block } => Some(begins_at_block),
+            hiring::OpeningStage::Active {
+                stage:
+                    hiring::ActiveOpeningStage::ReviewPeriod {
+                        started_review_period_at_block,
+                        ..
+                    },
+                ..
+            } => Some(opening.max_review_period_length + started_review_period_at_block),
+            _ => None,
+        }
+    }
+
+    // Adds the opening to the schedule of its next autonomous transition.
+    fn schedule_opening_transition(
+        opening_id: T::OpeningId,
+        opening: &Opening<BalanceOf<T>, T::BlockNumber, T::ApplicationId>,
+    ) {
+        let transition_block = if let Some(block) = Self::opening_transition_block(opening) {
+            block
+        } else {
+            return;
+        };
+
+        let schedule_opening = |opening_ids: &mut Vec<T::OpeningId>| {
+            if !opening_ids.contains(&opening_id) {
+                opening_ids.push(opening_id);
+            }
+        };
+
+        if let hiring::OpeningStage::WaitingToBegin { .. } = opening.stage {
+            <OpeningIdsByActivationBlock<T>>::mutate(transition_block, schedule_opening);
+        } else {
+            <OpeningIdsByReviewPeriodEndBlock<T>>::mutate(transition_block, schedule_opening);
+        }
+    }
+
+    // Removes the opening from the schedule of its next autonomous transition.
+    fn unschedule_opening_transition(
+        opening_id: T::OpeningId,
+        opening: &Opening<BalanceOf<T>, T::BlockNumber, T::ApplicationId>,
+    ) {
+        let transition_block = if let Some(block) = Self::opening_transition_block(opening) {
+            block
+        } else {
+            return;
+        };
+
+        if let hiring::OpeningStage::WaitingToBegin { .. } = opening.stage {
+            let mut opening_ids = <OpeningIdsByActivationBlock<T>>::get(transition_block);
+            opening_ids.retain(|id| *id != opening_id);
+
+            if opening_ids.is_empty() {
+                <OpeningIdsByActivationBlock<T>>::remove(transition_block);
             } else {
-                None
+                <OpeningIdsByActivationBlock<T>>::insert(transition_block, opening_ids);
             }
-        })
+        } else {
+            let mut opening_ids = <OpeningIdsByReviewPeriodEndBlock<T>>::get(transition_block);
+            opening_ids.retain(|id| *id != opening_id);
+
+            if opening_ids.is_empty() {
+                <OpeningIdsByReviewPeriodEndBlock<T>>::remove(transition_block);
+            } else {
+                <OpeningIdsByReviewPeriodEndBlock<T>>::insert(transition_block, opening_ids);
+            }
+        }
     }
 }
 
@@ -1252,16 +1506,58 @@ impl<T: Trait> Module<T> {
     }
 }
 
+// Application review
+impl<T: Trait> Module<T> {
+    // Ensures that application is active and its opening is in the review period.
+    fn ensure_application_is_under_review(
+        application_id: T::ApplicationId,
+    ) -> Result<
+        (
+            Application<T::OpeningId, T::BlockNumber, T::StakeId>,
+            Opening<BalanceOf<T>, T::BlockNumber, T::ApplicationId>,
+        ),
+        ReviewApplicationError,
+    > {
+        let (application, opening) = ensure_application_exists!(
+            T,
+            application_id,
+            ReviewApplicationError::ApplicationDoesNotExist,
+            auto_fetch_opening
+        )?;
+
+        // Application is active
+        ensure_eq!(
+            application.stage,
+            hiring::ApplicationStage::Active,
+            ReviewApplicationError::ApplicationNotActive
+        );
+
+        // Opening is in review period
+        let (active_stage, ..) = ensure_opening_is_active!(
+            opening.stage,
+            ReviewApplicationError::OpeningNotInReviewPeriodStage
+        )?;
+
+        active_stage.ensure_active_opening_is_in_review_period(
+            ReviewApplicationError::OpeningNotInReviewPeriodStage,
+        )?;
+
+        Ok((application, opening))
+    }
+}
+
 // Stake initiation
 impl<T: Trait> Module<T> {
     fn infallible_opt_stake_initiation(
         opt_imbalance: Option<NegativeImbalance<T>>,
         application_id: &T::ApplicationId,
+        opt_sponsor: &Option<T::AccountId>,
     ) -> Option<T::StakeId> {
         if let Some(imbalance) = opt_imbalance {
             Some(Self::infallible_stake_initiation_on_application(
                 imbalance,
                 application_id,
+                opt_sponsor,
             ))
         } else {
             None
@@ -1271,6 +1567,7 @@ impl<T: Trait> Module<T> {
     fn infallible_stake_initiation_on_application(
         imbalance: NegativeImbalance<T>,
         application_id: &T::ApplicationId,
+        opt_sponsor: &Option<T::AccountId>,
     ) -> T::StakeId {
         // Create stake
         let new_stake_id = T::StakeHandlerProvider::staking().create_stake();
@@ -1290,10 +1587,13 @@ impl<T: Trait> Module<T> {
         //
         // MUST work, is runtime invariant, false means code is broken.
         // But should we do panic in runtime? Is there safer way?
-        assert_eq!(
-            T::StakeHandlerProvider::staking().stake(&new_stake_id, imbalance),
-            Ok(())
-        );
+        let staking_result = if let Some(sponsor) = opt_sponsor {
+            T::StakeHandlerProvider::staking().stake_funded_by(&new_stake_id, imbalance, sponsor)
+        } else {
+            T::StakeHandlerProvider::staking().stake(&new_stake_id, imbalance)
+        };
+
+        assert_eq!(staking_result, Ok(()));
 
         new_stake_id
     }
@@ -1355,23 +1655,47 @@ impl<T: Trait> Module<T> {
             );
         }
 
+        let ranking_policy = &application_rationing_policy.applicant_ranking;
+
+        // First come applications cannot be crowded out.
+        if *ranking_policy == ApplicationRankingPolicy::FirstComeFirstServed {
+            return ApplicationWouldGetAddedEvaluation::No;
+        }
+
         // Here we try to figure out if the new application
-        // has sufficient stake to crowd out one of the already
+        // has sufficient rank to crowd out one of the already
         // active applicants.
 
-        // The total stake of new application
-        let total_stake_of_new_application = opt_role_stake_balance.unwrap_or_default()
-            + opt_application_stake_balance.unwrap_or_default();
+        let current_block_height = <system::Module<T>>::block_number();
+
+        // The rank of new application, it has no tenure yet
+        let rank_of_new_application = Self::application_rank(
+            ranking_policy,
+            opt_role_stake_balance.unwrap_or_default()
+                + opt_application_stake_balance.unwrap_or_default(),
+            current_block_height,
+            current_block_height,
+        );
 
-        // The total stake of all current active applications
+        // The lowest ranked active application
         let opt_min_item = active_applications_with_stake_iter
             .clone()
-            .min_by_key(|(_, _, total_stake)| *total_stake);
+            .map(|(application_id, application, total_stake)| {
+                let rank = Self::application_rank(
+                    ranking_policy,
+                    total_stake,
+                    application.add_to_opening_in_block,
+                    current_block_height,
+                );
+
+                (application_id, application, rank)
+            })
+            .min_by_key(|(_, _, rank)| *rank);
 
-        if let Some((application_id, _, lowest_active_total_stake)) = opt_min_item {
+        if let Some((application_id, _, lowest_active_rank)) = opt_min_item {
             // Finally we compare the two and come up with a final evaluation
-            if total_stake_of_new_application <= lowest_active_total_stake {
-                ApplicationWouldGetAddedEvaluation::No // stake too low!
+            if rank_of_new_application <= lowest_active_rank {
+                ApplicationWouldGetAddedEvaluation::No // rank too low!
             } else {
                 ApplicationWouldGetAddedEvaluation::Yes(
                     ApplicationAddedSuccess::CrowdsOutExistingApplication(*application_id),
@@ -1382,6 +1706,33 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    // Computes the rank of the application with provided total stake according to the ranking policy.
+    fn application_rank(
+        ranking_policy: &ApplicationRankingPolicy,
+        total_stake: BalanceOf<T>,
+        added_at_block: T::BlockNumber,
+        current_block_height: T::BlockNumber,
+    ) -> BalanceOf<T> {
+        match *ranking_policy {
+            ApplicationRankingPolicy::StakeWeightedWithTenure {
+                tenure_weight_per_block,
+                max_tenure_blocks,
+            } => {
+                let tenure_blocks = current_block_height
+                    .saturating_sub(added_at_block)
+                    .saturated_into::<u32>()
+                    .min(max_tenure_blocks);
+
+                let weight = tenure_blocks
+                    .saturating_mul(tenure_weight_per_block)
+                    .saturating_add(1000);
+
+                total_stake.saturating_mul(weight.into()) / BalanceOf::<T>::from(1000u32)
+            }
+            _ => total_stake,
+        }
+    }
+
     fn get_opt_stake_amount(stake_id: Option<T::StakeId>) -> BalanceOf<T> {
         stake_id.map_or(<BalanceOf<T> as Zero>::zero(), |stake_id| {
             // INVARIANT: stake MUST exist in the staking module
@@ -1493,6 +1844,14 @@ pub trait StakeHandler<T: StakeTrait> {
         imbalance: NegativeImbalance<T>,
     ) -> Result<(), StakeActionError<stake::StakingError>>;
 
+    /// Same as stake(), but records the funder as the source of the staked value.
+    fn stake_funded_by(
+        &self,
+        new_stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        funder: &T::AccountId,
+    ) -> Result<(), StakeActionError<stake::StakingError>>;
+
     /// Checks whether stake exists by its id
     fn stake_exists(&self, stake_id: T::StakeId) -> bool;
 
@@ -1535,6 +1894,15 @@ impl<T: Trait> StakeHandler<T> for HiringStakeHandler {
         <stake::Module<T>>::stake(new_stake_id, imbalance)
     }
 
+    fn stake_funded_by(
+        &self,
+        new_stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        funder: &T::AccountId,
+    ) -> Result<(), StakeActionError<StakingError>> {
+        <stake::Module<T>>::stake_funded_by(new_stake_id, imbalance, funder)
+    }
+
     fn stake_exists(&self, stake_id: T::StakeId) -> bool {
         <stake::Stakes<T>>::exists(stake_id)
     }
@@ -1569,6 +1937,16 @@ impl<T: Trait> StakeHandler<T> for Rc<RefCell<dyn StakeHandler<T>>> {
         self.borrow().stake(new_stake_id, imbalance)
     }
 
+    fn stake_funded_by(
+        &self,
+        new_stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        funder: &T::AccountId,
+    ) -> Result<(), StakeActionError<StakingError>> {
+        self.borrow()
+            .stake_funded_by(new_stake_id, imbalance, funder)
+    }
+
     fn stake_exists(&self, stake_id: T::StakeId) -> bool {
         self.borrow().stake_exists(stake_id)
     }
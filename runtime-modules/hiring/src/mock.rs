@@ -93,6 +93,7 @@ impl stake::Trait for Test {
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type StakingBackend = stake::PoolAccountBackend;
     type StakeId = u64;
     type SlashId = u64;
 }
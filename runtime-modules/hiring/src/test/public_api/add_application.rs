@@ -200,6 +200,7 @@ fn add_application_succeeds_with_crowding_out() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -233,12 +234,44 @@ fn add_application_succeeds_with_crowding_out() {
     });
 }
 
+#[test]
+fn add_application_fails_with_first_come_first_served_ranking() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
+            max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::FirstComeFirstServed,
+        });
+        opening_fixture.application_staking_policy = Some(StakingPolicy {
+            amount: 100,
+            amount_mode: StakingAmountLimitMode::AtLeast,
+            crowded_out_unstaking_period_length: None,
+            review_period_expired_unstaking_period_length: None,
+        });
+
+        let add_opening_result = opening_fixture.add_opening();
+        let opening_id = add_opening_result.unwrap();
+
+        let mut application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+        application_fixture.opt_application_stake_imbalance =
+            Some(stake::NegativeImbalance::<Test>::new(100));
+
+        assert!(application_fixture.add_application().is_ok());
+
+        application_fixture.opt_application_stake_imbalance =
+            Some(stake::NegativeImbalance::<Test>::new(1000));
+
+        application_fixture.call_and_assert(Err(AddApplicationError::NewApplicationWasCrowdedOut));
+    });
+}
+
 #[test]
 fn add_application_fails() {
     build_test_externalities().execute_with(|| {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -266,6 +299,7 @@ fn add_application_succeeds_with_created_application_stake() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -313,6 +347,7 @@ fn add_application_succeeds_with_crowding_out_with_staking_mocks() {
             opening_fixture.application_rationing_policy =
                 Some(hiring::ApplicationRationingPolicy {
                     max_active_applicants: 1,
+                    applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
                 });
             opening_fixture.application_staking_policy = Some(StakingPolicy {
                 amount: 100,
@@ -377,6 +412,7 @@ fn add_application_succeeds_with_crowding_out_with_role_staking_mocks() {
             opening_fixture.application_rationing_policy =
                 Some(hiring::ApplicationRationingPolicy {
                     max_active_applicants: 1,
+                    applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
                 });
             opening_fixture.role_staking_policy = Some(StakingPolicy {
                 amount: 100,
@@ -220,6 +220,7 @@ fn add_opening_succeeds_or_fails_due_to_invalid_application_rationing_policy() {
         let mut opening_data = AddOpeningFixture::default();
         opening_data.application_rationing_policy = Some(ApplicationRationingPolicy {
             max_active_applicants: 0,
+            applicant_ranking: ApplicationRankingPolicy::Stake,
         });
 
         opening_data.call_and_assert(Err(AddOpeningError::ApplicationRationingZeroMaxApplicants));
@@ -161,6 +161,7 @@ fn cancel_opening_succeeds_with_single_crowded_out_application() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -175,6 +175,7 @@ fn ensure_can_add_application_succeeds_with_application_rationing_policy() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         let add_opening_result = opening_fixture.add_opening();
         let opening_id = add_opening_result.unwrap();
@@ -189,6 +190,7 @@ fn ensure_can_add_application_fails_with_application_rationing_policy() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -309,6 +311,7 @@ fn ensure_can_add_application_new_application_should_be_crowded_out_with_staking
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(staking_policy);
 
@@ -334,6 +337,7 @@ fn ensure_can_add_application_should_crowd_out_application() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
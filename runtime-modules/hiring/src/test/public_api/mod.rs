@@ -8,6 +8,7 @@ mod cancel_opening;
 mod ensure_can_add_application;
 mod fill_opening;
 mod on_finalize;
+mod review_application;
 mod unstaked;
 
 pub use add_application::AddApplicationFixture;
@@ -175,3 +175,146 @@ fn on_finalize_should_deactivate_application_with_review_period_expired_cause()
         }
     });
 }
+
+#[test]
+fn opening_activation_is_scheduled_and_taken_on_finalize() {
+    build_test_externalities().execute_with(|| {
+        let opening_activation_block = 2;
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.activate_at = ActivateOpeningAt::ExactBlock(opening_activation_block);
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        assert_eq!(
+            Hiring::opening_ids_by_activation_block(opening_activation_block),
+            vec![opening_id]
+        );
+
+        run_to_block_and_finalize(opening_activation_block);
+
+        assert!(!<OpeningIdsByActivationBlock<Test>>::exists(
+            opening_activation_block
+        ));
+    });
+}
+
+#[test]
+fn opening_activation_is_unscheduled_on_begin_accepting_applications() {
+    build_test_externalities().execute_with(|| {
+        let opening_activation_block = 5;
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.activate_at = ActivateOpeningAt::ExactBlock(opening_activation_block);
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        assert!(Hiring::begin_accepting_applications(opening_id).is_ok());
+
+        assert!(!<OpeningIdsByActivationBlock<Test>>::exists(
+            opening_activation_block
+        ));
+    });
+}
+
+#[test]
+fn review_period_expiration_is_scheduled_on_begin_review() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.max_review_period_length = 2;
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+
+        let review_period_end_block = System::block_number() + 2;
+        assert_eq!(
+            Hiring::opening_ids_by_review_period_end_block(review_period_end_block),
+            vec![opening_id]
+        );
+
+        run_to_block_and_finalize(review_period_end_block);
+
+        assert!(!<OpeningIdsByReviewPeriodEndBlock<Test>>::exists(
+            review_period_end_block
+        ));
+    });
+}
+
+#[test]
+fn review_period_expiration_is_unscheduled_on_fill_opening() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.max_review_period_length = 2;
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+        assert!(Hiring::fill_opening(opening_id, BTreeSet::new(), None, None, None).is_ok());
+
+        assert!(!<OpeningIdsByReviewPeriodEndBlock<Test>>::exists(
+            System::block_number() + 2
+        ));
+    });
+}
+
+#[test]
+fn review_period_expiration_is_unscheduled_on_cancel_opening() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.max_review_period_length = 2;
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        let other_opening_id = opening_fixture.add_opening().unwrap();
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+        assert!(Hiring::begin_review(other_opening_id).is_ok());
+        assert!(Hiring::cancel_opening(opening_id, None, None).is_ok());
+
+        assert_eq!(
+            Hiring::opening_ids_by_review_period_end_block(System::block_number() + 2),
+            vec![other_opening_id]
+        );
+    });
+}
+
+#[test]
+fn existing_opening_transitions_are_scheduled_on_migration() {
+    build_test_externalities().execute_with(|| {
+        let opening_activation_block = 5;
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.activate_at = ActivateOpeningAt::ExactBlock(opening_activation_block);
+        let waiting_opening_id = opening_fixture.add_opening().unwrap();
+
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.max_review_period_length = 2;
+        let reviewed_opening_id = opening_fixture.add_opening().unwrap();
+        assert!(Hiring::begin_review(reviewed_opening_id).is_ok());
+
+        let review_period_end_block = System::block_number() + 2;
+
+        // Openings added before the schedule was introduced.
+        <OpeningIdsByActivationBlock<Test>>::remove(opening_activation_block);
+        <OpeningIdsByReviewPeriodEndBlock<Test>>::remove(review_period_end_block);
+
+        Hiring::schedule_existing_opening_transitions();
+        // Migration can be repeated without duplicates.
+        Hiring::schedule_existing_opening_transitions();
+
+        assert_eq!(
+            Hiring::opening_ids_by_activation_block(opening_activation_block),
+            vec![waiting_opening_id]
+        );
+        assert_eq!(
+            Hiring::opening_ids_by_review_period_end_block(review_period_end_block),
+            vec![reviewed_opening_id]
+        );
+
+        run_to_block_and_finalize(review_period_end_block);
+
+        let new_opening = <OpeningById<Test>>::get(reviewed_opening_id);
+        if let OpeningStage::Active { stage, .. } = new_opening.stage {
+            if let ActiveOpeningStage::Deactivated { .. } = stage {
+                // expected
+            } else {
+                panic!("should be Deactivated")
+            }
+        } else {
+            panic!("should be Active")
+        }
+    });
+}
@@ -0,0 +1,251 @@
+use crate::mock::*;
+use crate::test::public_api::*;
+use crate::test::*;
+use crate::ApplicationDeactivationCause;
+use primitives::H256;
+
+pub struct DeclineApplicationFixture {
+    pub application_id: ApplicationId,
+    pub application_stake_unstaking_period: Option<BlockNumber>,
+    pub role_stake_unstaking_period: Option<BlockNumber>,
+}
+
+impl DeclineApplicationFixture {
+    pub(crate) fn default_for_application_id(application_id: ApplicationId) -> Self {
+        DeclineApplicationFixture {
+            application_id,
+            application_stake_unstaking_period: None,
+            role_stake_unstaking_period: None,
+        }
+    }
+
+    fn call_and_assert(&self, expected_result: Result<(), ReviewApplicationError>) {
+        let old_application = <ApplicationById<Test>>::get(self.application_id);
+
+        let decline_application_result = Hiring::decline_application(
+            self.application_id,
+            self.application_stake_unstaking_period,
+            self.role_stake_unstaking_period,
+        );
+
+        assert_eq!(decline_application_result, expected_result);
+
+        let actual_application = <ApplicationById<Test>>::get(self.application_id);
+
+        if decline_application_result.is_ok() {
+            let expected_stage = if old_application.active_application_staking_id.is_some()
+                || old_application.active_role_staking_id.is_some()
+            {
+                ApplicationStage::Unstaking {
+                    deactivation_initiated: 1,
+                    cause: ApplicationDeactivationCause::Declined,
+                }
+            } else {
+                ApplicationStage::Inactive {
+                    deactivation_initiated: 1,
+                    deactivated: 1,
+                    cause: ApplicationDeactivationCause::Declined,
+                }
+            };
+
+            assert_eq!(actual_application.stage, expected_stage);
+            assert!(!Hiring::application_review_by_id(self.application_id).shortlisted);
+        } else {
+            assert_eq!(actual_application, old_application);
+        }
+    }
+}
+
+fn add_application_in_review_period() -> (OpeningId, ApplicationId) {
+    let opening_fixture = AddOpeningFixture::default();
+    let opening_id = opening_fixture.add_opening().unwrap();
+
+    let application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+    let application_id = application_fixture
+        .add_application()
+        .unwrap()
+        .application_id_added;
+
+    assert!(Hiring::begin_review(opening_id).is_ok());
+
+    (opening_id, application_id)
+}
+
+#[test]
+fn shortlist_application_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let (_, application_id) = add_application_in_review_period();
+
+        assert_eq!(Hiring::shortlist_application(application_id), Ok(()));
+
+        assert_eq!(
+            Hiring::application_review_by_id(application_id),
+            ApplicationReview {
+                shortlisted: true,
+                note_hash: None,
+            }
+        );
+    });
+}
+
+#[test]
+fn shortlist_application_fails_with_no_application() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(
+            Hiring::shortlist_application(0),
+            Err(ReviewApplicationError::ApplicationDoesNotExist)
+        );
+    });
+}
+
+#[test]
+fn shortlist_application_fails_for_opening_not_in_review_period() {
+    build_test_externalities().execute_with(|| {
+        let opening_fixture = AddOpeningFixture::default();
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        let application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+        let application_id = application_fixture
+            .add_application()
+            .unwrap()
+            .application_id_added;
+
+        assert_eq!(
+            Hiring::shortlist_application(application_id),
+            Err(ReviewApplicationError::OpeningNotInReviewPeriodStage)
+        );
+    });
+}
+
+#[test]
+fn set_application_review_note_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let (_, application_id) = add_application_in_review_period();
+
+        let note_hash = H256::repeat_byte(7);
+        assert_eq!(
+            Hiring::set_application_review_note(application_id, note_hash),
+            Ok(())
+        );
+
+        assert_eq!(
+            Hiring::application_review_by_id(application_id).note_hash,
+            Some(note_hash)
+        );
+    });
+}
+
+#[test]
+fn set_application_review_note_fails_for_declined_application() {
+    build_test_externalities().execute_with(|| {
+        let (_, application_id) = add_application_in_review_period();
+
+        DeclineApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Hiring::set_application_review_note(application_id, H256::repeat_byte(7)),
+            Err(ReviewApplicationError::ApplicationNotActive)
+        );
+    });
+}
+
+#[test]
+fn decline_application_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_id) = add_application_in_review_period();
+
+        assert_eq!(Hiring::shortlist_application(application_id), Ok(()));
+
+        DeclineApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Ok(()));
+
+        if let OpeningStage::Active {
+            active_application_count,
+            deactivated_application_count,
+            ..
+        } = Hiring::opening_by_id(opening_id).stage
+        {
+            assert_eq!(active_application_count, 0);
+            assert_eq!(deactivated_application_count, 1);
+        } else {
+            panic!("Opening must be active");
+        }
+    });
+}
+
+#[test]
+fn decline_application_fails_for_already_declined_application() {
+    build_test_externalities().execute_with(|| {
+        let (_, application_id) = add_application_in_review_period();
+
+        let decline_application_fixture =
+            DeclineApplicationFixture::default_for_application_id(application_id);
+
+        decline_application_fixture.call_and_assert(Ok(()));
+        decline_application_fixture
+            .call_and_assert(Err(ReviewApplicationError::ApplicationNotActive));
+    });
+}
+
+#[test]
+fn decline_application_fails_with_redundant_unstaking_period_provided() {
+    build_test_externalities().execute_with(|| {
+        let (_, application_id) = add_application_in_review_period();
+
+        let mut decline_application_fixture =
+            DeclineApplicationFixture::default_for_application_id(application_id);
+        decline_application_fixture.role_stake_unstaking_period = Some(3);
+
+        decline_application_fixture.call_and_assert(Err(
+            ReviewApplicationError::RedundantUnstakingPeriodProvided(StakePurpose::Role),
+        ));
+    });
+}
+
+#[test]
+fn decline_application_succeeds_with_application_stake_checks() {
+    handle_mock(|| {
+        build_test_externalities().execute_with(|| {
+            let mock = default_mock_for_creating_stake();
+            set_stake_handler_impl(mock.clone());
+
+            let mut opening_fixture = AddOpeningFixture::default();
+            opening_fixture.application_staking_policy = Some(StakingPolicy {
+                amount: 100,
+                amount_mode: StakingAmountLimitMode::AtLeast,
+                crowded_out_unstaking_period_length: None,
+                review_period_expired_unstaking_period_length: None,
+            });
+            let opening_id = opening_fixture.add_opening().unwrap();
+
+            let mut application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+            application_fixture.opt_application_stake_imbalance =
+                Some(stake::NegativeImbalance::<Test>::new(100));
+            let application_id = application_fixture
+                .add_application()
+                .unwrap()
+                .application_id_added;
+
+            assert!(Hiring::begin_review(opening_id).is_ok());
+
+            let mock2 = default_mock_for_unstaking();
+            set_stake_handler_impl(mock2.clone());
+
+            let mut decline_application_fixture =
+                DeclineApplicationFixture::default_for_application_id(application_id);
+            decline_application_fixture.application_stake_unstaking_period = Some(0);
+            decline_application_fixture.call_and_assert(Err(
+                ReviewApplicationError::UnstakingPeriodTooShort(StakePurpose::Application),
+            ));
+
+            decline_application_fixture.application_stake_unstaking_period = Some(5);
+            decline_application_fixture.call_and_assert(Ok(()));
+
+            TestApplicationDeactivatedHandler::assert_deactivated_application(
+                application_id,
+                ApplicationDeactivationCause::Declined,
+            );
+        });
+    });
+}
@@ -163,6 +163,7 @@ fn unstaked_returns_unstaking_in_progress() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -216,6 +217,7 @@ fn unstaked_returns_unstaked() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -264,6 +266,7 @@ fn unstaked_returns_unstaked_with_stake_checks() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
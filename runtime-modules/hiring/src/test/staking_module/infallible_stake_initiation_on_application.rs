@@ -19,6 +19,7 @@ fn infallible_stake_initiation_on_application_panics_on_existed_stake() {
             Hiring::infallible_stake_initiation_on_application(
                 stake::NegativeImbalance::<Test>::new(100),
                 &application_id,
+                &None,
             );
         });
     });
@@ -43,6 +44,7 @@ fn infallible_stake_initiation_on_application_panics_on_unsuccessful_staking() {
             Hiring::infallible_stake_initiation_on_application(
                 stake::NegativeImbalance::<Test>::new(100),
                 &application_id,
+                &None,
             );
         });
     });
@@ -64,6 +66,36 @@ fn infallible_stake_initiation_on_application_succeeds() {
             let staking_result = Hiring::infallible_stake_initiation_on_application(
                 stake::NegativeImbalance::<Test>::new(100),
                 &application_id,
+                &None,
+            );
+
+            assert_eq!(staking_result, 10);
+        });
+    });
+}
+
+#[test]
+fn infallible_stake_initiation_on_application_records_the_sponsor() {
+    handle_mock(|| {
+        build_test_externalities().execute_with(|| {
+            let sponsor = 7;
+            let mock = {
+                let mut mock = crate::MockStakeHandler::<Test>::new();
+                mock.expect_create_stake().times(1).returning(|| 10);
+                mock.expect_stake().times(0);
+                mock.expect_stake_funded_by()
+                    .withf(move |stake_id, _, funder| *stake_id == 10 && *funder == sponsor)
+                    .times(1)
+                    .returning(|_, _, _| Ok(()));
+                Rc::new(RefCell::new(mock))
+            };
+            set_stake_handler_impl(mock);
+
+            let application_id = 10;
+            let staking_result = Hiring::infallible_stake_initiation_on_application(
+                stake::NegativeImbalance::<Test>::new(100),
+                &application_id,
+                &Some(sponsor),
             );
 
             assert_eq!(staking_result, 10);
@@ -44,6 +44,7 @@ fn would_application_get_added_with_zero_opening_applicants() {
         wagaf.possible_opening_application_rationing_policy =
             Some(hiring::ApplicationRationingPolicy {
                 max_active_applicants: 1,
+                applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
             });
 
         wagaf.call_and_assert(ApplicationWouldGetAddedEvaluation::Yes(
@@ -58,6 +59,7 @@ fn would_application_get_added_with_too_low_stake() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -80,6 +82,7 @@ fn would_application_get_added_with_too_low_stake() {
         wagaf.possible_opening_application_rationing_policy =
             Some(hiring::ApplicationRationingPolicy {
                 max_active_applicants: 1,
+                applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
             });
         wagaf.opening_applicants.insert(application_id);
         wagaf.opt_application_stake_balance = Some(99);
@@ -117,6 +120,7 @@ fn would_application_get_added_with_too_low_stake_with_mocks() {
             opening_fixture.application_rationing_policy =
                 Some(hiring::ApplicationRationingPolicy {
                     max_active_applicants: 1,
+                    applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
                 });
             opening_fixture.application_staking_policy = Some(StakingPolicy {
                 amount: 100,
@@ -139,6 +143,7 @@ fn would_application_get_added_with_too_low_stake_with_mocks() {
             wagaf.possible_opening_application_rationing_policy =
                 Some(hiring::ApplicationRationingPolicy {
                     max_active_applicants: 1,
+                    applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
                 });
             wagaf.opening_applicants.insert(application_id);
             wagaf.opt_application_stake_balance = Some(99);
@@ -154,6 +159,7 @@ fn would_application_get_added_with_crowding_out() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -176,6 +182,7 @@ fn would_application_get_added_with_crowding_out() {
         wagaf.possible_opening_application_rationing_policy =
             Some(hiring::ApplicationRationingPolicy {
                 max_active_applicants: 1,
+                applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
             });
         wagaf.opening_applicants.insert(application_id);
         wagaf.opt_application_stake_balance = Some(101);
@@ -192,6 +199,7 @@ fn would_application_get_added_panics_with_bad_params() {
         let mut opening_fixture = AddOpeningFixture::default();
         opening_fixture.application_rationing_policy = Some(hiring::ApplicationRationingPolicy {
             max_active_applicants: 1,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
         });
         opening_fixture.application_staking_policy = Some(StakingPolicy {
             amount: 100,
@@ -213,9 +221,142 @@ fn would_application_get_added_panics_with_bad_params() {
         wagaf.possible_opening_application_rationing_policy =
             Some(hiring::ApplicationRationingPolicy {
                 max_active_applicants: 0,
+                applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
             });
         wagaf.opt_application_stake_balance = Some(100);
 
         wagaf.would_application_get_added();
     });
 }
+
+#[test]
+fn would_application_get_added_respects_applicant_ranking_policy() {
+    let stake = hiring::ApplicationRankingPolicy::Stake;
+    let first_come = hiring::ApplicationRankingPolicy::FirstComeFirstServed;
+    let stake_with_tenure = |tenure_weight_per_block, max_tenure_blocks| {
+        hiring::ApplicationRankingPolicy::StakeWeightedWithTenure {
+            tenure_weight_per_block,
+            max_tenure_blocks,
+        }
+    };
+
+    // (ranking policy, stake of the new application, is crowding out expected)
+    // Active application has the stake of 100 and the tenure of 10 blocks.
+    let test_matrix = vec![
+        (stake.clone(), 99, false),
+        (stake.clone(), 100, false),
+        (stake, 101, true),
+        (first_come.clone(), 101, false),
+        (first_come, 1000, false),
+        // weighted stake: 100 * (1000 + 10 * 10) / 1000 = 110
+        (stake_with_tenure(10, 100), 101, false),
+        (stake_with_tenure(10, 100), 110, false),
+        (stake_with_tenure(10, 100), 111, true),
+        // weighted stake with capped tenure: 100 * (1000 + 5 * 10) / 1000 = 105
+        (stake_with_tenure(10, 5), 105, false),
+        (stake_with_tenure(10, 5), 106, true),
+        // zero tenure weight: same as the stake ranking
+        (stake_with_tenure(0, 100), 101, true),
+    ];
+
+    for (applicant_ranking, new_application_stake, crowds_out) in test_matrix {
+        build_test_externalities().execute_with(|| {
+            let application_rationing_policy = hiring::ApplicationRationingPolicy {
+                max_active_applicants: 1,
+                applicant_ranking,
+            };
+
+            let mut opening_fixture = AddOpeningFixture::default();
+            opening_fixture.application_rationing_policy =
+                Some(application_rationing_policy.clone());
+            opening_fixture.application_staking_policy = Some(StakingPolicy {
+                amount: 100,
+                amount_mode: StakingAmountLimitMode::AtLeast,
+                crowded_out_unstaking_period_length: None,
+                review_period_expired_unstaking_period_length: None,
+            });
+
+            let opening_id = opening_fixture.add_opening().unwrap();
+
+            let mut application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+            application_fixture.opt_application_stake_imbalance =
+                Some(stake::NegativeImbalance::<Test>::new(100));
+
+            let application_id = application_fixture
+                .add_application()
+                .unwrap()
+                .application_id_added;
+
+            System::set_block_number(System::block_number() + 10);
+
+            let mut wagaf = WouldApplicationGetAddedFixture::default();
+            wagaf.possible_opening_application_rationing_policy =
+                Some(application_rationing_policy);
+            wagaf.opening_applicants.insert(application_id);
+            wagaf.opt_application_stake_balance = Some(new_application_stake);
+
+            let expected_result = if crowds_out {
+                ApplicationWouldGetAddedEvaluation::Yes(
+                    ApplicationAddedSuccess::CrowdsOutExistingApplication(application_id),
+                )
+            } else {
+                ApplicationWouldGetAddedEvaluation::No
+            };
+
+            wagaf.call_and_assert(expected_result);
+        });
+    }
+}
+
+#[test]
+fn would_application_get_added_crowds_out_lowest_ranked_application() {
+    build_test_externalities().execute_with(|| {
+        let application_rationing_policy = hiring::ApplicationRationingPolicy {
+            max_active_applicants: 2,
+            applicant_ranking: hiring::ApplicationRankingPolicy::StakeWeightedWithTenure {
+                tenure_weight_per_block: 100,
+                max_tenure_blocks: 10,
+            },
+        };
+
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.application_rationing_policy = Some(application_rationing_policy.clone());
+        opening_fixture.application_staking_policy = Some(StakingPolicy {
+            amount: 100,
+            amount_mode: StakingAmountLimitMode::AtLeast,
+            crowded_out_unstaking_period_length: None,
+            review_period_expired_unstaking_period_length: None,
+        });
+
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        // Older application with lower stake: 100 * (1000 + 10 * 100) / 1000 = 200
+        let mut application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+        application_fixture.opt_application_stake_imbalance =
+            Some(stake::NegativeImbalance::<Test>::new(100));
+        let old_application_id = application_fixture
+            .add_application()
+            .unwrap()
+            .application_id_added;
+
+        System::set_block_number(System::block_number() + 10);
+
+        // Newer application with higher stake: 150 * (1000 + 0 * 100) / 1000 = 150
+        application_fixture.opt_application_stake_imbalance =
+            Some(stake::NegativeImbalance::<Test>::new(150));
+        let new_application_id = application_fixture
+            .add_application()
+            .unwrap()
+            .application_id_added;
+
+        let mut wagaf = WouldApplicationGetAddedFixture::default();
+        wagaf.possible_opening_application_rationing_policy = Some(application_rationing_policy);
+        wagaf.opening_applicants.insert(old_application_id);
+        wagaf.opening_applicants.insert(new_application_id);
+        wagaf.opt_application_stake_balance = Some(160);
+
+        wagaf.call_and_assert(ApplicationWouldGetAddedEvaluation::Yes(
+            ApplicationAddedSuccess::CrowdsOutExistingApplication(new_application_id),
+        ));
+    });
+}
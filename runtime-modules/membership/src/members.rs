@@ -70,6 +70,9 @@ const DEFAULT_MAX_HANDLE_LENGTH: u32 = 40;
 const DEFAULT_MAX_AVATAR_URI_LENGTH: u32 = 1024;
 const DEFAULT_MAX_ABOUT_TEXT_LENGTH: u32 = 2048;
 
+// Default suspension rationale constraint
+const DEFAULT_MAX_SUSPENSION_RATIONALE_LENGTH: u32 = 2048;
+
 //#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode)]
 /// Stored information about a registered user
@@ -135,6 +138,20 @@ pub enum EntryMethod<T: Trait> {
     Genesis,
 }
 
+/// Suspension of a member. Suspended members cannot enter roles, apply, create proposals, vote,
+/// post or upload.
+#[derive(Encode, Decode, Clone, Debug, Default, Eq, PartialEq)]
+pub struct MemberSuspension<BlockNumber> {
+    /// Why the member was suspended
+    pub rationale: Vec<u8>,
+
+    /// Blocknumber when member was suspended
+    pub suspended_at: BlockNumber,
+
+    /// Blocknumber when the suspension expires, the suspension lasts until lifted if not set
+    pub ends_at: Option<BlockNumber>,
+}
+
 //#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Eq, PartialEq)]
 pub struct PaidMembershipTerms<T: Trait> {
@@ -196,6 +213,14 @@ decl_storage! {
         pub MaxAboutTextLength get(max_about_text_length) : u32 = DEFAULT_MAX_ABOUT_TEXT_LENGTH;
 
         pub MembershipIdByActorInRole get(membership_id_by_actor_in_role): map ActorInRole<T::ActorId> => T::MemberId;
+
+        /// Suspensions of the currently suspended members
+        pub MemberSuspensionById get(member_suspension) : map T::MemberId => Option<MemberSuspension<T::BlockNumber>>;
+
+        /// Members whose suspension expires at the block
+        pub MemberIdsBySuspensionEnd get(member_ids_by_suspension_end) : map T::BlockNumber => Vec<T::MemberId>;
+
+        pub MaxSuspensionRationaleLength get(max_suspension_rationale_length) : u32 = DEFAULT_MAX_SUSPENSION_RATIONALE_LENGTH;
     }
     add_extra_genesis {
         config(default_paid_membership_fee): BalanceOf<T>;
@@ -218,6 +243,7 @@ decl_storage! {
 decl_event! {
     pub enum Event<T> where
       <T as system::Trait>::AccountId,
+      <T as system::Trait>::BlockNumber,
       <T as Trait>::MemberId,
       <T as Trait>::ActorId, {
         MemberRegistered(MemberId, AccountId),
@@ -228,6 +254,8 @@ decl_event! {
         MemberSetControllerAccount(MemberId, AccountId),
         MemberRegisteredRole(MemberId, ActorInRole<ActorId>),
         MemberUnregisteredRole(MemberId, ActorInRole<ActorId>),
+        MemberSuspended(MemberId, Option<BlockNumber>),
+        MemberUnsuspended(MemberId),
     }
 }
 
@@ -235,6 +263,10 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
+        fn on_finalize(now: T::BlockNumber) {
+            Self::unsuspend_members_with_expired_suspension(now);
+        }
+
         /// Non-members can buy membership
         pub fn buy_membership(origin, paid_terms_id: T::PaidTermId, user_info: UserInfo) {
             let who = ensure_signed(origin)?;
@@ -385,6 +417,53 @@ decl_module! {
             ensure_root(origin)?;
             <ScreeningAuthority<T>>::put(authority);
         }
+
+        /// Suspend the member until the optional end block or until unsuspended. Can be done by the
+        /// screening authority or the root (council proposal).
+        pub fn suspend_member(
+            origin,
+            member_id: T::MemberId,
+            rationale: Vec<u8>,
+            ends_at: Option<T::BlockNumber>
+        ) {
+            Self::ensure_suspension_authority(origin)?;
+
+            let mut profile = Self::ensure_profile(member_id)?;
+
+            ensure!(!profile.suspended, "member already suspended");
+
+            Self::validate_suspension_rationale(&rationale)?;
+
+            let now = <system::Module<T>>::block_number();
+
+            if let Some(ends_at) = ends_at {
+                ensure!(ends_at > now, "suspension end should be in the future");
+
+                <MemberIdsBySuspensionEnd<T>>::mutate(ends_at, |ids| ids.push(member_id));
+            }
+
+            profile.suspended = true;
+            <MemberProfile<T>>::insert(member_id, profile);
+
+            <MemberSuspensionById<T>>::insert(member_id, MemberSuspension {
+                rationale,
+                suspended_at: now,
+                ends_at,
+            });
+
+            Self::deposit_event(RawEvent::MemberSuspended(member_id, ends_at));
+        }
+
+        /// Lift the member suspension. Can be done by the screening authority or the root (council proposal).
+        pub fn unsuspend_member(origin, member_id: T::MemberId) {
+            Self::ensure_suspension_authority(origin)?;
+
+            let profile = Self::ensure_profile(member_id)?;
+
+            ensure!(profile.suspended, "member not suspended");
+
+            Self::unsuspend(member_id, profile);
+        }
     }
 }
 
@@ -439,6 +518,76 @@ impl<T: Trait> Module<T> {
             || <MemberIdsByControllerAccountId<T>>::exists(who)
     }
 
+    /// Returns true if account is either the root or controller account of a member that is not suspended
+    pub fn is_unsuspended_member_account(who: &T::AccountId) -> bool {
+        Self::member_ids_by_root_account_id(who)
+            .iter()
+            .chain(Self::member_ids_by_controller_account_id(who).iter())
+            .any(|member_id| !Self::is_member_suspended(member_id))
+    }
+
+    /// Returns true if the member is suspended. Non existing members are not suspended.
+    pub fn is_member_suspended(member_id: &T::MemberId) -> bool {
+        Self::member_profile(member_id).map_or(false, |profile| profile.suspended)
+    }
+
+    // Ensures the origin is the screening authority or the root.
+    fn ensure_suspension_authority(origin: T::Origin) -> dispatch::Result {
+        if ensure_root(origin.clone()).is_ok() {
+            return Ok(());
+        }
+
+        let sender = ensure_signed(origin)?;
+
+        ensure!(
+            Self::screening_authority() == Some(sender),
+            "not suspension authority"
+        );
+
+        Ok(())
+    }
+
+    fn validate_suspension_rationale(rationale: &[u8]) -> dispatch::Result {
+        ensure!(!rationale.is_empty(), "suspension rationale is empty");
+        ensure!(
+            rationale.len() <= Self::max_suspension_rationale_length() as usize,
+            "suspension rationale too long"
+        );
+        Ok(())
+    }
+
+    // Lifts the member suspension, removing the scheduled suspension end.
+    fn unsuspend(member_id: T::MemberId, mut profile: Profile<T>) {
+        if let Some(suspension) = <MemberSuspensionById<T>>::take(member_id) {
+            if let Some(ends_at) = suspension.ends_at {
+                let mut ids = Self::member_ids_by_suspension_end(ends_at);
+                ids.retain(|id| *id != member_id);
+
+                if ids.is_empty() {
+                    <MemberIdsBySuspensionEnd<T>>::remove(ends_at);
+                } else {
+                    <MemberIdsBySuspensionEnd<T>>::insert(ends_at, ids);
+                }
+            }
+        }
+
+        profile.suspended = false;
+        <MemberProfile<T>>::insert(member_id, profile);
+
+        Self::deposit_event(RawEvent::MemberUnsuspended(member_id));
+    }
+
+    // Lifts the suspensions expiring at the block.
+    fn unsuspend_members_with_expired_suspension(now: T::BlockNumber) {
+        for member_id in <MemberIdsBySuspensionEnd<T>>::take(now) {
+            if let Some(profile) = Self::member_profile(member_id) {
+                if profile.suspended {
+                    Self::unsuspend(member_id, profile);
+                }
+            }
+        }
+    }
+
     fn ensure_active_terms_id(
         terms_id: T::PaidTermId,
     ) -> Result<PaidMembershipTerms<T>, &'static str> {
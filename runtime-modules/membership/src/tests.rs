@@ -3,6 +3,7 @@
 use super::genesis;
 use super::mock::*;
 
+use sr_primitives::traits::OnFinalize;
 use srml_support::*;
 
 fn assert_ok_unwrap<T>(value: Option<T>, err: &'static str) -> T {
@@ -415,3 +416,141 @@ fn registering_and_unregistering_roles_on_member() {
             ));
         });
 }
+
+#[test]
+fn suspend_and_unsuspend_member() {
+    let initial_members = [ALICE_ACCOUNT_ID];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            system::Module::<Test>::set_block_number(1);
+
+            let screening_authority = 5;
+            <members::ScreeningAuthority<Test>>::put(&screening_authority);
+
+            let member_id = Members::member_ids_by_root_account_id(&ALICE_ACCOUNT_ID)[0];
+            let rationale = b"spam".to_vec();
+
+            assert_dispatch_error_message(
+                Members::suspend_member(Origin::signed(2), member_id, rationale.clone(), None),
+                "not suspension authority",
+            );
+
+            assert_dispatch_error_message(
+                Members::suspend_member(
+                    Origin::signed(screening_authority),
+                    member_id,
+                    Vec::new(),
+                    None,
+                ),
+                "suspension rationale is empty",
+            );
+
+            assert_ok!(Members::suspend_member(
+                Origin::signed(screening_authority),
+                member_id,
+                rationale.clone(),
+                None
+            ));
+
+            assert!(Members::is_member_suspended(&member_id));
+            assert!(!Members::is_unsuspended_member_account(&ALICE_ACCOUNT_ID));
+            assert_eq!(
+                Members::member_suspension(&member_id),
+                Some(members::MemberSuspension {
+                    rationale: rationale.clone(),
+                    suspended_at: 1,
+                    ends_at: None,
+                })
+            );
+
+            // suspended members cannot enter roles
+            assert_dispatch_error_message(
+                Members::register_role_on_member(
+                    member_id,
+                    &members::ActorInRole::new(members::Role::ChannelOwner, 100),
+                ),
+                "SuspendedMemberCannotEnterRole",
+            );
+
+            assert_dispatch_error_message(
+                Members::suspend_member(
+                    Origin::signed(screening_authority),
+                    member_id,
+                    rationale,
+                    None,
+                ),
+                "member already suspended",
+            );
+
+            // the root (council proposal) can unsuspend
+            assert_ok!(Members::unsuspend_member(
+                system::RawOrigin::Root.into(),
+                member_id
+            ));
+
+            assert!(!Members::is_member_suspended(&member_id));
+            assert!(Members::is_unsuspended_member_account(&ALICE_ACCOUNT_ID));
+            assert_eq!(Members::member_suspension(&member_id), None);
+
+            assert_dispatch_error_message(
+                Members::unsuspend_member(system::RawOrigin::Root.into(), member_id),
+                "member not suspended",
+            );
+        });
+}
+
+#[test]
+fn member_suspension_expires() {
+    let initial_members = [ALICE_ACCOUNT_ID];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            system::Module::<Test>::set_block_number(1);
+
+            let member_id = Members::member_ids_by_root_account_id(&ALICE_ACCOUNT_ID)[0];
+            let ends_at = 10;
+
+            assert_dispatch_error_message(
+                Members::suspend_member(
+                    system::RawOrigin::Root.into(),
+                    member_id,
+                    b"spam".to_vec(),
+                    Some(1),
+                ),
+                "suspension end should be in the future",
+            );
+
+            assert_ok!(Members::suspend_member(
+                system::RawOrigin::Root.into(),
+                member_id,
+                b"spam".to_vec(),
+                Some(ends_at)
+            ));
+
+            assert_eq!(
+                Members::member_ids_by_suspension_end(ends_at),
+                vec![member_id]
+            );
+
+            <Members as OnFinalize<u64>>::on_finalize(ends_at - 1);
+            assert!(Members::is_member_suspended(&member_id));
+
+            <Members as OnFinalize<u64>>::on_finalize(ends_at);
+            assert!(!Members::is_member_suspended(&member_id));
+            assert_eq!(Members::member_suspension(&member_id), None);
+            assert!(!<members::MemberIdsBySuspensionEnd<Test>>::exists(ends_at));
+        });
+}
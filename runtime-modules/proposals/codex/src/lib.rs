@@ -34,6 +34,16 @@
 //! - [create_slash_working_group_leader_stake_proposal](./struct.Module.html#method.create_slash_working_group_leader_stake_proposal)
 //! - [create_set_working_group_leader_reward_proposal](./struct.Module.html#method.create_set_working_group_leader_reward_proposal)
 //! - [create_terminate_working_group_leader_role_proposal](./struct.Module.html#method.create_terminate_working_group_leader_role_proposal)
+//! - [create_cancel_working_group_slashing_proposal](./struct.Module.html#method.create_cancel_working_group_slashing_proposal)
+//! - [create_resume_working_group_slashing_proposal](./struct.Module.html#method.create_resume_working_group_slashing_proposal)
+//! - [create_add_working_group_leader_opening_template_proposal](./struct.Module.html#method.create_add_working_group_leader_opening_template_proposal)
+//! - [create_update_working_group_leader_opening_template_proposal](./struct.Module.html#method.create_update_working_group_leader_opening_template_proposal)
+//! - [create_remove_working_group_leader_opening_template_proposal](./struct.Module.html#method.create_remove_working_group_leader_opening_template_proposal)
+//! - [create_add_working_group_leader_opening_from_template_proposal](./struct.Module.html#method.create_add_working_group_leader_opening_from_template_proposal)
+//!
+//! ### Membership proposals
+//! - [create_suspend_member_proposal](./struct.Module.html#method.create_suspend_member_proposal)
+//! - [create_unsuspend_member_proposal](./struct.Module.html#method.create_unsuspend_member_proposal)
 //!
 //! ### Proposal implementations of this module
 //! - execute_text_proposal - prints the proposal to the log
@@ -80,7 +90,8 @@ use srml_support::{decl_error, decl_module, decl_storage, ensure, print};
 use system::ensure_root;
 
 pub use crate::proposal_types::{
-    AddOpeningParameters, FillOpeningParameters, ProposalsConfigParameters, TerminateRoleParameters,
+    AddOpeningFromTemplateParameters, AddOpeningParameters, AddOpeningTemplateParameters,
+    FillOpeningParameters, ProposalsConfigParameters, TerminateRoleParameters,
 };
 pub use proposal_types::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 
@@ -250,6 +261,18 @@ decl_error! {
 
         /// Invalid 'decrease stake proposal' parameter - cannot decrease by zero balance.
         DecreasingStakeIsZero,
+
+        /// Invalid 'suspend member proposal' parameter - the member doesn't exist.
+        SuspendedMemberDoesNotExist,
+
+        /// Invalid 'suspend member proposal' parameter - the rationale is empty.
+        SuspensionRationaleIsEmpty,
+
+        /// Invalid 'suspend member proposal' parameter - the rationale exceeded the length limit.
+        SuspensionRationaleSizeExceeded,
+
+        /// Invalid 'suspend member proposal' parameter - the suspension end is not in the future.
+        SuspensionEndIsNotInTheFuture,
     }
 }
 
@@ -403,6 +426,70 @@ decl_storage! {
         /// Grace period for the 'terminate working group leader role' proposal
         pub TerminateWorkingGroupLeaderRoleProposalGracePeriod get(terminate_working_group_leader_role_proposal_grace_period)
             config(): T::BlockNumber;
+
+        /// Voting period for the 'cancel working group slashing' proposal
+        pub CancelWorkingGroupSlashingProposalVotingPeriod get(cancel_working_group_slashing_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'cancel working group slashing' proposal
+        pub CancelWorkingGroupSlashingProposalGracePeriod get(cancel_working_group_slashing_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'resume working group slashing' proposal
+        pub ResumeWorkingGroupSlashingProposalVotingPeriod get(resume_working_group_slashing_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'resume working group slashing' proposal
+        pub ResumeWorkingGroupSlashingProposalGracePeriod get(resume_working_group_slashing_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'suspend member' proposal
+        pub SuspendMemberProposalVotingPeriod get(suspend_member_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'suspend member' proposal
+        pub SuspendMemberProposalGracePeriod get(suspend_member_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'unsuspend member' proposal
+        pub UnsuspendMemberProposalVotingPeriod get(unsuspend_member_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'unsuspend member' proposal
+        pub UnsuspendMemberProposalGracePeriod get(unsuspend_member_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'add working group opening template' proposal
+        pub AddWorkingGroupOpeningTemplateProposalVotingPeriod get(add_working_group_opening_template_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'add working group opening template' proposal
+        pub AddWorkingGroupOpeningTemplateProposalGracePeriod get(add_working_group_opening_template_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'update working group opening template' proposal
+        pub UpdateWorkingGroupOpeningTemplateProposalVotingPeriod get(update_working_group_opening_template_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'update working group opening template' proposal
+        pub UpdateWorkingGroupOpeningTemplateProposalGracePeriod get(update_working_group_opening_template_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'remove working group opening template' proposal
+        pub RemoveWorkingGroupOpeningTemplateProposalVotingPeriod get(remove_working_group_opening_template_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'remove working group opening template' proposal
+        pub RemoveWorkingGroupOpeningTemplateProposalGracePeriod get(remove_working_group_opening_template_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'add working group opening from template' proposal
+        pub AddWorkingGroupOpeningFromTemplateProposalVotingPeriod get(add_working_group_opening_from_template_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'add working group opening from template' proposal
+        pub AddWorkingGroupOpeningFromTemplateProposalGracePeriod get(add_working_group_opening_from_template_proposal_grace_period)
+            config(): T::BlockNumber;
     }
 }
 
@@ -877,6 +964,260 @@ decl_module! {
             Self::create_proposal(params)?;
         }
 
+        /// Create 'cancel working group slashing' proposal type.
+        /// This proposal uses `cancel_slashing()` extrinsic from the `working-group`  module.
+        pub fn create_cancel_working_group_slashing_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            worker_id: working_group::WorkerId<T>,
+            slash_id: working_group::SlashId<T>,
+            working_group: WorkingGroup,
+        ) {
+            let proposal_details = ProposalDetails::CancelWorkingGroupSlashing(
+                worker_id,
+                slash_id,
+                working_group
+            );
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::cancel_working_group_slashing_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'resume working group slashing' proposal type.
+        /// This proposal uses `resume_slashing()` extrinsic from the `working-group`  module.
+        pub fn create_resume_working_group_slashing_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            worker_id: working_group::WorkerId<T>,
+            slash_id: working_group::SlashId<T>,
+            working_group: WorkingGroup,
+        ) {
+            let proposal_details = ProposalDetails::ResumeWorkingGroupSlashing(
+                worker_id,
+                slash_id,
+                working_group
+            );
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::resume_working_group_slashing_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'suspend member' proposal type.
+        /// This proposal uses `suspend_member()` extrinsic from the `membership`  module.
+        pub fn create_suspend_member_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            suspended_member_id: MemberId<T>,
+            rationale: Vec<u8>,
+            ends_at: Option<T::BlockNumber>,
+        ) {
+            ensure!(
+                <membership::members::Module<T>>::member_profile(suspended_member_id).is_some(),
+                Error::SuspendedMemberDoesNotExist
+            );
+
+            ensure!(!rationale.is_empty(), Error::SuspensionRationaleIsEmpty);
+            ensure!(
+                rationale.len() as u32
+                    <= <membership::members::Module<T>>::max_suspension_rationale_length(),
+                Error::SuspensionRationaleSizeExceeded
+            );
+
+            if let Some(ends_at) = ends_at {
+                ensure!(
+                    ends_at > <system::Module<T>>::block_number(),
+                    Error::SuspensionEndIsNotInTheFuture
+                );
+            }
+
+            let proposal_details = ProposalDetails::SuspendMember(
+                suspended_member_id,
+                rationale,
+                ends_at
+            );
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::suspend_member_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'unsuspend member' proposal type.
+        /// This proposal uses `unsuspend_member()` extrinsic from the `membership`  module.
+        pub fn create_unsuspend_member_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            suspended_member_id: MemberId<T>,
+        ) {
+            let proposal_details = ProposalDetails::UnsuspendMember(suspended_member_id);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::unsuspend_member_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Add working group leader opening template' proposal type.
+        /// This proposal uses `add_opening_template()` extrinsic from the Joystream `working group` module.
+        pub fn create_add_working_group_leader_opening_template_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            add_opening_template_parameters: AddOpeningTemplateParameters<T::BlockNumber, BalanceOfGovernanceCurrency<T>>,
+        ) {
+            let proposal_details = ProposalDetails::AddWorkingGroupLeaderOpeningTemplate(add_opening_template_parameters);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::add_working_group_opening_template_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Update working group leader opening template' proposal type.
+        /// This proposal uses `update_opening_template()` extrinsic from the Joystream `working group` module.
+        pub fn create_update_working_group_leader_opening_template_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            template_id: working_group::OpeningTemplateId,
+            commitment: working_group::OpeningPolicyCommitment<T::BlockNumber, BalanceOfGovernanceCurrency<T>>,
+            working_group: WorkingGroup,
+        ) {
+            let proposal_details = ProposalDetails::UpdateWorkingGroupLeaderOpeningTemplate(
+                template_id,
+                commitment,
+                working_group
+            );
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::update_working_group_opening_template_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Remove working group leader opening template' proposal type.
+        /// This proposal uses `remove_opening_template()` extrinsic from the Joystream `working group` module.
+        pub fn create_remove_working_group_leader_opening_template_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            template_id: working_group::OpeningTemplateId,
+            working_group: WorkingGroup,
+        ) {
+            let proposal_details = ProposalDetails::RemoveWorkingGroupLeaderOpeningTemplate(template_id, working_group);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::remove_working_group_opening_template_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Add working group leader opening from template' proposal type.
+        /// This proposal uses `add_opening_from_template()` extrinsic from the Joystream `working group` module.
+        pub fn create_add_working_group_leader_opening_from_template_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            add_opening_from_template_parameters: AddOpeningFromTemplateParameters<T::BlockNumber, BalanceOfGovernanceCurrency<T>>,
+        ) {
+            let proposal_details = ProposalDetails::AddWorkingGroupLeaderOpeningFromTemplate(
+                add_opening_from_template_parameters
+            );
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::add_working_group_opening_from_template_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
 
 // *************** Extrinsic to execute
 
@@ -1146,5 +1487,53 @@ impl<T: Trait> Module<T> {
         <TerminateWorkingGroupLeaderRoleProposalGracePeriod<T>>::put(T::BlockNumber::from(
             p.terminate_working_group_leader_role_proposal_grace_period,
         ));
+        <CancelWorkingGroupSlashingProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.cancel_working_group_slashing_proposal_voting_period,
+        ));
+        <CancelWorkingGroupSlashingProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.cancel_working_group_slashing_proposal_grace_period,
+        ));
+        <ResumeWorkingGroupSlashingProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.resume_working_group_slashing_proposal_voting_period,
+        ));
+        <ResumeWorkingGroupSlashingProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.resume_working_group_slashing_proposal_grace_period,
+        ));
+        <SuspendMemberProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.suspend_member_proposal_voting_period,
+        ));
+        <SuspendMemberProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.suspend_member_proposal_grace_period,
+        ));
+        <UnsuspendMemberProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.unsuspend_member_proposal_voting_period,
+        ));
+        <UnsuspendMemberProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.unsuspend_member_proposal_grace_period,
+        ));
+        <AddWorkingGroupOpeningTemplateProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.add_working_group_opening_template_proposal_voting_period,
+        ));
+        <AddWorkingGroupOpeningTemplateProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.add_working_group_opening_template_proposal_grace_period,
+        ));
+        <UpdateWorkingGroupOpeningTemplateProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.update_working_group_opening_template_proposal_voting_period,
+        ));
+        <UpdateWorkingGroupOpeningTemplateProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.update_working_group_opening_template_proposal_grace_period,
+        ));
+        <RemoveWorkingGroupOpeningTemplateProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.remove_working_group_opening_template_proposal_voting_period,
+        ));
+        <RemoveWorkingGroupOpeningTemplateProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.remove_working_group_opening_template_proposal_grace_period,
+        ));
+        <AddWorkingGroupOpeningFromTemplateProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.add_working_group_opening_from_template_proposal_voting_period,
+        ));
+        <AddWorkingGroupOpeningFromTemplateProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.add_working_group_opening_from_template_proposal_grace_period,
+        ));
     }
 }
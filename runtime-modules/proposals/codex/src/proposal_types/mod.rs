@@ -27,6 +27,7 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     working_group::ApplicationId<T>,
     crate::BalanceOf<T>,
     working_group::WorkerId<T>,
+    working_group::SlashId<T>,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
@@ -42,6 +43,7 @@ pub enum ProposalDetails<
     ApplicationId,
     StakeBalance,
     WorkerId,
+    SlashId,
 > {
     /// The text of the `text` proposal
     Text(Vec<u8>),
@@ -99,6 +101,38 @@ pub enum ProposalDetails<
 
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+
+    /// Cancel the pending (possibly appealed) slashing of the working group worker/lead stake.
+    CancelWorkingGroupSlashing(WorkerId, SlashId, WorkingGroup),
+
+    /// Resume the appealed slashing of the working group worker/lead stake rejecting the appeal.
+    ResumeWorkingGroupSlashing(WorkerId, SlashId, WorkingGroup),
+
+    /// Suspend the member with the rationale until the optional end block.
+    SuspendMember(MemberId, Vec<u8>, Option<BlockNumber>),
+
+    /// Lift the member suspension.
+    UnsuspendMember(MemberId),
+
+    /// Add the named opening policy template for the working group leader openings.
+    AddWorkingGroupLeaderOpeningTemplate(
+        AddOpeningTemplateParameters<BlockNumber, CurrencyBalance>,
+    ),
+
+    /// Update the working group leader opening template policy.
+    UpdateWorkingGroupLeaderOpeningTemplate(
+        working_group::OpeningTemplateId,
+        working_group::OpeningPolicyCommitment<BlockNumber, CurrencyBalance>,
+        WorkingGroup,
+    ),
+
+    /// Remove the working group leader opening template.
+    RemoveWorkingGroupLeaderOpeningTemplate(working_group::OpeningTemplateId, WorkingGroup),
+
+    /// Add opening for the working group leader position from the opening template.
+    AddWorkingGroupLeaderOpeningFromTemplate(
+        AddOpeningFromTemplateParameters<BlockNumber, CurrencyBalance>,
+    ),
 }
 
 impl<
@@ -111,6 +145,7 @@ impl<
         ApplicationId,
         StakeBalance,
         WorkerId,
+        SlashId,
     > Default
     for ProposalDetails<
         MintedBalance,
@@ -122,6 +157,7 @@ impl<
         ApplicationId,
         StakeBalance,
         WorkerId,
+        SlashId,
     >
 {
     fn default() -> Self {
@@ -180,6 +216,43 @@ pub struct AddOpeningParameters<BlockNumber, Balance> {
     pub working_group: WorkingGroup,
 }
 
+/// Parameters for the 'add opening template for the leader openings' proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct AddOpeningTemplateParameters<BlockNumber, Balance> {
+    /// Template name.
+    pub name: Vec<u8>,
+
+    /// Opening conditions of the template.
+    pub commitment: working_group::OpeningPolicyCommitment<BlockNumber, Balance>,
+
+    /// Defines working group of the template.
+    pub working_group: WorkingGroup,
+}
+
+/// Parameters for the 'add opening for the leader position from the template' proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct AddOpeningFromTemplateParameters<BlockNumber, Balance> {
+    /// Activate opening at block.
+    pub activate_at: hiring::ActivateOpeningAt<BlockNumber>,
+
+    /// Opening template identifier.
+    pub template_id: working_group::OpeningTemplateId,
+
+    /// Expected current version of the template.
+    pub template_version: u32,
+
+    /// Overrides of the template opening conditions.
+    pub overrides: working_group::OpeningPolicyCommitmentOverrides<BlockNumber, Balance>,
+
+    /// Opening description.
+    pub human_readable_text: Vec<u8>,
+
+    /// Defines working group with the open position.
+    pub working_group: WorkingGroup,
+}
+
 /// ********** Deprecated during the Nicaea release.
 /// It is kept only for backward compatibility in the Pioneer. **********
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -311,6 +384,54 @@ pub struct ProposalsConfigParameters {
 
     /// 'Terminate working group leader role' proposal grace period
     pub terminate_working_group_leader_role_proposal_grace_period: u32,
+
+    /// 'Cancel working group slashing' proposal voting period
+    pub cancel_working_group_slashing_proposal_voting_period: u32,
+
+    /// 'Cancel working group slashing' proposal grace period
+    pub cancel_working_group_slashing_proposal_grace_period: u32,
+
+    /// 'Resume working group slashing' proposal voting period
+    pub resume_working_group_slashing_proposal_voting_period: u32,
+
+    /// 'Resume working group slashing' proposal grace period
+    pub resume_working_group_slashing_proposal_grace_period: u32,
+
+    /// 'Suspend member' proposal voting period
+    pub suspend_member_proposal_voting_period: u32,
+
+    /// 'Suspend member' proposal grace period
+    pub suspend_member_proposal_grace_period: u32,
+
+    /// 'Unsuspend member' proposal voting period
+    pub unsuspend_member_proposal_voting_period: u32,
+
+    /// 'Unsuspend member' proposal grace period
+    pub unsuspend_member_proposal_grace_period: u32,
+
+    /// 'Add working group opening template' proposal voting period
+    pub add_working_group_opening_template_proposal_voting_period: u32,
+
+    /// 'Add working group opening template' proposal grace period
+    pub add_working_group_opening_template_proposal_grace_period: u32,
+
+    /// 'Update working group opening template' proposal voting period
+    pub update_working_group_opening_template_proposal_voting_period: u32,
+
+    /// 'Update working group opening template' proposal grace period
+    pub update_working_group_opening_template_proposal_grace_period: u32,
+
+    /// 'Remove working group opening template' proposal voting period
+    pub remove_working_group_opening_template_proposal_voting_period: u32,
+
+    /// 'Remove working group opening template' proposal grace period
+    pub remove_working_group_opening_template_proposal_grace_period: u32,
+
+    /// 'Add working group opening from template' proposal voting period
+    pub add_working_group_opening_from_template_proposal_voting_period: u32,
+
+    /// 'Add working group opening from template' proposal grace period
+    pub add_working_group_opening_from_template_proposal_grace_period: u32,
 }
 
 impl Default for ProposalsConfigParameters {
@@ -346,6 +467,22 @@ impl Default for ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0u32,
             terminate_working_group_leader_role_proposal_voting_period: 72200u32,
             terminate_working_group_leader_role_proposal_grace_period: 0u32,
+            cancel_working_group_slashing_proposal_voting_period: 43200u32,
+            cancel_working_group_slashing_proposal_grace_period: 0u32,
+            resume_working_group_slashing_proposal_voting_period: 43200u32,
+            resume_working_group_slashing_proposal_grace_period: 0u32,
+            suspend_member_proposal_voting_period: 43200u32,
+            suspend_member_proposal_grace_period: 0u32,
+            unsuspend_member_proposal_voting_period: 43200u32,
+            unsuspend_member_proposal_grace_period: 0u32,
+            add_working_group_opening_template_proposal_voting_period: 72000u32,
+            add_working_group_opening_template_proposal_grace_period: 0u32,
+            update_working_group_opening_template_proposal_voting_period: 72000u32,
+            update_working_group_opening_template_proposal_grace_period: 0u32,
+            remove_working_group_opening_template_proposal_voting_period: 43200u32,
+            remove_working_group_opening_template_proposal_grace_period: 0u32,
+            add_working_group_opening_from_template_proposal_voting_period: 72000u32,
+            add_working_group_opening_from_template_proposal_grace_period: 0u32,
         }
     }
 }
@@ -386,6 +523,22 @@ impl ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0,
             terminate_working_group_leader_role_proposal_voting_period: voting_period,
             terminate_working_group_leader_role_proposal_grace_period: 0,
+            cancel_working_group_slashing_proposal_voting_period: voting_period,
+            cancel_working_group_slashing_proposal_grace_period: 0,
+            resume_working_group_slashing_proposal_voting_period: voting_period,
+            resume_working_group_slashing_proposal_grace_period: 0,
+            suspend_member_proposal_voting_period: voting_period,
+            suspend_member_proposal_grace_period: 0,
+            unsuspend_member_proposal_voting_period: voting_period,
+            unsuspend_member_proposal_grace_period: 0,
+            add_working_group_opening_template_proposal_voting_period: voting_period,
+            add_working_group_opening_template_proposal_grace_period: 0,
+            update_working_group_opening_template_proposal_voting_period: voting_period,
+            update_working_group_opening_template_proposal_grace_period: 0,
+            remove_working_group_opening_template_proposal_voting_period: voting_period,
+            remove_working_group_opening_template_proposal_grace_period: 0,
+            add_working_group_opening_from_template_proposal_voting_period: voting_period,
+            add_working_group_opening_from_template_proposal_grace_period: 0,
         }
     }
 }
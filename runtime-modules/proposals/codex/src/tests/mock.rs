@@ -80,6 +80,7 @@ impl stake::Trait for Test {
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type StakingBackend = stake::PoolAccountBackend;
     type StakeId = u64;
     type SlashId = u64;
 }
@@ -113,9 +114,14 @@ impl Default for crate::Call<Test> {
     }
 }
 
+parameter_types! {
+    pub const MaxMintLedgerEntries: u32 = 100;
+}
+
 impl mint::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type MaxMintLedgerEntries = MaxMintLedgerEntries;
 }
 
 impl governance::council::Trait for Test {
@@ -275,10 +281,17 @@ impl timestamp::Trait for Test {
 }
 
 pub fn initial_test_ext() -> runtime_io::TestExternalities {
-    let t = system::GenesisConfig::default()
+    let mut t = system::GenesisConfig::default()
         .build_storage::<Test>()
         .unwrap();
 
+    membership::members::GenesisConfig::<Test> {
+        default_paid_membership_fee: 0,
+        members: vec![(10, "member10".into(), "".into(), "".into())],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
     t.into()
 }
 
@@ -15,7 +15,7 @@ pub use mock::*;
 
 use common::working_group::WorkingGroup;
 use hiring::ActivateOpeningAt;
-use working_group::OpeningPolicyCommitment;
+use working_group::{OpeningPolicyCommitment, OpeningPolicyCommitmentOverrides};
 
 pub(crate) fn increase_total_balance_issuance(balance: u64) {
     increase_total_balance_issuance_using_account_id(999, balance);
@@ -41,7 +41,7 @@ where
     invalid_stake_call: InvalidStakeCall,
     successful_call: SuccessfulCall,
     proposal_parameters: ProposalParameters<u64, u64>,
-    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64>,
+    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64, u64>,
 }
 
 impl<InsufficientRightsCall, EmptyStakeCall, InvalidStakeCall, SuccessfulCall>
@@ -924,6 +924,70 @@ fn set_default_proposal_parameters_succeeded() {
             <TerminateWorkingGroupLeaderRoleProposalGracePeriod<Test>>::get(),
             p.terminate_working_group_leader_role_proposal_grace_period as u64
         );
+        assert_eq!(
+            <CancelWorkingGroupSlashingProposalVotingPeriod<Test>>::get(),
+            p.cancel_working_group_slashing_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <CancelWorkingGroupSlashingProposalGracePeriod<Test>>::get(),
+            p.cancel_working_group_slashing_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <ResumeWorkingGroupSlashingProposalVotingPeriod<Test>>::get(),
+            p.resume_working_group_slashing_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <ResumeWorkingGroupSlashingProposalGracePeriod<Test>>::get(),
+            p.resume_working_group_slashing_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SuspendMemberProposalVotingPeriod<Test>>::get(),
+            p.suspend_member_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SuspendMemberProposalGracePeriod<Test>>::get(),
+            p.suspend_member_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <UnsuspendMemberProposalVotingPeriod<Test>>::get(),
+            p.unsuspend_member_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <UnsuspendMemberProposalGracePeriod<Test>>::get(),
+            p.unsuspend_member_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <AddWorkingGroupOpeningTemplateProposalVotingPeriod<Test>>::get(),
+            p.add_working_group_opening_template_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <AddWorkingGroupOpeningTemplateProposalGracePeriod<Test>>::get(),
+            p.add_working_group_opening_template_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <UpdateWorkingGroupOpeningTemplateProposalVotingPeriod<Test>>::get(),
+            p.update_working_group_opening_template_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <UpdateWorkingGroupOpeningTemplateProposalGracePeriod<Test>>::get(),
+            p.update_working_group_opening_template_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <RemoveWorkingGroupOpeningTemplateProposalVotingPeriod<Test>>::get(),
+            p.remove_working_group_opening_template_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <RemoveWorkingGroupOpeningTemplateProposalGracePeriod<Test>>::get(),
+            p.remove_working_group_opening_template_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <AddWorkingGroupOpeningFromTemplateProposalVotingPeriod<Test>>::get(),
+            p.add_working_group_opening_from_template_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <AddWorkingGroupOpeningFromTemplateProposalGracePeriod<Test>>::get(),
+            p.add_working_group_opening_from_template_proposal_grace_period as u64
+        );
     });
 }
 
@@ -1518,3 +1582,572 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
         proposal_fixture.check_all();
     });
 }
+
+#[test]
+fn create_cancel_working_group_slashing_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_cancel_working_group_slashing_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                    0,
+                    WorkingGroup::Storage,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_cancel_working_group_slashing_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                    0,
+                    WorkingGroup::Storage,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_cancel_working_group_slashing_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    10,
+                    0,
+                    WorkingGroup::Storage,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_cancel_working_group_slashing_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    10,
+                    0,
+                    WorkingGroup::Storage,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::cancel_working_group_slashing_proposal::<Test>(),
+            proposal_details: ProposalDetails::CancelWorkingGroupSlashing(
+                10,
+                0,
+                WorkingGroup::Storage,
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_resume_working_group_slashing_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_resume_working_group_slashing_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                    0,
+                    WorkingGroup::Storage,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_resume_working_group_slashing_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                    0,
+                    WorkingGroup::Storage,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_resume_working_group_slashing_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    10,
+                    0,
+                    WorkingGroup::Storage,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_resume_working_group_slashing_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    10,
+                    0,
+                    WorkingGroup::Storage,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::resume_working_group_slashing_proposal::<Test>(),
+            proposal_details: ProposalDetails::ResumeWorkingGroupSlashing(
+                10,
+                0,
+                WorkingGroup::Storage,
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_suspend_member_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_suspend_member_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    0,
+                    b"rationale".to_vec(),
+                    Some(100),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_suspend_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    0,
+                    b"rationale".to_vec(),
+                    Some(100),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_suspend_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    0,
+                    b"rationale".to_vec(),
+                    Some(100),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_suspend_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    0,
+                    b"rationale".to_vec(),
+                    Some(100),
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::suspend_member_proposal::<Test>(
+            ),
+            proposal_details: ProposalDetails::SuspendMember(0, b"rationale".to_vec(), Some(100)),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_suspend_member_proposal_codex_call_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        system::Module::<Test>::set_block_number(10);
+
+        assert_eq!(
+            ProposalCodex::create_suspend_member_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                None,
+                5,
+                b"rationale".to_vec(),
+                Some(100),
+            ),
+            Err(Error::SuspendedMemberDoesNotExist)
+        );
+
+        assert_eq!(
+            ProposalCodex::create_suspend_member_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                None,
+                0,
+                Vec::new(),
+                Some(100),
+            ),
+            Err(Error::SuspensionRationaleIsEmpty)
+        );
+
+        let long_rationale = [0u8; 30000].to_vec();
+        assert_eq!(
+            ProposalCodex::create_suspend_member_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                None,
+                0,
+                long_rationale,
+                Some(100),
+            ),
+            Err(Error::SuspensionRationaleSizeExceeded)
+        );
+
+        assert_eq!(
+            ProposalCodex::create_suspend_member_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                None,
+                0,
+                b"rationale".to_vec(),
+                Some(10),
+            ),
+            Err(Error::SuspensionEndIsNotInTheFuture)
+        );
+    });
+}
+
+#[test]
+fn create_unsuspend_member_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_unsuspend_member_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_unsuspend_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_unsuspend_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    10,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_unsuspend_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    10,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::unsuspend_member_proposal::<Test>(),
+            proposal_details: ProposalDetails::UnsuspendMember(10),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_add_working_group_leader_opening_template_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let add_opening_template_parameters = AddOpeningTemplateParameters {
+            name: b"template".to_vec(),
+            commitment: OpeningPolicyCommitment::default(),
+            working_group: WorkingGroup::Storage,
+        };
+
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_add_working_group_leader_opening_template_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    add_opening_template_parameters.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_add_working_group_leader_opening_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    add_opening_template_parameters.clone(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_add_working_group_leader_opening_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    add_opening_template_parameters.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_add_working_group_leader_opening_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    add_opening_template_parameters.clone(),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::add_working_group_opening_template_proposal::<Test>(),
+            proposal_details: ProposalDetails::AddWorkingGroupLeaderOpeningTemplate(
+                add_opening_template_parameters.clone(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_update_working_group_leader_opening_template_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_update_working_group_leader_opening_template_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    OpeningPolicyCommitment::default(),
+                    WorkingGroup::Storage,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_update_working_group_leader_opening_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    OpeningPolicyCommitment::default(),
+                    WorkingGroup::Storage,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_update_working_group_leader_opening_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1,
+                    OpeningPolicyCommitment::default(),
+                    WorkingGroup::Storage,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_update_working_group_leader_opening_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    1,
+                    OpeningPolicyCommitment::default(),
+                    WorkingGroup::Storage,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::update_working_group_opening_template_proposal::<
+                    Test,
+                >(),
+            proposal_details: ProposalDetails::UpdateWorkingGroupLeaderOpeningTemplate(
+                1,
+                OpeningPolicyCommitment::default(),
+                WorkingGroup::Storage,
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_remove_working_group_leader_opening_template_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_remove_working_group_leader_opening_template_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    WorkingGroup::Storage,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_remove_working_group_leader_opening_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    WorkingGroup::Storage,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_remove_working_group_leader_opening_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1,
+                    WorkingGroup::Storage,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_remove_working_group_leader_opening_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    1,
+                    WorkingGroup::Storage,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::remove_working_group_opening_template_proposal::<
+                    Test,
+                >(),
+            proposal_details: ProposalDetails::RemoveWorkingGroupLeaderOpeningTemplate(
+                1,
+                WorkingGroup::Storage,
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_add_working_group_leader_opening_from_template_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let add_opening_from_template_parameters = AddOpeningFromTemplateParameters {
+            activate_at: ActivateOpeningAt::CurrentBlock,
+            template_id: 1,
+            template_version: 1,
+            overrides: OpeningPolicyCommitmentOverrides::default(),
+            human_readable_text: b"some text".to_vec(),
+            working_group: WorkingGroup::Storage,
+        };
+
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_add_working_group_leader_opening_from_template_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    add_opening_from_template_parameters.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_add_working_group_leader_opening_from_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    add_opening_from_template_parameters.clone(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_add_working_group_leader_opening_from_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    add_opening_from_template_parameters.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_add_working_group_leader_opening_from_template_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    add_opening_from_template_parameters.clone(),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::add_working_group_opening_from_template_proposal::<Test>(),
+            proposal_details: ProposalDetails::AddWorkingGroupLeaderOpeningFromTemplate(
+                add_opening_from_template_parameters.clone(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
@@ -82,6 +82,7 @@ impl stake::Trait for Test {
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = BalanceManagerStakingEventsHandler;
+    type StakingBackend = stake::PoolAccountBackend;
     type StakeId = u64;
     type SlashId = u64;
 }
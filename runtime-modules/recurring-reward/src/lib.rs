@@ -10,7 +10,10 @@
 use rstd::prelude::*;
 
 use codec::{Codec, Decode, Encode};
+use rstd::cmp::min;
 use runtime_primitives::traits::{MaybeSerialize, Member, One, SimpleArithmetic, Zero};
+use srml_support::storage::generator::StorageLinkedMap;
+use srml_support::storage::unhashed;
 use srml_support::{decl_module, decl_storage, ensure, Parameter};
 
 use minting::{self, BalanceOf};
@@ -55,6 +58,21 @@ pub trait PayoutStatusHandler<T: Trait> {
         destination_account: &T::AccountId,
         amount: BalanceOf<T>,
     );
+
+    /// Arrears were settled fully or partially, the remaining arrears are provided.
+    fn arrears_settled(
+        id: T::RewardRelationshipId,
+        destination_account: &T::AccountId,
+        settled_amount: BalanceOf<T>,
+        remaining_arrears: BalanceOf<T>,
+    );
+
+    /// Relationship reached its end block or maximum total payout and was deactivated.
+    fn relationship_completed(
+        id: T::RewardRelationshipId,
+        destination_account: &T::AccountId,
+        total_reward_received: BalanceOf<T>,
+    );
 }
 
 /// Makes `()` empty tuple, a PayoutStatusHandler that does nothing.
@@ -72,6 +90,21 @@ impl<T: Trait> PayoutStatusHandler<T> for () {
         _amount: BalanceOf<T>,
     ) {
     }
+
+    fn arrears_settled(
+        _id: T::RewardRelationshipId,
+        _destination_account: &T::AccountId,
+        _settled_amount: BalanceOf<T>,
+        _remaining_arrears: BalanceOf<T>,
+    ) {
+    }
+
+    fn relationship_completed(
+        _id: T::RewardRelationshipId,
+        _destination_account: &T::AccountId,
+        _total_reward_received: BalanceOf<T>,
+    ) {
+    }
 }
 
 /// A recipient of recurring rewards
@@ -113,6 +146,27 @@ pub struct RewardRelationship<AccountId, Balance, BlockNumber, MintId, Recipient
 
     /// Total payout failed in this relationship
     total_reward_missed: Balance,
+
+    /// When set, identifies block from which no payouts are made and the relationship
+    /// is deactivated
+    ends_at_block: Option<BlockNumber>,
+
+    /// When set, limits the total payout received in this relationship, the relationship
+    /// is deactivated when the limit is reached
+    max_total_payout: Option<Balance>,
+}
+
+/// Reward relationship layout before the end block and maximum total payout were introduced.
+#[derive(Encode, Decode)]
+struct LegacyRewardRelationship<AccountId, Balance, BlockNumber, MintId, RecipientId> {
+    recipient: RecipientId,
+    mint_id: MintId,
+    account: AccountId,
+    amount_per_payout: Balance,
+    next_payment_at_block: Option<BlockNumber>,
+    payout_interval: Option<BlockNumber>,
+    total_reward_received: Balance,
+    total_reward_missed: Balance,
 }
 
 impl<AccountId: Clone, Balance: Clone, BlockNumber: Clone, MintId: Clone, RecipientId: Clone>
@@ -123,6 +177,21 @@ impl<AccountId: Clone, Balance: Clone, BlockNumber: Clone, MintId: Clone, Recipi
         self.next_payment_at_block.is_some()
     }
 
+    /// Block number of the next payout, if the relationship is active.
+    pub fn next_payment_at_block(&self) -> Option<BlockNumber> {
+        self.next_payment_at_block.clone()
+    }
+
+    /// Block from which no payouts are made, if set.
+    pub fn ends_at_block(&self) -> Option<BlockNumber> {
+        self.ends_at_block.clone()
+    }
+
+    /// Limit of the total payout received in this relationship, if set.
+    pub fn max_total_payout(&self) -> Option<Balance> {
+        self.max_total_payout.clone()
+    }
+
     /// Make clone which is activated.
     pub fn clone_activated(&self, start_at: &BlockNumber) -> Self {
         Self {
@@ -140,6 +209,63 @@ impl<AccountId: Clone, Balance: Clone, BlockNumber: Clone, MintId: Clone, Recipi
     }
 }
 
+/// Policy of keeping the missed payouts of a reward relationship to be settled later.
+#[derive(Encode, Decode, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArrearsPolicy<Balance, BlockNumber> {
+    /// Maximum amount of the missed payouts kept in arrears.
+    pub max_arrears: Balance,
+
+    /// Number of blocks after the oldest unsettled missed payout when the arrears expire.
+    pub expiry_period: BlockNumber,
+}
+
+/// Unsettled missed payouts of a reward relationship.
+#[derive(Encode, Decode, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RewardArrears<Balance, BlockNumber> {
+    /// Policy the arrears are kept by
+    pub policy: ArrearsPolicy<Balance, BlockNumber>,
+
+    /// Amount of the unsettled missed payouts
+    pub balance: Balance,
+
+    /// When set, identifies block when the unsettled arrears expire
+    pub expires_at_block: Option<BlockNumber>,
+}
+
+impl<Balance: SimpleArithmetic + Copy, BlockNumber: SimpleArithmetic + Copy>
+    RewardArrears<Balance, BlockNumber>
+{
+    /// Drops the arrears balance if it is expired at the provided block.
+    fn expire(&mut self, now: BlockNumber) {
+        if let Some(expires_at_block) = self.expires_at_block {
+            if now >= expires_at_block {
+                self.balance = Zero::zero();
+                self.expires_at_block = None;
+            }
+        }
+    }
+
+    /// Adds missed payout to the arrears balance, not exceeding the policy maximum.
+    fn add_missed_payout(&mut self, amount: Balance, now: BlockNumber) {
+        self.expire(now);
+
+        self.balance = min(self.balance.saturating_add(amount), self.policy.max_arrears);
+
+        if self.expires_at_block.is_none() && !self.balance.is_zero() {
+            self.expires_at_block = Some(now + self.policy.expiry_period);
+        }
+    }
+
+    /// Subtracts the settled amount from the arrears balance.
+    fn settle(&mut self, amount: Balance) {
+        self.balance -= amount;
+
+        if self.balance.is_zero() {
+            self.expires_at_block = None;
+        }
+    }
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as RecurringReward {
         Recipients get(recipients): linked_map T::RecipientId => Recipient<BalanceOf<T>>;
@@ -149,6 +275,12 @@ decl_storage! {
         pub RewardRelationships get(reward_relationships): linked_map T::RewardRelationshipId => RewardRelationship<T::AccountId, BalanceOf<T>, T::BlockNumber, T::MintId, T::RecipientId>;
 
         RewardRelationshipsCreated get(reward_relationships_created): T::RewardRelationshipId;
+
+        /// Active reward relationships by the block of their next payout.
+        pub RewardRelationshipsByPayoutBlock get(reward_relationships_by_payout_block): map T::BlockNumber => Vec<T::RewardRelationshipId>;
+
+        /// Arrears of the reward relationships with the arrears policy set.
+        pub RewardArrearsByRelationshipId get(reward_arrears): map T::RewardRelationshipId => RewardArrears<BalanceOf<T>, T::BlockNumber>;
     }
 }
 
@@ -167,6 +299,9 @@ pub enum RewardsError {
     RewardSourceNotFound,
     NextPaymentNotInFuture,
     RewardRelationshipNotFound,
+    ArrearsPolicyNotSet,
+    NoArrearsToSettle,
+    EndNotInFuture,
 }
 
 impl<T: Trait> Module<T> {
@@ -179,6 +314,8 @@ impl<T: Trait> Module<T> {
     }
 
     /// Adds a new RewardRelationship, for a given source mint, recipient, account.
+    /// Relationship is deactivated when the optional end block or maximum total payout is reached.
+    #[allow(clippy::too_many_arguments)] // the relationship is defined by all of the arguments
     pub fn add_reward_relationship(
         mint_id: T::MintId,
         recipient: T::RecipientId,
@@ -186,6 +323,8 @@ impl<T: Trait> Module<T> {
         amount_per_payout: BalanceOf<T>,
         next_payment_at_block: T::BlockNumber,
         payout_interval: Option<T::BlockNumber>,
+        ends_at_block: Option<T::BlockNumber>,
+        max_total_payout: Option<BalanceOf<T>>,
     ) -> Result<T::RewardRelationshipId, RewardsError> {
         ensure!(
             <minting::Module<T>>::mint_exists(mint_id),
@@ -199,6 +338,12 @@ impl<T: Trait> Module<T> {
             next_payment_at_block > <system::Module<T>>::block_number(),
             RewardsError::NextPaymentNotInFuture
         );
+        if let Some(ends_at_block) = ends_at_block {
+            ensure!(
+                ends_at_block > <system::Module<T>>::block_number(),
+                RewardsError::EndNotInFuture
+            );
+        }
 
         let relationship_id = Self::reward_relationships_created();
         <RewardRelationshipsCreated<T>>::put(relationship_id + One::one());
@@ -213,15 +358,25 @@ impl<T: Trait> Module<T> {
                 payout_interval,
                 total_reward_received: Zero::zero(),
                 total_reward_missed: Zero::zero(),
+                ends_at_block,
+                max_total_payout,
             },
         );
+        Self::schedule_payout(relationship_id, next_payment_at_block);
         Ok(relationship_id)
     }
 
     /// Removes a relationship from RewardRelashionships and its recipient.
     pub fn remove_reward_relationship(id: T::RewardRelationshipId) {
         if <RewardRelationships<T>>::exists(&id) {
-            <Recipients<T>>::remove(<RewardRelationships<T>>::take(&id).recipient);
+            let relationship = <RewardRelationships<T>>::take(&id);
+
+            if let Some(next_payment_at_block) = relationship.next_payment_at_block {
+                Self::unschedule_payout(id, next_payment_at_block);
+            }
+
+            <RewardArrearsByRelationshipId<T>>::remove(&id);
+            <Recipients<T>>::remove(relationship.recipient);
         }
     }
 
@@ -242,6 +397,7 @@ impl<T: Trait> Module<T> {
                 reward_relationship.clone_activated(&next_payment_at_block);
 
             RewardRelationships::<T>::insert(id, activated_relationship);
+            Self::schedule_payout(id, next_payment_at_block);
 
             // We activated
             true
@@ -260,6 +416,10 @@ impl<T: Trait> Module<T> {
 
             RewardRelationships::<T>::insert(id, deactivated_relationship);
 
+            if let Some(next_payment_at_block) = reward_relationship.next_payment_at_block {
+                Self::unschedule_payout(id, next_payment_at_block);
+            }
+
             // Was deactivated
             true
         } else {
@@ -271,20 +431,28 @@ impl<T: Trait> Module<T> {
     }
 
     // For reward relationship found with given identifier, new values can be set for
-    // account, payout, block number when next payout will be made and the new interval after
-    // the next scheduled payout. All values are optional, but updating values are combined in this
-    // single method to ensure atomic updates.
+    // account, payout, block number when next payout will be made, the new interval after
+    // the next scheduled payout, the end block and the maximum total payout. All values are
+    // optional, but updating values are combined in this single method to ensure atomic updates.
     pub fn set_reward_relationship(
         id: T::RewardRelationshipId,
         new_account: Option<T::AccountId>,
         new_payout: Option<BalanceOf<T>>,
         new_next_payment_at: Option<Option<T::BlockNumber>>,
         new_payout_interval: Option<Option<T::BlockNumber>>,
+        new_ends_at_block: Option<Option<T::BlockNumber>>,
+        new_max_total_payout: Option<Option<BalanceOf<T>>>,
     ) -> Result<(), RewardsError> {
         ensure!(
             <RewardRelationships<T>>::exists(&id),
             RewardsError::RewardRelationshipNotFound
         );
+        if let Some(Some(ends_at_block)) = new_ends_at_block {
+            ensure!(
+                ends_at_block > <system::Module<T>>::block_number(),
+                RewardsError::EndNotInFuture
+            );
+        }
 
         let mut relationship = Self::reward_relationships(&id);
 
@@ -301,18 +469,149 @@ impl<T: Trait> Module<T> {
                     RewardsError::NextPaymentNotInFuture
                 );
             }
+
+            if let Some(old_next_payout_at_block) = relationship.next_payment_at_block {
+                Self::unschedule_payout(id, old_next_payout_at_block);
+            }
+            if let Some(blocknumber) = next_payout_at_block {
+                Self::schedule_payout(id, blocknumber);
+            }
+
             relationship.next_payment_at_block = next_payout_at_block;
         }
         if let Some(payout_interval) = new_payout_interval {
             relationship.payout_interval = payout_interval;
         }
+        if let Some(ends_at_block) = new_ends_at_block {
+            relationship.ends_at_block = ends_at_block;
+        }
+        if let Some(max_total_payout) = new_max_total_payout {
+            relationship.max_total_payout = max_total_payout;
+        }
 
         <RewardRelationships<T>>::insert(&id, relationship);
         Ok(())
     }
 
+    /// Sets the arrears policy of the reward relationship, missed payouts will be kept in arrears
+    /// to be settled at the next successful payout or by claiming them. Existing arrears are capped
+    /// by the new policy maximum. Providing no policy drops the existing arrears.
+    pub fn set_arrears_policy(
+        id: T::RewardRelationshipId,
+        policy: Option<ArrearsPolicy<BalanceOf<T>, T::BlockNumber>>,
+    ) -> Result<(), RewardsError> {
+        ensure!(
+            <RewardRelationships<T>>::exists(&id),
+            RewardsError::RewardRelationshipNotFound
+        );
+
+        if let Some(policy) = policy {
+            let mut arrears = Self::reward_arrears(&id);
+            arrears.policy = policy;
+            arrears.balance = min(arrears.balance, policy.max_arrears);
+
+            <RewardArrearsByRelationshipId<T>>::insert(&id, arrears);
+        } else {
+            <RewardArrearsByRelationshipId<T>>::remove(&id);
+        }
+
+        Ok(())
+    }
+
+    /// Settles the not expired arrears of the reward relationship up to the source mint capacity.
+    /// Returns the settled amount, which is zero if the mint has no capacity left.
+    pub fn claim_arrears(id: T::RewardRelationshipId) -> Result<BalanceOf<T>, RewardsError> {
+        ensure!(
+            <RewardRelationships<T>>::exists(&id),
+            RewardsError::RewardRelationshipNotFound
+        );
+        ensure!(
+            <RewardArrearsByRelationshipId<T>>::exists(&id),
+            RewardsError::ArrearsPolicyNotSet
+        );
+
+        let now = <system::Module<T>>::block_number();
+
+        let mut arrears = Self::reward_arrears(&id);
+        arrears.expire(now);
+        ensure!(!arrears.balance.is_zero(), RewardsError::NoArrearsToSettle);
+
+        let mut relationship = Self::reward_relationships(&id);
+        let mut recipient = Self::recipients(relationship.recipient);
+
+        let settled_amount = Self::settle_arrears(id, &mut relationship, &mut recipient, now);
+
+        <Recipients<T>>::insert(relationship.recipient, recipient);
+        <RewardRelationships<T>>::insert(&id, relationship);
+
+        Ok(settled_amount)
+    }
+
+    /// Converts reward relationships saved before the end block and maximum total payout were
+    /// introduced, legacy relationships get no end conditions. Should be called on the migration
+    /// to the new runtime version, before any other use of the relationships.
+    pub fn migrate_reward_relationships_end_conditions() {
+        let mut relationship_id = T::RewardRelationshipId::zero();
+
+        while relationship_id < Self::reward_relationships_created() {
+            let key = <RewardRelationships<T>>::storage_linked_map_final_key(relationship_id);
+
+            if let Some(raw_value) = unhashed::get_raw(&key) {
+                let mut input = &raw_value[..];
+
+                if let Ok(legacy) = LegacyRewardRelationship::decode(&mut input) {
+                    // Linked map values are followed by the linkage to the adjacent keys.
+                    // Values already saved with the new layout don't end with the linkage.
+                    let linkage = input;
+
+                    if Self::is_linkage(linkage) {
+                        let relationship: RewardRelationship<
+                            T::AccountId,
+                            BalanceOf<T>,
+                            T::BlockNumber,
+                            T::MintId,
+                            T::RecipientId,
+                        > = RewardRelationship {
+                            recipient: legacy.recipient,
+                            mint_id: legacy.mint_id,
+                            account: legacy.account,
+                            amount_per_payout: legacy.amount_per_payout,
+                            next_payment_at_block: legacy.next_payment_at_block,
+                            payout_interval: legacy.payout_interval,
+                            total_reward_received: legacy.total_reward_received,
+                            total_reward_missed: legacy.total_reward_missed,
+                            ends_at_block: None,
+                            max_total_payout: None,
+                        };
+
+                        let mut value = relationship.encode();
+                        value.extend_from_slice(linkage);
+
+                        unhashed::put_raw(&key, &value);
+                    }
+                }
+            }
+
+            relationship_id += One::one();
+        }
+    }
+
+    /// Schedules payouts of all existing active relationships with the next payout
+    /// not in the past. Should be called on the migration to the new runtime version.
+    pub fn schedule_existing_payouts() {
+        let now = <system::Module<T>>::block_number();
+
+        for (relationship_id, relationship) in <RewardRelationships<T>>::enumerate() {
+            if let Some(next_payment_at_block) = relationship.next_payment_at_block {
+                if next_payment_at_block >= now {
+                    Self::schedule_payout(relationship_id, next_payment_at_block);
+                }
+            }
+        }
+    }
+
     /*
-    For all relationships where next_payment_at_block is set and matches current block height,
+    For all relationships scheduled for the current block height,
     a call to pay_reward is made for the suitable amount, recipient and source.
     The next_payment_in_block is updated based on payout_interval.
     If the call succeeds, total_reward_received is incremented on both
@@ -320,7 +619,14 @@ impl<T: Trait> Module<T> {
     Otherwise, analogous steps for failure.
     */
     fn do_payouts(now: T::BlockNumber) {
-        for (relationship_id, ref mut relationship) in <RewardRelationships<T>>::enumerate() {
+        for relationship_id in <RewardRelationshipsByPayoutBlock<T>>::take(now) {
+            // Skip removed relationships
+            if !<RewardRelationships<T>>::exists(relationship_id) {
+                continue;
+            }
+
+            let mut relationship = Self::reward_relationships(relationship_id);
+
             assert!(<Recipients<T>>::exists(&relationship.recipient));
 
             let mut recipient = Self::recipients(relationship.recipient);
@@ -330,9 +636,15 @@ impl<T: Trait> Module<T> {
                     continue;
                 }
 
-                // Add the missed payout and try to pay those in addition to scheduled payout?
-                // let payout = relationship.total_reward_missed + relationship.amount_per_payout;
-                let payout = relationship.amount_per_payout;
+                // end conditions could be reached by updating the relationship
+                if Self::end_condition_reached(&relationship, now) {
+                    Self::complete_relationship(relationship_id, &mut relationship);
+                    <RewardRelationships<T>>::insert(relationship_id, relationship);
+                    continue;
+                }
+
+                let payout =
+                    Self::limit_by_max_total_payout(&relationship, relationship.amount_per_payout);
 
                 // try to make payment
                 if <minting::Module<T>>::transfer_tokens(
@@ -343,10 +655,17 @@ impl<T: Trait> Module<T> {
                 .is_err()
                 {
                     // add only newly scheduled payout to total missed payout
-                    relationship.total_reward_missed += relationship.amount_per_payout;
+                    relationship.total_reward_missed += payout;
 
                     // update recipient stats
-                    recipient.total_reward_missed += relationship.amount_per_payout;
+                    recipient.total_reward_missed += payout;
+
+                    // keep missed payout in arrears if the policy is set
+                    if <RewardArrearsByRelationshipId<T>>::exists(relationship_id) {
+                        <RewardArrearsByRelationshipId<T>>::mutate(relationship_id, |arrears| {
+                            arrears.add_missed_payout(payout, now)
+                        });
+                    }
 
                     T::PayoutStatusHandler::payout_failed(
                         relationship_id,
@@ -358,26 +677,33 @@ impl<T: Trait> Module<T> {
                     relationship.total_reward_received += payout;
                     recipient.total_reward_received += payout;
 
-                    // update missed payout stats
-                    // if relationship.total_reward_missed != Zero::zero() {
-                    //     // update recipient stats
-                    //     recipient.total_reward_missed -= relationship.total_reward_missed;
-
-                    //     // clear missed reward on relationship
-                    //     relationship.total_reward_missed = Zero::zero();
-                    // }
                     T::PayoutStatusHandler::payout_succeeded(
                         relationship_id,
                         &relationship.account,
                         payout,
                     );
+
+                    // settle arrears after the scheduled payout
+                    if <RewardArrearsByRelationshipId<T>>::exists(relationship_id) {
+                        Self::settle_arrears(
+                            relationship_id,
+                            &mut relationship,
+                            &mut recipient,
+                            now,
+                        );
+                    }
                 }
 
                 // update next payout blocknumber at interval if set
-                if let Some(payout_interval) = relationship.payout_interval {
-                    relationship.next_payment_at_block = Some(now + payout_interval);
-                } else {
-                    relationship.next_payment_at_block = None;
+                relationship.next_payment_at_block =
+                    relationship.payout_interval.map(|interval| now + interval);
+
+                if let Some(next_payment_at_block) = relationship.next_payment_at_block {
+                    if Self::end_condition_reached(&relationship, next_payment_at_block) {
+                        Self::complete_relationship(relationship_id, &mut relationship);
+                    } else {
+                        Self::schedule_payout(relationship_id, next_payment_at_block);
+                    }
                 }
 
                 <Recipients<T>>::insert(relationship.recipient, recipient);
@@ -388,6 +714,159 @@ impl<T: Trait> Module<T> {
 }
 
 impl<T: Trait> Module<T> {
+    // Pays the not expired arrears of the relationship up to the source mint capacity and updates
+    // the payout stats. Returns the settled amount.
+    fn settle_arrears(
+        id: T::RewardRelationshipId,
+        relationship: &mut RewardRelationship<
+            T::AccountId,
+            BalanceOf<T>,
+            T::BlockNumber,
+            T::MintId,
+            T::RecipientId,
+        >,
+        recipient: &mut Recipient<BalanceOf<T>>,
+        now: T::BlockNumber,
+    ) -> BalanceOf<T> {
+        let mut arrears = Self::reward_arrears(&id);
+        arrears.expire(now);
+
+        let mint_capacity =
+            <minting::Module<T>>::get_mint_capacity(relationship.mint_id).unwrap_or_default();
+        let mut settled_amount =
+            Self::limit_by_max_total_payout(relationship, min(arrears.balance, mint_capacity));
+
+        if !settled_amount.is_zero() {
+            if <minting::Module<T>>::transfer_tokens(
+                relationship.mint_id,
+                settled_amount,
+                &relationship.account,
+            )
+            .is_ok()
+            {
+                arrears.settle(settled_amount);
+
+                relationship.total_reward_received += settled_amount;
+                recipient.total_reward_received += settled_amount;
+
+                T::PayoutStatusHandler::arrears_settled(
+                    id,
+                    &relationship.account,
+                    settled_amount,
+                    arrears.balance,
+                );
+            } else {
+                settled_amount = Zero::zero();
+            }
+        }
+
+        <RewardArrearsByRelationshipId<T>>::insert(&id, arrears);
+
+        settled_amount
+    }
+
+    // Checks whether the raw value is the encoded linkage of the reward relationships linked map.
+    fn is_linkage(raw_value: &[u8]) -> bool {
+        let mut input = raw_value;
+
+        let linkage = <(
+            Option<T::RewardRelationshipId>,
+            Option<T::RewardRelationshipId>,
+        )>::decode(&mut input);
+
+        linkage.is_ok() && input.is_empty()
+    }
+
+    // Checks whether the relationship end block or maximum total payout is reached at the
+    // provided block.
+    fn end_condition_reached(
+        relationship: &RewardRelationship<
+            T::AccountId,
+            BalanceOf<T>,
+            T::BlockNumber,
+            T::MintId,
+            T::RecipientId,
+        >,
+        at_block: T::BlockNumber,
+    ) -> bool {
+        let end_block_reached = relationship
+            .ends_at_block
+            .map_or(false, |ends_at_block| at_block >= ends_at_block);
+
+        let max_total_payout_reached = relationship
+            .max_total_payout
+            .map_or(false, |max_total_payout| {
+                relationship.total_reward_received >= max_total_payout
+            });
+
+        end_block_reached || max_total_payout_reached
+    }
+
+    // Limits the amount to the payout remaining until the maximum total payout is reached.
+    fn limit_by_max_total_payout(
+        relationship: &RewardRelationship<
+            T::AccountId,
+            BalanceOf<T>,
+            T::BlockNumber,
+            T::MintId,
+            T::RecipientId,
+        >,
+        amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        if let Some(max_total_payout) = relationship.max_total_payout {
+            let remaining_payout = if max_total_payout > relationship.total_reward_received {
+                max_total_payout - relationship.total_reward_received
+            } else {
+                Zero::zero()
+            };
+
+            min(amount, remaining_payout)
+        } else {
+            amount
+        }
+    }
+
+    // Deactivates the relationship which reached its end and notifies the status handler.
+    fn complete_relationship(
+        id: T::RewardRelationshipId,
+        relationship: &mut RewardRelationship<
+            T::AccountId,
+            BalanceOf<T>,
+            T::BlockNumber,
+            T::MintId,
+            T::RecipientId,
+        >,
+    ) {
+        relationship.next_payment_at_block = None;
+
+        T::PayoutStatusHandler::relationship_completed(
+            id,
+            &relationship.account,
+            relationship.total_reward_received,
+        );
+    }
+
+    // Adds the relationship to the payout queue of the provided block.
+    fn schedule_payout(id: T::RewardRelationshipId, payout_at_block: T::BlockNumber) {
+        <RewardRelationshipsByPayoutBlock<T>>::mutate(payout_at_block, |ids| {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        });
+    }
+
+    // Removes the relationship from the payout queue of the provided block.
+    fn unschedule_payout(id: T::RewardRelationshipId, payout_at_block: T::BlockNumber) {
+        let mut ids = Self::reward_relationships_by_payout_block(payout_at_block);
+        ids.retain(|scheduled_id| *scheduled_id != id);
+
+        if ids.is_empty() {
+            <RewardRelationshipsByPayoutBlock<T>>::remove(payout_at_block);
+        } else {
+            <RewardRelationshipsByPayoutBlock<T>>::insert(payout_at_block, ids);
+        }
+    }
+
     fn ensure_reward_relationship_exists(
         id: &T::RewardRelationshipId,
     ) -> Result<
@@ -82,9 +82,14 @@ impl Trait for Test {
     type RewardRelationshipId = u64;
 }
 
+parameter_types! {
+    pub const MaxMintLedgerEntries: u32 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type MaxMintLedgerEntries = MaxMintLedgerEntries;
 }
 
 pub fn build_test_externalities() -> runtime_io::TestExternalities {
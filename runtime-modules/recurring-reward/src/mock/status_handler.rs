@@ -1,18 +1,22 @@
 #![cfg(test)]
 
 use super::Test;
-use crate::{PayoutStatusHandler, Trait};
+use crate::{BalanceOf, PayoutStatusHandler, Trait};
 use std::cell::RefCell;
 
 struct StatusHandlerState<T: Trait> {
     successes: Vec<T::RewardRelationshipId>,
     failures: Vec<T::RewardRelationshipId>,
+    arrears_settlements: Vec<(T::RewardRelationshipId, BalanceOf<T>, BalanceOf<T>)>,
+    completions: Vec<T::RewardRelationshipId>,
 }
 
 impl<T: Trait> StatusHandlerState<T> {
     pub fn reset(&mut self) {
         self.successes = vec![];
         self.failures = vec![];
+        self.arrears_settlements = vec![];
+        self.completions = vec![];
     }
 }
 
@@ -21,6 +25,8 @@ impl<T: Trait> Default for StatusHandlerState<T> {
         Self {
             successes: vec![],
             failures: vec![],
+            arrears_settlements: vec![],
+            completions: vec![],
         }
     }
 }
@@ -48,6 +54,20 @@ impl MockStatusHandler {
         });
         value
     }
+    pub fn arrears_settlements() -> Vec<(u64, u64, u64)> {
+        let mut value = vec![];
+        STATUS_HANDLER_STATE.with(|cell| {
+            value = cell.borrow_mut().arrears_settlements.clone();
+        });
+        value
+    }
+    pub fn completions() -> Vec<u64> {
+        let mut value = vec![];
+        STATUS_HANDLER_STATE.with(|cell| {
+            value = cell.borrow_mut().completions.clone();
+        });
+        value
+    }
 }
 impl PayoutStatusHandler<Test> for MockStatusHandler {
     fn payout_succeeded(id: u64, _destination_account: &u64, _amount: u64) {
@@ -61,4 +81,23 @@ impl PayoutStatusHandler<Test> for MockStatusHandler {
             cell.borrow_mut().failures.push(id);
         });
     }
+
+    fn arrears_settled(
+        id: u64,
+        _destination_account: &u64,
+        settled_amount: u64,
+        remaining_arrears: u64,
+    ) {
+        STATUS_HANDLER_STATE.with(|cell| {
+            cell.borrow_mut()
+                .arrears_settlements
+                .push((id, settled_amount, remaining_arrears));
+        });
+    }
+
+    fn relationship_completed(id: u64, _destination_account: &u64, _total_reward_received: u64) {
+        STATUS_HANDLER_STATE.with(|cell| {
+            cell.borrow_mut().completions.push(id);
+        });
+    }
 }
@@ -5,7 +5,7 @@ use crate::mock::*;
 use srml_support::traits::Currency;
 
 fn create_new_mint_with_capacity(capacity: u64) -> u64 {
-    let mint_id = Minting::add_mint(capacity, None).ok().unwrap();
+    let mint_id = Minting::add_mint(capacity, None, None).ok().unwrap();
     assert!(Minting::mint_exists(mint_id));
     assert_eq!(Minting::get_mint_capacity(mint_id).ok().unwrap(), capacity);
     mint_id
@@ -41,6 +41,8 @@ fn adding_relationships() {
             payout,
             next_payment_at,
             Some(interval),
+            None,
+            None,
         );
         assert!(relationship.is_ok());
         let relationship_id = relationship.ok().unwrap();
@@ -66,6 +68,8 @@ fn adding_relationships() {
                 100,
                 next_payment_at,
                 None,
+                None,
+                None,
             )
             .expect_err("should fail if mint doesn't exist"),
             RewardsError::RewardSourceNotFound
@@ -90,6 +94,8 @@ fn one_off_payout() {
             payout,
             next_payout_at,
             None,
+            None,
+            None,
         );
         assert!(relationship.is_ok());
         let relationship_id = relationship.ok().unwrap();
@@ -141,6 +147,8 @@ fn recurring_payout() {
             payout,
             next_payout_at,
             Some(interval),
+            None,
+            None,
         );
         assert!(relationship.is_ok());
         let relationship_id = relationship.ok().unwrap();
@@ -189,6 +197,8 @@ fn track_missed_payouts() {
             payout,
             next_payout_at,
             None,
+            None,
+            None,
         );
         assert!(relationship.is_ok());
         let relationship_id = relationship.ok().unwrap();
@@ -232,6 +242,8 @@ fn activate_and_deactivate_relationship() {
             payout,
             next_payout_at,
             None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -257,3 +269,663 @@ fn activate_and_deactivate_relationship() {
         assert!(!Rewards::try_to_activate_relationship(relationship_id, next_payout_at).unwrap());
     });
 }
+
+#[test]
+fn payouts_are_scheduled_by_payout_block() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 600;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            1,
+            1000,
+            next_payout_at,
+            Some(interval),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Rewards::reward_relationships_by_payout_block(next_payout_at),
+            vec![relationship_id]
+        );
+
+        // Payout takes the relationship out of the queue and schedules the next payout
+        Rewards::do_payouts(next_payout_at);
+        assert!(!<RewardRelationshipsByPayoutBlock<Test>>::exists(
+            next_payout_at
+        ));
+        assert_eq!(
+            Rewards::reward_relationships_by_payout_block(next_payout_at + interval),
+            vec![relationship_id]
+        );
+
+        // Deactivation removes the relationship from the queue
+        assert!(Rewards::try_to_deactivate_relationship(relationship_id).unwrap());
+        assert!(!<RewardRelationshipsByPayoutBlock<Test>>::exists(
+            next_payout_at + interval
+        ));
+
+        // Activation schedules the relationship again
+        let activated_payout_at = 13000;
+        assert!(
+            Rewards::try_to_activate_relationship(relationship_id, activated_payout_at).unwrap()
+        );
+        assert_eq!(
+            Rewards::reward_relationships_by_payout_block(activated_payout_at),
+            vec![relationship_id]
+        );
+
+        // Setting the next payout re-schedules the relationship
+        let new_payout_at = 14000;
+        assert!(Rewards::set_reward_relationship(
+            relationship_id,
+            None,
+            None,
+            Some(Some(new_payout_at)),
+            None,
+            None,
+            None
+        )
+        .is_ok());
+        assert!(!<RewardRelationshipsByPayoutBlock<Test>>::exists(
+            activated_payout_at
+        ));
+        assert_eq!(
+            Rewards::reward_relationships_by_payout_block(new_payout_at),
+            vec![relationship_id]
+        );
+
+        // Clearing the next payout removes the relationship from the queue
+        assert!(Rewards::set_reward_relationship(
+            relationship_id,
+            None,
+            None,
+            Some(None),
+            None,
+            None,
+            None
+        )
+        .is_ok());
+        assert!(!<RewardRelationshipsByPayoutBlock<Test>>::exists(
+            new_payout_at
+        ));
+    });
+}
+
+#[test]
+fn removed_relationship_is_not_paid() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let next_payout_at: u64 = 12222;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            1,
+            1000,
+            next_payout_at,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        Rewards::remove_reward_relationship(relationship_id);
+        assert!(!<RewardRelationshipsByPayoutBlock<Test>>::exists(
+            next_payout_at
+        ));
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(MockStatusHandler::successes(), 0);
+    });
+}
+
+#[test]
+fn existing_payouts_are_scheduled_on_migration() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let next_payout_at: u64 = 12222;
+
+        let relationship_ids = (0..3)
+            .map(|_| {
+                let recipient_id = Rewards::add_recipient();
+                Rewards::add_reward_relationship(
+                    mint_id,
+                    recipient_id,
+                    1,
+                    1000,
+                    next_payout_at,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(Rewards::try_to_deactivate_relationship(relationship_ids[2]).unwrap());
+
+        // Relationships added before the payout queue was introduced.
+        <RewardRelationshipsByPayoutBlock<Test>>::remove(next_payout_at);
+
+        Rewards::schedule_existing_payouts();
+        // Migration can be repeated without duplicates.
+        Rewards::schedule_existing_payouts();
+
+        assert_eq!(
+            Rewards::reward_relationships_by_payout_block(next_payout_at),
+            vec![relationship_ids[0], relationship_ids[1]]
+        );
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(MockStatusHandler::successes(), 2);
+    });
+}
+
+// Benchmark-style test: the per-block payout work is bounded by the number of relationships
+// due in the block, not by the total number of relationships.
+#[test]
+fn payout_work_is_independent_of_total_relationship_count() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000000);
+        let due_payout_at: u64 = 12222;
+        let due_relationships_count = 5;
+
+        let add_relationships = |count: u64, next_payout_at: u64| {
+            for _ in 0..count {
+                let recipient_id = Rewards::add_recipient();
+                Rewards::add_reward_relationship(
+                    mint_id,
+                    recipient_id,
+                    1,
+                    10,
+                    next_payout_at,
+                    Some(1000),
+                    None,
+                    None,
+                )
+                .unwrap();
+            }
+        };
+
+        for total_relationships_count in &[10, 100, 1000] {
+            // Relationships not due in the block.
+            add_relationships(*total_relationships_count, due_payout_at + 1);
+            // Relationships due in the block.
+            add_relationships(due_relationships_count, due_payout_at);
+
+            // The only relationships loaded are those in the block payout queue.
+            assert_eq!(
+                Rewards::reward_relationships_by_payout_block(due_payout_at).len() as u64,
+                due_relationships_count
+            );
+
+            MockStatusHandler::reset();
+            Rewards::do_payouts(due_payout_at);
+            assert_eq!(
+                MockStatusHandler::successes(),
+                due_relationships_count as usize
+            );
+        }
+    });
+}
+
+fn add_recurring_relationship_with_arrears_policy(
+    mint_id: u64,
+    payout: u64,
+    next_payout_at: u64,
+    interval: u64,
+) -> u64 {
+    let recipient_id = Rewards::add_recipient();
+    let relationship_id = Rewards::add_reward_relationship(
+        mint_id,
+        recipient_id,
+        1,
+        payout,
+        next_payout_at,
+        Some(interval),
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        Rewards::set_arrears_policy(
+            relationship_id,
+            Some(ArrearsPolicy {
+                max_arrears: payout + payout / 2,
+                expiry_period: interval * 10,
+            })
+        ),
+        Ok(())
+    );
+
+    relationship_id
+}
+
+#[test]
+fn missed_payouts_are_not_kept_without_arrears_policy() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let next_payout_at: u64 = 12222;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            1,
+            1000,
+            next_payout_at,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(MockStatusHandler::failures(), 1);
+
+        assert!(!<RewardArrearsByRelationshipId<Test>>::exists(
+            relationship_id
+        ));
+        assert_eq!(
+            Rewards::claim_arrears(relationship_id),
+            Err(RewardsError::ArrearsPolicyNotSet)
+        );
+    });
+}
+
+#[test]
+fn arrears_are_capped_and_settled_at_next_successful_payout() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(0);
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 100;
+
+        let relationship_id = add_recurring_relationship_with_arrears_policy(
+            mint_id,
+            payout,
+            next_payout_at,
+            interval,
+        );
+
+        // Two missed payouts are kept in arrears up to the policy maximum
+        Rewards::do_payouts(next_payout_at);
+        Rewards::do_payouts(next_payout_at + interval);
+        assert_eq!(MockStatusHandler::failures(), 2);
+
+        let arrears = Rewards::reward_arrears(relationship_id);
+        assert_eq!(arrears.balance, 1500);
+        assert_eq!(
+            arrears.expires_at_block,
+            Some(next_payout_at + interval * 10)
+        );
+
+        // Mint capacity suffices for the scheduled payout and part of the arrears
+        assert!(Minting::set_mint_capacity(mint_id, 1800).is_ok());
+        let starting_balance = Balances::free_balance(&1);
+
+        Rewards::do_payouts(next_payout_at + interval * 2);
+        assert_eq!(MockStatusHandler::successes(), 1);
+        assert_eq!(
+            MockStatusHandler::arrears_settlements(),
+            vec![(relationship_id, 800, 700)]
+        );
+        assert_eq!(Balances::free_balance(&1), starting_balance + 1800);
+
+        let arrears = Rewards::reward_arrears(relationship_id);
+        assert_eq!(arrears.balance, 700);
+
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert_eq!(relationship.total_reward_received, 1800);
+        assert_eq!(relationship.total_reward_missed, payout * 2);
+    });
+}
+
+#[test]
+fn claim_arrears_succeeds() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(0);
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 10100;
+        let interval: u64 = 100;
+
+        let relationship_id = add_recurring_relationship_with_arrears_policy(
+            mint_id,
+            payout,
+            next_payout_at,
+            interval,
+        );
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::reward_arrears(relationship_id).balance, payout);
+
+        // Partial settlement limited by the mint capacity
+        assert!(Minting::set_mint_capacity(mint_id, 300).is_ok());
+        assert_eq!(Rewards::claim_arrears(relationship_id), Ok(300));
+
+        // Nothing is settled without the mint capacity
+        assert_eq!(Rewards::claim_arrears(relationship_id), Ok(0));
+
+        assert!(Minting::set_mint_capacity(mint_id, 5000).is_ok());
+        assert_eq!(Rewards::claim_arrears(relationship_id), Ok(700));
+
+        assert_eq!(
+            MockStatusHandler::arrears_settlements(),
+            vec![(relationship_id, 300, 700), (relationship_id, 700, 0)]
+        );
+
+        let arrears = Rewards::reward_arrears(relationship_id);
+        assert_eq!(arrears.balance, 0);
+        assert_eq!(arrears.expires_at_block, None);
+
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert_eq!(relationship.total_reward_received, payout);
+
+        assert_eq!(
+            Rewards::claim_arrears(relationship_id),
+            Err(RewardsError::NoArrearsToSettle)
+        );
+    });
+}
+
+#[test]
+fn claim_arrears_fails_with_invalid_relationship() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(
+            Rewards::claim_arrears(1),
+            Err(RewardsError::RewardRelationshipNotFound)
+        );
+        assert_eq!(
+            Rewards::set_arrears_policy(1, None),
+            Err(RewardsError::RewardRelationshipNotFound)
+        );
+    });
+}
+
+#[test]
+fn arrears_expire() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(0);
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 10100;
+        let interval: u64 = 100;
+
+        let relationship_id = add_recurring_relationship_with_arrears_policy(
+            mint_id,
+            payout,
+            next_payout_at,
+            interval,
+        );
+
+        Rewards::do_payouts(next_payout_at);
+        assert!(Minting::set_mint_capacity(mint_id, 5000).is_ok());
+
+        System::set_block_number(next_payout_at + interval * 10);
+        assert_eq!(
+            Rewards::claim_arrears(relationship_id),
+            Err(RewardsError::NoArrearsToSettle)
+        );
+        assert!(MockStatusHandler::arrears_settlements().is_empty());
+    });
+}
+
+#[test]
+fn relationship_is_completed_at_end_block() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 100;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            1,
+            payout,
+            next_payout_at,
+            Some(interval),
+            Some(next_payout_at + interval * 2 + 50),
+            None,
+        )
+        .unwrap();
+
+        for i in 0..3 {
+            Rewards::do_payouts(next_payout_at + interval * i);
+        }
+        assert_eq!(MockStatusHandler::successes(), 3);
+        assert_eq!(MockStatusHandler::completions(), vec![relationship_id]);
+
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert!(!relationship.is_active());
+        assert_eq!(relationship.total_reward_received, payout * 3);
+        assert!(!<RewardRelationshipsByPayoutBlock<Test>>::exists(
+            next_payout_at + interval * 3
+        ));
+    });
+}
+
+#[test]
+fn relationship_is_completed_at_max_total_payout() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 100;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            1,
+            payout,
+            next_payout_at,
+            Some(interval),
+            None,
+            Some(2500),
+        )
+        .unwrap();
+
+        let starting_balance = Balances::free_balance(&1);
+
+        for i in 0..4 {
+            Rewards::do_payouts(next_payout_at + interval * i);
+        }
+
+        // The last payout is limited by the maximum total payout
+        assert_eq!(MockStatusHandler::successes(), 3);
+        assert_eq!(MockStatusHandler::completions(), vec![relationship_id]);
+        assert_eq!(Balances::free_balance(&1), starting_balance + 2500);
+
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert!(!relationship.is_active());
+        assert_eq!(relationship.total_reward_received, 2500);
+    });
+}
+
+#[test]
+fn relationship_is_completed_when_max_total_payout_is_lowered() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 100;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            1,
+            payout,
+            next_payout_at,
+            Some(interval),
+            None,
+            None,
+        )
+        .unwrap();
+
+        Rewards::do_payouts(next_payout_at);
+
+        assert!(Rewards::set_reward_relationship(
+            relationship_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Some(payout))
+        )
+        .is_ok());
+        assert_eq!(
+            Rewards::reward_relationships(&relationship_id).max_total_payout(),
+            Some(payout)
+        );
+
+        Rewards::do_payouts(next_payout_at + interval);
+
+        assert_eq!(MockStatusHandler::successes(), 1);
+        assert_eq!(MockStatusHandler::completions(), vec![relationship_id]);
+        assert!(!Rewards::reward_relationships(&relationship_id).is_active());
+    });
+}
+
+#[test]
+fn relationship_end_must_be_in_future() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let next_payout_at: u64 = 12222;
+
+        assert_eq!(
+            Rewards::add_reward_relationship(
+                mint_id,
+                recipient_id,
+                1,
+                1000,
+                next_payout_at,
+                None,
+                Some(10000),
+                None,
+            ),
+            Err(RewardsError::EndNotInFuture)
+        );
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            1,
+            1000,
+            next_payout_at,
+            None,
+            Some(20000),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Rewards::set_reward_relationship(
+                relationship_id,
+                None,
+                None,
+                None,
+                None,
+                Some(Some(9000)),
+                None
+            ),
+            Err(RewardsError::EndNotInFuture)
+        );
+        assert_eq!(
+            Rewards::reward_relationships(&relationship_id).ends_at_block(),
+            Some(20000)
+        );
+    });
+}
+
+#[test]
+fn legacy_relationships_are_migrated() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let next_payout_at: u64 = 12222;
+
+        let relationship_ids = (0..3)
+            .map(|_| {
+                let recipient_id = Rewards::add_recipient();
+                Rewards::add_reward_relationship(
+                    mint_id,
+                    recipient_id,
+                    1,
+                    1000,
+                    next_payout_at,
+                    Some(100),
+                    Some(20000),
+                    None,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Save the first two relationships with the legacy layout keeping the linkage.
+        for relationship_id in &relationship_ids[..2] {
+            let relationship = Rewards::reward_relationships(relationship_id);
+            let key = <RewardRelationships<Test>>::storage_linked_map_final_key(relationship_id);
+            let raw_value = unhashed::get_raw(&key).unwrap();
+            let linkage = &raw_value[relationship.encode().len()..];
+
+            let mut legacy_value = LegacyRewardRelationship {
+                recipient: relationship.recipient,
+                mint_id: relationship.mint_id,
+                account: relationship.account,
+                amount_per_payout: relationship.amount_per_payout,
+                next_payment_at_block: relationship.next_payment_at_block,
+                payout_interval: relationship.payout_interval,
+                total_reward_received: relationship.total_reward_received,
+                total_reward_missed: relationship.total_reward_missed,
+            }
+            .encode();
+            legacy_value.extend_from_slice(linkage);
+
+            unhashed::put_raw(&key, &legacy_value);
+        }
+
+        Rewards::migrate_reward_relationships_end_conditions();
+        // Migration can be repeated.
+        Rewards::migrate_reward_relationships_end_conditions();
+
+        assert_eq!(
+            <RewardRelationships<Test>>::enumerate().count(),
+            relationship_ids.len()
+        );
+
+        for (index, relationship_id) in relationship_ids.iter().enumerate() {
+            let relationship = Rewards::reward_relationships(relationship_id);
+            let expected_ends_at_block = if index < 2 { None } else { Some(20000) };
+
+            assert_eq!(relationship.ends_at_block(), expected_ends_at_block);
+            assert_eq!(relationship.max_total_payout(), None);
+            assert_eq!(relationship.next_payment_at_block(), Some(next_payout_at));
+            assert_eq!(relationship.amount_per_payout, 1000);
+        }
+    });
+}
@@ -14,6 +14,8 @@
 //! - [unset_ipns_id](./struct.Module.html#method.unset_ipns_id) - Deletes the AccountInfo with the IPNS identity for the storage provider.
 //! - [set_default_lifetime](./struct.Module.html#method.set_default_lifetime) - Sets default lifetime for storage providers accounts info.
 //! - [set_bootstrap_endpoints](./struct.Module.html#method.set_bootstrap_endpoints) - Sets bootstrap endpoints for the Colossus.
+//! - [set_service_endpoints](./struct.Module.html#method.set_service_endpoints) - Publishes the endpoint records for the storage provider.
+//! - [unset_service_endpoints](./struct.Module.html#method.unset_service_endpoints) - Deletes the endpoint records for the storage provider.
 //!
 
 // Ensure we're `no_std` when compiling for Wasm.
@@ -27,6 +29,7 @@ use rstd::prelude::*;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
+use common::ipfs::IdentifierError;
 use srml_support::{decl_event, decl_module, decl_storage, ensure};
 use system::{self, ensure_root};
 /*
@@ -42,12 +45,18 @@ use system::{self, ensure_root};
   https://github.com/multiformats/multicodec/
   https://github.com/multiformats/multihash/
 */
-/// base58 encoded IPNS identity multihash codec
+/// base58 encoded IPNS identity multihash codec. Validated with the common::ipfs module.
 pub type IPNSIdentity = Vec<u8>;
 
 /// HTTP Url string to a discovery service endpoint
 pub type Url = Vec<u8>;
 
+/// libp2p multiaddr string, e.g. '/ip4/127.0.0.1/tcp/4001'
+pub type Multiaddr = Vec<u8>;
+
+/// Service API version supported by the storage provider.
+pub type ApiVersion = u32;
+
 // The storage working group instance alias.
 pub(crate) type StorageWorkingGroupInstance = working_group::Instance2;
 
@@ -60,6 +69,11 @@ pub type StorageProviderId<T> = working_group::WorkerId<T>;
 pub(crate) const MINIMUM_LIFETIME: u32 = 600; // 1hr assuming 6s block times
 pub(crate) const DEFAULT_LIFETIME: u32 = MINIMUM_LIFETIME * 24; // 24hr
 
+pub(crate) const MAX_ENDPOINTS_PER_KIND: usize = 10;
+pub(crate) const MAX_ENDPOINT_LENGTH: usize = 256;
+pub(crate) const MAX_API_VERSIONS: usize = 10;
+pub(crate) const MAX_REGION_LENGTH: usize = 64;
+
 /// Defines the expiration date for the storage provider.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
@@ -70,6 +84,31 @@ pub struct AccountInfo<BlockNumber> {
     pub expires_at: BlockNumber,
 }
 
+/// Endpoint records published by the storage provider. Allows to reach the provider without
+/// IPNS resolution.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ServiceEndpoints {
+    /// HTTP(S) urls of the provider services.
+    pub urls: Vec<Url>,
+    /// libp2p multiaddrs of the provider node.
+    pub multiaddrs: Vec<Multiaddr>,
+    /// Supported service API versions.
+    pub api_versions: Vec<ApiVersion>,
+    /// Optional provider region, e.g. 'eu-west'.
+    pub region: Option<Vec<u8>>,
+}
+
+/// Endpoint records with the expiration date for the storage provider.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ServiceEndpointsRecord<BlockNumber> {
+    /// Published endpoints.
+    pub endpoints: ServiceEndpoints,
+    /// Block at which information expires.
+    pub expires_at: BlockNumber,
+}
+
 /// The _Service discovery_ main _Trait_.
 pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
     /// _Service discovery_ event type.
@@ -85,6 +124,10 @@ decl_storage! {
         pub AccountInfoByStorageProviderId get(account_info_by_storage_provider_id):
             map StorageProviderId<T> => AccountInfo<T::BlockNumber>;
 
+        /// Mapping of service providers' storage provider id to their endpoint records
+        pub ServiceEndpointsByStorageProviderId get(service_endpoints_by_storage_provider_id):
+            map StorageProviderId<T> => ServiceEndpointsRecord<T::BlockNumber>;
+
         /// Lifetime of an AccountInfo record in AccountInfoByAccountId map
         pub DefaultLifetime get(default_lifetime) config():
             T::BlockNumber = T::BlockNumber::from(DEFAULT_LIFETIME);
@@ -106,6 +149,16 @@ decl_event! {
         /// Params:
         /// - Id of the storage provider.
         AccountInfoRemoved(StorageProviderId),
+
+        /// Emits on updating of the service endpoints.
+        /// Params:
+        /// - Id of the storage provider.
+        ServiceEndpointsUpdated(StorageProviderId),
+
+        /// Emits on removing of the service endpoints.
+        /// Params:
+        /// - Id of the storage provider.
+        ServiceEndpointsRemoved(StorageProviderId),
     }
 }
 
@@ -124,7 +177,7 @@ decl_module! {
         ) {
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
 
-            // TODO: ensure id is a valid base58 encoded IPNS identity
+            common::ipfs::validate_ipns_identity(&id).map_err(Self::ipns_identity_error)?;
 
             //
             // == MUTATION SAFE ==
@@ -151,6 +204,42 @@ decl_module! {
             }
         }
 
+        /// Publishes the endpoint records for the storage provider. Records expire after the
+        /// default lifetime as the AccountInfo does. Requires signed storage provider credentials.
+        pub fn set_service_endpoints(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            endpoints: ServiceEndpoints,
+        ) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            Self::ensure_service_endpoints_are_valid(&endpoints)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ServiceEndpointsByStorageProviderId<T>>::insert(storage_provider_id, ServiceEndpointsRecord {
+                endpoints,
+                expires_at: <system::Module<T>>::block_number() + Self::default_lifetime(),
+            });
+
+            Self::deposit_event(RawEvent::ServiceEndpointsUpdated(storage_provider_id));
+        }
+
+        /// Deletes the endpoint records for the storage provider.
+        /// Requires signed storage provider credentials.
+        pub fn unset_service_endpoints(origin, storage_provider_id: StorageProviderId<T>) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            // == MUTATION SAFE ==
+
+            if <ServiceEndpointsByStorageProviderId<T>>::exists(storage_provider_id) {
+                <ServiceEndpointsByStorageProviderId<T>>::remove(storage_provider_id);
+                Self::deposit_event(RawEvent::ServiceEndpointsRemoved(storage_provider_id));
+            }
+        }
+
         // Privileged methods
 
         /// Sets default lifetime for storage providers accounts info. Requires root privileges.
@@ -182,4 +271,87 @@ impl<T: Trait> Module<T> {
             || <system::Module<T>>::block_number()
                 > <AccountInfoByStorageProviderId<T>>::get(storage_provider_id).expires_at
     }
+
+    /// Verifies that the endpoint records for the storage provider are still valid.
+    pub fn is_service_endpoints_record_expired(storage_provider_id: &StorageProviderId<T>) -> bool {
+        !<ServiceEndpointsByStorageProviderId<T>>::exists(storage_provider_id)
+            || <system::Module<T>>::block_number()
+                > <ServiceEndpointsByStorageProviderId<T>>::get(storage_provider_id).expires_at
+    }
+
+    /// Returns the endpoint records for the storage provider if they are not expired.
+    pub fn service_endpoints(
+        storage_provider_id: &StorageProviderId<T>,
+    ) -> Option<ServiceEndpoints> {
+        if Self::is_service_endpoints_record_expired(storage_provider_id) {
+            None
+        } else {
+            Some(<ServiceEndpointsByStorageProviderId<T>>::get(storage_provider_id).endpoints)
+        }
+    }
+
+    // Maps the IPNS identity validation error to the module error.
+    fn ipns_identity_error(error: IdentifierError) -> &'static str {
+        match error {
+            IdentifierError::Empty => "discovery: IPNS identity is empty",
+            IdentifierError::TooLong => "discovery: IPNS identity is too long",
+            IdentifierError::InvalidEncoding => "discovery: IPNS identity must be base58 encoded",
+            IdentifierError::UnsupportedMultibase => {
+                "discovery: IPNS identity multibase is not supported"
+            }
+            IdentifierError::UnsupportedCidVersion => {
+                "discovery: IPNS identity CID version is not supported"
+            }
+            IdentifierError::InvalidVarint => "discovery: IPNS identity contains an invalid varint",
+            IdentifierError::InvalidMultihash => {
+                "discovery: IPNS identity contains an invalid multihash"
+            }
+            IdentifierError::UnsupportedHashFunction => {
+                "discovery: IPNS identity hash function is not supported"
+            }
+        }
+    }
+
+    fn ensure_service_endpoints_are_valid(
+        endpoints: &ServiceEndpoints,
+    ) -> Result<(), &'static str> {
+        ensure!(
+            endpoints.urls.len() <= MAX_ENDPOINTS_PER_KIND,
+            "discovery: too many urls"
+        );
+        for url in endpoints.urls.iter() {
+            ensure!(
+                url.len() <= MAX_ENDPOINT_LENGTH
+                    && (url.starts_with(b"http://") || url.starts_with(b"https://")),
+                "discovery: url must be a valid HTTP(S) url"
+            );
+        }
+
+        ensure!(
+            endpoints.multiaddrs.len() <= MAX_ENDPOINTS_PER_KIND,
+            "discovery: too many multiaddrs"
+        );
+        for multiaddr in endpoints.multiaddrs.iter() {
+            ensure!(
+                multiaddr.len() > 1
+                    && multiaddr.len() <= MAX_ENDPOINT_LENGTH
+                    && multiaddr.starts_with(b"/"),
+                "discovery: multiaddr must be a valid libp2p multiaddr"
+            );
+        }
+
+        ensure!(
+            endpoints.api_versions.len() <= MAX_API_VERSIONS,
+            "discovery: too many api versions"
+        );
+
+        if let Some(region) = &endpoints.region {
+            ensure!(
+                !region.is_empty() && region.len() <= MAX_REGION_LENGTH,
+                "discovery: invalid region length"
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -14,6 +14,8 @@ use srml_support::{impl_outer_event, impl_outer_origin, parameter_types};
 // The storage working group instance alias.
 pub type StorageWorkingGroupInstance = working_group::Instance2;
 
+pub const TEST_IPNS_IDENTITY: &str = "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N";
+
 mod working_group_mod {
     pub use super::StorageWorkingGroupInstance;
     pub use working_group::Event;
@@ -86,15 +88,21 @@ impl hiring::Trait for Test {
     type StakeHandlerProvider = hiring::Module<Self>;
 }
 
+parameter_types! {
+    pub const MaxMintLedgerEntries: u32 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type MaxMintLedgerEntries = MaxMintLedgerEntries;
 }
 
 impl stake::Trait for Test {
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type StakingBackend = stake::PoolAccountBackend;
     type StakeId = u64;
     type SlashId = u64;
 }
@@ -132,11 +140,21 @@ impl recurringrewards::Trait for Test {
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 3;
+    pub const MaxWorkerHistoryLength: u32 = 3;
+    pub const MaxWorkerSuspensionPeriod: u64 = 10;
+    pub const SlashingDisputePeriod: u64 = 5;
+    pub const ExtraRewardsBudgetPeriod: u64 = 100;
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxWorkerHistoryLength = MaxWorkerHistoryLength;
+    type MaxWorkerSuspensionPeriod = MaxWorkerSuspensionPeriod;
+    type SlashingDisputePeriod = SlashingDisputePeriod;
+    type ExtraRewardsBudgetPeriod = ExtraRewardsBudgetPeriod;
+    type SlashingAppealThreadId = u64;
+    type SlashingAppealDiscussion = ();
 }
 
 impl timestamp::Trait for Test {
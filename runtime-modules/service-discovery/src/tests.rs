@@ -13,7 +13,7 @@ fn set_ipns_id() {
 
         let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
 
-        let identity = "alice".as_bytes().to_vec();
+        let identity = TEST_IPNS_IDENTITY.as_bytes().to_vec();
         let ttl = <Test as system::Trait>::BlockNumber::from(DEFAULT_LIFETIME);
         assert!(Discovery::set_ipns_id(
             Origin::signed(storage_provider_account_id),
@@ -61,6 +61,41 @@ fn set_ipns_id() {
     });
 }
 
+#[test]
+fn set_ipns_id_fails_with_invalid_identity() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let invalid_identities = vec![
+            ("", "discovery: IPNS identity is empty"),
+            ("alice", "discovery: IPNS identity must be base58 encoded"),
+            (
+                "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5",
+                "discovery: IPNS identity contains an invalid multihash",
+            ),
+            (
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+                "discovery: IPNS identity must be base58 encoded",
+            ),
+        ];
+
+        for (identity, expected_error) in invalid_identities {
+            assert_eq!(
+                Discovery::set_ipns_id(
+                    Origin::signed(storage_provider_account_id),
+                    storage_provider_id,
+                    identity.as_bytes().to_vec(),
+                ),
+                Err(expected_error)
+            );
+        }
+
+        assert!(!<AccountInfoByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+    });
+}
+
 #[test]
 fn unset_ipns_id() {
     initial_test_ext().execute_with(|| {
@@ -70,7 +105,7 @@ fn unset_ipns_id() {
             &storage_provider_id,
             AccountInfo {
                 expires_at: 1000,
-                identity: "alice".as_bytes().to_vec(),
+                identity: TEST_IPNS_IDENTITY.as_bytes().to_vec(),
             },
         );
 
@@ -115,7 +150,7 @@ fn is_account_info_expired() {
     initial_test_ext().execute_with(|| {
         let storage_provider_id = 1;
         let expires_at = 1000;
-        let id = "alice".as_bytes().to_vec();
+        let id = TEST_IPNS_IDENTITY.as_bytes().to_vec();
         <AccountInfoByStorageProviderId<Test>>::insert(
             &storage_provider_id,
             AccountInfo {
@@ -173,3 +208,173 @@ fn set_bootstrap_endpoints() {
         assert_eq!(Discovery::bootstrap_endpoints(), endpoints, "");
     });
 }
+
+fn default_service_endpoints() -> ServiceEndpoints {
+    ServiceEndpoints {
+        urls: vec![b"https://storage.joystream.org".to_vec()],
+        multiaddrs: vec![b"/ip4/127.0.0.1/tcp/4001".to_vec()],
+        api_versions: vec![1, 2],
+        region: Some(b"eu-west".to_vec()),
+    }
+}
+
+#[test]
+fn set_service_endpoints() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 1000;
+        System::set_block_number(current_block_number);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let endpoints = default_service_endpoints();
+        let ttl = <Test as system::Trait>::BlockNumber::from(DEFAULT_LIFETIME);
+        assert!(Discovery::set_service_endpoints(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            endpoints.clone(),
+        )
+        .is_ok());
+
+        assert_eq!(
+            Discovery::service_endpoints_by_storage_provider_id(&storage_provider_id),
+            ServiceEndpointsRecord {
+                endpoints: endpoints.clone(),
+                expires_at: current_block_number + ttl
+            }
+        );
+        assert_eq!(
+            Discovery::service_endpoints(&storage_provider_id),
+            Some(endpoints.clone())
+        );
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::ApplyExtrinsic(0),
+                event: MetaEvent::discovery(RawEvent::ServiceEndpointsUpdated(storage_provider_id)),
+                topics: vec![]
+            }
+        );
+
+        // Endpoints expire as the account info does.
+        System::set_block_number(current_block_number + ttl + 1);
+        assert!(Discovery::is_service_endpoints_record_expired(
+            &storage_provider_id
+        ));
+        assert_eq!(Discovery::service_endpoints(&storage_provider_id), None);
+
+        // Invalid storage provider data
+        let invalid_storage_provider_id = 2;
+        let invalid_storage_provider_account_id = 2;
+        assert!(Discovery::set_service_endpoints(
+            Origin::signed(invalid_storage_provider_account_id),
+            invalid_storage_provider_id,
+            endpoints,
+        )
+        .is_err());
+        assert!(!<ServiceEndpointsByStorageProviderId<Test>>::exists(
+            &invalid_storage_provider_id
+        ));
+    });
+}
+
+#[test]
+fn set_service_endpoints_fails_with_invalid_endpoints() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let mut too_many_urls = default_service_endpoints();
+        too_many_urls.urls = vec![b"https://a.org".to_vec(); MAX_ENDPOINTS_PER_KIND + 1];
+
+        let mut invalid_url = default_service_endpoints();
+        invalid_url.urls = vec![b"ftp://a.org".to_vec()];
+
+        let mut too_long_url = default_service_endpoints();
+        let mut long_url = b"https://".to_vec();
+        long_url.extend(vec![b'a'; MAX_ENDPOINT_LENGTH]);
+        too_long_url.urls = vec![long_url];
+
+        let mut too_many_multiaddrs = default_service_endpoints();
+        too_many_multiaddrs.multiaddrs =
+            vec![b"/ip4/127.0.0.1/tcp/4001".to_vec(); MAX_ENDPOINTS_PER_KIND + 1];
+
+        let mut invalid_multiaddr = default_service_endpoints();
+        invalid_multiaddr.multiaddrs = vec![b"127.0.0.1:4001".to_vec()];
+
+        let mut too_many_api_versions = default_service_endpoints();
+        too_many_api_versions.api_versions = vec![1; MAX_API_VERSIONS + 1];
+
+        let mut invalid_region = default_service_endpoints();
+        invalid_region.region = Some(vec![b'a'; MAX_REGION_LENGTH + 1]);
+
+        let invalid_endpoints = vec![
+            (too_many_urls, "discovery: too many urls"),
+            (invalid_url, "discovery: url must be a valid HTTP(S) url"),
+            (too_long_url, "discovery: url must be a valid HTTP(S) url"),
+            (too_many_multiaddrs, "discovery: too many multiaddrs"),
+            (
+                invalid_multiaddr,
+                "discovery: multiaddr must be a valid libp2p multiaddr",
+            ),
+            (too_many_api_versions, "discovery: too many api versions"),
+            (invalid_region, "discovery: invalid region length"),
+        ];
+
+        for (endpoints, error) in invalid_endpoints {
+            assert_eq!(
+                Discovery::set_service_endpoints(
+                    Origin::signed(storage_provider_account_id),
+                    storage_provider_id,
+                    endpoints,
+                ),
+                Err(error)
+            );
+        }
+
+        assert!(!<ServiceEndpointsByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+    });
+}
+
+#[test]
+fn unset_service_endpoints() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        <ServiceEndpointsByStorageProviderId<Test>>::insert(
+            &storage_provider_id,
+            ServiceEndpointsRecord {
+                endpoints: default_service_endpoints(),
+                expires_at: 1000,
+            },
+        );
+
+        assert!(Discovery::unset_service_endpoints(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id
+        )
+        .is_ok());
+        assert!(!<ServiceEndpointsByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::ApplyExtrinsic(0),
+                event: MetaEvent::discovery(RawEvent::ServiceEndpointsRemoved(storage_provider_id)),
+                topics: vec![]
+            }
+        );
+
+        // Invalid storage provider data
+        let invalid_storage_provider_id = 2;
+        let invalid_storage_provider_account_id = 2;
+        assert!(Discovery::unset_service_endpoints(
+            Origin::signed(invalid_storage_provider_account_id),
+            invalid_storage_provider_id,
+        )
+        .is_err());
+    });
+}
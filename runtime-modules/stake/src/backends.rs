@@ -0,0 +1,225 @@
+use crate::{
+    BalanceOf, LockedFunds, LockedFundsByStakeId, Module, NegativeImbalance, TotalLockedByAccount,
+    Trait, TransferFromAccountError, UnreleasedFundsByStakeId,
+};
+use rstd::cmp::min;
+use rstd::marker::PhantomData;
+use rstd::prelude::*;
+use runtime_primitives::traits::{Bounded, Zero};
+use srml_support::traits::{
+    Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, LockableCurrency,
+    WithdrawReasons,
+};
+use srml_support::StorageMap;
+
+/// Holds the funds backing the stakes. The stake module calls the backend only after the state of
+/// the stake was checked, so holding and releasing the funds matches the staked amounts.
+pub trait StakingBackend<T: Trait> {
+    /// Takes the value from the source account to back the stake.
+    fn hold_from_account(
+        stake_id: &T::StakeId,
+        source_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+    ) -> Result<(), TransferFromAccountError>;
+
+    /// Takes the value of the negative imbalance to back the stake. The optional owner is the account
+    /// the value was withdrawn from.
+    fn hold(
+        stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        opt_owner: Option<&T::AccountId>,
+    );
+
+    /// Releases the value backing the stake as a negative imbalance. The imbalance can fall short of
+    /// the value if the funds backing the stake were lost, the backend records the shortfall.
+    fn release(stake_id: &T::StakeId, value: BalanceOf<T>) -> NegativeImbalance<T>;
+}
+
+/// Moves the staked funds to the stake pool account of the module.
+pub struct PoolAccountBackend;
+
+impl<T: Trait> StakingBackend<T> for PoolAccountBackend {
+    fn hold_from_account(
+        _stake_id: &T::StakeId,
+        source_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+    ) -> Result<(), TransferFromAccountError> {
+        // We don't use T::Currency::transfer() to prevent fees being incurred.
+        let negative_imbalance = T::Currency::withdraw(
+            source_account_id,
+            value,
+            WithdrawReasons::all(),
+            ExistenceRequirement::AllowDeath,
+        )
+        .map_err(|_err| TransferFromAccountError::InsufficientBalance)?;
+
+        T::Currency::resolve_creating(&Module::<T>::stake_pool_account_id(), negative_imbalance);
+
+        Ok(())
+    }
+
+    fn hold(
+        _stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        _opt_owner: Option<&T::AccountId>,
+    ) {
+        // move the negative imbalance into the stake pool
+        T::Currency::resolve_creating(&Module::<T>::stake_pool_account_id(), imbalance);
+    }
+
+    fn release(_stake_id: &T::StakeId, value: BalanceOf<T>) -> NegativeImbalance<T> {
+        // We don't use T::Currency::transfer() to prevent fees being incurred.
+        T::Currency::withdraw(
+            &Module::<T>::stake_pool_account_id(),
+            value,
+            WithdrawReasons::all(),
+            ExistenceRequirement::AllowDeath,
+        )
+        .expect("pool had less than expected funds!")
+    }
+}
+
+/// Keeps the staked funds in the owner accounts under the lock with the provided identifier, so the
+/// funds can back other locks at the same time. The released value is slashed from the owners on
+/// demand. The stakes with no known owner of the funds are backed by the stake pool account.
+pub struct LockBackend<L>(PhantomData<L>);
+
+impl<T: Trait, L: Get<LockIdentifier>> StakingBackend<T> for LockBackend<L>
+where
+    T::Currency: LockableCurrency<T::AccountId, Moment = T::BlockNumber>,
+{
+    fn hold_from_account(
+        stake_id: &T::StakeId,
+        source_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+    ) -> Result<(), TransferFromAccountError> {
+        // The stake locks of an account cannot overlap.
+        let total_locked = Module::<T>::total_locked(source_account_id) + value;
+
+        if T::Currency::free_balance(source_account_id) < total_locked {
+            return Err(TransferFromAccountError::InsufficientBalance);
+        }
+
+        Self::lock::<T>(stake_id, source_account_id, value);
+
+        Ok(())
+    }
+
+    fn hold(
+        stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        opt_owner: Option<&T::AccountId>,
+    ) {
+        if let Some(owner) = opt_owner {
+            let value = imbalance.peek();
+
+            T::Currency::resolve_creating(owner, imbalance);
+
+            Self::lock::<T>(stake_id, owner, value);
+        } else {
+            <PoolAccountBackend as StakingBackend<T>>::hold(stake_id, imbalance, None);
+        }
+    }
+
+    fn release(stake_id: &T::StakeId, value: BalanceOf<T>) -> NegativeImbalance<T> {
+        let mut imbalance = NegativeImbalance::<T>::zero();
+        let mut remaining = value;
+        let mut locked_funds = Vec::new();
+
+        // the locked funds are released before the funds in the pool
+        for funds in Module::<T>::locked_funds(stake_id) {
+            let part = min(funds.amount, remaining);
+            remaining -= part;
+
+            if !part.is_zero() {
+                Self::unlock::<T>(&funds.owner, part);
+
+                // Slashing ignores the locks, the funds could back other locks.
+                let (slashed, unslashed) = T::Currency::slash(&funds.owner, part);
+                imbalance.subsume(slashed);
+
+                // The locks don't protect the funds from being slashed by other modules, the
+                // missing value is recorded instead of being taken from the other stakes in the pool.
+                if !unslashed.is_zero() {
+                    <UnreleasedFundsByStakeId<T>>::mutate(stake_id, |unreleased| {
+                        *unreleased += unslashed
+                    });
+                }
+            }
+
+            if funds.amount > part {
+                locked_funds.push(LockedFunds {
+                    owner: funds.owner,
+                    amount: funds.amount - part,
+                });
+            }
+        }
+
+        if locked_funds.is_empty() {
+            <LockedFundsByStakeId<T>>::remove(stake_id);
+        } else {
+            <LockedFundsByStakeId<T>>::insert(stake_id, locked_funds);
+        }
+
+        if !remaining.is_zero() {
+            imbalance.subsume(<PoolAccountBackend as StakingBackend<T>>::release(
+                stake_id, remaining,
+            ));
+        }
+
+        imbalance
+    }
+}
+
+impl<L: Get<LockIdentifier>> LockBackend<L> {
+    // Locks the value in the owner account for the stake.
+    fn lock<T: Trait>(stake_id: &T::StakeId, owner: &T::AccountId, value: BalanceOf<T>)
+    where
+        T::Currency: LockableCurrency<T::AccountId, Moment = T::BlockNumber>,
+    {
+        <LockedFundsByStakeId<T>>::mutate(stake_id, |locked_funds| {
+            if let Some(funds) = locked_funds.iter_mut().find(|funds| funds.owner == *owner) {
+                funds.amount += value;
+            } else {
+                locked_funds.push(LockedFunds {
+                    owner: owner.clone(),
+                    amount: value,
+                });
+            }
+        });
+
+        Self::set_total_locked::<T>(owner, Module::<T>::total_locked(owner) + value);
+    }
+
+    // Unlocks the value in the owner account.
+    fn unlock<T: Trait>(owner: &T::AccountId, value: BalanceOf<T>)
+    where
+        T::Currency: LockableCurrency<T::AccountId, Moment = T::BlockNumber>,
+    {
+        let total_locked = Module::<T>::total_locked(owner);
+
+        Self::set_total_locked::<T>(owner, total_locked - min(value, total_locked));
+    }
+
+    // Updates the stake lock of the account, the lock is removed with the last locked funds.
+    fn set_total_locked<T: Trait>(owner: &T::AccountId, total_locked: BalanceOf<T>)
+    where
+        T::Currency: LockableCurrency<T::AccountId, Moment = T::BlockNumber>,
+    {
+        if total_locked.is_zero() {
+            <TotalLockedByAccount<T>>::remove(owner);
+
+            T::Currency::remove_lock(L::get(), owner);
+        } else {
+            <TotalLockedByAccount<T>>::insert(owner, total_locked);
+
+            T::Currency::set_lock(
+                L::get(),
+                owner,
+                total_locked,
+                T::BlockNumber::max_value(),
+                WithdrawReasons::all(),
+            );
+        }
+    }
+}
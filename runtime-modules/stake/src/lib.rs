@@ -5,14 +5,19 @@ use rstd::prelude::*;
 
 use codec::{Codec, Decode, Encode};
 use runtime_primitives::traits::{
-    AccountIdConversion, MaybeSerialize, Member, One, SimpleArithmetic, Zero,
+    AccountIdConversion, MaybeSerialize, Member, One, Saturating, SimpleArithmetic, Zero,
 };
 use runtime_primitives::ModuleId;
-use srml_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
+use srml_support::storage::generator::StorageLinkedMap;
+use srml_support::storage::unhashed;
+use srml_support::traits::{Currency, Get, Imbalance};
 use srml_support::{decl_module, decl_storage, ensure, Parameter};
 
+use rstd::cmp::min;
 use rstd::collections::btree_map::BTreeMap;
 
+mod backends;
+pub use backends::*;
 mod errors;
 pub use errors::*;
 mod macroes;
@@ -35,6 +40,9 @@ pub trait Trait: system::Trait + Sized {
     /// Type that will handle various staking events
     type StakingEventsHandler: StakingEventsHandler<Self>;
 
+    /// Type that holds the staked funds, either in the stake pool account or locked in the owner accounts
+    type StakingBackend: StakingBackend<Self>;
+
     /// The type used as a stake identifier.
     type StakeId: Parameter
         + Member
@@ -148,13 +156,49 @@ pub struct Slash<BlockNumber, Balance> {
     /// Blocks are only counted towards slashing execution delay when active.
     pub is_active: bool,
 
-    /// The number blocks which must be finalised while in the active period before the slashing can be executed
-    pub blocks_remaining_in_active_period_for_slashing: BlockNumber,
+    /// While the slashing is active, the count of blocks finalised by the module at the end of
+    /// which the slashing is executed. While paused, the number of blocks which must be finalised
+    /// in the active period before the slashing can be executed.
+    pub slash_at_block: BlockNumber,
 
     /// Amount to slash
     pub slash_amount: Balance,
 }
 
+impl<BlockNumber, Balance> Slash<BlockNumber, Balance>
+where
+    BlockNumber: SimpleArithmetic + Copy,
+{
+    /// The number blocks which must be finalised while in the active period before the slashing
+    /// can be executed, given the count of blocks already finalised by the module.
+    pub fn blocks_remaining_in_active_period_for_slashing(
+        &self,
+        blocks_finalized: BlockNumber,
+    ) -> BlockNumber {
+        if self.is_active {
+            blocks_remaining_until(self.slash_at_block, blocks_finalized)
+        } else {
+            self.slash_at_block
+        }
+    }
+
+    /// Stops counting blocks towards the slashing execution delay, keeping the remaining blocks.
+    fn pause(&mut self, blocks_finalized: BlockNumber) {
+        if self.is_active {
+            self.slash_at_block = blocks_remaining_until(self.slash_at_block, blocks_finalized);
+            self.is_active = false;
+        }
+    }
+
+    /// Resumes counting blocks towards the slashing execution delay from the current block.
+    fn resume(&mut self, blocks_finalized: BlockNumber) {
+        if !self.is_active {
+            self.slash_at_block = last_block_of_period(self.slash_at_block, blocks_finalized);
+            self.is_active = true;
+        }
+    }
+}
+
 #[derive(Encode, Decode, Debug, Default, Eq, PartialEq)]
 pub struct UnstakingState<BlockNumber> {
     /// The block where the unstaking was initiated
@@ -164,8 +208,68 @@ pub struct UnstakingState<BlockNumber> {
     /// Blocks are only counted towards unstaking period when active.
     pub is_active: bool,
 
-    /// The number blocks which must be finalised while in the active period before the unstaking is finished
-    pub blocks_remaining_in_active_period_for_unstaking: BlockNumber,
+    /// While the unstaking is active, the count of blocks finalised by the module at the end of
+    /// which the unstaking is finished. While paused, the number of blocks which must be finalised
+    /// in the active period before the unstaking is finished.
+    pub unstake_at_block: BlockNumber,
+}
+
+impl<BlockNumber> UnstakingState<BlockNumber>
+where
+    BlockNumber: SimpleArithmetic + Copy,
+{
+    /// The number blocks which must be finalised while in the active period before the unstaking
+    /// is finished, given the count of blocks already finalised by the module.
+    pub fn blocks_remaining_in_active_period_for_unstaking(
+        &self,
+        blocks_finalized: BlockNumber,
+    ) -> BlockNumber {
+        if self.is_active {
+            blocks_remaining_until(self.unstake_at_block, blocks_finalized)
+        } else {
+            self.unstake_at_block
+        }
+    }
+
+    /// Stops counting blocks towards the unstaking period, keeping the remaining blocks.
+    fn pause(&mut self, blocks_finalized: BlockNumber) {
+        if self.is_active {
+            self.unstake_at_block = blocks_remaining_until(self.unstake_at_block, blocks_finalized);
+            self.is_active = false;
+        }
+    }
+
+    /// Resumes counting blocks towards the unstaking period from the current block.
+    fn resume(&mut self, blocks_finalized: BlockNumber) {
+        if !self.is_active {
+            self.unstake_at_block = last_block_of_period(self.unstake_at_block, blocks_finalized);
+            self.is_active = true;
+        }
+    }
+}
+
+/// Number of blocks which must be finalised, counting the current block, until the end of the due block.
+fn blocks_remaining_until<BlockNumber: SimpleArithmetic + Copy>(
+    due_at_block: BlockNumber,
+    now: BlockNumber,
+) -> BlockNumber {
+    if due_at_block >= now {
+        due_at_block - now + One::one()
+    } else {
+        One::one()
+    }
+}
+
+/// The last block of a period of the provided number of blocks, counting the current block.
+fn last_block_of_period<BlockNumber: SimpleArithmetic + Copy>(
+    period: BlockNumber,
+    now: BlockNumber,
+) -> BlockNumber {
+    if period > Zero::zero() {
+        now + period - One::one()
+    } else {
+        now
+    }
 }
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq)]
@@ -205,31 +309,36 @@ where
     Balance: SimpleArithmetic + Copy,
     SlashId: Ord + Copy,
 {
-    /// Iterates over all ongoing slashes and decrements blocks_remaining_in_active_period_for_slashing of active slashes (advancing the timer).
-    /// Returns true if there was at least one slashe that was active and had its timer advanced.
-    fn advance_slashing_timer(&mut self) -> bool {
-        let mut did_advance_timers = false;
-
-        for (_slash_id, slash) in self.ongoing_slashes.iter_mut() {
-            if slash.is_active
-                && slash.blocks_remaining_in_active_period_for_slashing > Zero::zero()
-            {
-                slash.blocks_remaining_in_active_period_for_slashing -= One::one();
-                did_advance_timers = true;
+    /// Returns the blocks at which the active slashes and the active unstaking are due, without duplicates.
+    fn due_blocks(&self) -> Vec<BlockNumber> {
+        let mut due_blocks = self
+            .ongoing_slashes
+            .values()
+            .filter(|slash| slash.is_active)
+            .map(|slash| slash.slash_at_block)
+            .collect::<Vec<_>>();
+
+        if let StakedStatus::Unstaking(ref unstaking_state) = self.staked_status {
+            if unstaking_state.is_active {
+                due_blocks.push(unstaking_state.unstake_at_block);
             }
         }
 
-        did_advance_timers
+        due_blocks.sort();
+        due_blocks.dedup();
+
+        due_blocks
     }
 
     /// Returns pair of slash_id and slashes that should be executed
-    fn get_slashes_to_finalize(&mut self) -> Vec<(SlashId, Slash<BlockNumber, Balance>)> {
+    fn get_slashes_to_finalize(
+        &mut self,
+        blocks_finalized: BlockNumber,
+    ) -> Vec<(SlashId, Slash<BlockNumber, Balance>)> {
         let slashes_to_finalize = self
             .ongoing_slashes
             .iter()
-            .filter(|(_, slash)| {
-                slash.blocks_remaining_in_active_period_for_slashing == Zero::zero()
-            })
+            .filter(|(_, slash)| slash.is_active && slash.slash_at_block <= blocks_finalized)
             .map(|(slash_id, _)| *slash_id)
             .collect::<Vec<_>>();
 
@@ -267,10 +376,14 @@ where
 
     /// For all slahes that should be executed, will apply the Slash to the staked amount, and drop it from the ongoing slashes map.
     /// Returns a vector of the executed slashes outcome: (SlashId, Slashed Amount, Remaining Staked Amount)
-    fn finalize_slashes(&mut self, minimum_balance: Balance) -> Vec<(SlashId, Balance, Balance)> {
+    fn finalize_slashes(
+        &mut self,
+        minimum_balance: Balance,
+        blocks_finalized: BlockNumber,
+    ) -> Vec<(SlashId, Balance, Balance)> {
         let mut finalized_slashes: Vec<(SlashId, Balance, Balance)> = vec![];
 
-        for (slash_id, slash) in self.get_slashes_to_finalize().iter() {
+        for (slash_id, slash) in self.get_slashes_to_finalize(blocks_finalized).iter() {
             // apply the slashing and get back actual amount slashed
             let slashed_amount = self.apply_slash(slash.slash_amount, minimum_balance);
 
@@ -320,6 +433,22 @@ where
         self.staking_status == StakingStatus::NotStaked
     }
 
+    /// Returns the blocks at which the active slashes and the active unstaking of the stake are due.
+    fn due_blocks(&self) -> Vec<BlockNumber> {
+        match self.staking_status {
+            StakingStatus::Staked(ref staked_state) => staked_state.due_blocks(),
+            _ => vec![],
+        }
+    }
+
+    /// Whether any slashing of the stake is ongoing, irrespective if active or not.
+    fn has_ongoing_slashes(&self) -> bool {
+        match self.staking_status {
+            StakingStatus::Staked(ref staked_state) => !staked_state.ongoing_slashes.is_empty(),
+            _ => false,
+        }
+    }
+
     /// If staking status is Staked and not currently Unstaking it will increase the staked amount by value.
     /// On success returns new total staked value.
     /// Increasing stake by zero is an error.
@@ -436,6 +565,7 @@ where
         slash_amount: Balance,
         slash_period: BlockNumber,
         now: BlockNumber,
+        blocks_finalized: BlockNumber,
     ) -> Result<SlashId, InitiateSlashingError> {
         ensure!(
             slash_period > Zero::zero(),
@@ -455,7 +585,7 @@ where
                     slash_id,
                     Slash {
                         is_active: true,
-                        blocks_remaining_in_active_period_for_slashing: slash_period,
+                        slash_at_block: last_block_of_period(slash_period, blocks_finalized),
                         slash_amount,
                         started_at_block: now,
                     },
@@ -464,7 +594,7 @@ where
                 // pause Unstaking if unstaking is active
                 if let StakedStatus::Unstaking(ref mut unstaking_state) = staked_state.staked_status
                 {
-                    unstaking_state.is_active = false;
+                    unstaking_state.pause(blocks_finalized);
                 }
 
                 Ok(slash_id)
@@ -473,13 +603,17 @@ where
         }
     }
 
-    fn pause_slashing(&mut self, slash_id: &SlashId) -> Result<(), PauseSlashingError> {
+    fn pause_slashing(
+        &mut self,
+        slash_id: &SlashId,
+        blocks_finalized: BlockNumber,
+    ) -> Result<(), PauseSlashingError> {
         match self.staking_status {
             StakingStatus::Staked(ref mut staked_state) => {
                 match staked_state.ongoing_slashes.get_mut(slash_id) {
                     Some(ref mut slash) => {
                         if slash.is_active {
-                            slash.is_active = false;
+                            slash.pause(blocks_finalized);
                             Ok(())
                         } else {
                             Err(PauseSlashingError::AlreadyPaused)
@@ -492,7 +626,11 @@ where
         }
     }
 
-    fn resume_slashing(&mut self, slash_id: &SlashId) -> Result<(), ResumeSlashingError> {
+    fn resume_slashing(
+        &mut self,
+        slash_id: &SlashId,
+        blocks_finalized: BlockNumber,
+    ) -> Result<(), ResumeSlashingError> {
         match self.staking_status {
             StakingStatus::Staked(ref mut staked_state) => {
                 match staked_state.ongoing_slashes.get_mut(slash_id) {
@@ -500,7 +638,7 @@ where
                         if slash.is_active {
                             Err(ResumeSlashingError::NotPaused)
                         } else {
-                            slash.is_active = true;
+                            slash.resume(blocks_finalized);
                             Ok(())
                         }
                     }
@@ -511,7 +649,11 @@ where
         }
     }
 
-    fn cancel_slashing(&mut self, slash_id: &SlashId) -> Result<(), CancelSlashingError> {
+    fn cancel_slashing(
+        &mut self,
+        slash_id: &SlashId,
+        blocks_finalized: BlockNumber,
+    ) -> Result<(), CancelSlashingError> {
         match self.staking_status {
             StakingStatus::Staked(ref mut staked_state) => {
                 if staked_state.ongoing_slashes.remove(slash_id).is_none() {
@@ -523,7 +665,7 @@ where
                     if let StakedStatus::Unstaking(ref mut unstaking_state) =
                         staked_state.staked_status
                     {
-                        unstaking_state.is_active = true;
+                        unstaking_state.resume(blocks_finalized);
                     }
                 }
 
@@ -556,6 +698,7 @@ where
         &mut self,
         unstaking_period: BlockNumber,
         now: BlockNumber,
+        blocks_finalized: BlockNumber,
     ) -> Result<(), InitiateUnstakingError> {
         ensure!(
             unstaking_period > Zero::zero(),
@@ -579,7 +722,7 @@ where
                 staked_state.staked_status = StakedStatus::Unstaking(UnstakingState {
                     started_at_block: now,
                     is_active: true,
-                    blocks_remaining_in_active_period_for_unstaking: unstaking_period,
+                    unstake_at_block: last_block_of_period(unstaking_period, blocks_finalized),
                 });
 
                 Ok(())
@@ -590,12 +733,15 @@ where
         }
     }
 
-    fn pause_unstaking(&mut self) -> Result<(), PauseUnstakingError> {
+    fn pause_unstaking(
+        &mut self,
+        blocks_finalized: BlockNumber,
+    ) -> Result<(), PauseUnstakingError> {
         match self.staking_status {
             StakingStatus::Staked(ref mut staked_state) => match staked_state.staked_status {
                 StakedStatus::Unstaking(ref mut unstaking_state) => {
                     if unstaking_state.is_active {
-                        unstaking_state.is_active = false;
+                        unstaking_state.pause(blocks_finalized);
                         Ok(())
                     } else {
                         Err(PauseUnstakingError::AlreadyPaused)
@@ -607,12 +753,15 @@ where
         }
     }
 
-    fn resume_unstaking(&mut self) -> Result<(), ResumeUnstakingError> {
+    fn resume_unstaking(
+        &mut self,
+        blocks_finalized: BlockNumber,
+    ) -> Result<(), ResumeUnstakingError> {
         match self.staking_status {
             StakingStatus::Staked(ref mut staked_state) => match staked_state.staked_status {
                 StakedStatus::Unstaking(ref mut unstaking_state) => {
                     if !unstaking_state.is_active {
-                        unstaking_state.is_active = true;
+                        unstaking_state.resume(blocks_finalized);
                         Ok(())
                     } else {
                         Err(ResumeUnstakingError::NotPaused)
@@ -627,53 +776,39 @@ where
     fn finalize_slashing(
         &mut self,
         minimum_balance: Balance,
-    ) -> (bool, Vec<(SlashId, Balance, Balance)>) {
+        blocks_finalized: BlockNumber,
+    ) -> Vec<(SlashId, Balance, Balance)> {
         match self.staking_status {
             StakingStatus::Staked(ref mut staked_state) => {
-                // tick the slashing timer
-                let did_update = staked_state.advance_slashing_timer();
-
                 // finalize and apply slashes
-                let slashed = staked_state.finalize_slashes(minimum_balance);
-
-                (did_update, slashed)
+                staked_state.finalize_slashes(minimum_balance, blocks_finalized)
             }
-            _ => (false, vec![]),
+            _ => vec![],
         }
     }
 
-    fn finalize_unstaking(&mut self) -> (bool, Option<Balance>) {
-        let (did_update, unstaked) = match self.staking_status {
+    fn finalize_unstaking(&mut self, blocks_finalized: BlockNumber) -> Option<Balance> {
+        let unstaked = match self.staking_status {
             StakingStatus::Staked(ref mut staked_state) => match staked_state.staked_status {
                 StakedStatus::Unstaking(ref mut unstaking_state) => {
                     // if all slashes were processed and there are no more active slashes
                     // resume unstaking
                     if staked_state.ongoing_slashes.is_empty() {
-                        unstaking_state.is_active = true;
-                    }
-
-                    // tick the unstaking timer
-                    if unstaking_state.is_active
-                        && unstaking_state.blocks_remaining_in_active_period_for_unstaking
-                            > Zero::zero()
-                    {
-                        // tick the unstaking timer
-                        unstaking_state.blocks_remaining_in_active_period_for_unstaking -=
-                            One::one();
+                        unstaking_state.resume(blocks_finalized);
                     }
 
                     // finalize unstaking
-                    if unstaking_state.blocks_remaining_in_active_period_for_unstaking
-                        == Zero::zero()
+                    if unstaking_state.is_active
+                        && unstaking_state.unstake_at_block <= blocks_finalized
                     {
-                        (true, Some(staked_state.staked_amount))
+                        Some(staked_state.staked_amount)
                     } else {
-                        (unstaking_state.is_active, None)
+                        None
                     }
                 }
-                _ => (false, None),
+                _ => None,
             },
-            _ => (false, None),
+            _ => None,
         };
 
         // if unstaking was finalized transition to NotStaked state
@@ -681,25 +816,153 @@ where
             self.staking_status = StakingStatus::NotStaked;
         }
 
-        (did_update, unstaked)
+        unstaked
     }
 
     fn finalize_slashing_and_unstaking(
         &mut self,
         minimum_balance: Balance,
-    ) -> (bool, Vec<(SlashId, Balance, Balance)>, Option<Balance>) {
-        let (did_update_slashing_timers, slashed) = self.finalize_slashing(minimum_balance);
+        blocks_finalized: BlockNumber,
+    ) -> (Vec<(SlashId, Balance, Balance)>, Option<Balance>) {
+        let slashed = self.finalize_slashing(minimum_balance, blocks_finalized);
 
-        let (did_update_unstaking_timer, unstaked) = self.finalize_unstaking();
+        let unstaked = self.finalize_unstaking(blocks_finalized);
 
-        (
-            did_update_slashing_timers || did_update_unstaking_timer,
-            slashed,
-            unstaked,
-        )
+        (slashed, unstaked)
+    }
+}
+
+/// Slash layout before the slashing due block was introduced.
+#[derive(Encode, Decode)]
+struct LegacySlash<BlockNumber, Balance> {
+    started_at_block: BlockNumber,
+    is_active: bool,
+    blocks_remaining_in_active_period_for_slashing: BlockNumber,
+    slash_amount: Balance,
+}
+
+/// Unstaking state layout before the unstaking due block was introduced.
+#[derive(Encode, Decode)]
+struct LegacyUnstakingState<BlockNumber> {
+    started_at_block: BlockNumber,
+    is_active: bool,
+    blocks_remaining_in_active_period_for_unstaking: BlockNumber,
+}
+
+#[derive(Encode, Decode)]
+enum LegacyStakedStatus<BlockNumber> {
+    Normal,
+    Unstaking(LegacyUnstakingState<BlockNumber>),
+}
+
+#[derive(Encode, Decode)]
+struct LegacyStakedState<BlockNumber, Balance, SlashId: Ord> {
+    staked_amount: Balance,
+    staked_status: LegacyStakedStatus<BlockNumber>,
+    next_slash_id: SlashId,
+    ongoing_slashes: BTreeMap<SlashId, LegacySlash<BlockNumber, Balance>>,
+}
+
+#[derive(Encode, Decode)]
+enum LegacyStakingStatus<BlockNumber, Balance, SlashId: Ord> {
+    NotStaked,
+    Staked(LegacyStakedState<BlockNumber, Balance, SlashId>),
+}
+
+/// Stake layout before the slashing and unstaking due blocks were introduced.
+#[derive(Encode, Decode)]
+struct LegacyStake<BlockNumber, Balance, SlashId: Ord> {
+    created: BlockNumber,
+    staking_status: LegacyStakingStatus<BlockNumber, Balance, SlashId>,
+}
+
+impl<BlockNumber, Balance, SlashId> LegacyStake<BlockNumber, Balance, SlashId>
+where
+    BlockNumber: SimpleArithmetic + Copy,
+    SlashId: Ord,
+{
+    /// Converts the remaining blocks of the active slashes and unstaking into due blocks, counting
+    /// from the provided count of blocks finalised by the module. The paused ones keep the
+    /// remaining blocks.
+    fn into_stake(self, blocks_finalized: BlockNumber) -> Stake<BlockNumber, Balance, SlashId> {
+        let staking_status = match self.staking_status {
+            LegacyStakingStatus::NotStaked => StakingStatus::NotStaked,
+            LegacyStakingStatus::Staked(staked_state) => {
+                let ongoing_slashes = staked_state
+                    .ongoing_slashes
+                    .into_iter()
+                    .map(|(slash_id, slash)| {
+                        let mut new_slash = Slash {
+                            started_at_block: slash.started_at_block,
+                            is_active: false,
+                            slash_at_block: slash.blocks_remaining_in_active_period_for_slashing,
+                            slash_amount: slash.slash_amount,
+                        };
+
+                        if slash.is_active {
+                            new_slash.resume(blocks_finalized);
+                        }
+
+                        (slash_id, new_slash)
+                    })
+                    .collect::<BTreeMap<_, _>>();
+
+                let staked_status = match staked_state.staked_status {
+                    LegacyStakedStatus::Normal => StakedStatus::Normal,
+                    LegacyStakedStatus::Unstaking(unstaking_state) => {
+                        let mut new_unstaking_state = UnstakingState {
+                            started_at_block: unstaking_state.started_at_block,
+                            is_active: false,
+                            unstake_at_block: unstaking_state
+                                .blocks_remaining_in_active_period_for_unstaking,
+                        };
+
+                        // unstaking was resumed on finalization whenever there were no slashes
+                        if unstaking_state.is_active || ongoing_slashes.is_empty() {
+                            new_unstaking_state.resume(blocks_finalized);
+                        }
+
+                        StakedStatus::Unstaking(new_unstaking_state)
+                    }
+                };
+
+                StakingStatus::Staked(StakedState {
+                    staked_amount: staked_state.staked_amount,
+                    staked_status,
+                    next_slash_id: staked_state.next_slash_id,
+                    ongoing_slashes,
+                })
+            }
+        };
+
+        Stake {
+            created: self.created,
+            staking_status,
+        }
     }
 }
 
+/// Funds provided to a stake by an account. The share follows the funder's part of the staked
+/// amount, it is reduced pro rata when the stake is slashed or decreased.
+#[derive(Encode, Decode, Clone, Debug, Default, Eq, PartialEq)]
+pub struct StakeFunding<AccountId, Balance> {
+    /// Account which provided the funds
+    pub funder: AccountId,
+
+    /// Part of the staked amount which belongs to the funder
+    pub share: Balance,
+}
+
+/// Funds of an account locked for a stake by the lock based staking backend.
+#[derive(Encode, Decode, Clone, Debug, Default, Eq, PartialEq)]
+pub struct LockedFunds<AccountId, Balance> {
+    /// Account which holds the locked funds
+    pub owner: AccountId,
+
+    /// Locked amount
+    pub amount: Balance,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct SlashImmediateOutcome<Balance, NegativeImbalance> {
     pub caused_unstake: bool,
@@ -716,6 +979,27 @@ decl_storage! {
         /// Identifier value for next stake, and count of total stakes created (not necessarily the number of current
         /// stakes in the Stakes map as stakes can be removed.)
         pub StakesCreated get(stakes_created): T::StakeId;
+
+        /// Number of blocks in which the slashing and unstaking were finalised. The slashing and
+        /// unstaking periods are counted in these blocks.
+        pub BlocksFinalized get(blocks_finalized): T::BlockNumber;
+
+        /// Stakes with an active slashing or unstaking due at the end of the block, by the count of
+        /// blocks finalised before it.
+        pub StakeIdsByDueBlock get(stake_ids_by_due_block): map T::BlockNumber => Vec<T::StakeId>;
+
+        /// Funding sources of the stakes staked with known funders, and their shares.
+        pub StakeFundingByStakeId get(stake_funding): map T::StakeId => Vec<StakeFunding<T::AccountId, BalanceOf<T>>>;
+
+        /// Funds locked in the owner accounts for the stakes, used by the lock based staking backend.
+        pub LockedFundsByStakeId get(locked_funds): map T::StakeId => Vec<LockedFunds<T::AccountId, BalanceOf<T>>>;
+
+        /// Total amount locked for the stakes in the account, used by the lock based staking backend.
+        pub TotalLockedByAccount get(total_locked): map T::AccountId => BalanceOf<T>;
+
+        /// Released value the lock based staking backend could not slash from the owner accounts, as
+        /// the locked funds were taken from the accounts by other means (e.g. slashed by other modules).
+        pub UnreleasedFundsByStakeId get(unreleased_funds): map T::StakeId => BalanceOf<T>;
     }
 }
 
@@ -729,6 +1013,7 @@ decl_module! {
 
 impl<T: Trait> Module<T> {
     /// The account ID of theis module which holds all the staked balance. (referred to as the stake pool)
+    /// The lock based staking backend keeps only the stakes with no known owner of the funds in the pool.
     ///
     /// This actually does computation. If you need to keep using it, then make sure you cache the
     /// value and only call this once. Is it deterministic?
@@ -760,6 +1045,8 @@ impl<T: Trait> Module<T> {
         );
 
         <Stakes<T>>::remove(stake_id);
+        <StakeFundingByStakeId<T>>::remove(stake_id);
+        <UnreleasedFundsByStakeId<T>>::remove(stake_id);
 
         Ok(())
     }
@@ -785,6 +1072,15 @@ impl<T: Trait> Module<T> {
     pub fn stake(
         stake_id: &T::StakeId,
         imbalance: NegativeImbalance<T>,
+    ) -> Result<(), StakeActionError<StakingError>> {
+        Self::stake_with_opt_owner(stake_id, imbalance, None)
+    }
+
+    // Starts staking the imbalance, the optional owner is the account the imbalance was withdrawn from.
+    fn stake_with_opt_owner(
+        stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        opt_owner: Option<&T::AccountId>,
     ) -> Result<(), StakeActionError<StakingError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
@@ -794,7 +1090,7 @@ impl<T: Trait> Module<T> {
 
         <Stakes<T>>::insert(stake_id, stake);
 
-        Self::deposit_funds_into_stake_pool(imbalance);
+        T::StakingBackend::hold(stake_id, imbalance, opt_owner);
 
         Ok(())
     }
@@ -809,55 +1105,39 @@ impl<T: Trait> Module<T> {
         stake.start_staking(value, T::Currency::minimum_balance())?;
 
         // Its important to only do the transfer as the last step to ensure starting staking was possible.
-        Self::transfer_funds_from_account_into_stake_pool(source_account_id, value)?;
+        T::StakingBackend::hold_from_account(stake_id, source_account_id, value)?;
 
         <Stakes<T>>::insert(stake_id, stake);
 
+        Self::add_stake_funding(stake_id, source_account_id, value);
+
         Ok(())
     }
 
-    /// Moves funds from specified account into the module's account
-    fn transfer_funds_from_account_into_stake_pool(
-        source: &T::AccountId,
-        value: BalanceOf<T>,
-    ) -> Result<(), TransferFromAccountError> {
-        // We don't use T::Currency::transfer() to prevent fees being incurred.
-        let negative_imbalance = T::Currency::withdraw(
-            source,
-            value,
-            WithdrawReasons::all(),
-            ExistenceRequirement::AllowDeath,
-        )
-        .map_err(|_err| TransferFromAccountError::InsufficientBalance)?;
+    /// Same as stake(), but records the funder as the source of the staked value, so the
+    /// value can be returned to it on unstaking.
+    pub fn stake_funded_by(
+        stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        funder: &T::AccountId,
+    ) -> Result<(), StakeActionError<StakingError>> {
+        let value = imbalance.peek();
 
-        Self::deposit_funds_into_stake_pool(negative_imbalance);
-        Ok(())
-    }
+        Self::stake_with_opt_owner(stake_id, imbalance, Some(funder))?;
 
-    fn deposit_funds_into_stake_pool(imbalance: NegativeImbalance<T>) {
-        // move the negative imbalance into the stake pool
-        T::Currency::resolve_creating(&Self::stake_pool_account_id(), imbalance);
-    }
+        Self::add_stake_funding(stake_id, funder, value);
 
-    /// Moves funds from the module's account into specified account. Should never fail if used internally.
-    /// Will panic! if value exceeds balance in the pool.
-    fn transfer_funds_from_pool_into_account(destination: &T::AccountId, value: BalanceOf<T>) {
-        let imbalance = Self::withdraw_funds_from_stake_pool(value);
-        T::Currency::resolve_creating(destination, imbalance);
+        Ok(())
     }
 
-    /// Withdraws value from the pool and returns a NegativeImbalance.
-    /// As long as it is only called internally when executing slashes and unstaking, it
-    /// should never fail as the pool balance is always in sync with total amount at stake.
-    fn withdraw_funds_from_stake_pool(value: BalanceOf<T>) -> NegativeImbalance<T> {
-        // We don't use T::Currency::transfer() to prevent fees being incurred.
-        T::Currency::withdraw(
-            &Self::stake_pool_account_id(),
-            value,
-            WithdrawReasons::all(),
-            ExistenceRequirement::AllowDeath,
-        )
-        .expect("pool had less than expected funds!")
+    /// Releases the value backing the stake into the destination account. Should never fail if used internally.
+    fn release_funds_into_account(
+        stake_id: &T::StakeId,
+        destination: &T::AccountId,
+        value: BalanceOf<T>,
+    ) {
+        let imbalance = T::StakingBackend::release(stake_id, value);
+        T::Currency::resolve_creating(destination, imbalance);
     }
 
     /// Dry run to see if the state of stake allows for increasing stake. This should be called
@@ -881,20 +1161,30 @@ impl<T: Trait> Module<T> {
     pub fn increase_stake(
         stake_id: &T::StakeId,
         imbalance: NegativeImbalance<T>,
+    ) -> Result<BalanceOf<T>, StakeActionError<IncreasingStakeError>> {
+        Self::increase_stake_with_opt_owner(stake_id, imbalance, None)
+    }
+
+    // Increases the stake by the imbalance, the optional owner is the account the imbalance was withdrawn from.
+    fn increase_stake_with_opt_owner(
+        stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        opt_owner: Option<&T::AccountId>,
     ) -> Result<BalanceOf<T>, StakeActionError<IncreasingStakeError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
         let total_staked_amount = stake.increase_stake(imbalance.peek())?;
         <Stakes<T>>::insert(stake_id, stake);
 
-        Self::deposit_funds_into_stake_pool(imbalance);
+        T::StakingBackend::hold(stake_id, imbalance, opt_owner);
 
         Ok(total_staked_amount)
     }
 
     /// Provided the stake exists and is in state Staked.Normal, and the given source account covers the amount,
     /// then the amount is transferred to the module's account, and the corresponding staked_amount is increased
-    /// by the amount. New value of staked_amount is returned.
+    /// by the amount. New value of staked_amount is returned. The source account is added to the funders of the
+    /// stakes with known funders.
     pub fn increase_stake_from_account(
         stake_id: &T::StakeId,
         source_account_id: &T::AccountId,
@@ -911,10 +1201,32 @@ impl<T: Trait> Module<T> {
 
         let total_staked_amount = stake.increase_stake(value)?;
 
-        Self::transfer_funds_from_account_into_stake_pool(&source_account_id, value)?;
+        T::StakingBackend::hold_from_account(stake_id, &source_account_id, value)?;
 
         <Stakes<T>>::insert(stake_id, stake);
 
+        // Keep track of the funding only for the stakes with known funders.
+        if <StakeFundingByStakeId<T>>::exists(stake_id) {
+            Self::add_stake_funding(stake_id, source_account_id, value);
+        }
+
+        Ok(total_staked_amount)
+    }
+
+    /// Same as increase_stake(), but records the funder as the source of the added value, so the
+    /// value can be returned to it on unstaking.
+    pub fn increase_stake_funded_by(
+        stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+        funder: &T::AccountId,
+    ) -> Result<BalanceOf<T>, StakeActionError<IncreasingStakeError>> {
+        let value = imbalance.peek();
+
+        let total_staked_amount =
+            Self::increase_stake_with_opt_owner(stake_id, imbalance, Some(funder))?;
+
+        Self::add_stake_funding(stake_id, funder, value);
+
         Ok(total_staked_amount)
     }
 
@@ -941,7 +1253,9 @@ impl<T: Trait> Module<T> {
 
         <Stakes<T>>::insert(stake_id, stake);
 
-        let imbalance = Self::withdraw_funds_from_stake_pool(deduct_from_pool);
+        Self::reduce_stake_funding(stake_id, deduct_from_pool);
+
+        let imbalance = T::StakingBackend::release(stake_id, deduct_from_pool);
 
         Ok((staked_amount, imbalance))
     }
@@ -961,11 +1275,38 @@ impl<T: Trait> Module<T> {
 
         <Stakes<T>>::insert(stake_id, stake);
 
-        Self::transfer_funds_from_pool_into_account(&destination_account_id, deduct_from_pool);
+        Self::reduce_stake_funding(stake_id, deduct_from_pool);
+
+        Self::release_funds_into_account(stake_id, &destination_account_id, deduct_from_pool);
 
         Ok(staked_amount)
     }
 
+    /// Provided the stake exists and is in state Staked.Normal, and the given stake holds at least the value,
+    /// then the value is refunded to the funders of the stake pro rata to their shares, and the corresponding
+    /// staked_amount is decreased by the value. Returns the new value of staked_amount and the part of the value
+    /// not covered by the recorded funders.
+    pub fn decrease_stake_to_funders(
+        stake_id: &T::StakeId,
+        value: BalanceOf<T>,
+    ) -> Result<(BalanceOf<T>, NegativeImbalance<T>), StakeActionError<DecreasingStakeError>> {
+        let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
+
+        let (deduct_from_pool, staked_amount) =
+            stake.decrease_stake(value, T::Currency::minimum_balance())?;
+
+        <Stakes<T>>::insert(stake_id, stake);
+
+        let imbalance = T::StakingBackend::release(stake_id, deduct_from_pool);
+
+        // refund before reducing the shares, the funding record is removed with the last share
+        let imbalance = Self::refund_stake_funders(stake_id, imbalance);
+
+        Self::reduce_stake_funding(stake_id, deduct_from_pool);
+
+        Ok((staked_amount, imbalance))
+    }
+
     /// Slashes a stake with immediate effect, returns the outcome of the slashing.
     /// Can optionally specify if slashing can result in immediate unstaking if staked amount
     /// after slashing goes to zero.
@@ -991,14 +1332,18 @@ impl<T: Trait> Module<T> {
         let caused_unstake = unstake_on_zero_staked && remaining_stake == BalanceOf::<T>::zero();
 
         if caused_unstake {
+            let due_blocks = stake.due_blocks();
             stake.staking_status = StakingStatus::NotStaked;
+            Self::update_due_blocks(stake_id, &due_blocks, &stake.due_blocks());
         }
 
         // Update state before calling handlers!
         <Stakes<T>>::insert(stake_id, stake);
 
-        // Remove the slashed amount from the pool
-        let slashed_imbalance = Self::withdraw_funds_from_stake_pool(actually_slashed);
+        Self::reduce_stake_funding(stake_id, actually_slashed);
+
+        // Release the slashed amount from the staking backend
+        let slashed_imbalance = T::StakingBackend::release(stake_id, actually_slashed);
 
         // Notify slashing event handler before unstaked handler.
         let remaining_imbalance_after_slash_handler = T::StakingEventsHandler::slashed(
@@ -1012,11 +1357,15 @@ impl<T: Trait> Module<T> {
         let remaining_imbalance = if caused_unstake {
             // Notify unstaked handler with any remaining unused imbalance
             // from the slashing event handler
-            T::StakingEventsHandler::unstaked(
+            let remaining_imbalance = T::StakingEventsHandler::unstaked(
                 &stake_id,
                 staked_amount_before_slash,
                 remaining_imbalance_after_slash_handler,
-            )
+            );
+
+            <StakeFundingByStakeId<T>>::remove(stake_id);
+
+            remaining_imbalance
         } else {
             remaining_imbalance_after_slash_handler
         };
@@ -1037,12 +1386,16 @@ impl<T: Trait> Module<T> {
     ) -> Result<T::SlashId, StakeActionError<InitiateSlashingError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
+        let due_blocks = stake.due_blocks();
+
         let slash_id = stake.initiate_slashing(
             slash_amount,
             slash_period,
             <system::Module<T>>::block_number(),
+            Self::blocks_finalized(),
         )?;
 
+        Self::update_due_blocks(stake_id, &due_blocks, &stake.due_blocks());
         <Stakes<T>>::insert(stake_id, stake);
         Ok(slash_id)
     }
@@ -1054,13 +1407,29 @@ impl<T: Trait> Module<T> {
     ) -> Result<(), StakeActionError<PauseSlashingError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
-        stake.pause_slashing(slash_id)?;
+        let due_blocks = stake.due_blocks();
+
+        stake.pause_slashing(slash_id, Self::blocks_finalized())?;
+
+        Self::update_due_blocks(stake_id, &due_blocks, &stake.due_blocks());
 
         <Stakes<T>>::insert(stake_id, stake);
 
         Ok(())
     }
 
+    pub fn ensure_can_pause_slashing(
+        stake_id: &T::StakeId,
+        slash_id: &T::SlashId,
+    ) -> Result<(), StakeActionError<PauseSlashingError>> {
+        let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
+
+        stake
+            .pause_slashing(slash_id, Self::blocks_finalized())
+            .err()
+            .map_or(Ok(()), |err| Err(StakeActionError::Error(err)))
+    }
+
     /// Resume a currently paused ongoing slashing.
     pub fn resume_slashing(
         stake_id: &T::StakeId,
@@ -1068,7 +1437,11 @@ impl<T: Trait> Module<T> {
     ) -> Result<(), StakeActionError<ResumeSlashingError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
-        stake.resume_slashing(slash_id)?;
+        let due_blocks = stake.due_blocks();
+
+        stake.resume_slashing(slash_id, Self::blocks_finalized())?;
+
+        Self::update_due_blocks(stake_id, &due_blocks, &stake.due_blocks());
 
         <Stakes<T>>::insert(stake_id, stake);
         Ok(())
@@ -1081,7 +1454,11 @@ impl<T: Trait> Module<T> {
     ) -> Result<(), StakeActionError<CancelSlashingError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
-        stake.cancel_slashing(slash_id)?;
+        let due_blocks = stake.due_blocks();
+
+        stake.cancel_slashing(slash_id, Self::blocks_finalized())?;
+
+        Self::update_due_blocks(stake_id, &due_blocks, &stake.due_blocks());
 
         <Stakes<T>>::insert(stake_id, stake);
 
@@ -1096,14 +1473,22 @@ impl<T: Trait> Module<T> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
         if let Some(unstaking_period) = unstaking_period {
-            stake.initiate_unstaking(unstaking_period, <system::Module<T>>::block_number())?;
+            let due_blocks = stake.due_blocks();
+            stake.initiate_unstaking(
+                unstaking_period,
+                <system::Module<T>>::block_number(),
+                Self::blocks_finalized(),
+            )?;
+            Self::update_due_blocks(stake_id, &due_blocks, &stake.due_blocks());
             <Stakes<T>>::insert(stake_id, stake);
         } else {
             let staked_amount = stake.unstake()?;
             <Stakes<T>>::insert(stake_id, stake);
 
-            let imbalance = Self::withdraw_funds_from_stake_pool(staked_amount);
+            let imbalance = T::StakingBackend::release(stake_id, staked_amount);
             let _ = T::StakingEventsHandler::unstaked(stake_id, staked_amount, imbalance);
+
+            <StakeFundingByStakeId<T>>::remove(stake_id);
         }
 
         Ok(())
@@ -1115,7 +1500,16 @@ impl<T: Trait> Module<T> {
     ) -> Result<(), StakeActionError<PauseUnstakingError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
-        stake.pause_unstaking()?;
+        let due_blocks = stake.due_blocks();
+
+        stake.pause_unstaking(Self::blocks_finalized())?;
+
+        Self::update_due_blocks(stake_id, &due_blocks, &stake.due_blocks());
+
+        // unstaking without slashes is resumed when the stake is finalised
+        if !stake.has_ongoing_slashes() {
+            Self::schedule_stake(stake_id, Self::blocks_finalized());
+        }
 
         <Stakes<T>>::insert(stake_id, stake);
 
@@ -1128,31 +1522,51 @@ impl<T: Trait> Module<T> {
     ) -> Result<(), StakeActionError<ResumeUnstakingError>> {
         let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
 
-        stake.resume_unstaking()?;
+        let due_blocks = stake.due_blocks();
+
+        stake.resume_unstaking(Self::blocks_finalized())?;
+
+        Self::update_due_blocks(stake_id, &due_blocks, &stake.due_blocks());
 
         <Stakes<T>>::insert(stake_id, stake);
 
         Ok(())
     }
 
-    /// Handle timers for finalizing unstaking and slashing.
+    /// Finalizes the slashing and unstaking due at the end of the current block, and counts the block as finalised.
     /// Finalised unstaking results in the staked_balance in the given stake to removed from the pool, the corresponding
     /// imbalance is provided to the unstaked() hook in the StakingEventsHandler.
     /// Finalised slashing results in the staked_balance in the given stake being correspondingly reduced, and the imbalance
     /// is provided to the slashed() hook in the StakingEventsHandler.
     fn finalize_slashing_and_unstaking() {
-        for (stake_id, ref mut stake) in <Stakes<T>>::enumerate() {
-            let (updated, slashed, unstaked) =
-                stake.finalize_slashing_and_unstaking(T::Currency::minimum_balance());
+        let blocks_finalized = Self::blocks_finalized();
+        let stake_ids = <StakeIdsByDueBlock<T>>::take(blocks_finalized);
 
-            // update the state before making external calls to StakingEventsHandler
-            if updated {
-                <Stakes<T>>::insert(stake_id, stake)
+        // count the block before making external calls, the periods of slashing and unstaking
+        // initiated from the StakingEventsHandler are counted from the next block
+        <BlocksFinalized<T>>::put(blocks_finalized + One::one());
+
+        for stake_id in stake_ids {
+            // Skip removed stakes
+            if !<Stakes<T>>::exists(stake_id) {
+                continue;
             }
 
+            let mut stake = Self::stakes(stake_id);
+            let due_blocks = stake.due_blocks();
+
+            let (slashed, unstaked) = stake
+                .finalize_slashing_and_unstaking(T::Currency::minimum_balance(), blocks_finalized);
+
+            // update the state before making external calls to StakingEventsHandler
+            Self::update_due_blocks(&stake_id, &due_blocks, &stake.due_blocks());
+            <Stakes<T>>::insert(stake_id, stake);
+
             for (slash_id, slashed_amount, staked_amount) in slashed.into_iter() {
-                // remove the slashed amount from the pool
-                let imbalance = Self::withdraw_funds_from_stake_pool(slashed_amount);
+                Self::reduce_stake_funding(&stake_id, slashed_amount);
+
+                // release the slashed amount from the staking backend
+                let imbalance = T::StakingBackend::release(&stake_id, slashed_amount);
 
                 let _ = T::StakingEventsHandler::slashed(
                     &stake_id,
@@ -1164,11 +1578,206 @@ impl<T: Trait> Module<T> {
             }
 
             if let Some(staked_amount) = unstaked {
-                // remove the unstaked amount from the pool
-                let imbalance = Self::withdraw_funds_from_stake_pool(staked_amount);
+                // release the unstaked amount from the staking backend
+                let imbalance = T::StakingBackend::release(&stake_id, staked_amount);
 
                 let _ = T::StakingEventsHandler::unstaked(&stake_id, staked_amount, imbalance);
+
+                <StakeFundingByStakeId<T>>::remove(stake_id);
+            }
+        }
+    }
+
+    /// Splits the amount between the funders of the stake, pro rata to their shares. Returns
+    /// an empty split for stakes without recorded funders.
+    pub fn split_by_stake_funding(
+        stake_id: &T::StakeId,
+        amount: BalanceOf<T>,
+    ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        let funding = Self::stake_funding(stake_id);
+
+        let total_shares = funding
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |total, funding| {
+                total + funding.share
+            });
+
+        if total_shares.is_zero() {
+            return vec![];
+        }
+
+        let last_index = funding.len() - 1;
+        let mut remaining = amount;
+
+        funding
+            .into_iter()
+            .enumerate()
+            .map(|(index, funding)| {
+                // the last funder gets the remainder left by rounding down
+                let part = if index == last_index {
+                    remaining
+                } else {
+                    min(
+                        amount.saturating_mul(funding.share) / total_shares,
+                        remaining,
+                    )
+                };
+
+                remaining -= part;
+
+                (funding.funder, part)
+            })
+            .collect()
+    }
+
+    /// Returns the imbalance to the funders of the stake, pro rata to their shares. Stakes
+    /// without recorded funders are left untouched and the imbalance is returned unused.
+    /// Intended to be used by the StakingEventsHandler on unstaking.
+    pub fn refund_stake_funders(
+        stake_id: &T::StakeId,
+        imbalance: NegativeImbalance<T>,
+    ) -> NegativeImbalance<T> {
+        let split = Self::split_by_stake_funding(stake_id, imbalance.peek());
+
+        if split.is_empty() {
+            return imbalance;
+        }
+
+        let mut remaining_imbalance = imbalance;
+
+        for (funder, part) in split {
+            let (refund, rest) = remaining_imbalance.split(part);
+            remaining_imbalance = rest;
+
+            T::Currency::resolve_creating(&funder, refund);
+        }
+
+        remaining_imbalance
+    }
+
+    // Adds the value to the share of the funder, recording a new funder if needed.
+    fn add_stake_funding(stake_id: &T::StakeId, funder: &T::AccountId, value: BalanceOf<T>) {
+        <StakeFundingByStakeId<T>>::mutate(stake_id, |funding| {
+            if let Some(funding) = funding.iter_mut().find(|funding| funding.funder == *funder) {
+                funding.share += value;
+            } else {
+                funding.push(StakeFunding {
+                    funder: funder.clone(),
+                    share: value,
+                });
+            }
+        });
+    }
+
+    // Reduces the shares of the funders pro rata by the amount which left the stake.
+    fn reduce_stake_funding(stake_id: &T::StakeId, amount: BalanceOf<T>) {
+        let split = Self::split_by_stake_funding(stake_id, amount);
+
+        if split.is_empty() {
+            return;
+        }
+
+        let funding = Self::stake_funding(stake_id)
+            .into_iter()
+            .zip(split.into_iter())
+            .map(|(funding, (_, part))| StakeFunding {
+                funder: funding.funder,
+                share: funding.share - min(part, funding.share),
+            })
+            .filter(|funding| !funding.share.is_zero())
+            .collect::<Vec<_>>();
+
+        if funding.is_empty() {
+            <StakeFundingByStakeId<T>>::remove(stake_id);
+        } else {
+            <StakeFundingByStakeId<T>>::insert(stake_id, funding);
+        }
+    }
+
+    /// Converts stakes saved before the slashing and unstaking due blocks were introduced, and
+    /// schedules their active slashing and unstaking. Should be called on the migration to the new
+    /// runtime version, before any other use of the stakes.
+    pub fn migrate_stakes_due_blocks() {
+        let blocks_finalized = Self::blocks_finalized();
+        let mut stake_id = T::StakeId::zero();
+
+        while stake_id < Self::stakes_created() {
+            let key = <Stakes<T>>::storage_linked_map_final_key(stake_id);
+
+            if let Some(raw_value) = unhashed::get_raw(&key) {
+                let mut input = &raw_value[..];
+
+                if let Ok(legacy) =
+                    LegacyStake::<T::BlockNumber, BalanceOf<T>, T::SlashId>::decode(&mut input)
+                {
+                    // Linked map values are followed by the linkage to the adjacent keys.
+                    // Values already saved with the new layout don't end with the linkage.
+                    let linkage = input;
+
+                    if Self::is_linkage(linkage) {
+                        let stake = legacy.into_stake(blocks_finalized);
+
+                        Self::update_due_blocks(&stake_id, &[], &stake.due_blocks());
+
+                        let mut value = stake.encode();
+                        value.extend_from_slice(linkage);
+
+                        unhashed::put_raw(&key, &value);
+                    }
+                }
+            }
+
+            stake_id += One::one();
+        }
+    }
+
+    // Checks whether the raw value is the encoded linkage of the stakes linked map.
+    fn is_linkage(raw_value: &[u8]) -> bool {
+        let mut input = raw_value;
+
+        let linkage = <(Option<T::StakeId>, Option<T::StakeId>)>::decode(&mut input);
+
+        linkage.is_ok() && input.is_empty()
+    }
+
+    // Moves the stake between the due block queues, when the blocks at which its slashing or
+    // unstaking is due have changed.
+    fn update_due_blocks(
+        stake_id: &T::StakeId,
+        old_due_blocks: &[T::BlockNumber],
+        new_due_blocks: &[T::BlockNumber],
+    ) {
+        for due_block in old_due_blocks {
+            if !new_due_blocks.contains(due_block) {
+                Self::unschedule_stake(stake_id, *due_block);
+            }
+        }
+
+        for due_block in new_due_blocks {
+            if !old_due_blocks.contains(due_block) {
+                Self::schedule_stake(stake_id, *due_block);
             }
         }
     }
+
+    // Adds the stake to the due block queue of the provided block, if not already added.
+    fn schedule_stake(stake_id: &T::StakeId, due_block: T::BlockNumber) {
+        <StakeIdsByDueBlock<T>>::mutate(due_block, |ids| {
+            if !ids.contains(stake_id) {
+                ids.push(*stake_id);
+            }
+        });
+    }
+
+    // Removes the stake from the due block queue of the provided block.
+    fn unschedule_stake(stake_id: &T::StakeId, due_block: T::BlockNumber) {
+        let mut ids = Self::stake_ids_by_due_block(due_block);
+        ids.retain(|scheduled_id| scheduled_id != stake_id);
+
+        if ids.is_empty() {
+            <StakeIdsByDueBlock<T>>::remove(due_block);
+        } else {
+            <StakeIdsByDueBlock<T>>::insert(due_block, ids);
+        }
+    }
 }
@@ -11,7 +11,9 @@ use runtime_primitives::{
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
+use srml_support::traits::LockIdentifier;
 use srml_support::{impl_outer_origin, parameter_types};
+use std::cell::Cell;
 
 impl_outer_origin! {
     pub enum Origin for Test {}
@@ -53,6 +55,7 @@ parameter_types! {
     pub const TransactionBaseFee: u32 = 5;
     pub const TransactionByteFee: u32 = 0;
     pub const StakePoolId: [u8; 8] = *b"joystake";
+    pub const StakeLockId: LockIdentifier = *b"stakelck";
 }
 
 impl balances::Trait for Test {
@@ -76,10 +79,67 @@ impl Trait for Test {
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type StakingBackend = TestStakingBackend;
     type StakeId = u64;
     type SlashId = u64;
 }
 
+thread_local! {
+    static USE_LOCK_BACKEND: Cell<bool> = Cell::new(false);
+}
+
+/// Selects the lock based staking backend for the current test.
+pub fn use_lock_backend() {
+    USE_LOCK_BACKEND.with(|use_lock_backend| use_lock_backend.set(true));
+}
+
+fn lock_backend_is_used() -> bool {
+    USE_LOCK_BACKEND.with(|use_lock_backend| use_lock_backend.get())
+}
+
+pub type TestLockBackend = LockBackend<StakeLockId>;
+
+// Switches between the staking backends, so both can be tested with the same runtime.
+pub struct TestStakingBackend;
+
+impl StakingBackend<Test> for TestStakingBackend {
+    fn hold_from_account(
+        stake_id: &u64,
+        source_account_id: &u64,
+        value: u64,
+    ) -> Result<(), TransferFromAccountError> {
+        if lock_backend_is_used() {
+            <TestLockBackend as StakingBackend<Test>>::hold_from_account(
+                stake_id,
+                source_account_id,
+                value,
+            )
+        } else {
+            <PoolAccountBackend as StakingBackend<Test>>::hold_from_account(
+                stake_id,
+                source_account_id,
+                value,
+            )
+        }
+    }
+
+    fn hold(stake_id: &u64, imbalance: NegativeImbalance<Test>, opt_owner: Option<&u64>) {
+        if lock_backend_is_used() {
+            <TestLockBackend as StakingBackend<Test>>::hold(stake_id, imbalance, opt_owner)
+        } else {
+            <PoolAccountBackend as StakingBackend<Test>>::hold(stake_id, imbalance, opt_owner)
+        }
+    }
+
+    fn release(stake_id: &u64, value: u64) -> NegativeImbalance<Test> {
+        if lock_backend_is_used() {
+            <TestLockBackend as StakingBackend<Test>>::release(stake_id, value)
+        } else {
+            <PoolAccountBackend as StakingBackend<Test>>::release(stake_id, value)
+        }
+    }
+}
+
 pub fn build_test_externalities() -> runtime_io::TestExternalities {
     let t = system::GenesisConfig::default()
         .build_storage::<Test>()
@@ -3,6 +3,8 @@
 use super::*;
 use crate::mock::*;
 use runtime_primitives::traits::OnFinalize;
+use srml_support::storage::unhashed;
+use srml_support::traits::{ExistenceRequirement, WithdrawReasons};
 use srml_support::{assert_err, assert_ok};
 
 #[test]
@@ -384,7 +386,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
                     next_slash_id: 0,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: 0,
-                        blocks_remaining_in_active_period_for_unstaking: 100,
+                        unstake_at_block: 99,
                         is_active: true,
                     }),
                 }),
@@ -403,7 +405,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
             Slash {
                 started_at_block: System::block_number(),
                 is_active: true,
-                blocks_remaining_in_active_period_for_slashing: 10,
+                slash_at_block: 9,
                 slash_amount: 5000,
             },
         );
@@ -418,7 +420,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
                     next_slash_id: slash_id + 1,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: 0,
-                        blocks_remaining_in_active_period_for_unstaking: 100,
+                        unstake_at_block: 100,
                         is_active: false,
                     }),
                 })
@@ -440,7 +442,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
             Slash {
                 started_at_block: System::block_number(),
                 is_active: false,
-                blocks_remaining_in_active_period_for_slashing: 10,
+                slash_at_block: 10,
                 slash_amount: 5000,
             },
         );
@@ -454,7 +456,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
                     next_slash_id: slash_id + 1,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: 0,
-                        blocks_remaining_in_active_period_for_unstaking: 100,
+                        unstake_at_block: 100,
                         is_active: false,
                     }),
                 })
@@ -476,7 +478,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
             Slash {
                 started_at_block: System::block_number(),
                 is_active: true,
-                blocks_remaining_in_active_period_for_slashing: 10,
+                slash_at_block: 9,
                 slash_amount: 5000,
             },
         );
@@ -490,7 +492,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
                     next_slash_id: slash_id + 1,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: 0,
-                        blocks_remaining_in_active_period_for_unstaking: 100,
+                        unstake_at_block: 100,
                         is_active: false,
                     }),
                 })
@@ -517,7 +519,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
                     next_slash_id: slash_id + 1,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: 0,
-                        blocks_remaining_in_active_period_for_unstaking: 100,
+                        unstake_at_block: 99,
                         is_active: true,
                     }),
                 })
@@ -536,7 +538,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
             Slash {
                 started_at_block: System::block_number(),
                 is_active: true,
-                blocks_remaining_in_active_period_for_slashing: 1,
+                slash_at_block: 1,
                 slash_amount: slashing_amount,
             },
         );
@@ -550,7 +552,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
                     next_slash_id: slash_id + 1,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: 0,
-                        blocks_remaining_in_active_period_for_unstaking: 100,
+                        unstake_at_block: 100,
                         is_active: false,
                     }),
                 })
@@ -568,7 +570,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
                     next_slash_id: slash_id + 1,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: 0,
-                        blocks_remaining_in_active_period_for_unstaking: 99,
+                        unstake_at_block: 100,
                         is_active: true
                     })
                 })
@@ -625,7 +627,7 @@ fn initiating_pausing_resuming_unstaking() {
             Slash {
                 started_at_block: System::block_number(),
                 is_active: true,
-                blocks_remaining_in_active_period_for_slashing: 100,
+                slash_at_block: 99,
                 slash_amount: 100,
             },
         );
@@ -664,7 +666,7 @@ fn initiating_pausing_resuming_unstaking() {
                     next_slash_id: 2,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: System::block_number(),
-                        blocks_remaining_in_active_period_for_unstaking: 2,
+                        unstake_at_block: 1,
                         is_active: true
                     })
                 })
@@ -682,7 +684,7 @@ fn initiating_pausing_resuming_unstaking() {
                     next_slash_id: 2,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: System::block_number(),
-                        blocks_remaining_in_active_period_for_unstaking: 1,
+                        unstake_at_block: 1,
                         is_active: true
                     })
                 })
@@ -705,6 +707,458 @@ fn initiating_pausing_resuming_unstaking() {
     });
 }
 
+#[test]
+fn paused_unstaking_is_rescheduled_on_resume() {
+    build_test_externalities().execute_with(|| {
+        let staked_amount = Balances::minimum_balance() + 10000;
+        let _ = Balances::deposit_creating(&1, staked_amount);
+
+        let stake_id = StakePool::create_stake();
+        assert_ok!(StakePool::stake_from_account(&stake_id, &1, staked_amount));
+
+        assert_ok!(StakePool::initiate_unstaking(&stake_id, Some(5)));
+        assert_eq!(StakePool::stake_ids_by_due_block(4), vec![stake_id]);
+
+        StakePool::on_finalize(System::block_number());
+        StakePool::on_finalize(System::block_number());
+        assert_eq!(StakePool::blocks_finalized(), 2);
+
+        // unstaking is paused by the slashing
+        let slash_id = StakePool::initiate_slashing(&stake_id, 100, 10).unwrap();
+        assert!(!<StakeIdsByDueBlock<Test>>::exists(4));
+        assert_eq!(StakePool::stake_ids_by_due_block(11), vec![stake_id]);
+
+        for _ in 0..4 {
+            StakePool::on_finalize(System::block_number());
+        }
+        assert!(!StakePool::stakes(&stake_id).is_not_staked());
+
+        // the three blocks remaining when paused are counted from the block of resuming
+        assert_ok!(StakePool::cancel_slashing(&stake_id, &slash_id));
+        assert!(!<StakeIdsByDueBlock<Test>>::exists(11));
+        assert_eq!(StakePool::stake_ids_by_due_block(8), vec![stake_id]);
+
+        StakePool::on_finalize(System::block_number());
+        StakePool::on_finalize(System::block_number());
+        assert!(!StakePool::stakes(&stake_id).is_not_staked());
+
+        StakePool::on_finalize(System::block_number());
+        assert!(StakePool::stakes(&stake_id).is_not_staked());
+        assert!(!<StakeIdsByDueBlock<Test>>::exists(8));
+        assert_eq!(StakePool::stake_pool_balance(), 0);
+    });
+}
+
+#[test]
+fn paused_unstaking_without_slashes_is_resumed_at_the_end_of_the_block() {
+    build_test_externalities().execute_with(|| {
+        let staked_amount = Balances::minimum_balance() + 10000;
+        let _ = Balances::deposit_creating(&1, staked_amount);
+
+        let stake_id = StakePool::create_stake();
+        assert_ok!(StakePool::stake_from_account(&stake_id, &1, staked_amount));
+
+        assert_ok!(StakePool::initiate_unstaking(&stake_id, Some(5)));
+
+        StakePool::on_finalize(System::block_number());
+        StakePool::on_finalize(System::block_number());
+
+        assert_ok!(StakePool::pause_unstaking(&stake_id));
+        assert!(!<StakeIdsByDueBlock<Test>>::exists(4));
+        assert_eq!(StakePool::stake_ids_by_due_block(2), vec![stake_id]);
+
+        StakePool::on_finalize(System::block_number());
+        assert_eq!(StakePool::stake_ids_by_due_block(4), vec![stake_id]);
+
+        StakePool::on_finalize(System::block_number());
+        StakePool::on_finalize(System::block_number());
+        assert!(StakePool::stakes(&stake_id).is_not_staked());
+    });
+}
+
+#[test]
+fn remaining_blocks_are_counted_from_the_due_blocks() {
+    let slash = Slash {
+        started_at_block: 0,
+        is_active: true,
+        slash_at_block: 12,
+        slash_amount: 100,
+    };
+    assert_eq!(slash.blocks_remaining_in_active_period_for_slashing(10), 3);
+
+    let paused_slash = Slash {
+        is_active: false,
+        slash_at_block: 3,
+        ..slash
+    };
+    assert_eq!(
+        paused_slash.blocks_remaining_in_active_period_for_slashing(10),
+        3
+    );
+
+    let unstaking_state = UnstakingState {
+        started_at_block: 0,
+        is_active: true,
+        unstake_at_block: 10,
+    };
+    assert_eq!(
+        unstaking_state.blocks_remaining_in_active_period_for_unstaking(10),
+        1
+    );
+
+    let paused_unstaking_state = UnstakingState {
+        started_at_block: 0,
+        is_active: false,
+        unstake_at_block: 20,
+    };
+    assert_eq!(
+        paused_unstaking_state.blocks_remaining_in_active_period_for_unstaking(10),
+        20
+    );
+}
+
+#[test]
+fn legacy_stakes_are_migrated() {
+    build_test_externalities().execute_with(|| {
+        <BlocksFinalized<Test>>::put(10);
+
+        let staked_amount = Balances::minimum_balance() + 10000;
+
+        let slashed_stake_id = StakePool::create_stake();
+        let unstaking_stake_id = StakePool::create_stake();
+
+        let mut legacy_slashes = BTreeMap::new();
+        legacy_slashes.insert(
+            0,
+            LegacySlash {
+                started_at_block: 5,
+                is_active: true,
+                blocks_remaining_in_active_period_for_slashing: 3,
+                slash_amount: 100,
+            },
+        );
+        legacy_slashes.insert(
+            1,
+            LegacySlash {
+                started_at_block: 5,
+                is_active: false,
+                blocks_remaining_in_active_period_for_slashing: 4,
+                slash_amount: 200,
+            },
+        );
+
+        let legacy_stakes: Vec<(
+            <Test as Trait>::StakeId,
+            LegacyStake<
+                <Test as system::Trait>::BlockNumber,
+                BalanceOf<Test>,
+                <Test as Trait>::SlashId,
+            >,
+        )> = vec![
+            (
+                slashed_stake_id,
+                LegacyStake {
+                    created: 1,
+                    staking_status: LegacyStakingStatus::Staked(LegacyStakedState {
+                        staked_amount,
+                        staked_status: LegacyStakedStatus::Unstaking(LegacyUnstakingState {
+                            started_at_block: 2,
+                            is_active: false,
+                            blocks_remaining_in_active_period_for_unstaking: 20,
+                        }),
+                        next_slash_id: 2,
+                        ongoing_slashes: legacy_slashes,
+                    }),
+                },
+            ),
+            (
+                unstaking_stake_id,
+                LegacyStake {
+                    created: 1,
+                    staking_status: LegacyStakingStatus::Staked(LegacyStakedState {
+                        staked_amount,
+                        staked_status: LegacyStakedStatus::Unstaking(LegacyUnstakingState {
+                            started_at_block: 2,
+                            is_active: false,
+                            blocks_remaining_in_active_period_for_unstaking: 5,
+                        }),
+                        next_slash_id: 0,
+                        ongoing_slashes: BTreeMap::new(),
+                    }),
+                },
+            ),
+        ];
+
+        // Save the stakes with the legacy layout keeping the linkage.
+        for (stake_id, legacy_stake) in legacy_stakes {
+            let stake = StakePool::stakes(stake_id);
+            let key = <Stakes<Test>>::storage_linked_map_final_key(stake_id);
+            let raw_value = unhashed::get_raw(&key).unwrap();
+            let linkage = &raw_value[stake.encode().len()..];
+
+            let mut legacy_value = legacy_stake.encode();
+            legacy_value.extend_from_slice(linkage);
+
+            unhashed::put_raw(&key, &legacy_value);
+        }
+
+        StakePool::migrate_stakes_due_blocks();
+        // Migration can be repeated.
+        StakePool::migrate_stakes_due_blocks();
+
+        assert_eq!(<Stakes<Test>>::enumerate().count(), 2);
+
+        let mut expected_slashes: fixtures::OngoingSlashes = BTreeMap::new();
+        expected_slashes.insert(
+            0,
+            Slash {
+                started_at_block: 5,
+                is_active: true,
+                slash_at_block: 12,
+                slash_amount: 100,
+            },
+        );
+        expected_slashes.insert(
+            1,
+            Slash {
+                started_at_block: 5,
+                is_active: false,
+                slash_at_block: 4,
+                slash_amount: 200,
+            },
+        );
+
+        assert_eq!(
+            StakePool::stakes(slashed_stake_id),
+            Stake {
+                created: 1,
+                staking_status: StakingStatus::Staked(StakedState {
+                    staked_amount,
+                    staked_status: StakedStatus::Unstaking(UnstakingState {
+                        started_at_block: 2,
+                        is_active: false,
+                        unstake_at_block: 20,
+                    }),
+                    next_slash_id: 2,
+                    ongoing_slashes: expected_slashes,
+                }),
+            }
+        );
+
+        // unstaking without slashes was resumed on finalization
+        assert_eq!(
+            StakePool::stakes(unstaking_stake_id),
+            Stake {
+                created: 1,
+                staking_status: StakingStatus::Staked(StakedState {
+                    staked_amount,
+                    staked_status: StakedStatus::Unstaking(UnstakingState {
+                        started_at_block: 2,
+                        is_active: true,
+                        unstake_at_block: 14,
+                    }),
+                    next_slash_id: 0,
+                    ongoing_slashes: BTreeMap::new(),
+                }),
+            }
+        );
+
+        assert_eq!(
+            StakePool::stake_ids_by_due_block(12),
+            vec![slashed_stake_id]
+        );
+        assert!(!<StakeIdsByDueBlock<Test>>::exists(4));
+        assert!(!<StakeIdsByDueBlock<Test>>::exists(20));
+        assert_eq!(
+            StakePool::stake_ids_by_due_block(14),
+            vec![unstaking_stake_id]
+        );
+    });
+}
+
+#[test]
+fn stake_funding_is_split_pro_rata() {
+    build_test_externalities().execute_with(|| {
+        let starting_balance = Balances::minimum_balance() + 10000;
+        let _ = Balances::deposit_creating(&1, starting_balance);
+        let _ = Balances::deposit_creating(&2, starting_balance);
+
+        let stake_id = StakePool::create_stake();
+        assert_ok!(StakePool::stake_from_account(&stake_id, &1, 3000));
+        assert_ok!(StakePool::increase_stake_from_account(&stake_id, &2, 1000));
+
+        assert_eq!(
+            StakePool::stake_funding(&stake_id),
+            vec![
+                StakeFunding {
+                    funder: 1,
+                    share: 3000
+                },
+                StakeFunding {
+                    funder: 2,
+                    share: 1000
+                },
+            ]
+        );
+
+        // slashing reduces the shares pro rata
+        assert!(StakePool::slash_immediate(&stake_id, 400, false).is_ok());
+        assert_eq!(
+            StakePool::split_by_stake_funding(&stake_id, 3600),
+            vec![(1, 2700), (2, 900)]
+        );
+
+        let (_, imbalance) = StakePool::decrease_stake(&stake_id, 1200).unwrap();
+        assert_eq!(
+            StakePool::split_by_stake_funding(&stake_id, 2400),
+            vec![(1, 1800), (2, 600)]
+        );
+
+        let remaining_imbalance = StakePool::refund_stake_funders(&stake_id, imbalance);
+        assert_eq!(remaining_imbalance.peek(), 0);
+        assert_eq!(Balances::free_balance(&1), starting_balance - 3000 + 900);
+        assert_eq!(Balances::free_balance(&2), starting_balance - 1000 + 300);
+
+        assert_ok!(StakePool::initiate_unstaking(&stake_id, None));
+        assert!(!<StakeFundingByStakeId<Test>>::exists(&stake_id));
+    });
+}
+
+#[test]
+fn stake_without_funders_is_not_refunded() {
+    build_test_externalities().execute_with(|| {
+        let staked_amount = Balances::minimum_balance() + 10000;
+
+        let stake_id = StakePool::create_stake();
+        assert_ok!(StakePool::stake(
+            &stake_id,
+            NegativeImbalance::<Test>::new(staked_amount)
+        ));
+
+        assert!(StakePool::split_by_stake_funding(&stake_id, staked_amount).is_empty());
+
+        let (_, imbalance) = StakePool::decrease_stake(&stake_id, 1000).unwrap();
+        let remaining_imbalance = StakePool::refund_stake_funders(&stake_id, imbalance);
+        assert_eq!(remaining_imbalance.peek(), 1000);
+    });
+}
+
+#[test]
+fn lock_backend_keeps_staked_funds_in_the_owner_account() {
+    build_test_externalities().execute_with(|| {
+        use_lock_backend();
+
+        let starting_balance = Balances::minimum_balance() + 10000;
+        let _ = Balances::deposit_creating(&1, starting_balance);
+
+        let stake_id = StakePool::create_stake();
+        assert_ok!(StakePool::stake_from_account(&stake_id, &1, 3000));
+
+        assert_eq!(Balances::free_balance(&1), starting_balance);
+        assert_eq!(StakePool::stake_pool_balance(), 0);
+        assert_eq!(StakePool::total_locked(&1), 3000);
+        assert_eq!(
+            StakePool::locked_funds(&stake_id),
+            vec![LockedFunds {
+                owner: 1,
+                amount: 3000
+            }]
+        );
+
+        // locked funds cannot be withdrawn
+        assert!(Balances::withdraw(
+            &1,
+            starting_balance - 2999,
+            WithdrawReasons::all(),
+            ExistenceRequirement::AllowDeath
+        )
+        .is_err());
+
+        // stake locks of an account cannot overlap
+        let other_stake_id = StakePool::create_stake();
+        assert_err!(
+            StakePool::stake_from_account(&other_stake_id, &1, starting_balance - 2999),
+            StakeActionError::Error(StakingFromAccountError::InsufficientBalanceInSourceAccount)
+        );
+
+        // decreasing the stake to the owner only unlocks the funds
+        assert_ok!(StakePool::decrease_stake_to_account(&stake_id, &1, 1000));
+        assert_eq!(Balances::free_balance(&1), starting_balance);
+        assert_eq!(StakePool::total_locked(&1), 2000);
+
+        // slashing takes the funds from the owner account
+        assert!(StakePool::slash_immediate(&stake_id, 500, false).is_ok());
+        assert_eq!(Balances::free_balance(&1), starting_balance - 500);
+        assert_eq!(StakePool::total_locked(&1), 1500);
+
+        // the default staking events handler burns the unstaked funds
+        assert_ok!(StakePool::initiate_unstaking(&stake_id, None));
+        assert_eq!(Balances::free_balance(&1), starting_balance - 2000);
+        assert!(!<TotalLockedByAccount<Test>>::exists(&1));
+        assert!(!<LockedFundsByStakeId<Test>>::exists(&stake_id));
+        assert!(Balances::locks(&1).is_empty());
+    });
+}
+
+#[test]
+fn lock_backend_records_the_funds_lost_by_the_owner() {
+    build_test_externalities().execute_with(|| {
+        use_lock_backend();
+
+        let starting_balance = Balances::minimum_balance() + 10000;
+        let _ = Balances::deposit_creating(&1, starting_balance);
+
+        let stake_id = StakePool::create_stake();
+        assert_ok!(StakePool::stake_from_account(&stake_id, &1, 3000));
+
+        // slashing by other modules ignores the stake lock
+        let _ = Balances::slash(&1, starting_balance - 800);
+        assert_eq!(Balances::free_balance(&1), 800);
+
+        // only the remaining funds of the owner are released
+        let (_, imbalance) = StakePool::decrease_stake(&stake_id, 1000).unwrap();
+        assert_eq!(imbalance.peek(), 800);
+        assert_eq!(StakePool::unreleased_funds(&stake_id), 200);
+        assert_eq!(StakePool::total_locked(&1), 2000);
+        assert_eq!(StakePool::stake_pool_balance(), 0);
+    });
+}
+
+#[test]
+fn lock_backend_pools_the_stakes_without_owner() {
+    build_test_externalities().execute_with(|| {
+        use_lock_backend();
+
+        let staked_amount = Balances::minimum_balance() + 10000;
+
+        let stake_id = StakePool::create_stake();
+        assert_ok!(StakePool::stake(
+            &stake_id,
+            NegativeImbalance::<Test>::new(staked_amount)
+        ));
+
+        assert_eq!(StakePool::stake_pool_balance(), staked_amount);
+        assert!(StakePool::locked_funds(&stake_id).is_empty());
+
+        assert_ok!(StakePool::increase_stake_funded_by(
+            &stake_id,
+            NegativeImbalance::<Test>::new(1000),
+            &1
+        ));
+
+        assert_eq!(Balances::free_balance(&1), 1000);
+        assert_eq!(StakePool::total_locked(&1), 1000);
+        assert_eq!(StakePool::stake_pool_balance(), staked_amount);
+
+        // the locked funds are released first
+        let (_, imbalance) = StakePool::decrease_stake(&stake_id, 1500).unwrap();
+        assert_eq!(imbalance.peek(), 1500);
+        assert_eq!(Balances::free_balance(&1), 0);
+        assert_eq!(StakePool::total_locked(&1), 0);
+        assert_eq!(StakePool::stake_pool_balance(), staked_amount - 500);
+    });
+}
+
 #[test]
 fn unstake() {
     build_test_externalities().execute_with(|| {
@@ -736,7 +1190,7 @@ fn unstake() {
             Slash {
                 started_at_block: System::block_number(),
                 is_active: true,
-                blocks_remaining_in_active_period_for_slashing: 100,
+                slash_at_block: 99,
                 slash_amount: 100,
             },
         );
@@ -771,7 +1225,7 @@ fn unstake() {
                     next_slash_id: 0,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: 0,
-                        blocks_remaining_in_active_period_for_unstaking: 100,
+                        unstake_at_block: 99,
                         is_active: true,
                     }),
                 }),
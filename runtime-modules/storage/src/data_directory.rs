@@ -5,12 +5,18 @@
 //! ## Comments
 //!
 //! Data object type registry module uses  working group module to authorize actions.
+//! Content is validated against its data object type constraints. The upload fee is withdrawn
+//! from the member account and added to the storage working group mint capacity.
 //!
 //! ## Supported extrinsics
 //!
 //! ### Public extrinsic
 //! - [add_content](./struct.Module.html#method.add_content) - Adds the content to the system.
 //!
+//! ### Public API
+//! - [verify_chunk_proof](./struct.Module.html#method.verify_chunk_proof) - Verifies a content
+//! chunk against the stored Merkle root.
+//!
 //! ### Private extrinsics
 //! - accept_content - Storage provider accepts a content.
 //! - reject_content - Storage provider rejects a content.
@@ -24,16 +30,19 @@
 use codec::{Decode, Encode};
 use rstd::collections::btree_map::BTreeMap;
 use rstd::prelude::*;
-use sr_primitives::traits::{MaybeSerialize, Member};
-use srml_support::traits::Get;
+use sr_primitives::traits::{Hash, MaybeSerialize, Member, Saturating, Zero};
+use srml_support::traits::{Currency, ExistenceRequirement, Get, WithdrawReasons};
 use srml_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
 use system::{self, ensure_root};
 
+use common::ipfs::IdentifierError;
 use common::origin::ActorOriginValidator;
 pub(crate) use common::BlockAndTime;
 
 use crate::data_object_type_registry;
-use crate::data_object_type_registry::IsActiveDataObjectType;
+use crate::data_object_type_registry::{
+    BalanceOf, DataObjectTypeConstraintsProvider, IsActiveDataObjectType,
+};
 use crate::{MemberId, StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
 
 /// The _Data directory_ main _Trait_.
@@ -56,12 +65,20 @@ pub trait Trait:
     ///Active data object type validator.
     type IsActiveDataObjectType: data_object_type_registry::IsActiveDataObjectType<Self>;
 
+    /// Data object type constraints provider.
+    type DataObjectTypeConstraintsProvider: data_object_type_registry::DataObjectTypeConstraintsProvider<
+        Self,
+    >;
+
     /// Validates member id and origin combination.
     type MemberOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
 
     type MaxObjectsPerInjection: Get<u32>;
 }
 
+// Alias for the currency used by the storage working group mint.
+type CurrencyOf<T> = <T as minting::Trait>::Currency;
+
 decl_error! {
     /// _Data object storage registry_ module predefined errors.
     pub enum Error {
@@ -81,7 +98,61 @@ decl_error! {
         RequireRootOrigin,
 
         /// DataObject Injection Failed. Too Many DataObjects.
-        DataObjectsInjectionExceededLimit
+        DataObjectsInjectionExceededLimit,
+
+        /// Content chunk size should be positive.
+        ChunkSizeMustBePositive,
+
+        /// Content hash should be a 32-byte blake2 hash.
+        InvalidContentHashLength,
+
+        /// Chunk index is out of the content bounds.
+        ChunkIndexOutOfRange,
+
+        /// Chunk length doesn't match the content chunking metadata.
+        InvalidChunkLength,
+
+        /// Chunk proof doesn't match the content Merkle root.
+        InvalidChunkProof,
+
+        /// IPFS content id should not be empty.
+        IpfsContentIdIsEmpty,
+
+        /// IPFS content id is too long.
+        IpfsContentIdTooLong,
+
+        /// IPFS content id contains characters outside of the encoding alphabet.
+        IpfsContentIdInvalidEncoding,
+
+        /// IPFS content id multibase prefix is not supported.
+        IpfsContentIdUnsupportedMultibase,
+
+        /// IPFS content id should be a CIDv0 or CIDv1.
+        IpfsContentIdUnsupportedCidVersion,
+
+        /// IPFS content id contains a malformed varint.
+        IpfsContentIdInvalidVarint,
+
+        /// IPFS content id contains a malformed multihash.
+        IpfsContentIdInvalidMultihash,
+
+        /// IPFS content id multihash function is not supported.
+        IpfsContentIdUnsupportedHashFunction,
+
+        /// Content size exceeds the data object type max size.
+        DataObjectSizeExceeded,
+
+        /// MIME type is not allowed for the data object type.
+        MimeTypeNotAllowed,
+
+        /// Member account balance is insufficient to pay the upload fee.
+        InsufficientBalanceForUploadFee,
+
+        /// Storage working group mint doesn't exist.
+        StorageWorkingGroupMintNotFound,
+
+        /// Upload fee would raise the storage working group mint capacity over its lifetime limit.
+        UploadFeeExceedsMintLifetimeLimit
     }
 }
 
@@ -104,6 +175,21 @@ impl From<working_group::Error> for Error {
     }
 }
 
+impl From<IdentifierError> for Error {
+    fn from(error: IdentifierError) -> Self {
+        match error {
+            IdentifierError::Empty => Error::IpfsContentIdIsEmpty,
+            IdentifierError::TooLong => Error::IpfsContentIdTooLong,
+            IdentifierError::InvalidEncoding => Error::IpfsContentIdInvalidEncoding,
+            IdentifierError::UnsupportedMultibase => Error::IpfsContentIdUnsupportedMultibase,
+            IdentifierError::UnsupportedCidVersion => Error::IpfsContentIdUnsupportedCidVersion,
+            IdentifierError::InvalidVarint => Error::IpfsContentIdInvalidVarint,
+            IdentifierError::InvalidMultihash => Error::IpfsContentIdInvalidMultihash,
+            IdentifierError::UnsupportedHashFunction => Error::IpfsContentIdUnsupportedHashFunction,
+        }
+    }
+}
+
 /// The decision of the storage provider when it acts as liaison.
 #[derive(Clone, Encode, Decode, PartialEq, Debug)]
 pub enum LiaisonJudgement {
@@ -123,6 +209,28 @@ impl Default for LiaisonJudgement {
     }
 }
 
+/// Blake2-256 hash of the whole content. Use ContentHash::new() to create a validated instance.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug, Default)]
+pub struct ContentHash(Vec<u8>);
+
+impl ContentHash {
+    /// Blake2-256 hash length in bytes.
+    pub const LENGTH: usize = 32;
+
+    /// Creates a content hash from the raw bytes. Fails if the length doesn't match the
+    /// blake2-256 hash length.
+    pub fn new(hash: Vec<u8>) -> Result<Self, Error> {
+        ensure!(hash.len() == Self::LENGTH, Error::InvalidContentHashLength);
+
+        Ok(ContentHash(hash))
+    }
+
+    /// Returns raw hash bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 /// Alias for DataObjectInternal
 pub type DataObject<T> = DataObjectInternal<
     MemberId<T>,
@@ -130,11 +238,19 @@ pub type DataObject<T> = DataObjectInternal<
     <T as timestamp::Trait>::Moment,
     <T as data_object_type_registry::Trait>::DataObjectTypeId,
     StorageProviderId<T>,
+    <T as system::Trait>::Hash,
 >;
 
 /// Manages content ids, type and storage provider decision about it.
 #[derive(Clone, Encode, Decode, PartialEq, Debug)]
-pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, StorageProviderId> {
+pub struct DataObjectInternal<
+    MemberId,
+    BlockNumber,
+    Moment,
+    DataObjectTypeId,
+    StorageProviderId,
+    Hash,
+> {
     /// Content owner.
     pub owner: MemberId,
 
@@ -147,6 +263,9 @@ pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, S
     /// Content size in bytes.
     pub size: u64,
 
+    /// Content MIME type (or file extension).
+    pub mime_type: Vec<u8>,
+
     /// Storage provider id of the liaison.
     pub liaison: StorageProviderId,
 
@@ -155,6 +274,45 @@ pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, S
 
     /// IPFS content id.
     pub ipfs_content_id: Vec<u8>,
+
+    /// Merkle root of the content chunks. Leaves are chunk hashes; an unpaired node at any tree
+    /// level is hashed with its own copy.
+    pub content_merkle_root: Hash,
+
+    /// Content chunk size in bytes. The last chunk can be smaller.
+    pub chunk_size: u64,
+
+    /// Optional blake2-256 hash of the whole content.
+    pub content_hash: Option<ContentHash>,
+}
+
+impl<MemberId, BlockNumber, Moment, DataObjectTypeId, StorageProviderId, Hash>
+    DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, StorageProviderId, Hash>
+{
+    /// Returns the number of content chunks.
+    pub fn chunks_count(&self) -> u64 {
+        if self.chunk_size == 0 {
+            return 0;
+        }
+
+        let full_chunks = self.size / self.chunk_size;
+        if self.size % self.chunk_size == 0 {
+            full_chunks
+        } else {
+            full_chunks + 1
+        }
+    }
+
+    /// Returns expected chunk length in bytes or None if the chunk index is out of range.
+    pub fn chunk_length(&self, chunk_index: u64) -> Option<u64> {
+        if chunk_index >= self.chunks_count() {
+            return None;
+        }
+
+        let chunk_offset = chunk_index * self.chunk_size;
+
+        Some(rstd::cmp::min(self.chunk_size, self.size - chunk_offset))
+    }
 }
 
 /// A map collection of unique DataObjects keyed by the ContentId
@@ -211,39 +369,66 @@ decl_module! {
         const MaxObjectsPerInjection: u32 = T::MaxObjectsPerInjection::get();
 
         /// Adds the content to the system. Member id should match its origin. The created DataObject
-        /// awaits liaison to accept or reject it.
+        /// awaits liaison to accept or reject it. Content chunking metadata (Merkle root of the
+        /// chunk hashes and chunk size) allows to verify the partial data and resume uploads.
+        /// Content should satisfy the data object type constraints, the upload fee is paid by
+        /// the member account.
         pub fn add_content(
             origin,
             member_id: MemberId<T>,
             content_id: T::ContentId,
             type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
             size: u64,
-            ipfs_content_id: Vec<u8>
+            mime_type: Vec<u8>,
+            ipfs_content_id: Vec<u8>,
+            content_merkle_root: T::Hash,
+            chunk_size: u64,
+            content_hash: Option<Vec<u8>>
         ) {
-            T::MemberOriginValidator::ensure_actor_origin(
+            let account_id = T::MemberOriginValidator::ensure_actor_origin(
                 origin,
                 member_id,
             )?;
 
+            ensure!(chunk_size > 0, Error::ChunkSizeMustBePositive);
+
+            common::ipfs::validate_cid(&ipfs_content_id)?;
+
+            let content_hash = content_hash.map(ContentHash::new).transpose()?;
+
             ensure!(T::IsActiveDataObjectType::is_active_data_object_type(&type_id),
                 Error::DataObjectTypeMustBeActive);
 
             ensure!(!<DataObjectByContentId<T>>::exists(content_id),
                 Error::DataObjectAlreadyAdded);
 
+            let upload_fee = Self::ensure_data_object_type_constraints(
+                &type_id,
+                size,
+                &mime_type,
+                &account_id,
+            )?;
+
             let liaison = T::StorageProviderHelper::get_random_storage_provider()?;
 
             // Let's create the entry then
             let data: DataObject<T> = DataObjectInternal {
                 type_id,
                 size,
+                mime_type,
                 added_at: common::current_block_time::<T>(),
                 owner: member_id,
                 liaison,
                 liaison_judgement: LiaisonJudgement::Pending,
                 ipfs_content_id,
+                content_merkle_root,
+                chunk_size,
+                content_hash,
             };
 
+            // The fee payment is the last check: nothing is mutated if it fails.
+            Self::pay_upload_fee(&account_id, upload_fee)?;
+
             //
             // == MUTATION SAFE ==
             //
@@ -328,6 +513,128 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
+    /// Verifies the content chunk against the Merkle root stored for the content. The proof
+    /// contains sibling hashes from the leaf level up to the root.
+    pub fn verify_chunk_proof(
+        content_id: T::ContentId,
+        chunk_index: u64,
+        chunk: &[u8],
+        proof: &[T::Hash],
+    ) -> Result<(), Error> {
+        let data = Self::data_object_by_content_id(&content_id).ok_or(Error::CidNotFound)?;
+
+        let chunk_length = data
+            .chunk_length(chunk_index)
+            .ok_or(Error::ChunkIndexOutOfRange)?;
+
+        ensure!(
+            chunk.len() as u64 == chunk_length,
+            Error::InvalidChunkLength
+        );
+
+        let mut node_index = chunk_index;
+        let mut node_hash = T::Hashing::hash(chunk);
+        for sibling_hash in proof.iter() {
+            let mut nodes = Vec::new();
+            if node_index % 2 == 0 {
+                nodes.extend_from_slice(node_hash.as_ref());
+                nodes.extend_from_slice(sibling_hash.as_ref());
+            } else {
+                nodes.extend_from_slice(sibling_hash.as_ref());
+                nodes.extend_from_slice(node_hash.as_ref());
+            }
+
+            node_hash = T::Hashing::hash(&nodes);
+            node_index /= 2;
+        }
+
+        ensure!(
+            node_hash == data.content_merkle_root,
+            Error::InvalidChunkProof
+        );
+
+        Ok(())
+    }
+
+    // Ensures the content fits the data object type constraints and the member can pay the
+    // upload fee. Returns the upload fee.
+    fn ensure_data_object_type_constraints(
+        type_id: &<T as data_object_type_registry::Trait>::DataObjectTypeId,
+        size: u64,
+        mime_type: &[u8],
+        account_id: &T::AccountId,
+    ) -> Result<BalanceOf<T>, Error> {
+        let constraints =
+            T::DataObjectTypeConstraintsProvider::data_object_type_constraints(type_id);
+
+        ensure!(
+            constraints.is_size_allowed(size),
+            Error::DataObjectSizeExceeded
+        );
+
+        ensure!(
+            constraints.is_mime_type_allowed(mime_type),
+            Error::MimeTypeNotAllowed
+        );
+
+        let upload_fee = constraints.upload_fee(size);
+
+        if upload_fee > Zero::zero() {
+            ensure!(
+                <minting::Module<T>>::mint_exists(<StorageWorkingGroup<T>>::mint()),
+                Error::StorageWorkingGroupMintNotFound
+            );
+
+            let free_balance = CurrencyOf::<T>::free_balance(account_id);
+            ensure!(
+                free_balance >= upload_fee,
+                Error::InsufficientBalanceForUploadFee
+            );
+
+            CurrencyOf::<T>::ensure_can_withdraw(
+                account_id,
+                upload_fee,
+                WithdrawReasons::all(),
+                free_balance - upload_fee,
+            )
+            .map_err(|_| Error::InsufficientBalanceForUploadFee)?;
+        }
+
+        Ok(upload_fee)
+    }
+
+    // Withdraws the upload fee from the member account and adds it to the storage working group
+    // mint capacity. Returns an error without mutations if the fee cannot be paid.
+    fn pay_upload_fee(account_id: &T::AccountId, upload_fee: BalanceOf<T>) -> Result<(), Error> {
+        if upload_fee.is_zero() {
+            return Ok(());
+        }
+
+        let mint_id = <StorageWorkingGroup<T>>::mint();
+        ensure!(
+            <minting::Module<T>>::mint_exists(mint_id),
+            Error::StorageWorkingGroupMintNotFound
+        );
+
+        let mint = <minting::Module<T>>::mints(mint_id);
+        let capacity = mint.capacity().saturating_add(upload_fee);
+        ensure!(
+            mint.within_lifetime_limit(capacity),
+            Error::UploadFeeExceedsMintLifetimeLimit
+        );
+
+        let _ = CurrencyOf::<T>::withdraw(
+            account_id,
+            upload_fee,
+            WithdrawReasons::all(),
+            ExistenceRequirement::AllowDeath,
+        )
+        .map_err(|_| Error::InsufficientBalanceForUploadFee)?;
+
+        <minting::Module<T>>::set_mint_capacity(mint_id, capacity)
+            .map_err(|_| Error::StorageWorkingGroupMintNotFound)
+    }
+
     fn update_content_judgement(
         storage_provider_id: &StorageProviderId<T>,
         content_id: T::ContentId,
@@ -6,6 +6,9 @@
 //! Data object type registry module uses  working group module to authorize actions. Only leader can
 //! call extrinsics.
 //!
+//! Data object type constraints (max object size, allowed MIME types and the upload fee) are
+//! enforced by the data directory module on the content adding.
+//!
 //! ## Supported extrinsics
 //!
 //! - [register_data_object_type](./struct.Module.html#method.register_data_object_type) - Registers the new data object type.
@@ -25,12 +28,29 @@
 use crate::{StorageWorkingGroup, StorageWorkingGroupInstance};
 use codec::{Codec, Decode, Encode};
 use rstd::prelude::*;
-use sr_primitives::traits::{MaybeSerialize, Member, SimpleArithmetic};
-use srml_support::{decl_error, decl_event, decl_module, decl_storage, Parameter};
+use sr_primitives::traits::{
+    MaybeSerialize, Member, SaturatedConversion, Saturating, SimpleArithmetic, Zero,
+};
+use srml_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
 
 const DEFAULT_TYPE_DESCRIPTION: &str = "Default data object type for audio and video content.";
 const DEFAULT_FIRST_DATA_OBJECT_TYPE_ID: u32 = 1;
 
+/// Max number of the allowed MIME types (or extensions) per data object type.
+pub const MAX_ALLOWED_MIME_TYPES: usize = 100;
+
+/// Max length of the MIME type (or extension) in bytes.
+pub const MAX_MIME_TYPE_LENGTH: usize = 255;
+
+/// Balance alias for the data object type upload fee.
+pub type BalanceOf<T> = minting::BalanceOf<T>;
+
+/// Alias for the data object type with the runtime balance.
+pub type DataObjectTypeOf<T> = DataObjectType<BalanceOf<T>>;
+
+/// Alias for the data object type constraints with the runtime balance.
+pub type DataObjectTypeConstraintsOf<T> = DataObjectTypeConstraints<BalanceOf<T>>;
+
 /// The _Data object type registry_ main _Trait_.
 pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
     /// _Data object type registry_ event type.
@@ -55,6 +75,15 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Max object size should be positive.
+        MaxObjectSizeMustBePositive,
+
+        /// Allowed MIME types list exceeds the limit.
+        TooManyAllowedMimeTypes,
+
+        /// MIME type (or extension) should be non-empty and fit the length limit.
+        InvalidMimeType,
     }
 }
 
@@ -77,21 +106,66 @@ impl From<working_group::Error> for Error {
     }
 }
 
+/// Constraints for the data objects of the type. Default constraints are permissive.
+#[derive(Clone, Encode, Decode, PartialEq, Debug, Default)]
+pub struct DataObjectTypeConstraints<Balance> {
+    /// Max data object size in bytes. None means no size limit.
+    pub max_object_size: Option<u64>,
+
+    /// Allowed MIME types or file extensions. Empty list allows any type.
+    pub allowed_mime_types: Vec<Vec<u8>>,
+
+    /// Upload fee per byte paid to the storage working group mint.
+    pub fee_per_byte: Option<Balance>,
+}
+
+impl<Balance: SimpleArithmetic + Copy> DataObjectTypeConstraints<Balance> {
+    /// Verifies that the data object size fits the max size.
+    pub fn is_size_allowed(&self, size: u64) -> bool {
+        self.max_object_size
+            .map_or(true, |max_object_size| size <= max_object_size)
+    }
+
+    /// Verifies that the MIME type (or extension) is in the allowed list.
+    pub fn is_mime_type_allowed(&self, mime_type: &[u8]) -> bool {
+        self.allowed_mime_types.is_empty()
+            || self
+                .allowed_mime_types
+                .iter()
+                .any(|allowed_mime_type| allowed_mime_type.as_slice() == mime_type)
+    }
+
+    /// Calculates the upload fee for the data object size.
+    pub fn upload_fee(&self, size: u64) -> Balance {
+        self.fee_per_byte.map_or(Balance::zero(), |fee_per_byte| {
+            fee_per_byte.saturating_mul(size.saturated_into())
+        })
+    }
+}
+
 /// Contains description and constrains for the data object.
 #[derive(Clone, Encode, Decode, PartialEq, Debug)]
-pub struct DataObjectType {
+pub struct DataObjectType<Balance> {
     /// Data object description.
     pub description: Vec<u8>,
 
     /// Active/Disabled flag.
     pub active: bool,
+
+    /// Data object constraints.
+    pub constraints: DataObjectTypeConstraints<Balance>,
 }
 
-impl Default for DataObjectType {
+impl<Balance> Default for DataObjectType<Balance> {
     fn default() -> Self {
         DataObjectType {
             description: DEFAULT_TYPE_DESCRIPTION.as_bytes().to_vec(),
             active: true,
+            constraints: DataObjectTypeConstraints {
+                max_object_size: None,
+                allowed_mime_types: Vec::new(),
+                fee_per_byte: None,
+            },
         }
     }
 }
@@ -107,7 +181,7 @@ decl_storage! {
             config.first_data_object_type_id): T::DataObjectTypeId = T::DataObjectTypeId::from(DEFAULT_FIRST_DATA_OBJECT_TYPE_ID);
 
         /// Mapping of Data object types.
-        pub DataObjectTypes get(data_object_types): map T::DataObjectTypeId => Option<DataObjectType>;
+        pub DataObjectTypes get(data_object_types): map T::DataObjectTypeId => Option<DataObjectTypeOf<T>>;
     }
 }
 
@@ -139,7 +213,7 @@ decl_module! {
         fn on_initialize() {
             // Create a default data object type if it was not created yet.
             if !<DataObjectTypes<T>>::exists(Self::first_data_object_type_id()) {
-                let do_type: DataObjectType = DataObjectType::default();
+                let do_type: DataObjectTypeOf<T> = DataObjectType::default();
                 let new_type_id = Self::next_data_object_type_id();
 
                 <DataObjectTypes<T>>::insert(new_type_id, do_type);
@@ -148,13 +222,16 @@ decl_module! {
         }
 
         /// Registers the new data object type. Requires leader privileges.
-        pub fn register_data_object_type(origin, data_object_type: DataObjectType) {
+        pub fn register_data_object_type(origin, data_object_type: DataObjectTypeOf<T>) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
+            Self::ensure_data_object_type_constraints_are_valid(&data_object_type.constraints)?;
+
             let new_do_type_id = Self::next_data_object_type_id();
-            let do_type: DataObjectType = DataObjectType {
+            let do_type: DataObjectTypeOf<T> = DataObjectType {
                 description: data_object_type.description.clone(),
                 active: data_object_type.active,
+                constraints: data_object_type.constraints,
             };
 
             //
@@ -168,13 +245,16 @@ decl_module! {
         }
 
         /// Updates existing data object type. Requires leader privileges.
-        pub fn update_data_object_type(origin, id: T::DataObjectTypeId, data_object_type: DataObjectType) {
+        pub fn update_data_object_type(origin, id: T::DataObjectTypeId, data_object_type: DataObjectTypeOf<T>) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
             let mut do_type = Self::ensure_data_object_type(id)?;
 
+            Self::ensure_data_object_type_constraints_are_valid(&data_object_type.constraints)?;
+
             do_type.description = data_object_type.description.clone();
             do_type.active = data_object_type.active;
+            do_type.constraints = data_object_type.constraints;
 
             //
             // == MUTATION SAFE ==
@@ -222,9 +302,31 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
-    fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectType, Error> {
+    fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectTypeOf<T>, Error> {
         Self::data_object_types(&id).ok_or(Error::DataObjectTypeNotFound)
     }
+
+    fn ensure_data_object_type_constraints_are_valid(
+        constraints: &DataObjectTypeConstraintsOf<T>,
+    ) -> Result<(), Error> {
+        if let Some(max_object_size) = constraints.max_object_size {
+            ensure!(max_object_size > 0, Error::MaxObjectSizeMustBePositive);
+        }
+
+        ensure!(
+            constraints.allowed_mime_types.len() <= MAX_ALLOWED_MIME_TYPES,
+            Error::TooManyAllowedMimeTypes
+        );
+
+        for mime_type in constraints.allowed_mime_types.iter() {
+            ensure!(
+                !mime_type.is_empty() && mime_type.len() <= MAX_MIME_TYPE_LENGTH,
+                Error::InvalidMimeType
+            );
+        }
+
+        Ok(())
+    }
 }
 
 /// Active data object type validator trait.
@@ -241,3 +343,18 @@ impl<T: Trait> IsActiveDataObjectType<T> for Module<T> {
         }
     }
 }
+
+/// Data object type constraints provider trait.
+pub trait DataObjectTypeConstraintsProvider<T: Trait> {
+    /// Returns constraints for the data object type. Missing type has permissive constraints:
+    /// its existence is verified with the IsActiveDataObjectType.
+    fn data_object_type_constraints(id: &T::DataObjectTypeId) -> DataObjectTypeConstraintsOf<T>;
+}
+
+impl<T: Trait> DataObjectTypeConstraintsProvider<T> for Module<T> {
+    fn data_object_type_constraints(id: &T::DataObjectTypeId) -> DataObjectTypeConstraintsOf<T> {
+        Self::data_object_types(id)
+            .map(|do_type| do_type.constraints)
+            .unwrap_or_default()
+    }
+}
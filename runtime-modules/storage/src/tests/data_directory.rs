@@ -1,8 +1,10 @@
 #![cfg(test)]
 
 use super::mock::*;
-use crate::data_directory::Error;
+use crate::data_directory::{ContentHash, Error};
 use rstd::collections::btree_map::BTreeMap;
+use sr_primitives::traits::Hash;
+use srml_support::traits::Currency;
 use system::RawOrigin;
 
 #[test]
@@ -17,7 +19,11 @@ fn succeed_adding_content() {
             1,
             1234,
             0,
-            vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            H256::zero(),
+            1024,
+            None,
         );
         assert!(res.is_ok());
     });
@@ -34,7 +40,11 @@ fn add_content_fails_with_invalid_origin() {
             1,
             1234,
             0,
-            vec![1, 3, 3, 7],
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            H256::zero(),
+            1024,
+            None,
         );
         assert_eq!(res, Err(Error::Other("RequireSignedOrigin")));
     });
@@ -52,7 +62,11 @@ fn accept_and_reject_content_fail_with_invalid_storage_provider() {
             1,
             1234,
             0,
-            vec![1, 2, 3, 4],
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            H256::zero(),
+            1024,
+            None,
         );
         assert!(res.is_ok());
 
@@ -95,7 +109,11 @@ fn accept_content_as_liaison() {
             1,
             1234,
             0,
-            vec![1, 2, 3, 4],
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            H256::zero(),
+            1024,
+            None,
         );
         assert!(res.is_ok());
 
@@ -139,7 +157,11 @@ fn reject_content_as_liaison() {
             1,
             1234,
             0,
-            vec![1, 2, 3, 4],
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            H256::zero(),
+            1024,
+            None,
         );
         assert!(res.is_ok());
 
@@ -183,6 +205,7 @@ fn data_object_injection_works() {
         let object = data_directory::DataObjectInternal {
             type_id: 1,
             size: 1234,
+            mime_type: vec![],
             added_at: data_directory::BlockAndTime {
                 block: 10,
                 time: 1024,
@@ -191,6 +214,9 @@ fn data_object_injection_works() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![],
+            content_merkle_root: H256::zero(),
+            chunk_size: 1234,
+            content_hash: None,
         };
 
         let content_id_1 = 1;
@@ -235,7 +261,11 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             content_id_1,
             1,
             10,
-            vec![8, 8, 8, 8],
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            H256::zero(),
+            1024,
+            None,
         );
         assert!(res.is_ok());
         let res = TestDataDirectory::add_content(
@@ -244,7 +274,11 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             content_id_2,
             2,
             20,
-            vec![9, 9, 9, 9],
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            H256::zero(),
+            1024,
+            None,
         );
         assert!(res.is_ok());
 
@@ -253,6 +287,7 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         let object1 = data_directory::DataObjectInternal {
             type_id: 1,
             size: 6666,
+            mime_type: vec![],
             added_at: data_directory::BlockAndTime {
                 block: 10,
                 time: 1000,
@@ -261,11 +296,15 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![5, 6, 7],
+            content_merkle_root: H256::zero(),
+            chunk_size: 1234,
+            content_hash: None,
         };
 
         let object2 = data_directory::DataObjectInternal {
             type_id: 1,
             size: 7777,
+            mime_type: vec![],
             added_at: data_directory::BlockAndTime {
                 block: 20,
                 time: 2000,
@@ -274,6 +313,9 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![5, 6, 7],
+            content_merkle_root: H256::zero(),
+            chunk_size: 1234,
+            content_hash: None,
         };
 
         objects.insert(content_id_1, object1.clone());
@@ -298,3 +340,350 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         );
     });
 }
+
+#[test]
+fn add_content_fails_with_zero_chunk_size() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            1,
+            1,
+            1234,
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            H256::zero(),
+            0,
+            None,
+        );
+        assert_eq!(res, Err(Error::ChunkSizeMustBePositive));
+    });
+}
+
+#[test]
+fn add_content_fails_with_invalid_content_hash() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            1,
+            1,
+            1234,
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            H256::zero(),
+            1024,
+            Some(vec![1, 2, 3]),
+        );
+        assert_eq!(res, Err(Error::InvalidContentHashLength));
+    });
+}
+
+#[test]
+fn add_content_saves_chunking_metadata() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+        let content_hash = vec![7u8; ContentHash::LENGTH];
+        let merkle_root = H256::repeat_byte(1);
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            1,
+            1234,
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            merkle_root,
+            1024,
+            Some(content_hash.clone()),
+        );
+        assert!(res.is_ok());
+
+        let data_object = TestDataDirectory::data_object_by_content_id(content_id).unwrap();
+        assert_eq!(data_object.content_merkle_root, merkle_root);
+        assert_eq!(data_object.chunk_size, 1024);
+        assert_eq!(
+            data_object.content_hash,
+            Some(ContentHash::new(content_hash).unwrap())
+        );
+        assert_eq!(data_object.chunks_count(), 2);
+        assert_eq!(data_object.chunk_length(1), Some(210));
+        assert_eq!(data_object.chunk_length(2), None);
+    });
+}
+
+fn hash_nodes(left: &H256, right: &H256) -> H256 {
+    let mut nodes = Vec::new();
+    nodes.extend_from_slice(left.as_ref());
+    nodes.extend_from_slice(right.as_ref());
+
+    BlakeTwo256::hash(&nodes)
+}
+
+#[test]
+fn verify_chunk_proof_succeeds() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+
+        // Three chunks: 4 + 4 + 2 bytes.
+        let chunks = vec![vec![1u8, 2, 3, 4], vec![5u8, 6, 7, 8], vec![9u8, 10]];
+        let leaves: Vec<H256> = chunks.iter().map(|c| BlakeTwo256::hash(c)).collect();
+
+        // The unpaired third leaf is hashed with its own copy.
+        let left_node = hash_nodes(&leaves[0], &leaves[1]);
+        let right_node = hash_nodes(&leaves[2], &leaves[2]);
+        let merkle_root = hash_nodes(&left_node, &right_node);
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            1,
+            10,
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            merkle_root,
+            4,
+            None,
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::verify_chunk_proof(
+                content_id,
+                0,
+                &chunks[0],
+                &[leaves[1], right_node]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            TestDataDirectory::verify_chunk_proof(
+                content_id,
+                1,
+                &chunks[1],
+                &[leaves[0], right_node]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            TestDataDirectory::verify_chunk_proof(
+                content_id,
+                2,
+                &chunks[2],
+                &[leaves[2], left_node]
+            ),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn verify_chunk_proof_fails() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+
+        let chunks = vec![vec![1u8, 2, 3, 4], vec![5u8, 6, 7, 8]];
+        let leaves: Vec<H256> = chunks.iter().map(|c| BlakeTwo256::hash(c)).collect();
+        let merkle_root = hash_nodes(&leaves[0], &leaves[1]);
+
+        assert_eq!(
+            TestDataDirectory::verify_chunk_proof(content_id, 0, &chunks[0], &[leaves[1]]),
+            Err(Error::CidNotFound)
+        );
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            1,
+            8,
+            b"video/mp4".to_vec(),
+            TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+            merkle_root,
+            4,
+            None,
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::verify_chunk_proof(content_id, 2, &chunks[0], &[leaves[1]]),
+            Err(Error::ChunkIndexOutOfRange)
+        );
+        assert_eq!(
+            TestDataDirectory::verify_chunk_proof(content_id, 0, &[1, 2, 3], &[leaves[1]]),
+            Err(Error::InvalidChunkLength)
+        );
+        assert_eq!(
+            TestDataDirectory::verify_chunk_proof(content_id, 1, &chunks[0], &[leaves[1]]),
+            Err(Error::InvalidChunkProof)
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_with_invalid_ipfs_content_id() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+
+        let invalid_content_ids = vec![
+            (Vec::new(), Error::IpfsContentIdIsEmpty),
+            (vec![1, 3, 3, 7], Error::IpfsContentIdUnsupportedMultibase),
+            (
+                b"zQmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdO".to_vec(),
+                Error::IpfsContentIdInvalidEncoding,
+            ),
+        ];
+
+        for (content_id, expected_error) in invalid_content_ids {
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                member_id,
+                1,
+                1,
+                1234,
+                b"video/mp4".to_vec(),
+                content_id,
+                H256::zero(),
+                1024,
+                None,
+            );
+            assert_eq!(res, Err(expected_error));
+        }
+    });
+}
+
+fn add_content_with_type(type_id: u64, size: u64, mime_type: &[u8]) -> Result<(), Error> {
+    TestDataDirectory::add_content(
+        Origin::signed(1),
+        1,
+        1,
+        type_id,
+        size,
+        mime_type.to_vec(),
+        TEST_IPFS_CONTENT_ID.as_bytes().to_vec(),
+        H256::zero(),
+        1024,
+        None,
+    )
+}
+
+#[test]
+fn add_content_fails_with_exceeded_size() {
+    with_default_mock_builder(|| {
+        let type_id = register_data_object_type_with_constraints(
+            data_object_type_registry::DataObjectTypeConstraints {
+                max_object_size: Some(1000),
+                allowed_mime_types: Vec::new(),
+                fee_per_byte: None,
+            },
+        );
+
+        assert_eq!(
+            add_content_with_type(type_id, 1001, b"video/mp4"),
+            Err(Error::DataObjectSizeExceeded)
+        );
+        assert_eq!(add_content_with_type(type_id, 1000, b"video/mp4"), Ok(()));
+    });
+}
+
+#[test]
+fn add_content_fails_with_not_allowed_mime_type() {
+    with_default_mock_builder(|| {
+        let type_id = register_data_object_type_with_constraints(
+            data_object_type_registry::DataObjectTypeConstraints {
+                max_object_size: None,
+                allowed_mime_types: vec![b"video/mp4".to_vec(), b"mkv".to_vec()],
+                fee_per_byte: None,
+            },
+        );
+
+        assert_eq!(
+            add_content_with_type(type_id, 1000, b"image/png"),
+            Err(Error::MimeTypeNotAllowed)
+        );
+        assert_eq!(add_content_with_type(type_id, 1000, b"mkv"), Ok(()));
+    });
+}
+
+#[test]
+fn add_content_pays_upload_fee_to_the_mint() {
+    with_default_mock_builder(|| {
+        let mint_id = create_storage_working_group_mint();
+        let type_id = register_data_object_type_with_constraints(
+            data_object_type_registry::DataObjectTypeConstraints {
+                max_object_size: None,
+                allowed_mime_types: Vec::new(),
+                fee_per_byte: Some(2),
+            },
+        );
+
+        let _ = Balances::deposit_creating(&1, 3000);
+
+        assert_eq!(add_content_with_type(type_id, 1000, b"video/mp4"), Ok(()));
+
+        assert_eq!(Balances::free_balance(&1), 1000);
+        assert_eq!(
+            minting::Module::<Test>::get_mint_capacity(mint_id),
+            Ok(2000)
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_with_insufficient_balance_for_upload_fee() {
+    with_default_mock_builder(|| {
+        let mint_id = create_storage_working_group_mint();
+        let type_id = register_data_object_type_with_constraints(
+            data_object_type_registry::DataObjectTypeConstraints {
+                max_object_size: None,
+                allowed_mime_types: Vec::new(),
+                fee_per_byte: Some(2),
+            },
+        );
+
+        let _ = Balances::deposit_creating(&1, 1999);
+
+        assert_eq!(
+            add_content_with_type(type_id, 1000, b"video/mp4"),
+            Err(Error::InsufficientBalanceForUploadFee)
+        );
+
+        assert_eq!(Balances::free_balance(&1), 1999);
+        assert_eq!(minting::Module::<Test>::get_mint_capacity(mint_id), Ok(0));
+    });
+}
+
+#[test]
+fn add_content_fails_without_storage_working_group_mint() {
+    with_default_mock_builder(|| {
+        let type_id = register_data_object_type_with_constraints(
+            data_object_type_registry::DataObjectTypeConstraints {
+                max_object_size: None,
+                allowed_mime_types: Vec::new(),
+                fee_per_byte: Some(2),
+            },
+        );
+
+        let _ = Balances::deposit_creating(&1, 3000);
+
+        assert_eq!(
+            add_content_with_type(type_id, 1000, b"video/mp4"),
+            Err(Error::StorageWorkingGroupMintNotFound)
+        );
+    });
+}
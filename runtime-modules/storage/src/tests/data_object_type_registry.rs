@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::mock::*;
-use srml_support::{StorageLinkedMap, StorageValue};
+use srml_support::{StorageLinkedMap, StorageMap, StorageValue};
 use system::{self, EventRecord, Phase, RawOrigin};
 
 const DEFAULT_LEADER_ACCOUNT_ID: u64 = 1;
@@ -61,6 +61,7 @@ fn succeed_register() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            constraints: Default::default(),
         };
         let res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -79,6 +80,7 @@ fn activate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            constraints: Default::default(),
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -106,6 +108,7 @@ fn deactivate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: true,
+            constraints: Default::default(),
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -133,6 +136,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            constraints: Default::default(),
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -144,6 +148,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            constraints: Default::default(),
         };
 
         let invalid_leader_account_id = 2;
@@ -165,6 +170,7 @@ fn update_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            constraints: Default::default(),
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -179,6 +185,7 @@ fn update_existing() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            constraints: Default::default(),
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -191,6 +198,7 @@ fn update_existing() {
         let updated3: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            constraints: Default::default(),
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -218,6 +226,7 @@ fn register_data_object_type_failed_with_no_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            constraints: Default::default(),
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -236,6 +245,7 @@ fn activate_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            constraints: Default::default(),
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -295,3 +305,118 @@ fn activate_existing() {
         assert!(!data.unwrap().active);
     });
 }
+
+#[test]
+fn register_and_update_data_object_type_with_constraints() {
+    with_default_mock_builder(|| {
+        SetLeadFixture::set_default_lead();
+
+        let constraints = data_object_type_registry::DataObjectTypeConstraints {
+            max_object_size: Some(1000),
+            allowed_mime_types: vec![b"video/mp4".to_vec(), b"mkv".to_vec()],
+            fee_per_byte: Some(2),
+        };
+        let data: TestDataObjectType = TestDataObjectType {
+            description: "foo".as_bytes().to_vec(),
+            active: true,
+            constraints: constraints.clone(),
+        };
+        let res = TestDataObjectTypeRegistry::register_data_object_type(
+            RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+            data,
+        );
+        assert!(res.is_ok());
+        let dot_id = get_last_data_object_type_id();
+
+        let data = TestDataObjectTypeRegistry::data_object_types(dot_id).unwrap();
+        assert_eq!(data.constraints, constraints);
+
+        let updated: TestDataObjectType = TestDataObjectType {
+            description: "foo".as_bytes().to_vec(),
+            active: true,
+            constraints: Default::default(),
+        };
+        let res = TestDataObjectTypeRegistry::update_data_object_type(
+            RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+            dot_id,
+            updated,
+        );
+        assert!(res.is_ok());
+
+        let data = TestDataObjectTypeRegistry::data_object_types(dot_id).unwrap();
+        assert_eq!(data.constraints, Default::default());
+    });
+}
+
+#[test]
+fn register_data_object_type_fails_with_invalid_constraints() {
+    with_default_mock_builder(|| {
+        SetLeadFixture::set_default_lead();
+
+        let invalid_constraints = vec![
+            (
+                data_object_type_registry::DataObjectTypeConstraints {
+                    max_object_size: Some(0),
+                    allowed_mime_types: Vec::new(),
+                    fee_per_byte: None,
+                },
+                data_object_type_registry::Error::MaxObjectSizeMustBePositive,
+            ),
+            (
+                data_object_type_registry::DataObjectTypeConstraints {
+                    max_object_size: None,
+                    allowed_mime_types: vec![
+                        b"mp4".to_vec();
+                        data_object_type_registry::MAX_ALLOWED_MIME_TYPES + 1
+                    ],
+                    fee_per_byte: None,
+                },
+                data_object_type_registry::Error::TooManyAllowedMimeTypes,
+            ),
+            (
+                data_object_type_registry::DataObjectTypeConstraints {
+                    max_object_size: None,
+                    allowed_mime_types: vec![Vec::new()],
+                    fee_per_byte: None,
+                },
+                data_object_type_registry::Error::InvalidMimeType,
+            ),
+            (
+                data_object_type_registry::DataObjectTypeConstraints {
+                    max_object_size: None,
+                    allowed_mime_types: vec![vec![
+                        b'a';
+                        data_object_type_registry::MAX_MIME_TYPE_LENGTH
+                            + 1
+                    ]],
+                    fee_per_byte: None,
+                },
+                data_object_type_registry::Error::InvalidMimeType,
+            ),
+        ];
+
+        for (constraints, error) in invalid_constraints {
+            let data: TestDataObjectType = TestDataObjectType {
+                description: "foo".as_bytes().to_vec(),
+                active: true,
+                constraints: constraints.clone(),
+            };
+            let res = TestDataObjectTypeRegistry::register_data_object_type(
+                RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+                data.clone(),
+            );
+            assert_eq!(res, Err(error));
+
+            let res = TestDataObjectTypeRegistry::update_data_object_type(
+                RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+                TEST_FIRST_DATA_OBJECT_TYPE_ID,
+                data,
+            );
+            assert!(res.is_err());
+        }
+
+        assert!(!<data_object_type_registry::DataObjectTypes<Test>>::exists(
+            TEST_FIRST_DATA_OBJECT_TYPE_ID
+        ));
+    });
+}
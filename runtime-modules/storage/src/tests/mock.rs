@@ -16,7 +16,10 @@ pub use sr_primitives::{
 use crate::data_directory::ContentIdExists;
 use crate::data_object_type_registry::IsActiveDataObjectType;
 pub use crate::StorageWorkingGroupInstance;
-use srml_support::{impl_outer_event, impl_outer_origin, parameter_types, StorageLinkedMap};
+use srml_support::{
+    impl_outer_event, impl_outer_origin, parameter_types, StorageLinkedMap, StorageMap,
+    StorageValue,
+};
 
 mod working_group_mod {
     pub use super::StorageWorkingGroupInstance;
@@ -45,6 +48,7 @@ pub const TEST_FIRST_METADATA_ID: u64 = 4000;
 
 pub const TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID: u32 = 1;
 pub const TEST_MOCK_EXISTING_CID: u64 = 42;
+pub const TEST_IPFS_CONTENT_ID: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
 
 pub struct AnyDataObjectTypeIsActive {}
 impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataObjectTypeIsActive {
@@ -66,6 +70,7 @@ impl ContentIdExists<Test> for MockContent {
             TEST_MOCK_EXISTING_CID => Ok(data_directory::DataObjectInternal {
                 type_id: 1,
                 size: 1234,
+                mime_type: vec![],
                 added_at: data_directory::BlockAndTime {
                     block: 10,
                     time: 1024,
@@ -74,6 +79,9 @@ impl ContentIdExists<Test> for MockContent {
                 liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
                 liaison_judgement: data_directory::LiaisonJudgement::Pending,
                 ipfs_content_id: vec![],
+                content_merkle_root: H256::zero(),
+                chunk_size: 1234,
+                content_hash: None,
             }),
             _ => Err("nope, missing"),
         }
@@ -149,11 +157,21 @@ impl GovernanceCurrency for Test {
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 3;
+    pub const MaxWorkerHistoryLength: u32 = 3;
+    pub const MaxWorkerSuspensionPeriod: u64 = 10;
+    pub const SlashingDisputePeriod: u64 = 5;
+    pub const ExtraRewardsBudgetPeriod: u64 = 100;
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxWorkerHistoryLength = MaxWorkerHistoryLength;
+    type MaxWorkerSuspensionPeriod = MaxWorkerSuspensionPeriod;
+    type SlashingDisputePeriod = SlashingDisputePeriod;
+    type ExtraRewardsBudgetPeriod = ExtraRewardsBudgetPeriod;
+    type SlashingAppealThreadId = u64;
+    type SlashingAppealDiscussion = ();
 }
 
 impl data_object_type_registry::Trait for Test {
@@ -166,6 +184,7 @@ impl data_directory::Trait for Test {
     type ContentId = u64;
     type StorageProviderHelper = ();
     type IsActiveDataObjectType = AnyDataObjectTypeIsActive;
+    type DataObjectTypeConstraintsProvider = TestDataObjectTypeRegistry;
     type MemberOriginValidator = ();
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
 }
@@ -203,13 +222,19 @@ impl stake::Trait for Test {
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
+    type StakingBackend = stake::PoolAccountBackend;
     type StakeId = u64;
     type SlashId = u64;
 }
 
+parameter_types! {
+    pub const MaxMintLedgerEntries: u32 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type MaxMintLedgerEntries = MaxMintLedgerEntries;
 }
 
 impl recurringrewards::Trait for Test {
@@ -291,7 +316,7 @@ impl ExtBuilder {
 pub type Balances = balances::Module<Test>;
 pub type System = system::Module<Test>;
 pub type TestDataObjectTypeRegistry = data_object_type_registry::Module<Test>;
-pub type TestDataObjectType = data_object_type_registry::DataObjectType;
+pub type TestDataObjectType = data_object_type_registry::DataObjectType<u64>;
 pub type TestDataDirectory = data_directory::Module<Test>;
 pub type TestDataObjectStorageRegistry = data_object_storage_registry::Module<Test>;
 
@@ -323,3 +348,28 @@ pub(crate) fn hire_storage_provider() -> (u64, u32) {
 
     (role_account_id, storage_provider_id)
 }
+
+pub(crate) fn create_storage_working_group_mint() -> u64 {
+    let mint_id = minting::Module::<Test>::add_mint(0, None, None).unwrap();
+
+    <working_group::Mint<Test, StorageWorkingGroupInstance>>::put(mint_id);
+
+    mint_id
+}
+
+pub(crate) fn register_data_object_type_with_constraints(
+    constraints: data_object_type_registry::DataObjectTypeConstraints<u64>,
+) -> u64 {
+    let type_id = TEST_FIRST_DATA_OBJECT_TYPE_ID;
+
+    <data_object_type_registry::DataObjectTypes<Test>>::insert(
+        type_id,
+        TestDataObjectType {
+            description: "foo".as_bytes().to_vec(),
+            active: true,
+            constraints,
+        },
+    );
+
+    type_id
+}
@@ -6,12 +6,13 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(feature = "std")]
 use rstd::prelude::*;
 
 use codec::{Codec, Decode, Encode};
 use runtime_primitives::traits::{MaybeSerialize, Member, One, SimpleArithmetic, Zero};
-use srml_support::traits::Currency;
+use srml_support::storage::generator::StorageLinkedMap;
+use srml_support::storage::unhashed;
+use srml_support::traits::{Currency, Get};
 use srml_support::{decl_module, decl_storage, ensure, Parameter};
 
 mod mint;
@@ -33,6 +34,9 @@ pub trait Trait: system::Trait {
         + Copy
         + MaybeSerialize
         + PartialEq;
+
+    /// Maximum number of the latest entries kept in the mint ledger.
+    type MaxMintLedgerEntries: Get<u32>;
 }
 
 pub type BalanceOf<T> =
@@ -42,6 +46,8 @@ pub type BalanceOf<T> =
 pub enum GeneralError {
     MintNotFound,
     NextAdjustmentInPast,
+    InvalidAdjustmentPercentage,
+    CapacityExceedsLifetimeLimit,
 }
 
 /// Errors that can arise from attempt to mint and transfer tokens from a mint to
@@ -50,6 +56,7 @@ pub enum GeneralError {
 pub enum TransferError {
     MintNotFound,
     NotEnoughCapacity,
+    LifetimeLimitExceeded,
 }
 
 /// Errors that can arise from attempt to transfer capacity between mints.
@@ -58,12 +65,16 @@ pub enum CapacityTransferError {
     SourceMintNotFound,
     DestinationMintNotFound,
     NotEnoughCapacity,
+    DestinationLifetimeLimitExceeded,
 }
 
 impl From<MintingError> for CapacityTransferError {
     fn from(err: MintingError) -> CapacityTransferError {
         match err {
             MintingError::NotEnoughCapacity => CapacityTransferError::NotEnoughCapacity,
+            MintingError::LifetimeLimitExceeded => {
+                CapacityTransferError::DestinationLifetimeLimitExceeded
+            }
         }
     }
 }
@@ -72,6 +83,7 @@ impl From<MintingError> for TransferError {
     fn from(err: MintingError) -> TransferError {
         match err {
             MintingError::NotEnoughCapacity => TransferError::NotEnoughCapacity,
+            MintingError::LifetimeLimitExceeded => TransferError::LifetimeLimitExceeded,
         }
     }
 }
@@ -81,6 +93,8 @@ impl From<GeneralError> for &'static str {
         match err {
             GeneralError::MintNotFound => "MintNotFound",
             GeneralError::NextAdjustmentInPast => "NextAdjustmentInPast",
+            GeneralError::InvalidAdjustmentPercentage => "InvalidAdjustmentPercentage",
+            GeneralError::CapacityExceedsLifetimeLimit => "CapacityExceedsLifetimeLimit",
         }
     }
 }
@@ -90,6 +104,7 @@ impl From<TransferError> for &'static str {
         match err {
             TransferError::MintNotFound => "MintNotFound",
             TransferError::NotEnoughCapacity => "NotEnoughCapacity",
+            TransferError::LifetimeLimitExceeded => "LifetimeLimitExceeded",
         }
     }
 }
@@ -104,6 +119,38 @@ pub enum Adjustment<Balance: Zero, BlockNumber> {
     IntervalAfterFirstAdjustmentRelative(AdjustOnInterval<Balance, BlockNumber>, BlockNumber),
 }
 
+/// Operation recorded in the mint ledger.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+pub enum MintLedgerEntryKind<AccountId, MintId> {
+    /// Capacity was increased with `set_mint_capacity`
+    CapacityIncreased,
+    /// Capacity was decreased with `set_mint_capacity`
+    CapacityDecreased,
+    /// Capacity was increased by the scheduled adjustment
+    CapacityAdjustedUp,
+    /// Capacity was decreased by the scheduled adjustment
+    CapacityAdjustedDown,
+    /// Capacity was transferred to the mint
+    CapacityTransferredTo(MintId),
+    /// Capacity was transferred from the mint
+    CapacityTransferredFrom(MintId),
+    /// Tokens were minted to the account
+    TokensTransferred(AccountId),
+}
+
+/// Entry of the mint ledger.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+pub struct MintLedgerEntry<AccountId, Balance, BlockNumber, MintId> {
+    /// Recorded operation
+    pub kind: MintLedgerEntryKind<AccountId, MintId>,
+    /// Capacity change or transferred tokens amount
+    pub amount: Balance,
+    /// Mint capacity after the operation
+    pub capacity: Balance,
+    /// Block of the operation
+    pub block: BlockNumber,
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as TokenMint {
         /// Mints
@@ -111,6 +158,12 @@ decl_storage! {
 
         /// The number of mints created.
         pub MintsCreated get(mints_created): T::MintId;
+
+        /// Mints by the block of their next capacity adjustment.
+        pub MintIdsByAdjustmentBlock get(mint_ids_by_adjustment_block): map T::BlockNumber => Vec<T::MintId>;
+
+        /// The latest capacity changes and token transfers of the mints, for auditing the spending.
+        pub MintLedger get(mint_ledger): map T::MintId => Vec<MintLedgerEntry<T::AccountId, BalanceOf<T>, T::BlockNumber, T::MintId>>;
     }
 }
 
@@ -124,23 +177,48 @@ decl_module! {
 
 impl<T: Trait> Module<T> {
     fn update_mints(now: T::BlockNumber) {
-        // Are we reading value from storage twice?
-        for (mint_id, ref mut mint) in <Mints<T>>::enumerate() {
+        for mint_id in <MintIdsByAdjustmentBlock<T>>::take(now) {
+            // Skip removed mints
+            if !<Mints<T>>::exists(&mint_id) {
+                continue;
+            }
+
+            let mut mint = Self::mints(&mint_id);
+            let capacity_before = mint.capacity();
+
             if mint.maybe_do_capacity_adjustment(now) {
+                if let Some(next_adjustment) = mint.next_adjustment() {
+                    // The adjustment queue of the current block was already taken
+                    if next_adjustment.at_block > now {
+                        Self::schedule_adjustment(mint_id, next_adjustment.at_block);
+                    }
+                }
+
+                Self::record_capacity_change(
+                    mint_id,
+                    capacity_before,
+                    mint.capacity(),
+                    MintLedgerEntryKind::CapacityAdjustedUp,
+                    MintLedgerEntryKind::CapacityAdjustedDown,
+                    now,
+                );
+
                 <Mints<T>>::insert(&mint_id, mint);
             }
         }
     }
 
     /// Adds a new mint with given settings to mints, and returns new MintId.
+    /// The optional lifetime limit of the total minted tokens caps the mint capacity.
     pub fn add_mint(
         initial_capacity: BalanceOf<T>,
         adjustment: Option<Adjustment<BalanceOf<T>, T::BlockNumber>>,
+        max_total_minted: Option<BalanceOf<T>>,
     ) -> Result<T::MintId, GeneralError> {
         let now = <system::Module<T>>::block_number();
 
-        // Ensure the next adjustment if set, is in the future
         if let Some(adjustment) = adjustment {
+            // Ensure the next adjustment if set, is in the future
             if let Adjustment::IntervalAfterFirstAdjustmentAbsolute(_, first_adjustment_in) =
                 adjustment
             {
@@ -149,6 +227,27 @@ impl<T: Trait> Module<T> {
                     GeneralError::NextAdjustmentInPast
                 );
             }
+
+            // Ensure the percentage adjustment is valid
+            let adjust_on_interval = match adjustment {
+                Adjustment::Interval(adjust_on_interval)
+                | Adjustment::IntervalAfterFirstAdjustmentAbsolute(adjust_on_interval, _)
+                | Adjustment::IntervalAfterFirstAdjustmentRelative(adjust_on_interval, _) => {
+                    adjust_on_interval
+                }
+            };
+            ensure!(
+                adjust_on_interval.adjustment_type.is_valid(),
+                GeneralError::InvalidAdjustmentPercentage
+            );
+        }
+
+        // Ensure the initial capacity doesn't exceed the lifetime limit
+        if let Some(max_total_minted) = max_total_minted {
+            ensure!(
+                initial_capacity <= max_total_minted,
+                GeneralError::CapacityExceedsLifetimeLimit
+            );
         }
 
         // Determine next adjutment
@@ -177,14 +276,29 @@ impl<T: Trait> Module<T> {
         let mint_id = Self::mints_created();
         <MintsCreated<T>>::put(mint_id + One::one());
 
-        <Mints<T>>::insert(mint_id, Mint::new(initial_capacity, next_adjustment, now));
+        <Mints<T>>::insert(
+            mint_id,
+            Mint::new(initial_capacity, next_adjustment, now, max_total_minted),
+        );
+
+        if let Some(next_adjustment) = next_adjustment {
+            Self::schedule_adjustment(mint_id, next_adjustment.at_block);
+        }
 
         Ok(mint_id)
     }
 
     /// Removes a mint. Passing a non existent mint has no side effects.
     pub fn remove_mint(mint_id: T::MintId) {
-        <Mints<T>>::remove(&mint_id);
+        if <Mints<T>>::exists(&mint_id) {
+            let mint = <Mints<T>>::take(&mint_id);
+
+            if let Some(next_adjustment) = mint.next_adjustment() {
+                Self::unschedule_adjustment(mint_id, next_adjustment.at_block);
+            }
+
+            <MintLedger<T>>::remove(&mint_id);
+        }
     }
 
     /// Tries to transfer exact requested amount from mint to a recipient account id.
@@ -206,6 +320,13 @@ impl<T: Trait> Module<T> {
         // Try minting
         mint.mint_tokens(requested_amount)?;
 
+        Self::add_ledger_entry(
+            mint_id,
+            MintLedgerEntryKind::TokensTransferred(recipient.clone()),
+            requested_amount,
+            mint.capacity(),
+        );
+
         <Mints<T>>::insert(&mint_id, mint);
 
         // Deposit into recipient account
@@ -215,15 +336,33 @@ impl<T: Trait> Module<T> {
     }
 
     /// Provided mint exists, sets its capacity to specied value, return error otherwise.
+    /// The capacity can't exceed the remaining lifetime limit of the mint.
     pub fn set_mint_capacity(
         mint_id: T::MintId,
         capacity: BalanceOf<T>,
     ) -> Result<(), GeneralError> {
         ensure!(<Mints<T>>::exists(&mint_id), GeneralError::MintNotFound);
 
-        <Mints<T>>::mutate(&mint_id, |mint| {
-            mint.set_capacity(capacity);
-        });
+        let mut mint = Self::mints(&mint_id);
+
+        ensure!(
+            mint.within_lifetime_limit(capacity),
+            GeneralError::CapacityExceedsLifetimeLimit
+        );
+        let capacity_before = mint.capacity();
+
+        mint.set_capacity(capacity);
+
+        <Mints<T>>::insert(&mint_id, mint);
+
+        Self::record_capacity_change(
+            mint_id,
+            capacity_before,
+            capacity,
+            MintLedgerEntryKind::CapacityIncreased,
+            MintLedgerEntryKind::CapacityDecreased,
+            <system::Module<T>>::block_number(),
+        );
 
         Ok(())
     }
@@ -251,6 +390,21 @@ impl<T: Trait> Module<T> {
             })
         })?;
 
+        if !capacity_to_transfer.is_zero() {
+            Self::add_ledger_entry(
+                source,
+                MintLedgerEntryKind::CapacityTransferredTo(destination),
+                capacity_to_transfer,
+                Self::mints(&source).capacity(),
+            );
+            Self::add_ledger_entry(
+                destination,
+                MintLedgerEntryKind::CapacityTransferredFrom(source),
+                capacity_to_transfer,
+                Self::mints(&destination).capacity(),
+            );
+        }
+
         Ok(())
     }
 
@@ -277,4 +431,149 @@ impl<T: Trait> Module<T> {
     pub fn mint_exists(mint_id: T::MintId) -> bool {
         <Mints<T>>::exists(&mint_id)
     }
+
+    /// Converts mints saved before the lifetime limit of the total minted tokens was introduced,
+    /// legacy mints get no limit. Should be called on the migration to the new runtime version,
+    /// before any other use of the mints.
+    pub fn migrate_mints_lifetime_limit() {
+        let mut mint_id = T::MintId::zero();
+
+        while mint_id < Self::mints_created() {
+            let key = <Mints<T>>::storage_linked_map_final_key(mint_id);
+
+            if let Some(raw_value) = unhashed::get_raw(&key) {
+                let mut input = &raw_value[..];
+
+                if let Ok(legacy) = LegacyMint::<BalanceOf<T>, T::BlockNumber>::decode(&mut input) {
+                    // Linked map values are followed by the linkage to the adjacent keys.
+                    // Values already saved with the new layout don't end with the linkage.
+                    let linkage = input;
+
+                    if Self::is_linkage(linkage) {
+                        let mut value = Mint::from_legacy(legacy).encode();
+                        value.extend_from_slice(linkage);
+
+                        unhashed::put_raw(&key, &value);
+                    }
+                }
+            }
+
+            mint_id += One::one();
+        }
+    }
+
+    /// Schedules capacity adjustments of all existing mints with the next adjustment not in the
+    /// past. Should be called on the migration to the new runtime version.
+    pub fn schedule_existing_adjustments() {
+        let now = <system::Module<T>>::block_number();
+
+        for (mint_id, mint) in <Mints<T>>::enumerate() {
+            if let Some(next_adjustment) = mint.next_adjustment() {
+                if next_adjustment.at_block >= now {
+                    Self::schedule_adjustment(mint_id, next_adjustment.at_block);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    // Checks whether the raw value is the encoded linkage of the mints linked map.
+    fn is_linkage(raw_value: &[u8]) -> bool {
+        let mut input = raw_value;
+
+        let linkage = <(Option<T::MintId>, Option<T::MintId>)>::decode(&mut input);
+
+        linkage.is_ok() && input.is_empty()
+    }
+
+    // Adds the mint to the adjustment queue of the provided block.
+    fn schedule_adjustment(mint_id: T::MintId, at_block: T::BlockNumber) {
+        <MintIdsByAdjustmentBlock<T>>::mutate(at_block, |mint_ids| {
+            if !mint_ids.contains(&mint_id) {
+                mint_ids.push(mint_id);
+            }
+        });
+    }
+
+    // Removes the mint from the adjustment queue of the provided block.
+    fn unschedule_adjustment(mint_id: T::MintId, at_block: T::BlockNumber) {
+        let mut mint_ids = Self::mint_ids_by_adjustment_block(at_block);
+        mint_ids.retain(|scheduled_id| *scheduled_id != mint_id);
+
+        if mint_ids.is_empty() {
+            <MintIdsByAdjustmentBlock<T>>::remove(at_block);
+        } else {
+            <MintIdsByAdjustmentBlock<T>>::insert(at_block, mint_ids);
+        }
+    }
+
+    // Records the capacity change in the mint ledger with the entry kind matching its direction.
+    fn record_capacity_change(
+        mint_id: T::MintId,
+        capacity_before: BalanceOf<T>,
+        capacity_after: BalanceOf<T>,
+        increased_kind: MintLedgerEntryKind<T::AccountId, T::MintId>,
+        decreased_kind: MintLedgerEntryKind<T::AccountId, T::MintId>,
+        now: T::BlockNumber,
+    ) {
+        if capacity_after > capacity_before {
+            Self::add_ledger_entry_at(
+                mint_id,
+                increased_kind,
+                capacity_after - capacity_before,
+                capacity_after,
+                now,
+            );
+        } else if capacity_after < capacity_before {
+            Self::add_ledger_entry_at(
+                mint_id,
+                decreased_kind,
+                capacity_before - capacity_after,
+                capacity_after,
+                now,
+            );
+        }
+    }
+
+    // Records the operation at the current block in the mint ledger.
+    fn add_ledger_entry(
+        mint_id: T::MintId,
+        kind: MintLedgerEntryKind<T::AccountId, T::MintId>,
+        amount: BalanceOf<T>,
+        capacity: BalanceOf<T>,
+    ) {
+        Self::add_ledger_entry_at(
+            mint_id,
+            kind,
+            amount,
+            capacity,
+            <system::Module<T>>::block_number(),
+        );
+    }
+
+    // Records the operation in the mint ledger, dropping the oldest entries over the limit.
+    fn add_ledger_entry_at(
+        mint_id: T::MintId,
+        kind: MintLedgerEntryKind<T::AccountId, T::MintId>,
+        amount: BalanceOf<T>,
+        capacity: BalanceOf<T>,
+        block: T::BlockNumber,
+    ) {
+        let max_entries = T::MaxMintLedgerEntries::get() as usize;
+
+        <MintLedger<T>>::mutate(mint_id, |ledger| {
+            ledger.push(MintLedgerEntry {
+                kind,
+                amount,
+                capacity,
+                block,
+            });
+
+            if ledger.len() > max_entries {
+                let excess_entries = ledger.len() - max_entries;
+                ledger.drain(..excess_entries);
+            }
+        });
+    }
 }
@@ -1,4 +1,5 @@
 use codec::{Decode, Encode};
+use rstd::cmp::{max, min};
 use runtime_primitives::traits::{SimpleArithmetic, Zero};
 use srml_support::ensure;
 
@@ -10,6 +11,26 @@ pub enum AdjustCapacityBy<Balance: Zero> {
     Adding(Balance),
     /// Reduce capacity of the mint
     Reducing(Balance),
+    /// Add the percentage of the current capacity to the capacity of the mint
+    AddingPercentage(u16),
+    /// Reduce capacity of the mint by the percentage of the current capacity
+    ReducingPercentage(u16),
+    /// Set capacity of mint to specific value, if the current capacity is lower
+    ToppingUpTo(Balance),
+}
+
+impl<Balance: Zero> AdjustCapacityBy<Balance> {
+    /// Verifies whether the percentage adjustments are non-zero and don't reduce more
+    /// than the whole capacity.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            AdjustCapacityBy::AddingPercentage(percentage) => *percentage > 0,
+            AdjustCapacityBy::ReducingPercentage(percentage) => {
+                *percentage > 0 && *percentage <= 100
+            }
+            _ => true,
+        }
+    }
 }
 
 #[derive(Encode, Decode, Copy, Clone, Debug, Eq, PartialEq)]
@@ -42,11 +63,28 @@ where
     created_at: BlockNumber,
 
     total_minted: Balance,
+
+    // When set, the lifetime limit of the total minted tokens, the mint capacity
+    // never exceeds the remaining limit.
+    max_total_minted: Option<Balance>,
+}
+
+/// Mint layout before the lifetime limit of the total minted tokens was introduced.
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyMint<Balance, BlockNumber>
+where
+    Balance: Zero,
+{
+    pub(crate) capacity: Balance,
+    pub(crate) next_adjustment: Option<NextAdjustment<Balance, BlockNumber>>,
+    pub(crate) created_at: BlockNumber,
+    pub(crate) total_minted: Balance,
 }
 
 #[derive(PartialEq, Eq, Debug)]
 pub enum MintingError {
     NotEnoughCapacity,
+    LifetimeLimitExceeded,
 }
 
 impl<Balance, BlockNumber> Mint<Balance, BlockNumber>
@@ -58,12 +96,25 @@ where
         initial_capacity: Balance,
         next_adjustment: Option<NextAdjustment<Balance, BlockNumber>>,
         now: BlockNumber,
+        max_total_minted: Option<Balance>,
     ) -> Self {
         Mint {
             capacity: initial_capacity,
             created_at: now,
             total_minted: Zero::zero(),
             next_adjustment,
+            max_total_minted,
+        }
+    }
+
+    /// Converts the mint saved with the legacy layout, legacy mints have no lifetime limit.
+    pub(crate) fn from_legacy(legacy: LegacyMint<Balance, BlockNumber>) -> Self {
+        Mint {
+            capacity: legacy.capacity,
+            next_adjustment: legacy.next_adjustment,
+            created_at: legacy.created_at,
+            total_minted: legacy.total_minted,
+            max_total_minted: None,
         }
     }
 
@@ -72,6 +123,10 @@ where
             self.capacity >= requested_amount,
             MintingError::NotEnoughCapacity
         );
+        ensure!(
+            self.within_lifetime_limit(requested_amount),
+            MintingError::LifetimeLimitExceeded
+        );
         self.capacity -= requested_amount;
         self.total_minted += requested_amount;
         Ok(())
@@ -86,7 +141,7 @@ where
     }
 
     pub fn can_mint(&self, amount: Balance) -> bool {
-        self.capacity >= amount
+        self.capacity >= amount && self.within_lifetime_limit(amount)
     }
 
     pub fn created_at(&self) -> BlockNumber {
@@ -97,6 +152,28 @@ where
         self.total_minted
     }
 
+    pub fn max_total_minted(&self) -> Option<Balance> {
+        self.max_total_minted
+    }
+
+    /// Verifies whether the mint can ever mint the amount without exceeding the lifetime limit.
+    pub fn within_lifetime_limit(&self, amount: Balance) -> bool {
+        self.max_total_minted.map_or(true, |max_total_minted| {
+            max_total_minted >= self.total_minted && amount <= max_total_minted - self.total_minted
+        })
+    }
+
+    // Limits the capacity to the remaining lifetime limit of the total minted tokens.
+    fn limited_by_lifetime_limit(&self, capacity: Balance) -> Balance {
+        self.max_total_minted.map_or(capacity, |max_total_minted| {
+            if max_total_minted > self.total_minted {
+                min(capacity, max_total_minted - self.total_minted)
+            } else {
+                Zero::zero()
+            }
+        })
+    }
+
     pub fn transfer_capacity_to(
         &mut self,
         destination: &mut Self,
@@ -106,8 +183,13 @@ where
             self.capacity >= capacity_to_transfer,
             MintingError::NotEnoughCapacity
         );
+        let destination_capacity = destination.capacity.saturating_add(capacity_to_transfer);
+        ensure!(
+            destination.within_lifetime_limit(destination_capacity),
+            MintingError::LifetimeLimitExceeded
+        );
         self.capacity -= capacity_to_transfer;
-        destination.capacity += capacity_to_transfer;
+        destination.capacity = destination_capacity;
         Ok(())
     }
 
@@ -120,11 +202,11 @@ where
             if now != next_adjustment.at_block {
                 false
             } else {
-                // update mint capacity
-                self.capacity = Self::adjusted_capacity(
+                // update mint capacity, not exceeding the lifetime limit
+                self.capacity = self.limited_by_lifetime_limit(Self::adjusted_capacity(
                     self.capacity,
                     next_adjustment.adjustment.adjustment_type,
-                );
+                ));
 
                 // set next adjustment
                 self.next_adjustment = Some(NextAdjustment {
@@ -148,6 +230,17 @@ where
                     capacity - amount
                 }
             }
+            AdjustCapacityBy::AddingPercentage(percentage) => {
+                capacity.saturating_add(Self::percentage_of(capacity, percentage))
+            }
+            AdjustCapacityBy::ReducingPercentage(percentage) => {
+                capacity.saturating_sub(Self::percentage_of(capacity, percentage))
+            }
+            AdjustCapacityBy::ToppingUpTo(target) => max(capacity, target),
         }
     }
+
+    fn percentage_of(capacity: Balance, percentage: u16) -> Balance {
+        capacity.saturating_mul(Balance::from(percentage)) / Balance::from(100u16)
+    }
 }
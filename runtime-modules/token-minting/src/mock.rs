@@ -72,9 +72,14 @@ impl balances::Trait for Test {
     type CreationFee = CreationFee;
 }
 
+parameter_types! {
+    pub const MaxMintLedgerEntries: u32 = 5;
+}
+
 impl Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type MaxMintLedgerEntries = MaxMintLedgerEntries;
 }
 
 pub fn build_test_externalities() -> runtime_io::TestExternalities {
@@ -15,7 +15,7 @@ fn adding_and_removing_mints() {
             block_interval: 100,
         };
 
-        let mint_id = Minting::add_mint(capacity, Some(Adjustment::Interval(adjustment)))
+        let mint_id = Minting::add_mint(capacity, Some(Adjustment::Interval(adjustment)), None)
             .ok()
             .unwrap();
         assert!(Minting::mint_exists(mint_id));
@@ -40,7 +40,7 @@ fn minting() {
     build_test_externalities().execute_with(|| {
         let capacity: u64 = 5000;
 
-        let mint_id = Minting::add_mint(capacity, None).ok().unwrap();
+        let mint_id = Minting::add_mint(capacity, None, None).ok().unwrap();
 
         assert!(Minting::transfer_tokens(mint_id, 1000, &1).is_ok());
 
@@ -55,7 +55,7 @@ fn minting_exact() {
     build_test_externalities().execute_with(|| {
         let capacity: u64 = 1000;
 
-        let mint_id = Minting::add_mint(capacity, None).ok().unwrap();
+        let mint_id = Minting::add_mint(capacity, None, None).ok().unwrap();
 
         assert_eq!(
             Minting::transfer_tokens(mint_id, 2000, &1),
@@ -76,7 +76,7 @@ fn adjustment_adding() {
             block_interval: 100,
         };
 
-        let mint_id = Minting::add_mint(capacity, Some(Adjustment::Interval(adjustment)))
+        let mint_id = Minting::add_mint(capacity, Some(Adjustment::Interval(adjustment)), None)
             .ok()
             .unwrap();
 
@@ -111,7 +111,7 @@ fn adjustment_reducing() {
             block_interval: 100,
         };
 
-        let mint_id = Minting::add_mint(capacity, Some(Adjustment::Interval(adjustment)))
+        let mint_id = Minting::add_mint(capacity, Some(Adjustment::Interval(adjustment)), None)
             .ok()
             .unwrap();
 
@@ -151,7 +151,7 @@ fn adjustment_setting() {
             block_interval: 100,
         };
 
-        let mint_id = Minting::add_mint(capacity, Some(Adjustment::Interval(adjustment)))
+        let mint_id = Minting::add_mint(capacity, Some(Adjustment::Interval(adjustment)), None)
             .ok()
             .unwrap();
 
@@ -180,6 +180,7 @@ fn adjustment_first_interval() {
             Some(Adjustment::IntervalAfterFirstAdjustmentAbsolute(
                 adjustment, 1000,
             )),
+            None,
         )
         .ok()
         .unwrap();
@@ -200,3 +201,374 @@ fn adjustment_first_interval() {
         );
     });
 }
+
+#[test]
+fn adjustments_are_scheduled_by_block() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Adding(500),
+            block_interval: 100,
+        };
+
+        let mint_id = Minting::add_mint(1000, Some(Adjustment::Interval(adjustment)), None)
+            .ok()
+            .unwrap();
+        let other_mint_id = Minting::add_mint(
+            1000,
+            Some(Adjustment::IntervalAfterFirstAdjustmentRelative(
+                adjustment, 50,
+            )),
+            None,
+        )
+        .ok()
+        .unwrap();
+        let _ = Minting::add_mint(1000, None, None).ok().unwrap();
+
+        assert_eq!(Minting::mint_ids_by_adjustment_block(100), vec![mint_id]);
+        assert_eq!(
+            Minting::mint_ids_by_adjustment_block(50),
+            vec![other_mint_id]
+        );
+
+        // Adjustment takes the mint out of the queue and schedules the next adjustment
+        Minting::update_mints(100);
+        assert!(!<MintIdsByAdjustmentBlock<Test>>::exists(100));
+        assert_eq!(Minting::mint_ids_by_adjustment_block(200), vec![mint_id]);
+
+        // Removing the mint removes it from the queue
+        Minting::remove_mint(mint_id);
+        assert!(!<MintIdsByAdjustmentBlock<Test>>::exists(200));
+
+        Minting::update_mints(50);
+        assert_eq!(
+            Minting::mint_ids_by_adjustment_block(150),
+            vec![other_mint_id]
+        );
+    });
+}
+
+#[test]
+fn existing_adjustments_are_scheduled_on_migration() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Adding(500),
+            block_interval: 100,
+        };
+
+        let mint_id = Minting::add_mint(1000, Some(Adjustment::Interval(adjustment)), None)
+            .ok()
+            .unwrap();
+
+        // Mints added before the adjustment queue was introduced.
+        <MintIdsByAdjustmentBlock<Test>>::remove(100);
+
+        Minting::schedule_existing_adjustments();
+        // Migration can be repeated without duplicates.
+        Minting::schedule_existing_adjustments();
+
+        assert_eq!(Minting::mint_ids_by_adjustment_block(100), vec![mint_id]);
+
+        Minting::update_mints(100);
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(1500));
+    });
+}
+
+#[test]
+fn mint_ledger_records_capacity_changes_and_transfers() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Setting(3000),
+            block_interval: 100,
+        };
+
+        let mint_id = Minting::add_mint(5000, Some(Adjustment::Interval(adjustment)), None)
+            .ok()
+            .unwrap();
+        let other_mint_id = Minting::add_mint(0, None, None).ok().unwrap();
+
+        System::set_block_number(10);
+        assert!(Minting::transfer_tokens(mint_id, 1000, &1).is_ok());
+        assert!(Minting::transfer_capacity(mint_id, other_mint_id, 500).is_ok());
+        assert!(Minting::set_mint_capacity(mint_id, 4500).is_ok());
+
+        // Unchanged capacity is not recorded
+        assert!(Minting::set_mint_capacity(mint_id, 4500).is_ok());
+
+        Minting::update_mints(100);
+
+        assert_eq!(
+            Minting::mint_ledger(mint_id),
+            vec![
+                MintLedgerEntry {
+                    kind: MintLedgerEntryKind::TokensTransferred(1),
+                    amount: 1000,
+                    capacity: 4000,
+                    block: 10,
+                },
+                MintLedgerEntry {
+                    kind: MintLedgerEntryKind::CapacityTransferredTo(other_mint_id),
+                    amount: 500,
+                    capacity: 3500,
+                    block: 10,
+                },
+                MintLedgerEntry {
+                    kind: MintLedgerEntryKind::CapacityIncreased,
+                    amount: 1000,
+                    capacity: 4500,
+                    block: 10,
+                },
+                MintLedgerEntry {
+                    kind: MintLedgerEntryKind::CapacityAdjustedDown,
+                    amount: 1500,
+                    capacity: 3000,
+                    block: 100,
+                },
+            ]
+        );
+        assert_eq!(
+            Minting::mint_ledger(other_mint_id),
+            vec![MintLedgerEntry {
+                kind: MintLedgerEntryKind::CapacityTransferredFrom(mint_id),
+                amount: 500,
+                capacity: 500,
+                block: 10,
+            }]
+        );
+
+        Minting::remove_mint(mint_id);
+        assert!(!<MintLedger<Test>>::exists(mint_id));
+    });
+}
+
+#[test]
+fn mint_ledger_is_bounded() {
+    build_test_externalities().execute_with(|| {
+        let mint_id = Minting::add_mint(5000, None, None).ok().unwrap();
+
+        for amount in 1..=7 {
+            assert!(Minting::transfer_tokens(mint_id, amount, &1).is_ok());
+        }
+
+        let ledger = Minting::mint_ledger(mint_id);
+        assert_eq!(
+            ledger.len(),
+            <Test as Trait>::MaxMintLedgerEntries::get() as usize
+        );
+
+        // The oldest entries are dropped
+        let amounts = ledger.iter().map(|entry| entry.amount).collect::<Vec<_>>();
+        assert_eq!(amounts, vec![3, 4, 5, 6, 7]);
+    });
+}
+
+fn add_mint_with_interval_adjustment(
+    capacity: u64,
+    adjustment_type: AdjustCapacityBy<u64>,
+    max_total_minted: Option<u64>,
+) -> Result<u64, GeneralError> {
+    Minting::add_mint(
+        capacity,
+        Some(Adjustment::Interval(AdjustOnInterval {
+            adjustment_type,
+            block_interval: 100,
+        })),
+        max_total_minted,
+    )
+}
+
+#[test]
+fn adjustment_adding_percentage() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+
+        let mint_id =
+            add_mint_with_interval_adjustment(1000, AdjustCapacityBy::AddingPercentage(10), None)
+                .unwrap();
+
+        Minting::update_mints(100);
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(1100));
+
+        Minting::update_mints(200);
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(1210));
+    });
+}
+
+#[test]
+fn adjustment_reducing_percentage() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+
+        let mint_id =
+            add_mint_with_interval_adjustment(1000, AdjustCapacityBy::ReducingPercentage(25), None)
+                .unwrap();
+
+        Minting::update_mints(100);
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(750));
+
+        Minting::update_mints(200);
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(563));
+    });
+}
+
+#[test]
+fn adjustment_topping_up() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+
+        let mint_id =
+            add_mint_with_interval_adjustment(3000, AdjustCapacityBy::ToppingUpTo(3000), None)
+                .unwrap();
+
+        assert!(Minting::transfer_tokens(mint_id, 1000, &1).is_ok());
+
+        // Capacity is refilled up to the target
+        Minting::update_mints(100);
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(3000));
+
+        // Capacity over the target is kept
+        assert!(Minting::set_mint_capacity(mint_id, 4000).is_ok());
+        Minting::update_mints(200);
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(4000));
+    });
+}
+
+#[test]
+fn adding_mint_fails_with_invalid_adjustment_percentage() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(
+            add_mint_with_interval_adjustment(1000, AdjustCapacityBy::AddingPercentage(0), None),
+            Err(GeneralError::InvalidAdjustmentPercentage)
+        );
+        assert_eq!(
+            add_mint_with_interval_adjustment(1000, AdjustCapacityBy::ReducingPercentage(0), None),
+            Err(GeneralError::InvalidAdjustmentPercentage)
+        );
+        assert_eq!(
+            add_mint_with_interval_adjustment(
+                1000,
+                AdjustCapacityBy::ReducingPercentage(101),
+                None
+            ),
+            Err(GeneralError::InvalidAdjustmentPercentage)
+        );
+        assert!(add_mint_with_interval_adjustment(
+            1000,
+            AdjustCapacityBy::ReducingPercentage(100),
+            None
+        )
+        .is_ok());
+    });
+}
+
+#[test]
+fn lifetime_limit_caps_total_minted() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+
+        assert_eq!(
+            Minting::add_mint(3000, None, Some(2500)),
+            Err(GeneralError::CapacityExceedsLifetimeLimit)
+        );
+
+        let mint_id =
+            add_mint_with_interval_adjustment(2000, AdjustCapacityBy::Adding(1000), Some(2500))
+                .unwrap();
+
+        assert!(Minting::transfer_tokens(mint_id, 1500, &1).is_ok());
+
+        // Adjustment doesn't exceed the remaining lifetime limit
+        Minting::update_mints(100);
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(1000));
+
+        assert_eq!(
+            Minting::set_mint_capacity(mint_id, 1001),
+            Err(GeneralError::CapacityExceedsLifetimeLimit)
+        );
+
+        assert!(Minting::transfer_tokens(mint_id, 1000, &1).is_ok());
+        assert_eq!(Minting::mints(mint_id).total_minted(), 2500);
+
+        // Nothing can be minted after the lifetime limit is reached
+        Minting::update_mints(200);
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(0));
+        assert_eq!(
+            Minting::set_mint_capacity(mint_id, 1),
+            Err(GeneralError::CapacityExceedsLifetimeLimit)
+        );
+    });
+}
+
+#[test]
+fn can_mint_respects_lifetime_limit() {
+    let mint: Mint<u64, u64> = Mint::new(3000, None, 0, Some(2500));
+
+    assert!(mint.can_mint(2500));
+    assert!(!mint.can_mint(2501));
+}
+
+#[test]
+fn transfer_capacity_respects_destination_lifetime_limit() {
+    build_test_externalities().execute_with(|| {
+        let source_mint_id = Minting::add_mint(5000, None, None).unwrap();
+        let destination_mint_id = Minting::add_mint(0, None, Some(1000)).unwrap();
+
+        assert_eq!(
+            Minting::transfer_capacity(source_mint_id, destination_mint_id, 1001),
+            Err(CapacityTransferError::DestinationLifetimeLimitExceeded)
+        );
+        assert_eq!(Minting::get_mint_capacity(source_mint_id), Ok(5000));
+
+        assert!(Minting::transfer_capacity(source_mint_id, destination_mint_id, 1000).is_ok());
+        assert_eq!(Minting::get_mint_capacity(destination_mint_id), Ok(1000));
+    });
+}
+
+#[test]
+fn legacy_mints_are_migrated() {
+    build_test_externalities().execute_with(|| {
+        let mint_ids = (0..3)
+            .map(|_| Minting::add_mint(1000, None, Some(5000)).unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(Minting::transfer_tokens(mint_ids[0], 400, &1).is_ok());
+
+        // Save the first two mints with the legacy layout keeping the linkage.
+        for mint_id in &mint_ids[..2] {
+            let mint = Minting::mints(mint_id);
+            let key = <Mints<Test>>::storage_linked_map_final_key(mint_id);
+            let raw_value = unhashed::get_raw(&key).unwrap();
+            let linkage = &raw_value[mint.encode().len()..];
+
+            let mut legacy_value = LegacyMint {
+                capacity: mint.capacity(),
+                next_adjustment: mint.next_adjustment(),
+                created_at: mint.created_at(),
+                total_minted: mint.total_minted(),
+            }
+            .encode();
+            legacy_value.extend_from_slice(linkage);
+
+            unhashed::put_raw(&key, &legacy_value);
+        }
+
+        Minting::migrate_mints_lifetime_limit();
+        // Migration can be repeated.
+        Minting::migrate_mints_lifetime_limit();
+
+        assert_eq!(<Mints<Test>>::enumerate().count(), mint_ids.len());
+
+        let mint = Minting::mints(mint_ids[0]);
+        assert_eq!(mint.capacity(), 600);
+        assert_eq!(mint.total_minted(), 400);
+        assert_eq!(mint.max_total_minted(), None);
+
+        assert_eq!(Minting::mints(mint_ids[1]).max_total_minted(), None);
+        assert_eq!(Minting::mints(mint_ids[2]).max_total_minted(), Some(5000));
+    });
+}
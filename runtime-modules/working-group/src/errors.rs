@@ -301,6 +301,147 @@ decl_error! {
         /// Invalid OpeningPolicyCommitment parameter (application_rationing_policy):
         /// max_active_applicants should be non-zero.
         ApplicationRationingPolicyMaxActiveApplicantsIsZero,
+
+        /// Worker evaluation score is greater than the max evaluation score.
+        WorkerEvaluationScoreTooHigh,
+
+        /// Worker evaluation rationale is too short.
+        WorkerEvaluationRationaleTooShort,
+
+        /// Worker evaluation rationale is too long.
+        WorkerEvaluationRationaleTooLong,
+
+        /// Work report should reference at least one content hash.
+        WorkReportContentHashesAreEmpty,
+
+        /// Work report references too many content hashes.
+        WorkReportTooManyContentHashes,
+
+        /// Work report description is too short.
+        WorkReportDescriptionTooShort,
+
+        /// Work report description is too long.
+        WorkReportDescriptionTooLong,
+
+        /// Worker is suspended.
+        WorkerIsSuspended,
+
+        /// Worker is already suspended.
+        WorkerIsAlreadySuspended,
+
+        /// Worker is not suspended.
+        WorkerIsNotSuspended,
+
+        /// Worker suspension period should be non-zero.
+        WorkerSuspensionPeriodIsZero,
+
+        /// Worker suspension period is greater than the max suspension period.
+        WorkerSuspensionPeriodTooLong,
+
+        /// Suspended leader cannot act as the leader.
+        LeaderIsSuspended,
+
+        /// Worker already has a stake decrease request.
+        StakeDecreaseRequestAlreadyExists,
+
+        /// Worker has no stake decrease request.
+        StakeDecreaseRequestDoesNotExist,
+
+        /// Worker stake decrease request is already approved.
+        StakeDecreaseRequestIsAlreadyApproved,
+
+        /// Pending slash with such id does not exist for the worker.
+        PendingSlashDoesNotExist,
+
+        /// Slashing is already appealed.
+        SlashingIsAlreadyAppealed,
+
+        /// Slashing is not appealed.
+        SlashingIsNotAppealed,
+
+        /// Worker/lead cannot leave the role or be terminated with the pending slashes.
+        WorkerHasPendingSlashes,
+
+        /// Slash period should be greater than zero.
+        StakingErrorSlashPeriodShouldBeGreaterThanZero,
+
+        /// Slash not found.
+        StakingErrorSlashNotFound,
+
+        /// Slashing is already paused.
+        StakingErrorSlashingAlreadyPaused,
+
+        /// Slashing is not paused.
+        StakingErrorSlashingIsNotPaused,
+
+        /// Worker/lead role stake cannot be slashed according to the role slashing terms.
+        WorkerRoleIsUnslashable,
+
+        /// Max number of slashes of the worker/lead role stake is reached.
+        WorkerMaxSlashCountReached,
+
+        /// Max percentage points of the role stake slashed per time cannot exceed 100.
+        RoleSlashingTermsMaxPercentPtsPerTimeTooHigh,
+
+        /// Bonus or extra reward amount cannot be zero.
+        ExtraRewardAmountIsZero,
+
+        /// Extra reward payout interval cannot be zero.
+        ExtraRewardPayoutIntervalIsZero,
+
+        /// Extra reward duration should cover at least one payout interval.
+        ExtraRewardDurationTooShort,
+
+        /// Max number of the bonuses and extra rewards of the worker/lead is reached.
+        WorkerMaxExtraRewardsReached,
+
+        /// Bonuses and extra rewards granted within the budget period cannot exceed the mint capacity.
+        ExtraRewardsBudgetExceeded,
+
+        /// Opening template with provided id doesn't exist.
+        OpeningTemplateDoesNotExist,
+
+        /// Opening template name is too short.
+        OpeningTemplateNameTooShort,
+
+        /// Opening template name is too long.
+        OpeningTemplateNameTooLong,
+
+        /// Opening template with provided name already exists.
+        OpeningTemplateNameIsNotUnique,
+
+        /// Provided opening template version doesn't match the current template version.
+        OpeningTemplateVersionMismatch,
+
+        /// Reviewed application doesn't exist.
+        ReviewApplicationApplicationDoesNotExist,
+
+        /// Reviewed application is not active.
+        ReviewApplicationApplicationNotActive,
+
+        /// Opening of the reviewed application is not in the review period stage.
+        ReviewApplicationOpeningNotInReviewPeriodStage,
+
+        /// Unstaking period of the declined application is too short.
+        ReviewApplicationUnstakingPeriodTooShort,
+
+        /// Redundant unstaking period of the declined application provided.
+        ReviewApplicationRedundantUnstakingPeriod,
+
+        /// Arrears policy of the reward relationship is not set.
+        RecurringRewardsArrearsPolicyNotSet,
+
+        /// Reward relationship has no arrears to settle.
+        RecurringRewardsNoArrearsToSettle,
+
+        /// Reward relationship end is not in the future.
+        RecurringRewardsEndNotInFuture,
+
+        /// Stake sponsorship allowance doesn't cover the stakes.
+        InsufficientStakeSponsorshipAllowance,
+
+        /// Suspended member cannot apply on the opening.
+        SuspendedMemberCannotApply,
     }
 }
 
@@ -468,6 +609,28 @@ impl rstd::convert::From<WrappedError<hiring::DeactivateApplicationError>> for E
     }
 }
 
+impl rstd::convert::From<WrappedError<hiring::ReviewApplicationError>> for Error {
+    fn from(wrapper: WrappedError<hiring::ReviewApplicationError>) -> Self {
+        match wrapper.error {
+            hiring::ReviewApplicationError::ApplicationDoesNotExist => {
+                Error::ReviewApplicationApplicationDoesNotExist
+            }
+            hiring::ReviewApplicationError::ApplicationNotActive => {
+                Error::ReviewApplicationApplicationNotActive
+            }
+            hiring::ReviewApplicationError::OpeningNotInReviewPeriodStage => {
+                Error::ReviewApplicationOpeningNotInReviewPeriodStage
+            }
+            hiring::ReviewApplicationError::UnstakingPeriodTooShort(_stake_purpose) => {
+                Error::ReviewApplicationUnstakingPeriodTooShort
+            }
+            hiring::ReviewApplicationError::RedundantUnstakingPeriodProvided(_stake_purpose) => {
+                Error::ReviewApplicationRedundantUnstakingPeriod
+            }
+        }
+    }
+}
+
 impl rstd::convert::From<WrappedError<hiring::AddApplicationError>> for Error {
     fn from(wrapper: WrappedError<hiring::AddApplicationError>) -> Self {
         match wrapper.error {
@@ -524,6 +687,13 @@ impl rstd::convert::From<WrappedError<recurringrewards::RewardsError>> for Error
             recurringrewards::RewardsError::RewardRelationshipNotFound => {
                 Error::RecurringRewardsRewardRelationshipNotFound
             }
+            recurringrewards::RewardsError::ArrearsPolicyNotSet => {
+                Error::RecurringRewardsArrearsPolicyNotSet
+            }
+            recurringrewards::RewardsError::NoArrearsToSettle => {
+                Error::RecurringRewardsNoArrearsToSettle
+            }
+            recurringrewards::RewardsError::EndNotInFuture => Error::RecurringRewardsEndNotInFuture,
         }
     }
 }
@@ -652,11 +822,82 @@ impl rstd::convert::From<WrappedError<stake::StakeActionError<stake::ImmediateSl
     }
 }
 
+impl rstd::convert::From<WrappedError<stake::StakeActionError<stake::InitiateSlashingError>>>
+    for Error
+{
+    fn from(wrapper: WrappedError<stake::StakeActionError<stake::InitiateSlashingError>>) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => Error::StakingErrorStakeNotFound,
+            stake::StakeActionError::Error(slashing_error) => match slashing_error {
+                stake::InitiateSlashingError::NotStaked => Error::StakingErrorNotStaked,
+                stake::InitiateSlashingError::SlashPeriodShouldBeGreaterThanZero => {
+                    Error::StakingErrorSlashPeriodShouldBeGreaterThanZero
+                }
+                stake::InitiateSlashingError::SlashAmountShouldBeGreaterThanZero => {
+                    Error::StakingErrorSlashAmountShouldBeGreaterThanZero
+                }
+            },
+        }
+    }
+}
+
+impl rstd::convert::From<WrappedError<stake::StakeActionError<stake::PauseSlashingError>>>
+    for Error
+{
+    fn from(wrapper: WrappedError<stake::StakeActionError<stake::PauseSlashingError>>) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => Error::StakingErrorStakeNotFound,
+            stake::StakeActionError::Error(slashing_error) => match slashing_error {
+                stake::PauseSlashingError::SlashNotFound => Error::StakingErrorSlashNotFound,
+                stake::PauseSlashingError::NotStaked => Error::StakingErrorNotStaked,
+                stake::PauseSlashingError::AlreadyPaused => {
+                    Error::StakingErrorSlashingAlreadyPaused
+                }
+            },
+        }
+    }
+}
+
+impl rstd::convert::From<WrappedError<stake::StakeActionError<stake::ResumeSlashingError>>>
+    for Error
+{
+    fn from(wrapper: WrappedError<stake::StakeActionError<stake::ResumeSlashingError>>) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => Error::StakingErrorStakeNotFound,
+            stake::StakeActionError::Error(slashing_error) => match slashing_error {
+                stake::ResumeSlashingError::SlashNotFound => Error::StakingErrorSlashNotFound,
+                stake::ResumeSlashingError::NotStaked => Error::StakingErrorNotStaked,
+                stake::ResumeSlashingError::NotPaused => Error::StakingErrorSlashingIsNotPaused,
+            },
+        }
+    }
+}
+
+impl rstd::convert::From<WrappedError<stake::StakeActionError<stake::CancelSlashingError>>>
+    for Error
+{
+    fn from(wrapper: WrappedError<stake::StakeActionError<stake::CancelSlashingError>>) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => Error::StakingErrorStakeNotFound,
+            stake::StakeActionError::Error(slashing_error) => match slashing_error {
+                stake::CancelSlashingError::SlashNotFound => Error::StakingErrorSlashNotFound,
+                stake::CancelSlashingError::NotStaked => Error::StakingErrorNotStaked,
+            },
+        }
+    }
+}
+
 impl rstd::convert::From<WrappedError<minting::GeneralError>> for Error {
     fn from(wrapper: WrappedError<minting::GeneralError>) -> Self {
         match wrapper.error {
             minting::GeneralError::MintNotFound => Error::CannotFindMint,
             minting::GeneralError::NextAdjustmentInPast => Error::Other("NextAdjustmentInPast"),
+            minting::GeneralError::InvalidAdjustmentPercentage => {
+                Error::Other("InvalidAdjustmentPercentage")
+            }
+            minting::GeneralError::CapacityExceedsLifetimeLimit => {
+                Error::Other("CapacityExceedsLifetimeLimit")
+            }
         }
     }
 }
@@ -19,25 +19,56 @@
 //! - [add_opening](./struct.Module.html#method.add_opening) - Add an opening for a worker/lead role.
 //! - [accept_applications](./struct.Module.html#method.accept_applications)- Begin accepting worker/lead applications.
 //! - [begin_applicant_review](./struct.Module.html#method.begin_applicant_review) - Begin reviewing worker/lead applications.
+//! - [shortlist_application](./struct.Module.html#method.shortlist_application) - Shortlist the worker/lead application during the review.
+//! - [decline_application](./struct.Module.html#method.decline_application) - Decline the worker/lead application during the review.
+//! - [set_application_review_note](./struct.Module.html#method.set_application_review_note) - Attach the private score or note hash to the worker/lead application.
 //! - [fill_opening](./struct.Module.html#method.fill_opening) - Fill opening for worker/lead.
 //! - [withdraw_application](./struct.Module.html#method.withdraw_application) - Withdraw the worker/lead application.
 //! - [terminate_application](./struct.Module.html#method.terminate_application) - Terminate the worker/lead application.
 //! - [apply_on_opening](./struct.Module.html#method.apply_on_opening) - Apply on a worker/lead opening.
+//! - [add_opening_template](./struct.Module.html#method.add_opening_template) - Add the named opening policy template.
+//! - [update_opening_template](./struct.Module.html#method.update_opening_template) - Update the opening template policy (new template version).
+//! - [remove_opening_template](./struct.Module.html#method.remove_opening_template) - Remove the opening template.
+//! - [add_opening_from_template](./struct.Module.html#method.add_opening_from_template) - Add an opening for a worker/lead role from the template.
 //!
 //! ### Roles lifecycle
 //!
 //! - [update_role_account](./struct.Module.html#method.update_role_account) -  Update the role account of the worker/lead.
 //! - [update_reward_account](./struct.Module.html#method.update_reward_account) -  Update the reward account of the worker/lead.
 //! - [update_reward_amount](./struct.Module.html#method.update_reward_amount) -  Update the reward amount of the worker/lead.
+//! - [grant_bonus](./struct.Module.html#method.grant_bonus) -  Grant the one-off bonus to the worker/lead.
+//! - [grant_extra_reward](./struct.Module.html#method.grant_extra_reward) -  Grant the time-limited extra reward to the worker/lead.
+//! - [set_reward_arrears_policy](./struct.Module.html#method.set_reward_arrears_policy) -  Set the reward arrears policy of the worker/lead.
+//! - [claim_reward_arrears](./struct.Module.html#method.claim_reward_arrears) -  Claim the reward arrears by the worker/lead.
 //! - [leave_role](./struct.Module.html#method.leave_role) - Leave the role by the active worker/lead.
 //! - [terminate_role](./struct.Module.html#method.terminate_role) - Terminate the worker/lead role.
 //! - [set_mint_capacity](./struct.Module.html#method.set_mint_capacity) -  Sets the capacity to enable working group budget.
+//! - [suspend_worker](./struct.Module.html#method.suspend_worker) -  Temporarily suspend the worker/lead role.
+//! - [resume_worker](./struct.Module.html#method.resume_worker) -  Resume the suspended worker/lead role.
+//!
+//! ### Performance reviews
+//!
+//! - [evaluate_worker](./struct.Module.html#method.evaluate_worker) - File the worker/lead performance evaluation.
+//! - [submit_work_report](./struct.Module.html#method.submit_work_report) - Submit the worker/lead work report.
 //!
 //! ### Stakes
 //!
 //! - [slash_stake](./struct.Module.html#method.slash_stake) - Slashes the worker/lead stake.
+//! - [initiate_slashing](./struct.Module.html#method.initiate_slashing) - Initiates the delayed slashing of the worker/lead stake.
+//! - [appeal_slashing](./struct.Module.html#method.appeal_slashing) - Appeals the pending slashing, pauses it and opens the appeal discussion.
+//! - [cancel_slashing](./struct.Module.html#method.cancel_slashing) - Cancels the pending slashing (council decision).
+//! - [resume_slashing](./struct.Module.html#method.resume_slashing) - Resumes the appealed slashing rejecting the appeal (council decision).
 //! - [decrease_stake](./struct.Module.html#method.decrease_stake) - Decreases the worker/lead stake and returns the remainder to the worker _role_account_.
 //! - [increase_stake](./struct.Module.html#method.increase_stake) - Increases the worker/lead stake.
+//! - [request_stake_decrease](./struct.Module.html#method.request_stake_decrease) - Requests the worker/lead stake decrease.
+//! - [approve_stake_decrease](./struct.Module.html#method.approve_stake_decrease) - Approves the stake decrease request, the stake is released after the exit unstaking period.
+//! - [reject_stake_decrease](./struct.Module.html#method.reject_stake_decrease) - Rejects the stake decrease request.
+//!
+//! ## Spending reports
+//!
+//! The working group mint spending is accounted per council term in the
+//! [spending reports](./struct.Module.html#method.spending_report): paid worker, leader and extra
+//! rewards, stake refunds, missed rewards and the total paid to each worker/lead.
 //!
 
 // Ensure we're `no_std` when compiling for Wasm.
@@ -56,9 +87,9 @@ use rstd::collections::btree_map::BTreeMap;
 use rstd::collections::btree_set::BTreeSet;
 use rstd::prelude::*;
 use rstd::vec::Vec;
-use sr_primitives::traits::{Bounded, One, Zero};
+use sr_primitives::traits::{Bounded, One, SaturatedConversion, Saturating, Zero};
 use srml_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
-use srml_support::{decl_event, decl_module, decl_storage, ensure, print, StorageValue};
+use srml_support::{decl_event, decl_module, decl_storage, ensure, print, Parameter, StorageValue};
 use system::{ensure_root, ensure_signed};
 
 use crate::types::ExitInitiationOrigin;
@@ -67,13 +98,18 @@ use errors::WrappedError;
 
 pub use errors::Error;
 pub use types::{
-    Application, Opening, OpeningPolicyCommitment, OpeningType, RewardPolicy, RoleStakeProfile,
-    Worker,
+    Application, ExtraReward, GrantedExtraRewards, Opening, OpeningPolicyCommitment,
+    OpeningPolicyCommitmentOverrides, OpeningTemplate, OpeningType, PendingSlash, RewardPolicy,
+    RoleStakeProfile, SlashableTerms, SlashingTerms, SpendingCategory, SpendingReport,
+    StakeDecreaseRequest, WorkReport, Worker, WorkerEvaluation, WorkerSuspension,
 };
 
 /// Stake identifier in staking module
 pub type StakeId<T> = <T as stake::Trait>::StakeId;
 
+/// Slash identifier in staking module
+pub type SlashId<T> = <T as stake::Trait>::SlashId;
+
 /// Member identifier in membership::member module
 pub type MemberId<T> = <T as membership::members::Trait>::MemberId;
 
@@ -110,6 +146,66 @@ pub type WorkerId<T> = <T as membership::members::Trait>::ActorId;
 /// Alias for the application id from the hiring module.
 pub type HiringApplicationId<T> = <T as hiring::Trait>::ApplicationId;
 
+/// Alias for the worker/lead performance evaluation.
+pub type WorkerEvaluationOf<T> = WorkerEvaluation<<T as system::Trait>::BlockNumber>;
+
+/// Alias for the worker/lead role suspension.
+pub type WorkerSuspensionOf<T> = WorkerSuspension<<T as system::Trait>::BlockNumber>;
+
+/// Alias for the worker/lead stake decrease request.
+pub type StakeDecreaseRequestOf<T> =
+    StakeDecreaseRequest<BalanceOf<T>, <T as system::Trait>::BlockNumber>;
+
+/// Alias for the delayed slash of the worker/lead stake.
+pub type PendingSlashOf<T, I> = PendingSlash<
+    BalanceOf<T>,
+    <T as system::Trait>::BlockNumber,
+    <T as Trait<I>>::SlashingAppealThreadId,
+>;
+
+/// Alias for the worker/lead work report.
+pub type WorkReportOf<T> =
+    WorkReport<<T as system::Trait>::BlockNumber, <T as system::Trait>::Hash>;
+
+/// Alias for the worker/lead bonus or extra reward.
+pub type ExtraRewardOf<T> = ExtraReward<
+    <T as recurringrewards::Trait>::RewardRelationshipId,
+    <T as system::Trait>::BlockNumber,
+>;
+
+/// Alias for the worker/lead reward arrears policy.
+pub type ArrearsPolicyOf<T> =
+    recurringrewards::ArrearsPolicy<BalanceOfMint<T>, <T as system::Trait>::BlockNumber>;
+
+/// Alias for the bonuses and extra rewards granted within the budget period.
+pub type GrantedExtraRewardsOf<T> =
+    GrantedExtraRewards<BalanceOfMint<T>, <T as system::Trait>::BlockNumber>;
+
+/// Alias for the working group mint spending within the council term.
+pub type SpendingReportOf<T> = SpendingReport<BalanceOfMint<T>, WorkerId<T>>;
+
+/// Alias for the opening policy template.
+pub type OpeningTemplateOf<T> = OpeningTemplate<<T as system::Trait>::BlockNumber, BalanceOf<T>>;
+
+/// Alias for the opening policy commitment overrides.
+pub type OpeningPolicyCommitmentOverridesOf<T> =
+    OpeningPolicyCommitmentOverrides<<T as system::Trait>::BlockNumber, BalanceOf<T>>;
+
+/// Opening template identifier.
+pub type OpeningTemplateId = u64;
+
+/// Council term identifier: the number of the election round which elected the council.
+pub type CouncilTerm = u32;
+
+/// Max score of the worker/lead performance evaluation.
+pub const MAX_WORKER_EVALUATION_SCORE: u8 = 100;
+
+/// Max number of the content hashes referenced by a single work report.
+pub const MAX_WORK_REPORT_CONTENT_HASHES: usize = 100;
+
+/// Max number of the simultaneous bonuses and extra rewards of the worker/lead.
+pub const MAX_WORKER_EXTRA_REWARDS: usize = 10;
+
 // Type simplification
 type OpeningInfo<T> = (
     OpeningOf<T>,
@@ -146,6 +242,18 @@ type OpeningOf<T> = Opening<
 type ApplicationOf<T> =
     Application<<T as system::Trait>::AccountId, OpeningId<T>, MemberId<T>, HiringApplicationId<T>>;
 
+/// Opens the discussion of the worker/lead slashing appeal.
+pub trait SlashingAppealDiscussion<MemberId, ThreadId> {
+    /// Creates the appeal discussion thread on behalf of the appealing member.
+    fn create_appeal_thread(author_id: MemberId, title: Vec<u8>) -> Result<ThreadId, &'static str>;
+}
+
+impl<MemberId, ThreadId> SlashingAppealDiscussion<MemberId, ThreadId> for () {
+    fn create_appeal_thread(_: MemberId, _: Vec<u8>) -> Result<ThreadId, &'static str> {
+        Err("Slashing appeal discussion is not supported.")
+    }
+}
+
 /// The _Working group_ main _Trait_
 pub trait Trait<I: Instance>:
     system::Trait
@@ -160,6 +268,28 @@ pub trait Trait<I: Instance>:
 
     /// Defines max workers number in the working group.
     type MaxWorkerNumberLimit: Get<u32>;
+
+    /// Defines max number of the evaluations and work reports stored per worker/lead.
+    type MaxWorkerHistoryLength: Get<u32>;
+
+    /// Defines max period of the worker/lead role suspension.
+    type MaxWorkerSuspensionPeriod: Get<Self::BlockNumber>;
+
+    /// Defines the delay of the initiated slashing, the worker/lead can appeal within it.
+    type SlashingDisputePeriod: Get<Self::BlockNumber>;
+
+    /// Discussion thread identifier of the slashing appeal.
+    type SlashingAppealThreadId: Parameter + Default + Copy;
+
+    /// Opens the slashing appeal discussions.
+    type SlashingAppealDiscussion: SlashingAppealDiscussion<
+        MemberId<Self>,
+        Self::SlashingAppealThreadId,
+    >;
+
+    /// Defines the budget period of the bonuses and extra rewards: their total granted within
+    /// the period cannot exceed the working group mint capacity.
+    type ExtraRewardsBudgetPeriod: Get<Self::BlockNumber>;
 }
 
 decl_event!(
@@ -174,7 +304,10 @@ decl_event!(
         ApplicationIdToWorkerIdMap = ApplicationIdToWorkerIdMap<T>,
         RationaleText = Vec<u8>,
         MintBalanceOf = minting::BalanceOf<T>,
+        SlashId = SlashId<T>,
         <T as minting::Trait>::MintId,
+        MemberId = MemberId<T>,
+        StakeBalanceOf = BalanceOf<T>,
     {
         /// Emits on setting the leader.
         /// Params:
@@ -251,6 +384,21 @@ decl_event!(
         /// - Opening id
         BeganApplicationReview(OpeningId),
 
+        /// Emits on shortlisting the application during the worker/lead opening review.
+        /// Params:
+        /// - Worker application id
+        ApplicationShortlisted(ApplicationId),
+
+        /// Emits on declining the application during the worker/lead opening review.
+        /// Params:
+        /// - Worker application id
+        ApplicationDeclined(ApplicationId),
+
+        /// Emits on attaching the private score or note hash to the worker/lead application.
+        /// Params:
+        /// - Worker application id
+        ApplicationReviewNoteSet(ApplicationId),
+
         /// Emits on filling the worker opening.
         /// Params:
         /// - Worker opening id
@@ -277,6 +425,119 @@ decl_event!(
         /// - mint id.
         /// - new mint balance.
         MintCapacityChanged(MintId, MintBalanceOf),
+
+        /// Emits on filing the worker/lead performance evaluation.
+        /// Params:
+        /// - worker/lead id.
+        /// - evaluation score.
+        WorkerEvaluated(WorkerId, u8),
+
+        /// Emits on submitting the worker/lead work report.
+        /// Params:
+        /// - worker/lead id.
+        WorkReportSubmitted(WorkerId),
+
+        /// Emits on suspending the worker/lead role.
+        /// Params:
+        /// - worker/lead id.
+        /// - suspension rationale text
+        WorkerSuspended(WorkerId, RationaleText),
+
+        /// Emits on resuming the suspended worker/lead role.
+        /// Params:
+        /// - worker/lead id.
+        WorkerResumed(WorkerId),
+
+        /// Emits on requesting the worker/lead stake decrease.
+        /// Params:
+        /// - worker/lead id.
+        StakeDecreaseRequested(WorkerId),
+
+        /// Emits on approving the worker/lead stake decrease request.
+        /// Params:
+        /// - worker/lead id.
+        StakeDecreaseApproved(WorkerId),
+
+        /// Emits on rejecting the worker/lead stake decrease request.
+        /// Params:
+        /// - worker/lead id.
+        StakeDecreaseRejected(WorkerId),
+
+        /// Emits on failing to release the approved worker/lead stake decrease, e.g. when the
+        /// stake was slashed since the approval. The request is removed.
+        /// Params:
+        /// - worker/lead id.
+        StakeDecreaseReleaseFailed(WorkerId),
+
+        /// Emits on initiating the delayed slashing of the worker/lead stake.
+        /// Params:
+        /// - worker/lead id.
+        /// - slash id.
+        StakeSlashingInitiated(WorkerId, SlashId),
+
+        /// Emits on appealing the worker/lead stake slashing.
+        /// Params:
+        /// - worker/lead id.
+        /// - slash id.
+        StakeSlashingAppealed(WorkerId, SlashId),
+
+        /// Emits on cancelling the worker/lead stake slashing.
+        /// Params:
+        /// - worker/lead id.
+        /// - slash id.
+        StakeSlashingCancelled(WorkerId, SlashId),
+
+        /// Emits on resuming the appealed worker/lead stake slashing (the appeal is rejected).
+        /// Params:
+        /// - worker/lead id.
+        /// - slash id.
+        StakeSlashingResumed(WorkerId, SlashId),
+
+        /// Emits on adding the opening template.
+        /// Params:
+        /// - opening template id.
+        OpeningTemplateAdded(OpeningTemplateId),
+
+        /// Emits on updating the opening template.
+        /// Params:
+        /// - opening template id.
+        /// - new template version.
+        OpeningTemplateUpdated(OpeningTemplateId, u32),
+
+        /// Emits on removing the opening template.
+        /// Params:
+        /// - opening template id.
+        OpeningTemplateRemoved(OpeningTemplateId),
+
+        /// Emits on granting the worker/lead bonus.
+        /// Params:
+        /// - worker/lead id.
+        /// - bonus amount.
+        WorkerBonusGranted(WorkerId, MintBalanceOf),
+
+        /// Emits on granting the worker/lead extra reward.
+        /// Params:
+        /// - worker/lead id.
+        /// - total extra reward amount.
+        WorkerExtraRewardGranted(WorkerId, MintBalanceOf),
+
+        /// Emits on setting the reward arrears policy of the worker/lead.
+        /// Params:
+        /// - worker/lead id.
+        WorkerRewardArrearsPolicySet(WorkerId),
+
+        /// Emits on claiming the reward arrears by the worker/lead.
+        /// Params:
+        /// - worker/lead id.
+        /// - settled amount.
+        WorkerRewardArrearsClaimed(WorkerId, MintBalanceOf),
+
+        /// Emits on setting the stake sponsorship.
+        /// Params:
+        /// - sponsor account id.
+        /// - sponsored member id.
+        /// - stake allowance.
+        StakeSponsorshipSet(AccountId, MemberId, StakeBalanceOf),
     }
 );
 
@@ -309,6 +570,11 @@ decl_storage! {
         /// Maps identifier to corresponding worker.
         pub WorkerById get(worker_by_id) : linked_map WorkerId<T> => WorkerOf<T>;
 
+        /// Active workers/lead by the role account. Allows authorizing the role accounts without
+        /// enumerating the workers.
+        pub WorkerIdsByRoleAccountId get(fn worker_ids_by_role_account_id):
+            map T::AccountId => Vec<WorkerId<T>>;
+
         /// Count of active workers.
         pub ActiveWorkerCount get(fn active_worker_count): u32;
 
@@ -322,6 +588,88 @@ decl_storage! {
         /// Required by StakingEventsHandler callback call to refund the balance on unstaking.
         pub MemberIdByHiringApplicationId get(fn member_id_by_hiring_application_id):
             map HiringApplicationId<T> =>  MemberId<T>;
+
+        /// Performance evaluations of the workers/lead, oldest first. Kept after the worker leaves
+        /// the role for the council review. Keeps at most MaxWorkerHistoryLength last evaluations
+        /// per worker.
+        pub WorkerEvaluationsByWorkerId get(fn worker_evaluations):
+            map WorkerId<T> => Vec<WorkerEvaluationOf<T>>;
+
+        /// Work reports of the workers/lead, oldest first. Kept after the worker leaves the role
+        /// for the council review. Keeps at most MaxWorkerHistoryLength last reports per worker.
+        pub WorkReportsByWorkerId get(fn work_reports):
+            map WorkerId<T> => Vec<WorkReportOf<T>>;
+
+        /// Suspensions of the active workers/lead.
+        pub WorkerSuspensionById get(fn worker_suspension):
+            linked_map WorkerId<T> => WorkerSuspensionOf<T>;
+
+        /// Suspended workers/lead by the block of the suspension end.
+        pub WorkerIdsBySuspensionEnd get(fn worker_ids_by_suspension_end):
+            map T::BlockNumber => Vec<WorkerId<T>>;
+
+        /// Stake decrease requests of the active workers/lead: pending or approved and awaiting
+        /// the release.
+        pub StakeDecreaseRequestByWorkerId get(fn stake_decrease_request):
+            linked_map WorkerId<T> => StakeDecreaseRequestOf<T>;
+
+        /// Workers/lead with the approved stake decrease by the block of the release.
+        pub WorkerIdsByStakeDecreaseRelease get(fn worker_ids_by_stake_decrease_release):
+            map T::BlockNumber => Vec<WorkerId<T>>;
+
+        /// Delayed slashes of the active workers/lead awaiting the execution.
+        pub PendingSlashes get(fn pending_slash):
+            linked_map (WorkerId<T>, SlashId<T>) => PendingSlashOf<T, I>;
+
+        /// Ids of the pending slashes by the worker/lead.
+        pub PendingSlashIdsByWorkerId get(fn pending_slash_ids):
+            map WorkerId<T> => Vec<SlashId<T>>;
+
+        /// Workers/lead with the pending slashes by the role stake id.
+        pub WorkerIdBySlashedStakeId get(fn worker_id_by_slashed_stake_id):
+            map StakeId<T> => WorkerId<T>;
+
+        /// Role slashing terms of the active workers/lead, committed by the filled opening.
+        pub WorkerSlashingTermsById get(fn worker_slashing_terms):
+            map WorkerId<T> => SlashingTerms;
+
+        /// Number of slashes applied (or initiated) to the role stake of the active workers/lead.
+        pub WorkerSlashCountById get(fn worker_slash_count):
+            map WorkerId<T> => u16;
+
+        /// Bonuses and time-limited extra rewards of the active workers/lead.
+        /// Keeps at most MAX_WORKER_EXTRA_REWARDS rewards per worker.
+        pub ExtraRewardsByWorkerId get(fn extra_rewards):
+            linked_map WorkerId<T> => Vec<ExtraRewardOf<T>>;
+
+        /// Workers/lead paid by the reward relationships and the spending categories of the payouts.
+        pub RewardRelationshipOwnerById get(fn reward_relationship_owner):
+            map T::RewardRelationshipId => Option<(WorkerId<T>, SpendingCategory)>;
+
+        /// Workers/lead with the finished extra rewards by the block of the removal.
+        pub WorkerIdsByExtraRewardEnd get(fn worker_ids_by_extra_reward_end):
+            map T::BlockNumber => Vec<WorkerId<T>>;
+
+        /// Bonuses and extra rewards granted within the current budget period.
+        pub GrantedExtraRewardsInPeriod get(fn granted_extra_rewards): GrantedExtraRewardsOf<T>;
+
+        /// Next identifier for the new opening template.
+        pub NextOpeningTemplateId get(fn next_opening_template_id): OpeningTemplateId;
+
+        /// Maps identifier to the opening template.
+        pub OpeningTemplateById get(fn opening_template_by_id):
+            linked_map OpeningTemplateId => OpeningTemplateOf<T>;
+
+        /// Current council term of the spending accounting.
+        pub CurrentCouncilTerm get(fn current_council_term): CouncilTerm;
+
+        /// Working group mint spending per council term.
+        pub SpendingReportByCouncilTerm get(fn spending_report):
+            map CouncilTerm => SpendingReportOf<T>;
+
+        /// Amounts the sponsor accounts allow the members to stake from their funds when applying.
+        pub StakeSponsorshipAllowance get(fn stake_sponsorship_allowance):
+            double_map T::AccountId, twox_256(T::MemberId) => BalanceOf<T>;
     }
         add_extra_genesis {
         config(phantom): rstd::marker::PhantomData<I>;
@@ -351,6 +699,26 @@ decl_module! {
         /// Exports const -  max simultaneous active worker number.
         const MaxWorkerNumberLimit: u32 = T::MaxWorkerNumberLimit::get();
 
+        /// Exports const -  max number of the evaluations and work reports stored per worker/lead.
+        const MaxWorkerHistoryLength: u32 = T::MaxWorkerHistoryLength::get();
+
+        /// Exports const -  max period of the worker/lead role suspension.
+        const MaxWorkerSuspensionPeriod: T::BlockNumber = T::MaxWorkerSuspensionPeriod::get();
+
+        /// Exports const -  delay of the initiated slashing.
+        const SlashingDisputePeriod: T::BlockNumber = T::SlashingDisputePeriod::get();
+
+        /// Exports const -  budget period of the bonuses and extra rewards.
+        const ExtraRewardsBudgetPeriod: T::BlockNumber = T::ExtraRewardsBudgetPeriod::get();
+
+        /// Resumes the workers/lead with expired suspensions, releases the approved stake
+        /// decreases and removes the finished extra rewards.
+        fn on_finalize(now: T::BlockNumber) {
+            Self::resume_workers_with_expired_suspension(now);
+            Self::release_approved_stake_decreases(now);
+            Self::remove_finished_extra_rewards(now);
+        }
+
         // ****************** Roles lifecycle **********************
 
         /// Update the associated role account of the active worker/lead.
@@ -375,6 +743,8 @@ decl_module! {
             WorkerById::<T, I>::mutate(worker_id, |worker| {
                 worker.role_account_id = new_role_account_id.clone()
             });
+            Self::unindex_worker_role_account(&worker_id, &worker.role_account_id);
+            Self::index_worker_role_account(&worker_id, &new_role_account_id);
 
             // Trigger event
             Self::deposit_event(RawEvent::WorkerRoleAccountUpdated(worker_id, new_role_account_id));
@@ -403,7 +773,9 @@ decl_module! {
                     Some(new_reward_account_id.clone()), // new_account
                     None, // new_payout
                     None, //new_next_payment_at
-                    None) //new_payout_interval
+                    None, //new_payout_interval
+                    None, //new_ends_at_block
+                    None) //new_max_total_payout
             )?;
 
             // Trigger event
@@ -437,13 +809,141 @@ decl_module! {
                     None, // new_account
                     Some(new_amount), // new_payout
                     None, //new_next_payment_at
-                    None) //new_payout_interval
+                    None, //new_payout_interval
+                    None, //new_ends_at_block
+                    None) //new_max_total_payout
             )?;
 
             // Trigger event
             Self::deposit_event(RawEvent::WorkerRewardAmountUpdated(worker_id));
         }
 
+        /// Grant the one-off bonus to the active worker/lead. The bonus is paid from the working
+        /// group mint in the next block to the worker reward account.
+        /// Require signed leader origin or the root (to grant the leader bonus).
+        pub fn grant_bonus(origin, worker_id: WorkerId<T>, amount: BalanceOfMint<T>) {
+            // Ensure lead is set and is origin signer or it is the council.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            let worker = Self::ensure_worker_can_get_extra_reward(&worker_id)?;
+
+            ensure!(amount != Zero::zero(), Error::ExtraRewardAmountIsZero);
+
+            let granted_extra_rewards = Self::ensure_extra_reward_fits_budget(amount)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let payment_at = <system::Module<T>>::block_number() + One::one();
+
+            Self::add_extra_reward(
+                &worker_id,
+                &worker,
+                amount,
+                payment_at,
+                None,
+                payment_at,
+                granted_extra_rewards,
+            )?;
+
+            Self::deposit_event(RawEvent::WorkerBonusGranted(worker_id, amount));
+        }
+
+        /// Grant the time-limited extra reward to the active worker/lead. The extra reward is paid
+        /// from the working group mint each payout interval within the duration to the worker
+        /// reward account.
+        /// Require signed leader origin or the root (to grant the leader extra reward).
+        pub fn grant_extra_reward(
+            origin,
+            worker_id: WorkerId<T>,
+            amount_per_payout: BalanceOfMint<T>,
+            payout_interval: T::BlockNumber,
+            duration: T::BlockNumber,
+        ) {
+            // Ensure lead is set and is origin signer or it is the council.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            let worker = Self::ensure_worker_can_get_extra_reward(&worker_id)?;
+
+            ensure!(amount_per_payout != Zero::zero(), Error::ExtraRewardAmountIsZero);
+
+            ensure!(payout_interval != Zero::zero(), Error::ExtraRewardPayoutIntervalIsZero);
+
+            ensure!(duration >= payout_interval, Error::ExtraRewardDurationTooShort);
+
+            let payout_count: u64 = (duration / payout_interval).saturated_into();
+            let total_amount = amount_per_payout.saturating_mul(payout_count.saturated_into());
+
+            let granted_extra_rewards = Self::ensure_extra_reward_fits_budget(total_amount)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let now = <system::Module<T>>::block_number();
+
+            Self::add_extra_reward(
+                &worker_id,
+                &worker,
+                amount_per_payout,
+                now + payout_interval,
+                Some(payout_interval),
+                now + duration,
+                granted_extra_rewards,
+            )?;
+
+            Self::deposit_event(RawEvent::WorkerExtraRewardGranted(worker_id, total_amount));
+        }
+
+        /// Set the reward arrears policy of the active worker/lead: missed reward payouts are
+        /// kept in arrears to be settled later. Providing no policy drops the existing arrears.
+        /// Require signed leader origin or the root (to set the leader arrears policy).
+        pub fn set_reward_arrears_policy(
+            origin,
+            worker_id: WorkerId<T>,
+            policy: Option<ArrearsPolicyOf<T>>
+        ) {
+            // Ensure lead is set and is origin signer or it is the council.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            // Ensuring worker actually exists
+            let worker = Self::ensure_worker_exists(&worker_id)?;
+
+            // Ensure the worker actually has a recurring reward
+            let relationship_id = Self::ensure_worker_has_recurring_reward(&worker)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ensure_on_wrapped_error!(
+                recurringrewards::Module::<T>::set_arrears_policy(relationship_id, policy)
+            )?;
+
+            Self::deposit_event(RawEvent::WorkerRewardArrearsPolicySet(worker_id));
+        }
+
+        /// Claim the not expired reward arrears of the active worker/lead. The arrears are
+        /// settled from the working group mint up to its capacity.
+        pub fn claim_reward_arrears(origin, worker_id: WorkerId<T>) {
+            // Ensure there is a signer which matches role account of worker corresponding to provided id.
+            let worker = Self::ensure_worker_signed(origin, &worker_id)?;
+
+            // Ensure the worker actually has a recurring reward
+            let relationship_id = Self::ensure_worker_has_recurring_reward(&worker)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let settled_amount = ensure_on_wrapped_error!(
+                recurringrewards::Module::<T>::claim_arrears(relationship_id)
+            )?;
+
+            Self::deposit_event(RawEvent::WorkerRewardArrearsClaimed(worker_id, settled_amount));
+        }
+
         /// Leave the role by the active worker.
         pub fn leave_role(
             origin,
@@ -451,7 +951,10 @@ decl_module! {
             rationale_text: Vec<u8>
         ) {
             // Ensure there is a signer which matches role account of worker corresponding to provided id.
-            let active_worker = Self::ensure_worker_signed(origin, &worker_id)?;
+            // Suspended workers can leave the role voluntarily.
+            let active_worker = Self::ensure_worker_role_account_signed(origin, &worker_id)?;
+
+            Self::ensure_worker_has_no_pending_slashes(&worker_id)?;
 
             //
             // == MUTATION SAFE ==
@@ -466,7 +969,8 @@ decl_module! {
         }
 
         /// Terminate the active worker by the lead.
-        /// Require signed leader origin or the root (to terminate the leader role).
+        /// Require signed leader origin or the root (to terminate the leader role). Slashing the
+        /// stake on termination requires the root origin.
         pub fn terminate_role(
             origin,
             worker_id: WorkerId<T>,
@@ -481,9 +985,17 @@ decl_module! {
             // Ensuring worker actually exists.
             let worker = Self::ensure_worker_exists(&worker_id)?;
 
+            Self::ensure_worker_has_no_pending_slashes(&worker_id)?;
+
             // Ensure rationale text is valid.
             Self::ensure_worker_exit_rationale_text_is_valid(&rationale_text)?;
 
+            // Only the council slashes immediately, the lead initiates delayed slashes.
+            ensure!(
+                !slash_stake || exit_origin == ExitInitiationOrigin::Sudo,
+                Error::RequireRootOrigin
+            );
+
             //
             // == MUTATION SAFE ==
             //
@@ -500,75 +1012,293 @@ decl_module! {
             )?;
         }
 
-        // ****************** Hiring flow **********************
-
-        /// Add an opening for a worker role.
-        /// Require signed leader origin or the root (to add opening for the leader position).
-        pub fn add_opening(
+        /// Temporarily suspend the active worker/lead role. Pauses the worker reward relationship
+        /// and the extra reward relationships and blocks worker/lead actions until the worker
+        /// is resumed or the suspension expires.
+        /// Require signed leader origin or the root (to suspend the leader).
+        pub fn suspend_worker(
             origin,
-            activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
-            commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>,
-            human_readable_text: Vec<u8>,
-            opening_type: OpeningType,
-        ){
-            Self::ensure_origin_for_opening_type(origin, opening_type)?;
+            worker_id: WorkerId<T>,
+            suspension_period: T::BlockNumber,
+            rationale_text: Vec<u8>,
+        ) {
+            // Ensure lead is set or it is the council suspending the leader.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
 
-            Self::ensure_opening_human_readable_text_is_valid(&human_readable_text)?;
+            // Ensuring worker actually exists.
+            let worker = Self::ensure_worker_exists(&worker_id)?;
 
-            Self::ensure_opening_policy_commitment_is_valid(&commitment)?;
+            ensure!(
+                !<WorkerSuspensionById<T, I>>::exists(worker_id),
+                Error::WorkerIsAlreadySuspended
+            );
 
+            ensure!(suspension_period != Zero::zero(), Error::WorkerSuspensionPeriodIsZero);
+
+            ensure!(
+                suspension_period <= T::MaxWorkerSuspensionPeriod::get(),
+                Error::WorkerSuspensionPeriodTooLong
+            );
 
-            // Add opening
-            // NB: This call can in principle fail, because the staking policies
-            // may not respect the minimum currency requirement.
+            // Ensure rationale text is valid.
+            Self::ensure_worker_exit_rationale_text_is_valid(&rationale_text)?;
 
-            let policy_commitment = commitment.clone();
+            Self::ensure_worker_reward_relationships_exist(&worker_id, &worker)?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            let opening_id = ensure_on_wrapped_error!(
-                hiring::Module::<T>::add_opening(
-                    activate_at,
-                    commitment.max_review_period_length,
-                    commitment.application_rationing_policy,
-                    commitment.application_staking_policy,
-                    commitment.role_staking_policy,
-                    human_readable_text,
-            ))?;
+            let paused_reward_payment_at = worker
+                .reward_relationship
+                .and_then(Self::pause_reward_relationship);
 
-            let new_opening_id = NextOpeningId::<T, I>::get();
+            Self::pause_extra_rewards(&worker_id);
 
-            // Create and add worker opening.
-            let new_opening_by_id = Opening::<OpeningId<T>, T::BlockNumber, BalanceOf<T>, ApplicationId<T>> {
-                hiring_opening_id: opening_id,
-                applications: BTreeSet::new(),
-                policy_commitment,
-                opening_type,
+            let now = <system::Module<T>>::block_number();
+            let suspension = WorkerSuspension {
+                suspended_at: now,
+                ends_at: now + suspension_period,
+                rationale: rationale_text.clone(),
+                paused_reward_payment_at,
             };
 
-            OpeningById::<T, I>::insert(new_opening_id, new_opening_by_id);
-
-            // Update NextOpeningId
-            NextOpeningId::<T, I>::mutate(|id| *id += <OpeningId<T> as One>::one());
+            <WorkerSuspensionById<T, I>>::insert(worker_id, suspension);
+            <WorkerIdsBySuspensionEnd<T, I>>::mutate(now + suspension_period, |worker_ids| {
+                worker_ids.push(worker_id)
+            });
 
-            // Trigger event
-            Self::deposit_event(RawEvent::OpeningAdded(new_opening_id));
+            Self::deposit_event(RawEvent::WorkerSuspended(worker_id, rationale_text));
         }
 
-        /// Begin accepting worker applications to an opening that is active.
-        /// Require signed leader origin or the root (to accept applications for the leader position).
-        pub fn accept_applications(origin, opening_id: OpeningId<T>)  {
-            // Ensure opening exists in this working group
-            // NB: Even though call to hiring module will have implicit check for
-            // existence of opening as well, this check is to make sure that the opening is for
-            // this working group, not something else.
-            let (opening, _opening) = Self::ensure_opening_exists(&opening_id)?;
+        /// Resume the suspended worker/lead role. Resumes the paused worker reward relationship
+        /// and extra reward relationships.
+        /// Require signed leader origin or the root (to resume the leader).
+        pub fn resume_worker(origin, worker_id: WorkerId<T>) {
+            // Ensure lead is set or it is the council resuming the leader.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
 
-            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
+            // Ensuring worker actually exists.
+            let worker = Self::ensure_worker_exists(&worker_id)?;
 
-            // Attempt to begin accepting applications
+            ensure!(
+                <WorkerSuspensionById<T, I>>::exists(worker_id),
+                Error::WorkerIsNotSuspended
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let suspension = Self::remove_worker_suspension(&worker_id);
+
+            Self::resume_suspended_worker(&worker_id, &worker, suspension);
+        }
+
+        // ****************** Performance reviews **********************
+
+        /// File the performance evaluation of the active worker/lead.
+        /// Require signed leader origin or the root (to evaluate the leader).
+        pub fn evaluate_worker(
+            origin,
+            worker_id: WorkerId<T>,
+            score: u8,
+            rationale: Vec<u8>,
+        ) {
+            // Ensure lead is set or it is the council evaluating the leader.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            // Ensuring worker actually exists.
+            Self::ensure_worker_exists(&worker_id)?;
+
+            ensure!(score <= MAX_WORKER_EVALUATION_SCORE, Error::WorkerEvaluationScoreTooHigh);
+
+            Self::ensure_worker_evaluation_rationale_is_valid(&rationale)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let evaluation = WorkerEvaluation {
+                score,
+                rationale,
+                evaluated_at: <system::Module<T>>::block_number(),
+            };
+
+            WorkerEvaluationsByWorkerId::<T, I>::mutate(worker_id, |evaluations| {
+                Self::push_to_bounded_history(evaluations, evaluation)
+            });
+
+            Self::deposit_event(RawEvent::WorkerEvaluated(worker_id, score));
+        }
+
+        /// Submit the work report by the active worker/lead.
+        pub fn submit_work_report(
+            origin,
+            worker_id: WorkerId<T>,
+            content_hashes: Vec<T::Hash>,
+            description: Vec<u8>,
+        ) {
+            // Ensure there is a signer which matches role account of worker corresponding to provided id.
+            Self::ensure_worker_signed(origin, &worker_id)?;
+
+            ensure!(!content_hashes.is_empty(), Error::WorkReportContentHashesAreEmpty);
+
+            ensure!(
+                content_hashes.len() <= MAX_WORK_REPORT_CONTENT_HASHES,
+                Error::WorkReportTooManyContentHashes
+            );
+
+            Self::ensure_work_report_description_is_valid(&description)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let report = WorkReport {
+                content_hashes,
+                description,
+                submitted_at: <system::Module<T>>::block_number(),
+            };
+
+            WorkReportsByWorkerId::<T, I>::mutate(worker_id, |reports| {
+                Self::push_to_bounded_history(reports, report)
+            });
+
+            Self::deposit_event(RawEvent::WorkReportSubmitted(worker_id));
+        }
+
+        // ****************** Hiring flow **********************
+
+        /// Add an opening for a worker role.
+        /// Require signed leader origin or the root (to add opening for the leader position).
+        pub fn add_opening(
+            origin,
+            activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
+            commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>,
+            human_readable_text: Vec<u8>,
+            opening_type: OpeningType,
+        ){
+            Self::ensure_origin_for_opening_type(origin, opening_type)?;
+
+            Self::create_opening(activate_at, commitment, human_readable_text, opening_type)?;
+        }
+
+        /// Add the named opening policy template for the worker or leader openings.
+        /// Require signed leader origin or the root (to add template for the leader openings).
+        pub fn add_opening_template(
+            origin,
+            name: Vec<u8>,
+            opening_type: OpeningType,
+            commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>,
+        ) {
+            Self::ensure_origin_for_opening_type(origin, opening_type)?;
+
+            Self::ensure_opening_template_name_is_valid(&name)?;
+
+            Self::ensure_opening_policy_commitment_is_valid(&commitment)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let template_id = Self::next_opening_template_id();
+
+            let template = OpeningTemplate {
+                name,
+                version: 1,
+                opening_type,
+                policy_commitment: commitment,
+            };
+
+            <OpeningTemplateById<T, I>>::insert(template_id, template);
+
+            <NextOpeningTemplateId<I>>::mutate(|id| *id += 1);
+
+            Self::deposit_event(RawEvent::OpeningTemplateAdded(template_id));
+        }
+
+        /// Update the opening template policy. Increases the template version.
+        /// Require signed leader origin or the root (to update template for the leader openings).
+        pub fn update_opening_template(
+            origin,
+            template_id: OpeningTemplateId,
+            commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>,
+        ) {
+            let template = Self::ensure_opening_template_exists(&template_id)?;
+
+            Self::ensure_origin_for_opening_type(origin, template.opening_type)?;
+
+            Self::ensure_opening_policy_commitment_is_valid(&commitment)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let new_version = template.version + 1;
+
+            <OpeningTemplateById<T, I>>::insert(template_id, OpeningTemplate {
+                version: new_version,
+                policy_commitment: commitment,
+                ..template
+            });
+
+            Self::deposit_event(RawEvent::OpeningTemplateUpdated(template_id, new_version));
+        }
+
+        /// Remove the opening template. Openings created from the template are not affected.
+        /// Require signed leader origin or the root (to remove template for the leader openings).
+        pub fn remove_opening_template(origin, template_id: OpeningTemplateId) {
+            let template = Self::ensure_opening_template_exists(&template_id)?;
+
+            Self::ensure_origin_for_opening_type(origin, template.opening_type)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <OpeningTemplateById<T, I>>::remove(template_id);
+
+            Self::deposit_event(RawEvent::OpeningTemplateRemoved(template_id));
+        }
+
+        /// Add an opening for a worker/lead role from the template policy with the overrides.
+        /// The template version should match the current template version.
+        /// Require signed leader origin or the root (to add opening for the leader position).
+        pub fn add_opening_from_template(
+            origin,
+            activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
+            template_id: OpeningTemplateId,
+            template_version: u32,
+            overrides: OpeningPolicyCommitmentOverridesOf<T>,
+            human_readable_text: Vec<u8>,
+        ) {
+            let template = Self::ensure_opening_template_exists(&template_id)?;
+
+            Self::ensure_origin_for_opening_type(origin, template.opening_type)?;
+
+            ensure!(
+                template.version == template_version,
+                Error::OpeningTemplateVersionMismatch
+            );
+
+            let commitment = overrides.apply(template.policy_commitment);
+
+            Self::create_opening(activate_at, commitment, human_readable_text, template.opening_type)?;
+        }
+
+        /// Begin accepting worker applications to an opening that is active.
+        /// Require signed leader origin or the root (to accept applications for the leader position).
+        pub fn accept_applications(origin, opening_id: OpeningId<T>)  {
+            // Ensure opening exists in this working group
+            // NB: Even though call to hiring module will have implicit check for
+            // existence of opening as well, this check is to make sure that the opening is for
+            // this working group, not something else.
+            let (opening, _opening) = Self::ensure_opening_exists(&opening_id)?;
+
+            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
+
+            // Attempt to begin accepting applications
             // NB: Combined ensure check and mutation in hiring module
 
             //
@@ -597,85 +1327,80 @@ decl_module! {
             // Ensure origin which will server as the source account for staked funds is signed
             let source_account = ensure_signed(origin)?;
 
-            // In absence of a more general key delegation system which allows an account with some funds to
-            // grant another account permission to stake from its funds, the origin of this call must have the funds
-            // and cannot specify another arbitrary account as the source account.
+            // The origin of this call must have the funds and cannot specify another arbitrary account
+            // as the source account, the stakes funded by another account are applied with apply_on_opening_with_sponsor().
             // Ensure the source_account is either the controller or root account of member with given id
-            ensure!(
-                membership::members::Module::<T>::ensure_member_controller_account(&source_account, &member_id).is_ok() ||
-                membership::members::Module::<T>::ensure_member_root_account(&source_account, &member_id).is_ok(),
-                Error::OriginIsNeitherMemberControllerOrRoot
-            );
-
-            // Ensure worker opening exists
-            let (opening, _opening) = Self::ensure_opening_exists(&opening_id)?;
-
-            // Ensure that there is sufficient balance to cover stake proposed
-            Self::ensure_can_make_stake_imbalance(
-                vec![&opt_role_stake_balance, &opt_application_stake_balance],
-                &source_account
-            )
-            .map_err(|_| Error::InsufficientBalanceToApply)?;
-
-            // Ensure application text is valid
-            Self::ensure_application_text_is_valid(&human_readable_text)?;
-
-            // Ensure application can actually be added
-            ensure_on_wrapped_error!(
-                hiring::Module::<T>::ensure_can_add_application(
-                    opening.hiring_opening_id,
-                    opt_role_stake_balance,
-                    opt_application_stake_balance)
-            )?;
-
-            // Ensure member does not have an active application to this opening
-            Self::ensure_member_has_no_active_application_on_opening(
-                opening.applications,
-                member_id
+            Self::ensure_origin_is_member_controller_or_root(&source_account, &member_id)?;
+
+            Self::add_application_on_opening(
+                member_id,
+                opening_id,
+                role_account_id,
+                opt_role_stake_balance,
+                opt_application_stake_balance,
+                human_readable_text,
+                &source_account,
+                false
             )?;
+        }
 
-            //
-            // == MUTATION SAFE ==
-            //
+        /// Apply on a worker opening with the stakes funded by the sponsor account. The sponsor must
+        /// allow the member to stake from its funds with set_stake_sponsorship() beforehand, the allowance
+        /// is reduced by the staked amount. The stakes are returned to the sponsor on unstaking.
+        pub fn apply_on_opening_with_sponsor(
+            origin,
+            member_id: T::MemberId,
+            opening_id: OpeningId<T>,
+            role_account_id: T::AccountId,
+            opt_role_stake_balance: Option<BalanceOf<T>>,
+            opt_application_stake_balance: Option<BalanceOf<T>>,
+            human_readable_text: Vec<u8>,
+            sponsor_account_id: T::AccountId
+        ) {
+            let signer_account = ensure_signed(origin)?;
 
-            // Make imbalances for staking
-            let opt_role_stake_imbalance = Self::make_stake_opt_imbalance(&opt_role_stake_balance, &source_account);
-            let opt_application_stake_imbalance = Self::make_stake_opt_imbalance(&opt_application_stake_balance, &source_account);
+            Self::ensure_origin_is_member_controller_or_root(&signer_account, &member_id)?;
 
-            // Call hiring module to add application
-            let add_application = ensure_on_wrapped_error!(
-                    hiring::Module::<T>::add_application(
-                    opening.hiring_opening_id,
-                    opt_role_stake_imbalance,
-                    opt_application_stake_imbalance,
-                    human_readable_text
-                )
-            )?;
+            let total_stake = Self::total_stake_balance(vec![&opt_role_stake_balance, &opt_application_stake_balance]);
+            let allowance = Self::stake_sponsorship_allowance(&sponsor_account_id, member_id);
 
-            let hiring_application_id = add_application.application_id_added;
+            ensure!(total_stake <= allowance, Error::InsufficientStakeSponsorshipAllowance);
 
-            // Save member id to refund the stakes. This piece of date should outlive the 'worker'.
-            <MemberIdByHiringApplicationId<T, I>>::insert(hiring_application_id, member_id);
+            Self::add_application_on_opening(
+                member_id,
+                opening_id,
+                role_account_id,
+                opt_role_stake_balance,
+                opt_application_stake_balance,
+                human_readable_text,
+                &sponsor_account_id,
+                true
+            )?;
 
-            // Get id of new worker/lead application
-            let new_application_id = NextApplicationId::<T, I>::get();
+            Self::set_sponsorship_allowance(&sponsor_account_id, member_id, allowance - total_stake);
+        }
 
-            // Make worker/lead application
-            let application = Application::new(&role_account_id, &opening_id, &member_id, &hiring_application_id);
+        /// Allows the member to stake up to the allowance from the funds of the origin account, when
+        /// applying on the worker openings. Zero allowance revokes the sponsorship.
+        pub fn set_stake_sponsorship(
+            origin,
+            member_id: T::MemberId,
+            allowance: BalanceOf<T>
+        ) {
+            let sponsor_account = ensure_signed(origin)?;
 
-            // Store application
-            ApplicationById::<T, I>::insert(new_application_id, application);
+            ensure!(
+                <membership::members::MemberProfile<T>>::exists(member_id),
+                Error::MembershipInvalidMemberId
+            );
 
-            // Update next application identifier value
-            NextApplicationId::<T, I>::mutate(|id| *id += <ApplicationId<T> as One>::one());
+            //
+            // == MUTATION SAFE ==
+            //
 
-            // Add application to set of application in worker opening
-            OpeningById::<T, I>::mutate(opening_id, |opening| {
-                opening.applications.insert(new_application_id);
-            });
+            Self::set_sponsorship_allowance(&sponsor_account, member_id, allowance);
 
-            // Trigger event
-            Self::deposit_event(RawEvent::AppliedOnOpening(opening_id, new_application_id));
+            Self::deposit_event(RawEvent::StakeSponsorshipSet(sponsor_account, member_id, allowance));
         }
 
         /// Withdraw the worker application. Can be done by the worker itself only.
@@ -768,6 +1493,84 @@ decl_module! {
             Self::deposit_event(RawEvent::BeganApplicationReview(opening_id));
         }
 
+        /// Shortlist the application during the opening review.
+        /// Require signed leader origin or the root (to review applications for the leader position).
+        pub fn shortlist_application(origin, application_id: ApplicationId<T>) {
+            // Ensuring worker application actually exists
+            let (application, _, opening) = Self::ensure_application_exists(&application_id)?;
+
+            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Attempt to shortlist the application.
+            // NB: Combined ensure check and mutation in hiring module.
+            ensure_on_wrapped_error!(
+                hiring::Module::<T>::shortlist_application(application.hiring_application_id)
+            )?;
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ApplicationShortlisted(application_id));
+        }
+
+        /// Decline the application during the opening review. Application stakes are released
+        /// with the unstaking periods of the failed applicant from the opening policy commitment.
+        /// Require signed leader origin or the root (to review applications for the leader position).
+        pub fn decline_application(origin, application_id: ApplicationId<T>) {
+            // Ensuring worker application actually exists
+            let (application, _, opening) = Self::ensure_application_exists(&application_id)?;
+
+            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Attempt to decline the application.
+            // NB: Combined ensure check and mutation in hiring module.
+            ensure_on_wrapped_error!(
+                hiring::Module::<T>::decline_application(
+                    application.hiring_application_id,
+                    opening.policy_commitment.fill_opening_failed_applicant_application_stake_unstaking_period,
+                    opening.policy_commitment.fill_opening_failed_applicant_role_stake_unstaking_period
+                )
+            )?;
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ApplicationDeclined(application_id));
+        }
+
+        /// Attach the hash of the private score or note to the application during the opening review.
+        /// Require signed leader origin or the root (to review applications for the leader position).
+        pub fn set_application_review_note(
+            origin,
+            application_id: ApplicationId<T>,
+            note_hash: T::Hash
+        ) {
+            // Ensuring worker application actually exists
+            let (application, _, opening) = Self::ensure_application_exists(&application_id)?;
+
+            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Attempt to attach the note hash.
+            // NB: Combined ensure check and mutation in hiring module.
+            ensure_on_wrapped_error!(
+                hiring::Module::<T>::set_application_review_note(
+                    application.hiring_application_id,
+                    note_hash
+                )
+            )?;
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ApplicationReviewNoteSet(application_id));
+        }
+
         /// Fill opening for worker/lead.
         /// Require signed leader origin or the root (to fill opening for the leader position).
         pub fn fill_opening(
@@ -831,73 +1634,244 @@ decl_module! {
                 Error::SuccessfulWorkerApplicationDoesNotExist
             );
 
-            // Attempt to fill opening
-            let successful_application_ids = successful_iter
-                                            .clone()
-                                            .map(|(successful_application, _, _)| successful_application.hiring_application_id)
-                                            .collect::<BTreeSet<_>>();
+            // Attempt to fill opening
+            let successful_application_ids = successful_iter
+                                            .clone()
+                                            .map(|(successful_application, _, _)| successful_application.hiring_application_id)
+                                            .collect::<BTreeSet<_>>();
+
+            // Check for a single application for a leader.
+            if matches!(opening.opening_type, OpeningType::Leader) {
+                ensure!(successful_application_ids.len() == 1, Error::CannotHireMultipleLeaders);
+            }
+
+            // NB: Combined ensure check and mutation in hiring module
+            ensure_on_wrapped_error!(
+                hiring::Module::<T>::fill_opening(
+                    opening.hiring_opening_id,
+                    successful_application_ids,
+                    opening.policy_commitment.fill_opening_successful_applicant_application_stake_unstaking_period,
+                    opening.policy_commitment.fill_opening_failed_applicant_application_stake_unstaking_period,
+                    opening.policy_commitment.fill_opening_failed_applicant_role_stake_unstaking_period
+                )
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Process successful applications
+            let application_id_to_worker_id = Self::fulfill_successful_applications(
+                &opening,
+                create_reward_settings,
+                successful_iter.collect()
+            );
+
+            // Trigger event
+            Self::deposit_event(RawEvent::OpeningFilled(opening_id, application_id_to_worker_id));
+        }
+
+        // ****************** Stakes **********************
+
+        /// Slashes the worker stake immediately. No actions on zero stake.
+        /// Slashing is limited by the role slashing terms committed by the opening: unslashable
+        /// roles cannot be slashed, the number of slashes is limited and the slashing balance
+        /// is capped by the max percentage of the current stake.
+        /// Require the root origin (the council), the lead uses the delayed `initiate_slashing`.
+        pub fn slash_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
+            ensure_root(origin)?;
+
+            // Ensuring worker actually exists.
+            let worker = Self::ensure_worker_exists(&worker_id)?;
+
+            ensure!(balance != <BalanceOf<T>>::zero(), Error::StakeBalanceCannotBeZero);
+
+            let stake_profile = worker.role_stake_profile.ok_or(Error::NoWorkerStakeProfile)?;
+
+            let slashing_balance = Self::ensure_slashing_terms_allow_slashing(
+                &worker_id,
+                &stake_profile,
+                balance
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This external module call both checks and mutates the state.
+            ensure_on_wrapped_error!(
+                <stake::Module<T>>::slash_immediate(
+                    &stake_profile.stake_id,
+                    slashing_balance,
+                    false
+                )
+            )?;
+
+            Self::increase_worker_slash_count(&worker_id);
+
+            Self::deposit_event(RawEvent::StakeSlashed(worker_id));
+        }
+
+        /// Initiates the delayed slashing of the worker/lead stake. The slashing is executed after
+        /// the slashing dispute period unless it is appealed by the worker/lead. The worker/lead
+        /// cannot leave the role or be terminated until the slashing is executed or cancelled.
+        /// The slashing is limited by the role slashing terms the same way as the immediate one.
+        /// Require signed leader origin or the root (to slash the leader stake).
+        pub fn initiate_slashing(
+            origin,
+            worker_id: WorkerId<T>,
+            balance: BalanceOf<T>,
+            rationale_text: Vec<u8>,
+        ) {
+            // Ensure lead is set or it is the council slashing the leader.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            // Ensuring worker actually exists.
+            let worker = Self::ensure_worker_exists(&worker_id)?;
+
+            ensure!(balance != <BalanceOf<T>>::zero(), Error::StakeBalanceCannotBeZero);
+
+            let stake_profile = worker.role_stake_profile.ok_or(Error::NoWorkerStakeProfile)?;
+
+            let slashing_balance = Self::ensure_slashing_terms_allow_slashing(
+                &worker_id,
+                &stake_profile,
+                balance
+            )?;
+
+            // Ensure rationale text is valid.
+            Self::ensure_worker_exit_rationale_text_is_valid(&rationale_text)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This external module call both checks and mutates the state.
+            let slash_id = ensure_on_wrapped_error!(
+                <stake::Module<T>>::initiate_slashing(
+                    &stake_profile.stake_id,
+                    slashing_balance,
+                    T::SlashingDisputePeriod::get()
+                )
+            )?;
+
+            Self::increase_worker_slash_count(&worker_id);
+
+            let pending_slash = PendingSlash {
+                amount: slashing_balance,
+                rationale: rationale_text,
+                initiated_at: <system::Module<T>>::block_number(),
+                appeal_thread_id: None,
+            };
+
+            <PendingSlashes<T, I>>::insert((worker_id, slash_id), pending_slash);
+            <PendingSlashIdsByWorkerId<T, I>>::mutate(worker_id, |slash_ids| {
+                slash_ids.push(slash_id)
+            });
+            <WorkerIdBySlashedStakeId<T, I>>::insert(stake_profile.stake_id, worker_id);
+
+            Self::deposit_event(RawEvent::StakeSlashingInitiated(worker_id, slash_id));
+        }
+
+        /// Appeals the pending slashing of the worker/lead stake. Pauses the slashing and opens
+        /// the appeal discussion thread. The council can cancel the appealed slashing or resume it
+        /// rejecting the appeal. Suspended workers can appeal as well.
+        pub fn appeal_slashing(
+            origin,
+            worker_id: WorkerId<T>,
+            slash_id: SlashId<T>,
+            appeal_title: Vec<u8>,
+        ) {
+            // Checks worker origin, worker existence
+            let worker = Self::ensure_worker_role_account_signed(origin, &worker_id)?;
+
+            let stake_profile = worker.role_stake_profile.ok_or(Error::NoWorkerStakeProfile)?;
+
+            let pending_slash = Self::ensure_pending_slash_exists(&worker_id, &slash_id)?;
 
-            // Check for a single application for a leader.
-            if matches!(opening.opening_type, OpeningType::Leader) {
-                ensure!(successful_application_ids.len() == 1, Error::CannotHireMultipleLeaders);
-            }
+            ensure!(!pending_slash.is_appealed(), Error::SlashingIsAlreadyAppealed);
 
-            // NB: Combined ensure check and mutation in hiring module
             ensure_on_wrapped_error!(
-                hiring::Module::<T>::fill_opening(
-                    opening.hiring_opening_id,
-                    successful_application_ids,
-                    opening.policy_commitment.fill_opening_successful_applicant_application_stake_unstaking_period,
-                    opening.policy_commitment.fill_opening_failed_applicant_application_stake_unstaking_period,
-                    opening.policy_commitment.fill_opening_failed_applicant_role_stake_unstaking_period
-                )
+                <stake::Module<T>>::ensure_can_pause_slashing(&stake_profile.stake_id, &slash_id)
             )?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            // Process successful applications
-            let application_id_to_worker_id = Self::fulfill_successful_applications(
-                &opening,
-                create_reward_settings,
-                successful_iter.collect()
-            );
+            // This external module call both checks and mutates the state. It is the first
+            // mutation, so a failure leaves no changes.
+            let appeal_thread_id = T::SlashingAppealDiscussion::create_appeal_thread(
+                worker.member_id,
+                appeal_title
+            ).map_err(Error::Other)?;
 
-            // Trigger event
-            Self::deposit_event(RawEvent::OpeningFilled(opening_id, application_id_to_worker_id));
-        }
+            // The pausing is checked above, so the appeal thread is not left orphaned.
+            ensure_on_wrapped_error!(
+                <stake::Module<T>>::pause_slashing(&stake_profile.stake_id, &slash_id)
+            )?;
 
-        // ****************** Stakes **********************
+            <PendingSlashes<T, I>>::mutate((worker_id, slash_id), |pending_slash| {
+                pending_slash.appeal_thread_id = Some(appeal_thread_id)
+            });
 
-        /// Slashes the worker stake, demands a leader origin. No limits, no actions on zero stake.
-        /// If slashing balance greater than the existing stake - stake is slashed to zero.
-        /// Require signed leader origin or the root (to slash the leader stake).
-        pub fn slash_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
-            // Ensure lead is set or it is the council terminating the leader.
-            Self::ensure_origin_for_leader(origin, worker_id)?;
+            Self::deposit_event(RawEvent::StakeSlashingAppealed(worker_id, slash_id));
+        }
+
+        /// Cancels the pending slashing of the worker/lead stake. Requires root origin
+        /// (the council decision via proposal).
+        pub fn cancel_slashing(origin, worker_id: WorkerId<T>, slash_id: SlashId<T>) {
+            ensure_root(origin)?;
 
             // Ensuring worker actually exists.
             let worker = Self::ensure_worker_exists(&worker_id)?;
 
-            ensure!(balance != <BalanceOf<T>>::zero(), Error::StakeBalanceCannotBeZero);
+            let stake_profile = worker.role_stake_profile.ok_or(Error::NoWorkerStakeProfile)?;
+
+            Self::ensure_pending_slash_exists(&worker_id, &slash_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This external module call both checks and mutates the state.
+            ensure_on_wrapped_error!(
+                <stake::Module<T>>::cancel_slashing(&stake_profile.stake_id, &slash_id)
+            )?;
+
+            Self::remove_pending_slash(&worker_id, &stake_profile.stake_id, &slash_id);
+
+            // Cancelled slashing doesn't count against the slashing terms.
+            Self::decrease_worker_slash_count(&worker_id);
+
+            Self::deposit_event(RawEvent::StakeSlashingCancelled(worker_id, slash_id));
+        }
+
+        /// Resumes the appealed slashing of the worker/lead stake rejecting the appeal. The slashing
+        /// is executed after the rest of the slashing dispute period. Requires root origin
+        /// (the council decision via proposal).
+        pub fn resume_slashing(origin, worker_id: WorkerId<T>, slash_id: SlashId<T>) {
+            ensure_root(origin)?;
+
+            // Ensuring worker actually exists.
+            let worker = Self::ensure_worker_exists(&worker_id)?;
 
             let stake_profile = worker.role_stake_profile.ok_or(Error::NoWorkerStakeProfile)?;
 
+            let pending_slash = Self::ensure_pending_slash_exists(&worker_id, &slash_id)?;
+
+            ensure!(pending_slash.is_appealed(), Error::SlashingIsNotAppealed);
+
             //
             // == MUTATION SAFE ==
             //
 
             // This external module call both checks and mutates the state.
             ensure_on_wrapped_error!(
-                <stake::Module<T>>::slash_immediate(
-                    &stake_profile.stake_id,
-                    balance,
-                    false
-                )
+                <stake::Module<T>>::resume_slashing(&stake_profile.stake_id, &slash_id)
             )?;
 
-            Self::deposit_event(RawEvent::StakeSlashed(worker_id));
+            Self::deposit_event(RawEvent::StakeSlashingResumed(worker_id, slash_id));
         }
 
         /// Decreases the worker/lead stake and returns the remainder to the worker role_account_id.
@@ -919,7 +1893,7 @@ decl_module! {
 
             // This external module call both checks and mutates the state.
             ensure_on_wrapped_error!(
-                <stake::Module<T>>::decrease_stake_to_account(
+                Self::decrease_worker_stake(
                     &stake_profile.stake_id,
                     &worker.role_account_id,
                     balance
@@ -955,6 +1929,98 @@ decl_module! {
             Self::deposit_event(RawEvent::StakeIncreased(worker_id));
         }
 
+        /// Requests the worker/lead stake decrease, demands a worker origin. The request is
+        /// subject to the lead approval (or the root approval for the leader request).
+        pub fn request_stake_decrease(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
+            // Checks worker origin, worker existence
+            let worker = Self::ensure_worker_signed(origin, &worker_id)?;
+
+            ensure!(balance != <BalanceOf<T>>::zero(), Error::StakeBalanceCannotBeZero);
+
+            let stake_profile = worker.role_stake_profile.ok_or(Error::NoWorkerStakeProfile)?;
+
+            ensure!(
+                !<StakeDecreaseRequestByWorkerId<T, I>>::exists(worker_id),
+                Error::StakeDecreaseRequestAlreadyExists
+            );
+
+            ensure_on_wrapped_error!(
+                <stake::Module<T>>::ensure_can_decrease_stake(&stake_profile.stake_id, balance)
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let request = StakeDecreaseRequest {
+                amount: balance,
+                requested_at: <system::Module<T>>::block_number(),
+                release_at: None,
+            };
+
+            <StakeDecreaseRequestByWorkerId<T, I>>::insert(worker_id, request);
+
+            Self::deposit_event(RawEvent::StakeDecreaseRequested(worker_id));
+        }
+
+        /// Approves the pending worker/lead stake decrease request. The stake decrease is released
+        /// to the role account after the exit unstaking period of the role.
+        /// Require signed leader origin or the root (to approve the leader request).
+        pub fn approve_stake_decrease(origin, worker_id: WorkerId<T>) {
+            // Ensure lead is set or it is the council approving the leader request.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            let worker = Self::ensure_worker_exists(&worker_id)?;
+
+            let stake_profile = worker.role_stake_profile.ok_or(Error::NoWorkerStakeProfile)?;
+
+            Self::ensure_pending_stake_decrease_request(&worker_id)?;
+
+            // The stake could be slashed or decreased since the request.
+            let request = Self::stake_decrease_request(worker_id);
+            ensure_on_wrapped_error!(
+                <stake::Module<T>>::ensure_can_decrease_stake(
+                    &stake_profile.stake_id,
+                    request.amount
+                )
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let unstaking_period = stake_profile.exit_unstaking_period.unwrap_or_else(Zero::zero);
+            let release_at = <system::Module<T>>::block_number() + unstaking_period;
+
+            <StakeDecreaseRequestByWorkerId<T, I>>::mutate(worker_id, |request| {
+                request.release_at = Some(release_at)
+            });
+            <WorkerIdsByStakeDecreaseRelease<T, I>>::mutate(release_at, |worker_ids| {
+                worker_ids.push(worker_id)
+            });
+
+            Self::deposit_event(RawEvent::StakeDecreaseApproved(worker_id));
+        }
+
+        /// Rejects the pending worker/lead stake decrease request.
+        /// Require signed leader origin or the root (to reject the leader request).
+        pub fn reject_stake_decrease(origin, worker_id: WorkerId<T>) {
+            // Ensure lead is set or it is the council rejecting the leader request.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            Self::ensure_worker_exists(&worker_id)?;
+
+            Self::ensure_pending_stake_decrease_request(&worker_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <StakeDecreaseRequestByWorkerId<T, I>>::remove(worker_id);
+
+            Self::deposit_event(RawEvent::StakeDecreaseRejected(worker_id));
+        }
+
         /// Sets the capacity to enable working group budget. Requires root origin.
         pub fn set_mint_capacity(
             origin,
@@ -1082,6 +2148,14 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             );
         }
 
+        if let SlashingTerms::Slashable(ref slashable_terms) = policy_commitment.role_slashing_terms
+        {
+            ensure!(
+                slashable_terms.max_percent_pts_per_time <= 100,
+                Error::RoleSlashingTermsMaxPercentPtsPerTimeTooHigh
+            );
+        }
+
         Ok(())
     }
 
@@ -1138,6 +2212,11 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             return Err(Error::IsNotLeadAccount);
         }
 
+        ensure!(
+            !Self::is_worker_suspended(&leader_worker_id),
+            Error::LeaderIsSuspended
+        );
+
         Ok(())
     }
 
@@ -1217,13 +2296,7 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         let zero_balance = <BalanceOf<T> as Zero>::zero();
 
         // Total amount to be staked
-        let total_amount = opt_balances.iter().fold(zero_balance, |sum, opt_balance| {
-            sum + if let Some(balance) = opt_balance {
-                *balance
-            } else {
-                zero_balance
-            }
-        });
+        let total_amount = Self::total_stake_balance(opt_balances);
 
         if total_amount > zero_balance {
             // Ensure that
@@ -1245,6 +2318,155 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         }
     }
 
+    // Sums the provided optional stake balances.
+    fn total_stake_balance(opt_balances: Vec<&Option<BalanceOf<T>>>) -> BalanceOf<T> {
+        let zero_balance = <BalanceOf<T> as Zero>::zero();
+
+        opt_balances.iter().fold(zero_balance, |sum, opt_balance| {
+            sum + if let Some(balance) = opt_balance {
+                *balance
+            } else {
+                zero_balance
+            }
+        })
+    }
+
+    // Ensures the account is either the controller or root account of the member.
+    fn ensure_origin_is_member_controller_or_root(
+        account_id: &T::AccountId,
+        member_id: &T::MemberId,
+    ) -> Result<(), Error> {
+        ensure!(
+            membership::members::Module::<T>::ensure_member_controller_account(
+                account_id, member_id
+            )
+            .is_ok()
+                || membership::members::Module::<T>::ensure_member_root_account(
+                    account_id, member_id
+                )
+                .is_ok(),
+            Error::OriginIsNeitherMemberControllerOrRoot
+        );
+
+        Ok(())
+    }
+
+    // Adds the member application on the opening, staking from the source account. The stakes of
+    // the sponsored applications are recorded as funded by the source account.
+    #[allow(clippy::too_many_arguments)] // the extrinsic parameters are passed through
+    fn add_application_on_opening(
+        member_id: T::MemberId,
+        opening_id: OpeningId<T>,
+        role_account_id: T::AccountId,
+        opt_role_stake_balance: Option<BalanceOf<T>>,
+        opt_application_stake_balance: Option<BalanceOf<T>>,
+        human_readable_text: Vec<u8>,
+        source_account: &T::AccountId,
+        is_sponsored: bool,
+    ) -> Result<(), Error> {
+        // Ensure worker opening exists
+        let (opening, _opening) = Self::ensure_opening_exists(&opening_id)?;
+
+        // Ensure the member is not suspended
+        ensure!(
+            !membership::members::Module::<T>::is_member_suspended(&member_id),
+            Error::SuspendedMemberCannotApply
+        );
+
+        // Ensure that there is sufficient balance to cover stake proposed
+        Self::ensure_can_make_stake_imbalance(
+            vec![&opt_role_stake_balance, &opt_application_stake_balance],
+            source_account,
+        )
+        .map_err(|_| Error::InsufficientBalanceToApply)?;
+
+        // Ensure application text is valid
+        Self::ensure_application_text_is_valid(&human_readable_text)?;
+
+        // Ensure application can actually be added
+        ensure_on_wrapped_error!(hiring::Module::<T>::ensure_can_add_application(
+            opening.hiring_opening_id,
+            opt_role_stake_balance,
+            opt_application_stake_balance
+        ))?;
+
+        // Ensure member does not have an active application to this opening
+        Self::ensure_member_has_no_active_application_on_opening(opening.applications, member_id)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // Make imbalances for staking
+        let opt_role_stake_imbalance =
+            Self::make_stake_opt_imbalance(&opt_role_stake_balance, source_account);
+        let opt_application_stake_imbalance =
+            Self::make_stake_opt_imbalance(&opt_application_stake_balance, source_account);
+
+        // Call hiring module to add application
+        let add_application = if is_sponsored {
+            ensure_on_wrapped_error!(hiring::Module::<T>::add_sponsored_application(
+                opening.hiring_opening_id,
+                opt_role_stake_imbalance,
+                opt_application_stake_imbalance,
+                human_readable_text,
+                source_account.clone()
+            ))?
+        } else {
+            ensure_on_wrapped_error!(hiring::Module::<T>::add_application(
+                opening.hiring_opening_id,
+                opt_role_stake_imbalance,
+                opt_application_stake_imbalance,
+                human_readable_text
+            ))?
+        };
+
+        let hiring_application_id = add_application.application_id_added;
+
+        // Save member id to refund the stakes. This piece of date should outlive the 'worker'.
+        <MemberIdByHiringApplicationId<T, I>>::insert(hiring_application_id, member_id);
+
+        // Get id of new worker/lead application
+        let new_application_id = NextApplicationId::<T, I>::get();
+
+        // Make worker/lead application
+        let application = Application::new(
+            &role_account_id,
+            &opening_id,
+            &member_id,
+            &hiring_application_id,
+        );
+
+        // Store application
+        ApplicationById::<T, I>::insert(new_application_id, application);
+
+        // Update next application identifier value
+        NextApplicationId::<T, I>::mutate(|id| *id += <ApplicationId<T> as One>::one());
+
+        // Add application to set of application in worker opening
+        OpeningById::<T, I>::mutate(opening_id, |opening| {
+            opening.applications.insert(new_application_id);
+        });
+
+        // Trigger event
+        Self::deposit_event(RawEvent::AppliedOnOpening(opening_id, new_application_id));
+
+        Ok(())
+    }
+
+    // Sets the stake sponsorship allowance, removing the sponsorship on zero allowance.
+    fn set_sponsorship_allowance(
+        sponsor_account: &T::AccountId,
+        member_id: T::MemberId,
+        allowance: BalanceOf<T>,
+    ) {
+        if allowance.is_zero() {
+            <StakeSponsorshipAllowance<T, I>>::remove(sponsor_account, member_id);
+        } else {
+            <StakeSponsorshipAllowance<T, I>>::insert(sponsor_account, member_id, allowance);
+        }
+    }
+
     fn ensure_application_exists(
         application_id: &ApplicationId<T>,
     ) -> Result<ApplicationInfo<T>, Error> {
@@ -1264,6 +2486,22 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
     pub fn ensure_worker_signed(
         origin: T::Origin,
         worker_id: &WorkerId<T>,
+    ) -> Result<WorkerOf<T>, Error> {
+        let worker = Self::ensure_worker_role_account_signed(origin, worker_id)?;
+
+        // Ensure that worker is not suspended
+        ensure!(
+            !<WorkerSuspensionById<T, I>>::exists(worker_id),
+            Error::WorkerIsSuspended
+        );
+
+        Ok(worker)
+    }
+
+    // Ensures the origin is signed by the worker/lead role account. Suspended workers pass.
+    fn ensure_worker_role_account_signed(
+        origin: T::Origin,
+        worker_id: &WorkerId<T>,
     ) -> Result<WorkerOf<T>, Error> {
         // Ensure that it is signed
         let signer_account = ensure_signed(origin)?;
@@ -1280,6 +2518,28 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Ok(worker)
     }
 
+    // Ensures the worker/lead has no pending slashes, so the role stake can be unstaked.
+    fn ensure_worker_has_no_pending_slashes(worker_id: &WorkerId<T>) -> Result<(), Error> {
+        ensure!(
+            Self::pending_slash_ids(worker_id).is_empty(),
+            Error::WorkerHasPendingSlashes
+        );
+
+        Ok(())
+    }
+
+    fn ensure_pending_slash_exists(
+        worker_id: &WorkerId<T>,
+        slash_id: &SlashId<T>,
+    ) -> Result<PendingSlashOf<T, I>, Error> {
+        ensure!(
+            <PendingSlashes<T, I>>::exists((*worker_id, *slash_id)),
+            Error::PendingSlashDoesNotExist
+        );
+
+        Ok(Self::pending_slash((*worker_id, *slash_id)))
+    }
+
     fn ensure_worker_exists(worker_id: &WorkerId<T>) -> Result<WorkerOf<T>, Error> {
         ensure!(
             WorkerById::<T, I>::exists(worker_id),
@@ -1291,14 +2551,113 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Ok(worker)
     }
 
-    fn ensure_worker_has_recurring_reward(
-        worker: &WorkerOf<T>,
-    ) -> Result<T::RewardRelationshipId, Error> {
-        if let Some(relationship_id) = worker.reward_relationship {
-            Ok(relationship_id)
-        } else {
-            Err(Error::WorkerHasNoReward)
-        }
+    fn ensure_worker_has_recurring_reward(
+        worker: &WorkerOf<T>,
+    ) -> Result<T::RewardRelationshipId, Error> {
+        if let Some(relationship_id) = worker.reward_relationship {
+            Ok(relationship_id)
+        } else {
+            Err(Error::WorkerHasNoReward)
+        }
+    }
+
+    fn ensure_opening_template_exists(
+        template_id: &OpeningTemplateId,
+    ) -> Result<OpeningTemplateOf<T>, Error> {
+        ensure!(
+            <OpeningTemplateById<T, I>>::exists(template_id),
+            Error::OpeningTemplateDoesNotExist
+        );
+
+        Ok(Self::opening_template_by_id(template_id))
+    }
+
+    // Ensures the opening template name has the valid length and is unique.
+    fn ensure_opening_template_name_is_valid(name: &[u8]) -> Result<(), Error> {
+        default_text_constraint()
+            .ensure_valid(
+                name.len(),
+                Error::OpeningTemplateNameTooShort.into(),
+                Error::OpeningTemplateNameTooLong.into(),
+            )
+            .map_err(Error::from)?;
+
+        ensure!(
+            !<OpeningTemplateById<T, I>>::enumerate().any(|(_, template)| template.name == name),
+            Error::OpeningTemplateNameIsNotUnique
+        );
+
+        Ok(())
+    }
+
+    // Ensures the worker/lead exists, is not suspended and can get one more extra reward.
+    fn ensure_worker_can_get_extra_reward(worker_id: &WorkerId<T>) -> Result<WorkerOf<T>, Error> {
+        let worker = Self::ensure_worker_exists(worker_id)?;
+
+        ensure!(
+            !Self::is_worker_suspended(worker_id),
+            Error::WorkerIsSuspended
+        );
+
+        ensure!(
+            Self::extra_rewards(worker_id).len() < MAX_WORKER_EXTRA_REWARDS,
+            Error::WorkerMaxExtraRewardsReached
+        );
+
+        Ok(worker)
+    }
+
+    // Ensures the total of the extra rewards granted within the current budget period (including
+    // the new extra reward) doesn't exceed the working group mint capacity.
+    // Returns the updated granted extra rewards of the budget period.
+    fn ensure_extra_reward_fits_budget(
+        total_amount: BalanceOfMint<T>,
+    ) -> Result<GrantedExtraRewardsOf<T>, Error> {
+        let mint_id = Self::mint();
+
+        ensure!(<minting::Mints<T>>::exists(mint_id), Error::CannotFindMint);
+
+        let mint_capacity = <minting::Module<T>>::mints(mint_id).capacity();
+
+        let now = <system::Module<T>>::block_number();
+        let mut granted_extra_rewards = Self::granted_extra_rewards();
+
+        // Start the new budget period if the current one is over.
+        if granted_extra_rewards.period_started_at + T::ExtraRewardsBudgetPeriod::get() <= now {
+            granted_extra_rewards = GrantedExtraRewards {
+                period_started_at: now,
+                amount: Zero::zero(),
+            };
+        }
+
+        granted_extra_rewards.amount = granted_extra_rewards.amount.saturating_add(total_amount);
+
+        ensure!(
+            granted_extra_rewards.amount <= mint_capacity,
+            Error::ExtraRewardsBudgetExceeded
+        );
+
+        Ok(granted_extra_rewards)
+    }
+
+    fn ensure_worker_evaluation_rationale_is_valid(text: &[u8]) -> Result<(), Error> {
+        default_text_constraint()
+            .ensure_valid(
+                text.len(),
+                Error::WorkerEvaluationRationaleTooShort.into(),
+                Error::WorkerEvaluationRationaleTooLong.into(),
+            )
+            .map_err(|e| e.into())
+    }
+
+    fn ensure_work_report_description_is_valid(text: &[u8]) -> Result<(), Error> {
+        default_text_constraint()
+            .ensure_valid(
+                text.len(),
+                Error::WorkReportDescriptionTooShort.into(),
+                Error::WorkReportDescriptionTooLong.into(),
+            )
+            .map_err(|e| e.into())
     }
 
     fn ensure_worker_exit_rationale_text_is_valid(text: &[u8]) -> Result<(), Error> {
@@ -1335,9 +2694,17 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             return imbalance;
         }
 
+        // Sponsored stakes are returned to the sponsors.
+        let imbalance = <stake::Module<T>>::refund_stake_funders(&stake_id, imbalance);
+
+        if imbalance.peek().is_zero() {
+            return imbalance;
+        }
+
         let member_id = Module::<T, I>::member_id_by_hiring_application_id(hiring_application_id);
 
         if let Some(member_profile) = membership::members::MemberProfile::<T>::get(member_id) {
+            let refund_amount = imbalance.peek();
             let refunding_result = CurrencyOf::<T>::resolve_into_existing(
                 &member_profile.controller_account,
                 imbalance,
@@ -1348,6 +2715,8 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
                 // cannot return imbalance here, because of possible double spending.
                 return <NegativeImbalance<T>>::zero();
             }
+
+            Self::on_stake_refunded(refund_amount);
         } else {
             print("Working group broken invariant: no member profile.");
             return imbalance;
@@ -1356,6 +2725,28 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         <NegativeImbalance<T>>::zero()
     }
 
+    // Ensures the worker/lead has a stake decrease request awaiting the lead decision.
+    fn ensure_pending_stake_decrease_request(worker_id: &WorkerId<T>) -> Result<(), Error> {
+        ensure!(
+            <StakeDecreaseRequestByWorkerId<T, I>>::exists(worker_id),
+            Error::StakeDecreaseRequestDoesNotExist
+        );
+
+        let request = Self::stake_decrease_request(worker_id);
+
+        ensure!(
+            !request.is_approved(),
+            Error::StakeDecreaseRequestIsAlreadyApproved
+        );
+
+        Ok(())
+    }
+
+    /// Checks whether the worker/lead role is suspended.
+    pub fn is_worker_suspended(worker_id: &WorkerId<T>) -> bool {
+        <WorkerSuspensionById<T, I>>::exists(worker_id)
+    }
+
     /// Returns all existing worker id list excluding the current leader worker id.
     pub fn get_regular_worker_ids() -> Vec<WorkerId<T>> {
         let lead_worker_id = Self::current_lead();
@@ -1408,6 +2799,8 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             // Attempt to deactivate
             recurringrewards::Module::<T>::try_to_deactivate_relationship(reward_relationship_id)
                 .map_err(|_| Error::RelationshipMustExist)?;
+
+            <RewardRelationshipOwnerById<T, I>>::remove(reward_relationship_id);
         }; // else: Did not deactivate, there was no reward relationship!
 
         // Unstake if stake profile exists
@@ -1436,8 +2829,17 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 
         // Remove the worker from the storage.
         WorkerById::<T, I>::remove(worker_id);
+        Self::unindex_worker_role_account(worker_id, &worker.role_account_id);
+        WorkerSlashingTermsById::<T, I>::remove(worker_id);
+        WorkerSlashCountById::<T, I>::remove(worker_id);
+        Self::remove_worker_suspension(worker_id);
+        Self::remove_stake_decrease_request(worker_id);
+        Self::remove_pending_slashes(worker_id, worker);
         Self::decrease_active_worker_counter();
 
+        // Remove the bonuses and extra rewards.
+        Self::remove_extra_rewards(worker_id);
+
         // Trigger the event
         let event = match exit_initiation_origin {
             ExitInitiationOrigin::Lead => {
@@ -1456,6 +2858,504 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Ok(())
     }
 
+    // Removes the worker suspension with its suspension end index entry.
+    fn remove_worker_suspension(worker_id: &WorkerId<T>) -> WorkerSuspensionOf<T> {
+        let suspension = WorkerSuspensionById::<T, I>::take(worker_id);
+
+        let mut worker_ids = WorkerIdsBySuspensionEnd::<T, I>::get(suspension.ends_at);
+        worker_ids.retain(|id| id != worker_id);
+
+        if worker_ids.is_empty() {
+            WorkerIdsBySuspensionEnd::<T, I>::remove(suspension.ends_at);
+        } else {
+            WorkerIdsBySuspensionEnd::<T, I>::insert(suspension.ends_at, worker_ids);
+        }
+
+        suspension
+    }
+
+    // Resumes the paused reward relationship of the worker with the removed suspension.
+    fn resume_suspended_worker(
+        worker_id: &WorkerId<T>,
+        worker: &WorkerOf<T>,
+        suspension: WorkerSuspensionOf<T>,
+    ) {
+        if let (Some(relationship_id), Some(paused_reward_payment_at)) = (
+            worker.reward_relationship,
+            suspension.paused_reward_payment_at,
+        ) {
+            // Payout missed during the suspension is postponed to the next block.
+            let next_block = <system::Module<T>>::block_number() + One::one();
+            let next_payment_at = paused_reward_payment_at.max(next_block);
+
+            if recurringrewards::Module::<T>::try_to_activate_relationship(
+                relationship_id,
+                next_payment_at,
+            )
+            .is_err()
+            {
+                print("Working group broken invariant: no reward relationship for the worker.");
+            }
+        }
+
+        Self::resume_extra_rewards(worker_id);
+
+        Self::deposit_event(RawEvent::WorkerResumed(*worker_id));
+    }
+
+    // Resumes workers whose suspension expires at the block.
+    fn resume_workers_with_expired_suspension(now: T::BlockNumber) {
+        for worker_id in WorkerIdsBySuspensionEnd::<T, I>::take(now) {
+            let worker = Self::worker_by_id(worker_id);
+            let suspension = WorkerSuspensionById::<T, I>::take(worker_id);
+
+            Self::resume_suspended_worker(&worker_id, &worker, suspension);
+        }
+    }
+
+    // Decreases the stake, returning the value to the stake funders for the sponsored stakes and to
+    // the worker role account otherwise.
+    fn decrease_worker_stake(
+        stake_id: &StakeId<T>,
+        role_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+    ) -> Result<(), stake::StakeActionError<stake::DecreasingStakeError>> {
+        if <stake::StakeFundingByStakeId<T>>::exists(stake_id) {
+            <stake::Module<T>>::decrease_stake_to_funders(stake_id, value).map(|_| ())
+        } else {
+            <stake::Module<T>>::decrease_stake_to_account(stake_id, role_account_id, value)
+                .map(|_| ())
+        }
+    }
+
+    // Removes the stake decrease request with its release index entry.
+    fn remove_stake_decrease_request(worker_id: &WorkerId<T>) {
+        let request = StakeDecreaseRequestByWorkerId::<T, I>::take(worker_id);
+
+        if let Some(release_at) = request.release_at {
+            let mut worker_ids = WorkerIdsByStakeDecreaseRelease::<T, I>::get(release_at);
+            worker_ids.retain(|id| id != worker_id);
+
+            if worker_ids.is_empty() {
+                WorkerIdsByStakeDecreaseRelease::<T, I>::remove(release_at);
+            } else {
+                WorkerIdsByStakeDecreaseRelease::<T, I>::insert(release_at, worker_ids);
+            }
+        }
+    }
+
+    // Releases the approved stake decreases with the unstaking period expiring at the block.
+    fn release_approved_stake_decreases(now: T::BlockNumber) {
+        for worker_id in WorkerIdsByStakeDecreaseRelease::<T, I>::take(now) {
+            let request = StakeDecreaseRequestByWorkerId::<T, I>::take(worker_id);
+
+            let worker = Self::worker_by_id(worker_id);
+
+            if let Some(stake_profile) = worker.role_stake_profile {
+                // The stake could be slashed since the approval.
+                let event = if Self::decrease_worker_stake(
+                    &stake_profile.stake_id,
+                    &worker.role_account_id,
+                    request.amount,
+                )
+                .is_ok()
+                {
+                    RawEvent::StakeDecreased(worker_id)
+                } else {
+                    RawEvent::StakeDecreaseReleaseFailed(worker_id)
+                };
+
+                Self::deposit_event(event);
+            }
+        }
+    }
+
+    /// Removes the executed slashing from the pending slashes of the worker/lead.
+    /// Should be invoked by the staking events handler on the delayed slashing execution.
+    pub fn on_stake_slashed(stake_id: StakeId<T>, slash_id: SlashId<T>) {
+        if !<WorkerIdBySlashedStakeId<T, I>>::exists(stake_id) {
+            return;
+        }
+
+        let worker_id = Self::worker_id_by_slashed_stake_id(stake_id);
+
+        if <PendingSlashes<T, I>>::exists((worker_id, slash_id)) {
+            Self::remove_pending_slash(&worker_id, &stake_id, &slash_id);
+
+            Self::deposit_event(RawEvent::StakeSlashed(worker_id));
+        }
+    }
+
+    // Removes the pending slash with its index entries.
+    fn remove_pending_slash(worker_id: &WorkerId<T>, stake_id: &StakeId<T>, slash_id: &SlashId<T>) {
+        <PendingSlashes<T, I>>::remove((*worker_id, *slash_id));
+
+        let mut slash_ids = Self::pending_slash_ids(worker_id);
+        slash_ids.retain(|id| id != slash_id);
+
+        if slash_ids.is_empty() {
+            <PendingSlashIdsByWorkerId<T, I>>::remove(worker_id);
+            <WorkerIdBySlashedStakeId<T, I>>::remove(stake_id);
+        } else {
+            <PendingSlashIdsByWorkerId<T, I>>::insert(worker_id, slash_ids);
+        }
+    }
+
+    // Removes all pending slashes of the leaving worker/lead.
+    fn remove_pending_slashes(worker_id: &WorkerId<T>, worker: &WorkerOf<T>) {
+        for slash_id in <PendingSlashIdsByWorkerId<T, I>>::take(worker_id) {
+            <PendingSlashes<T, I>>::remove((*worker_id, slash_id));
+        }
+
+        if let Some(ref stake_profile) = worker.role_stake_profile {
+            <WorkerIdBySlashedStakeId<T, I>>::remove(stake_profile.stake_id);
+        }
+    }
+
+    // Creates the separate reward relationship for the worker/lead bonus or extra reward
+    // and accounts it in the extra rewards budget.
+    fn add_extra_reward(
+        worker_id: &WorkerId<T>,
+        worker: &WorkerOf<T>,
+        amount_per_payout: BalanceOfMint<T>,
+        next_payment_at: T::BlockNumber,
+        payout_interval: Option<T::BlockNumber>,
+        ends_at: T::BlockNumber,
+        granted_extra_rewards: GrantedExtraRewardsOf<T>,
+    ) -> Result<(), Error> {
+        let recipient = <recurringrewards::Module<T>>::add_recipient();
+
+        let reward_relationship_id =
+            ensure_on_wrapped_error!(<recurringrewards::Module<T>>::add_reward_relationship(
+                Self::mint(),
+                recipient,
+                Self::get_worker_reward_account(worker),
+                amount_per_payout,
+                next_payment_at,
+                payout_interval,
+                Some(ends_at + One::one()),
+                None,
+            ))?;
+
+        <RewardRelationshipOwnerById<T, I>>::insert(
+            reward_relationship_id,
+            (*worker_id, SpendingCategory::ExtraReward),
+        );
+
+        <ExtraRewardsByWorkerId<T, I>>::mutate(worker_id, |extra_rewards| {
+            extra_rewards.push(ExtraReward {
+                reward_relationship_id,
+                ends_at,
+                paused_payment_at: None,
+            })
+        });
+
+        // The reward relationship is deactivated after the last payout and removed in the
+        // next block.
+        <WorkerIdsByExtraRewardEnd<T, I>>::mutate(ends_at + One::one(), |worker_ids| {
+            if !worker_ids.contains(worker_id) {
+                worker_ids.push(*worker_id)
+            }
+        });
+
+        <GrantedExtraRewardsInPeriod<T, I>>::put(granted_extra_rewards);
+
+        Ok(())
+    }
+
+    // Returns the account of the worker/lead regular reward relationship or the member root
+    // account (regular reward destination) if the worker has no regular reward.
+    fn get_worker_reward_account(worker: &WorkerOf<T>) -> T::AccountId {
+        if let Some(relationship_id) = worker.reward_relationship {
+            if <recurringrewards::RewardRelationships<T>>::exists(relationship_id) {
+                return <recurringrewards::RewardRelationships<T>>::get(relationship_id).account;
+            }
+        }
+
+        <membership::members::Module<T>>::member_profile(worker.member_id)
+            .map_or(worker.role_account_id.clone(), |profile| {
+                profile.root_account
+            })
+    }
+
+    // Removes the paid bonuses and the expired extra rewards with their reward relationships.
+    fn remove_finished_extra_rewards(now: T::BlockNumber) {
+        for worker_id in WorkerIdsByExtraRewardEnd::<T, I>::take(now) {
+            let (finished_rewards, active_rewards): (Vec<_>, Vec<_>) =
+                Self::extra_rewards(worker_id)
+                    .into_iter()
+                    .partition(|reward| reward.ends_at < now);
+
+            for extra_reward in finished_rewards {
+                Self::remove_extra_reward_relationship(extra_reward.reward_relationship_id);
+            }
+
+            if active_rewards.is_empty() {
+                ExtraRewardsByWorkerId::<T, I>::remove(worker_id);
+            } else {
+                ExtraRewardsByWorkerId::<T, I>::insert(worker_id, active_rewards);
+            }
+        }
+    }
+
+    // Removes the extra rewards of the worker/lead with their reward relationships.
+    fn remove_extra_rewards(worker_id: &WorkerId<T>) {
+        for extra_reward in ExtraRewardsByWorkerId::<T, I>::take(worker_id) {
+            Self::remove_extra_reward_relationship(extra_reward.reward_relationship_id);
+
+            let removal_block = extra_reward.ends_at + One::one();
+            let mut worker_ids = Self::worker_ids_by_extra_reward_end(removal_block);
+            worker_ids.retain(|id| id != worker_id);
+
+            if worker_ids.is_empty() {
+                WorkerIdsByExtraRewardEnd::<T, I>::remove(removal_block);
+            } else {
+                WorkerIdsByExtraRewardEnd::<T, I>::insert(removal_block, worker_ids);
+            }
+        }
+    }
+
+    // Removes the extra reward relationship and its owner.
+    fn remove_extra_reward_relationship(relationship_id: T::RewardRelationshipId) {
+        recurringrewards::Module::<T>::remove_reward_relationship(relationship_id);
+        <RewardRelationshipOwnerById<T, I>>::remove(relationship_id);
+    }
+
+    // Ensures the worker reward relationship and the extra reward relationships exist.
+    fn ensure_worker_reward_relationships_exist(
+        worker_id: &WorkerId<T>,
+        worker: &WorkerOf<T>,
+    ) -> Result<(), Error> {
+        let extra_reward_relationship_ids = Self::extra_rewards(worker_id)
+            .into_iter()
+            .map(|extra_reward| extra_reward.reward_relationship_id);
+
+        for relationship_id in worker
+            .reward_relationship
+            .into_iter()
+            .chain(extra_reward_relationship_ids)
+        {
+            ensure!(
+                recurringrewards::RewardRelationships::<T>::exists(relationship_id),
+                Error::RelationshipMustExist
+            );
+        }
+
+        Ok(())
+    }
+
+    // Deactivates the reward relationship. Returns the next payment block of the deactivated
+    // relationship or None if it was not active.
+    fn pause_reward_relationship(
+        relationship_id: T::RewardRelationshipId,
+    ) -> Option<T::BlockNumber> {
+        let relationship = recurringrewards::RewardRelationships::<T>::get(relationship_id);
+
+        match recurringrewards::Module::<T>::try_to_deactivate_relationship(relationship_id) {
+            Ok(true) => relationship.next_payment_at_block(),
+            Ok(false) => None,
+            Err(_) => {
+                print("Working group broken invariant: no reward relationship to pause.");
+
+                None
+            }
+        }
+    }
+
+    // Pauses the extra reward relationships of the suspended worker/lead.
+    fn pause_extra_rewards(worker_id: &WorkerId<T>) {
+        let mut extra_rewards = Self::extra_rewards(worker_id);
+
+        for extra_reward in extra_rewards.iter_mut() {
+            let paused_payment_at =
+                Self::pause_reward_relationship(extra_reward.reward_relationship_id);
+
+            if paused_payment_at.is_some() {
+                extra_reward.paused_payment_at = paused_payment_at;
+            }
+        }
+
+        if !extra_rewards.is_empty() {
+            ExtraRewardsByWorkerId::<T, I>::insert(worker_id, extra_rewards);
+        }
+    }
+
+    // Resumes the extra reward relationships paused by the worker/lead suspension. The payout
+    // missed during the suspension is postponed to the next block. The extra rewards finished
+    // during the suspension are already removed.
+    fn resume_extra_rewards(worker_id: &WorkerId<T>) {
+        let mut extra_rewards = Self::extra_rewards(worker_id);
+        let next_block = <system::Module<T>>::block_number() + One::one();
+
+        for extra_reward in extra_rewards.iter_mut() {
+            if let Some(paused_payment_at) = extra_reward.paused_payment_at.take() {
+                if recurringrewards::Module::<T>::try_to_activate_relationship(
+                    extra_reward.reward_relationship_id,
+                    paused_payment_at.max(next_block),
+                )
+                .is_err()
+                {
+                    print("Working group broken invariant: no extra reward relationship.");
+                }
+            }
+        }
+
+        if !extra_rewards.is_empty() {
+            ExtraRewardsByWorkerId::<T, I>::insert(worker_id, extra_rewards);
+        }
+    }
+
+    /// Sets the council term to account the further working group spending in.
+    /// Should be invoked on the council election.
+    pub fn start_council_term(term: CouncilTerm) {
+        <CurrentCouncilTerm<I>>::put(term);
+    }
+
+    // Accounts the stake refund in the spending report of the current council term.
+    fn on_stake_refunded(amount: BalanceOf<T>) {
+        let amount: BalanceOfMint<T> = amount.saturated_into::<u128>().saturated_into();
+
+        <SpendingReportByCouncilTerm<T, I>>::mutate(Self::current_council_term(), |report| {
+            report.add_refund(amount)
+        });
+    }
+
+    /// Indexes the reward relationships of the workers/lead hired before the spending reports
+    /// were introduced. Should be invoked on the runtime upgrade.
+    pub fn index_existing_reward_relationship_owners() {
+        let current_lead = Self::current_lead();
+
+        for (worker_id, worker) in <WorkerById<T, I>>::enumerate() {
+            if let Some(relationship_id) = worker.reward_relationship {
+                let category = if current_lead == Some(worker_id) {
+                    SpendingCategory::LeaderReward
+                } else {
+                    SpendingCategory::WorkerReward
+                };
+
+                <RewardRelationshipOwnerById<T, I>>::insert(relationship_id, (worker_id, category));
+            }
+        }
+    }
+
+    /// Indexes the role accounts of the workers/lead hired before the role account index was
+    /// introduced. Should be invoked on the runtime upgrade.
+    pub fn index_existing_worker_role_accounts() {
+        for (worker_id, worker) in <WorkerById<T, I>>::enumerate() {
+            Self::index_worker_role_account(&worker_id, &worker.role_account_id);
+        }
+    }
+
+    // Adds the worker/lead to the role account index.
+    fn index_worker_role_account(worker_id: &WorkerId<T>, role_account_id: &T::AccountId) {
+        <WorkerIdsByRoleAccountId<T, I>>::mutate(role_account_id, |worker_ids| {
+            if !worker_ids.contains(worker_id) {
+                worker_ids.push(*worker_id)
+            }
+        });
+    }
+
+    // Removes the worker/lead from the role account index.
+    fn unindex_worker_role_account(worker_id: &WorkerId<T>, role_account_id: &T::AccountId) {
+        let mut worker_ids = Self::worker_ids_by_role_account_id(role_account_id);
+        worker_ids.retain(|id| id != worker_id);
+
+        if worker_ids.is_empty() {
+            <WorkerIdsByRoleAccountId<T, I>>::remove(role_account_id);
+        } else {
+            <WorkerIdsByRoleAccountId<T, I>>::insert(role_account_id, worker_ids);
+        }
+    }
+
+    /// Accounts the successful reward payout of the worker/lead in the spending report of the
+    /// current council term. Should be invoked by the recurring rewards payout status handler.
+    pub fn on_reward_payout_succeeded(
+        relationship_id: T::RewardRelationshipId,
+        amount: BalanceOfMint<T>,
+    ) {
+        if let Some((worker_id, category)) = Self::reward_relationship_owner(relationship_id) {
+            <SpendingReportByCouncilTerm<T, I>>::mutate(Self::current_council_term(), |report| {
+                report.add_spending(worker_id, category, amount)
+            });
+        }
+    }
+
+    /// Accounts the missed reward payout of the worker/lead in the spending report of the
+    /// current council term. Should be invoked by the recurring rewards payout status handler.
+    pub fn on_reward_payout_failed(
+        relationship_id: T::RewardRelationshipId,
+        amount: BalanceOfMint<T>,
+    ) {
+        if Self::reward_relationship_owner(relationship_id).is_some() {
+            <SpendingReportByCouncilTerm<T, I>>::mutate(Self::current_council_term(), |report| {
+                report.add_missed_reward(amount)
+            });
+        }
+    }
+
+    // Validates the opening policy commitment and the text and adds the worker/lead opening.
+    fn create_opening(
+        activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
+        commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>,
+        human_readable_text: Vec<u8>,
+        opening_type: OpeningType,
+    ) -> Result<OpeningId<T>, Error> {
+        Self::ensure_opening_human_readable_text_is_valid(&human_readable_text)?;
+
+        Self::ensure_opening_policy_commitment_is_valid(&commitment)?;
+
+        // Add opening
+        // NB: This call can in principle fail, because the staking policies
+        // may not respect the minimum currency requirement.
+
+        let policy_commitment = commitment.clone();
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        let opening_id = ensure_on_wrapped_error!(hiring::Module::<T>::add_opening(
+            activate_at,
+            commitment.max_review_period_length,
+            commitment.application_rationing_policy,
+            commitment.application_staking_policy,
+            commitment.role_staking_policy,
+            human_readable_text,
+        ))?;
+
+        let new_opening_id = NextOpeningId::<T, I>::get();
+
+        // Create and add worker opening.
+        let new_opening_by_id =
+            Opening::<OpeningId<T>, T::BlockNumber, BalanceOf<T>, ApplicationId<T>> {
+                hiring_opening_id: opening_id,
+                applications: BTreeSet::new(),
+                policy_commitment,
+                opening_type,
+            };
+
+        OpeningById::<T, I>::insert(new_opening_id, new_opening_by_id);
+
+        // Update NextOpeningId
+        NextOpeningId::<T, I>::mutate(|id| *id += <OpeningId<T> as One>::one());
+
+        // Trigger event
+        Self::deposit_event(RawEvent::OpeningAdded(new_opening_id));
+
+        Ok(new_opening_id)
+    }
+
+    // Appends the record to the history dropping the oldest records above the max history length.
+    fn push_to_bounded_history<R>(history: &mut Vec<R>, record: R) {
+        history.push(record);
+
+        let max_length = T::MaxWorkerHistoryLength::get() as usize;
+        if history.len() > max_length {
+            let excess = history.len() - max_length;
+            history.drain(..excess);
+        }
+    }
+
     fn initialize_working_group(
         opening_human_readable_text_constraint: InputValidationLengthConstraint,
         worker_application_human_readable_text_constraint: InputValidationLengthConstraint,
@@ -1463,7 +3363,8 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         working_group_mint_capacity: minting::BalanceOf<T>,
     ) {
         // Create a mint.
-        let mint_id_result = <minting::Module<T>>::add_mint(working_group_mint_capacity, None);
+        let mint_id_result =
+            <minting::Module<T>>::add_mint(working_group_mint_capacity, None, None);
 
         if let Ok(mint_id) = mint_id_result {
             <Mint<T, I>>::put(mint_id);
@@ -1533,6 +3434,8 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
                         checked_policy.amount_per_payout,
                         checked_policy.next_payment_at_block,
                         checked_policy.payout_interval,
+                        None,
+                        None,
                     )
                     .expect("Failed to create reward relationship!");
 
@@ -1569,8 +3472,27 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
                     &stake_profile,
                 );
 
+                // Keep track of the worker paid by the reward relationship
+                if let Some(relationship_id) = reward_relationship {
+                    let category = if matches!(opening.opening_type, OpeningType::Leader) {
+                        SpendingCategory::LeaderReward
+                    } else {
+                        SpendingCategory::WorkerReward
+                    };
+
+                    <RewardRelationshipOwnerById<T, I>>::insert(
+                        relationship_id,
+                        (new_worker_id, category),
+                    );
+                }
+
                 // Store a worker
+                Self::index_worker_role_account(&new_worker_id, &worker.role_account_id);
                 <WorkerById<T, I>>::insert(new_worker_id, worker);
+                <WorkerSlashingTermsById<T, I>>::insert(
+                    new_worker_id,
+                    opening.policy_commitment.role_slashing_terms.clone(),
+                );
                 Self::increase_active_worker_counter();
 
                 // Update next worker id
@@ -1587,6 +3509,59 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         application_id_to_worker_id
     }
 
+    // Ensures the role stake can be slashed according to the role slashing terms.
+    // Returns the slashing balance capped by the max percentage of the current stake.
+    // Workers hired before the slashing terms were stored are slashed without the limits.
+    fn ensure_slashing_terms_allow_slashing(
+        worker_id: &WorkerId<T>,
+        stake_profile: &RoleStakeProfile<StakeId<T>, T::BlockNumber>,
+        balance: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, Error> {
+        if !<WorkerSlashingTermsById<T, I>>::exists(worker_id) {
+            return Ok(balance);
+        }
+
+        let slashable_terms = match Self::worker_slashing_terms(worker_id) {
+            SlashingTerms::Unslashable => return Err(Error::WorkerRoleIsUnslashable),
+            SlashingTerms::Slashable(slashable_terms) => slashable_terms,
+        };
+
+        ensure!(
+            Self::worker_slash_count(worker_id) < slashable_terms.max_count,
+            Error::WorkerMaxSlashCountReached
+        );
+
+        let max_percent_pts =
+            <BalanceOf<T>>::from(u32::from(slashable_terms.max_percent_pts_per_time));
+        let max_slashing_balance = Self::get_staked_amount(&stake_profile.stake_id)
+            .saturating_mul(max_percent_pts)
+            / <BalanceOf<T>>::from(100u32);
+
+        Ok(balance.min(max_slashing_balance))
+    }
+
+    // Returns the staked amount or zero if the stake is not staked.
+    fn get_staked_amount(stake_id: &StakeId<T>) -> BalanceOf<T> {
+        match <stake::Module<T>>::stakes(stake_id).staking_status {
+            stake::StakingStatus::Staked(staked_state) => staked_state.staked_amount,
+            _ => Zero::zero(),
+        }
+    }
+
+    // Increases the slash counter of the worker/lead role stake (saturating).
+    fn increase_worker_slash_count(worker_id: &WorkerId<T>) {
+        <WorkerSlashCountById<T, I>>::mutate(worker_id, |slash_count| {
+            *slash_count = slash_count.saturating_add(1)
+        });
+    }
+
+    // Decreases the slash counter of the worker/lead role stake (saturating).
+    fn decrease_worker_slash_count(worker_id: &WorkerId<T>) {
+        <WorkerSlashCountById<T, I>>::mutate(worker_id, |slash_count| {
+            *slash_count = slash_count.saturating_sub(1)
+        });
+    }
+
     // Increases active worker counter (saturating).
     fn increase_active_worker_counter() {
         let next_active_worker_count_value = Self::active_worker_count().saturating_add(1);
@@ -1,15 +1,19 @@
 use super::mock::{
     Balances, Membership, System, Test, TestEvent, TestWorkingGroup, TestWorkingGroupInstance,
+    SLASHING_APPEAL_THREAD_ID,
 };
 use crate::tests::fill_worker_position;
 use crate::types::{
-    Application, Opening, OpeningPolicyCommitment, OpeningType, RewardPolicy, RoleStakeProfile,
-    Worker,
+    Application, ExtraReward, Opening, OpeningPolicyCommitment, OpeningPolicyCommitmentOverrides,
+    OpeningTemplate, OpeningType, RewardPolicy, RoleStakeProfile, StakeDecreaseRequest, WorkReport,
+    Worker, WorkerEvaluation, WorkerSuspension,
 };
+use crate::ArrearsPolicyOf;
 use crate::Error;
 use crate::RawEvent;
 use common::constraints::InputValidationLengthConstraint;
-use srml_support::{StorageLinkedMap, StorageValue};
+use primitives::H256;
+use srml_support::{StorageLinkedMap, StorageMap, StorageValue};
 use std::collections::BTreeSet;
 use system::{EventRecord, Phase, RawOrigin};
 
@@ -67,6 +71,381 @@ impl IncreaseWorkerStakeFixture {
     }
 }
 
+pub struct EvaluateWorkerFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+    score: u8,
+    rationale: Vec<u8>,
+}
+
+impl EvaluateWorkerFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(lead_account_id),
+            score: 80,
+            rationale: b"rationale".to_vec(),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_score(self, score: u8) -> Self {
+        Self { score, ..self }
+    }
+
+    pub fn with_rationale(self, rationale: Vec<u8>) -> Self {
+        Self { rationale, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_evaluations = TestWorkingGroup::worker_evaluations(self.worker_id);
+
+        let actual_result = TestWorkingGroup::evaluate_worker(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.score,
+            self.rationale.clone(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        let new_evaluations = TestWorkingGroup::worker_evaluations(self.worker_id);
+        if actual_result.is_ok() {
+            let expected_evaluation = WorkerEvaluation {
+                score: self.score,
+                rationale: self.rationale.clone(),
+                evaluated_at: System::block_number(),
+            };
+
+            assert_eq!(new_evaluations.last(), Some(&expected_evaluation));
+        } else {
+            assert_eq!(new_evaluations, old_evaluations);
+        }
+    }
+}
+
+pub struct SuspendWorkerFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+    suspension_period: u64,
+    rationale: Vec<u8>,
+}
+
+impl SuspendWorkerFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(lead_account_id),
+            suspension_period: 5,
+            rationale: b"rationale".to_vec(),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_suspension_period(self, suspension_period: u64) -> Self {
+        Self {
+            suspension_period,
+            ..self
+        }
+    }
+
+    pub fn with_rationale(self, rationale: Vec<u8>) -> Self {
+        Self { rationale, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let was_suspended = TestWorkingGroup::is_worker_suspended(&self.worker_id);
+        let worker = TestWorkingGroup::worker_by_id(self.worker_id);
+        let old_next_payment_at = worker.reward_relationship.and_then(|relationship_id| {
+            recurringrewards::RewardRelationships::<Test>::get(relationship_id)
+                .next_payment_at_block()
+        });
+
+        let actual_result = TestWorkingGroup::suspend_worker(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.suspension_period,
+            self.rationale.clone(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let now = System::block_number();
+
+            if let Some(relationship_id) = worker.reward_relationship {
+                let relationship =
+                    recurringrewards::RewardRelationships::<Test>::get(relationship_id);
+                assert!(!relationship.is_active());
+            }
+
+            let expected_suspension = WorkerSuspension {
+                suspended_at: now,
+                ends_at: now + self.suspension_period,
+                rationale: self.rationale.clone(),
+                paused_reward_payment_at: old_next_payment_at,
+            };
+
+            assert_eq!(
+                TestWorkingGroup::worker_suspension(self.worker_id),
+                expected_suspension
+            );
+        } else {
+            assert_eq!(
+                TestWorkingGroup::is_worker_suspended(&self.worker_id),
+                was_suspended
+            );
+        }
+    }
+}
+
+pub struct ResumeWorkerFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+}
+
+impl ResumeWorkerFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(lead_account_id),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let was_suspended = TestWorkingGroup::is_worker_suspended(&self.worker_id);
+
+        let actual_result =
+            TestWorkingGroup::resume_worker(self.origin.clone().into(), self.worker_id);
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert!(!TestWorkingGroup::is_worker_suspended(&self.worker_id));
+
+            let worker = TestWorkingGroup::worker_by_id(self.worker_id);
+            if let Some(relationship_id) = worker.reward_relationship {
+                let relationship =
+                    recurringrewards::RewardRelationships::<Test>::get(relationship_id);
+                assert!(relationship.is_active());
+            }
+        } else {
+            assert_eq!(
+                TestWorkingGroup::is_worker_suspended(&self.worker_id),
+                was_suspended
+            );
+        }
+    }
+}
+
+pub struct GrantBonusFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+    amount: u64,
+}
+
+impl GrantBonusFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(lead_account_id),
+            amount: 30,
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_amount(self, amount: u64) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_extra_rewards = TestWorkingGroup::extra_rewards(self.worker_id);
+        let next_relationship_id = recurringrewards::Module::<Test>::reward_relationships_created();
+
+        let actual_result =
+            TestWorkingGroup::grant_bonus(self.origin.clone().into(), self.worker_id, self.amount);
+        assert_eq!(actual_result, expected_result);
+
+        let new_extra_rewards = TestWorkingGroup::extra_rewards(self.worker_id);
+        if actual_result.is_ok() {
+            let payment_at = System::block_number() + 1;
+
+            assert_eq!(new_extra_rewards.len(), old_extra_rewards.len() + 1);
+            assert_eq!(
+                new_extra_rewards.last().cloned(),
+                Some(ExtraReward {
+                    reward_relationship_id: next_relationship_id,
+                    ends_at: payment_at,
+                    paused_payment_at: None,
+                })
+            );
+
+            let relationship =
+                recurringrewards::RewardRelationships::<Test>::get(next_relationship_id);
+            assert_eq!(relationship.amount_per_payout, self.amount);
+            assert_eq!(relationship.next_payment_at_block(), Some(payment_at));
+        } else {
+            assert_eq!(new_extra_rewards, old_extra_rewards);
+        }
+    }
+}
+
+pub struct GrantExtraRewardFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+    amount_per_payout: u64,
+    payout_interval: u64,
+    duration: u64,
+}
+
+impl GrantExtraRewardFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(lead_account_id),
+            amount_per_payout: 10,
+            payout_interval: 5,
+            duration: 12,
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_amount_per_payout(self, amount_per_payout: u64) -> Self {
+        Self {
+            amount_per_payout,
+            ..self
+        }
+    }
+
+    pub fn with_payout_interval(self, payout_interval: u64) -> Self {
+        Self {
+            payout_interval,
+            ..self
+        }
+    }
+
+    pub fn with_duration(self, duration: u64) -> Self {
+        Self { duration, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_extra_rewards = TestWorkingGroup::extra_rewards(self.worker_id);
+        let next_relationship_id = recurringrewards::Module::<Test>::reward_relationships_created();
+
+        let actual_result = TestWorkingGroup::grant_extra_reward(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.amount_per_payout,
+            self.payout_interval,
+            self.duration,
+        );
+        assert_eq!(actual_result, expected_result);
+
+        let new_extra_rewards = TestWorkingGroup::extra_rewards(self.worker_id);
+        if actual_result.is_ok() {
+            let now = System::block_number();
+
+            assert_eq!(new_extra_rewards.len(), old_extra_rewards.len() + 1);
+            assert_eq!(
+                new_extra_rewards.last().cloned(),
+                Some(ExtraReward {
+                    reward_relationship_id: next_relationship_id,
+                    ends_at: now + self.duration,
+                    paused_payment_at: None,
+                })
+            );
+
+            let relationship =
+                recurringrewards::RewardRelationships::<Test>::get(next_relationship_id);
+            assert_eq!(relationship.amount_per_payout, self.amount_per_payout);
+            assert_eq!(
+                relationship.next_payment_at_block(),
+                Some(now + self.payout_interval)
+            );
+        } else {
+            assert_eq!(new_extra_rewards, old_extra_rewards);
+        }
+    }
+}
+
+pub struct SubmitWorkReportFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+    content_hashes: Vec<H256>,
+    description: Vec<u8>,
+}
+
+impl SubmitWorkReportFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(1),
+            content_hashes: vec![H256::repeat_byte(1), H256::repeat_byte(2)],
+            description: b"description".to_vec(),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_content_hashes(self, content_hashes: Vec<H256>) -> Self {
+        Self {
+            content_hashes,
+            ..self
+        }
+    }
+
+    pub fn with_description(self, description: Vec<u8>) -> Self {
+        Self {
+            description,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_reports = TestWorkingGroup::work_reports(self.worker_id);
+
+        let actual_result = TestWorkingGroup::submit_work_report(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.content_hashes.clone(),
+            self.description.clone(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        let new_reports = TestWorkingGroup::work_reports(self.worker_id);
+        if actual_result.is_ok() {
+            let expected_report = WorkReport {
+                content_hashes: self.content_hashes.clone(),
+                description: self.description.clone(),
+                submitted_at: System::block_number(),
+            };
+
+            assert_eq!(new_reports.last(), Some(&expected_report));
+        } else {
+            assert_eq!(new_reports, old_reports);
+        }
+    }
+}
+
 pub struct TerminateWorkerRoleFixture {
     worker_id: u64,
     origin: RawOrigin<u64>,
@@ -194,29 +573,38 @@ impl UpdateWorkerRewardAmountFixture {
         }
     }
 }
-pub struct UpdateWorkerRewardAccountFixture {
+pub struct SetRewardArrearsPolicyFixture {
     worker_id: u64,
-    new_reward_account_id: u64,
+    policy: Option<ArrearsPolicyOf<Test>>,
     origin: RawOrigin<u64>,
 }
 
-impl UpdateWorkerRewardAccountFixture {
-    pub fn default_with_ids(worker_id: u64, new_reward_account_id: u64) -> Self {
+impl SetRewardArrearsPolicyFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
         Self {
             worker_id,
-            new_reward_account_id,
-            origin: RawOrigin::Signed(1),
+            policy: Some(recurringrewards::ArrearsPolicy {
+                max_arrears: 2000,
+                expiry_period: 100,
+            }),
+            origin: RawOrigin::Signed(lead_account_id),
         }
     }
     pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
         Self { origin, ..self }
     }
 
+    pub fn with_policy(self, policy: Option<ArrearsPolicyOf<Test>>) -> Self {
+        Self { policy, ..self }
+    }
+
     pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
-        let actual_result = TestWorkingGroup::update_reward_account(
+        let actual_result = TestWorkingGroup::set_reward_arrears_policy(
             self.origin.clone().into(),
             self.worker_id,
-            self.new_reward_account_id,
+            self.policy,
         );
 
         assert_eq!(actual_result.clone(), expected_result);
@@ -225,24 +613,31 @@ impl UpdateWorkerRewardAccountFixture {
             let worker = TestWorkingGroup::worker_by_id(self.worker_id);
             let relationship_id = worker.reward_relationship.unwrap();
 
-            let relationship = recurringrewards::RewardRelationships::<Test>::get(relationship_id);
+            let arrears_exist =
+                recurringrewards::RewardArrearsByRelationshipId::<Test>::exists(relationship_id);
 
-            assert_eq!(relationship.account, self.new_reward_account_id);
+            if let Some(policy) = self.policy {
+                assert!(arrears_exist);
+
+                let arrears =
+                    recurringrewards::RewardArrearsByRelationshipId::<Test>::get(relationship_id);
+                assert_eq!(arrears.policy, policy);
+            } else {
+                assert!(!arrears_exist);
+            }
         }
     }
 }
 
-pub struct UpdateWorkerRoleAccountFixture {
+pub struct ClaimRewardArrearsFixture {
     worker_id: u64,
-    new_role_account_id: u64,
     origin: RawOrigin<u64>,
 }
 
-impl UpdateWorkerRoleAccountFixture {
-    pub fn default_with_ids(worker_id: u64, new_role_account_id: u64) -> Self {
+impl ClaimRewardArrearsFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
         Self {
             worker_id,
-            new_role_account_id,
             origin: RawOrigin::Signed(1),
         }
     }
@@ -251,11 +646,99 @@ impl UpdateWorkerRoleAccountFixture {
     }
 
     pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
-        let actual_result = TestWorkingGroup::update_role_account(
-            self.origin.clone().into(),
-            self.worker_id,
-            self.new_role_account_id,
-        );
+        let reward_account = TestWorkingGroup::worker_by_id(self.worker_id)
+            .reward_relationship
+            .map(|relationship_id| {
+                recurringrewards::RewardRelationships::<Test>::get(relationship_id).account
+            });
+        let old_balance = reward_account.map(get_balance);
+        let old_arrears = reward_account.map(|_| self.get_arrears_balance());
+
+        let actual_result =
+            TestWorkingGroup::claim_reward_arrears(self.origin.clone().into(), self.worker_id);
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            let settled_amount = old_arrears.unwrap() - self.get_arrears_balance();
+
+            assert_eq!(
+                get_balance(reward_account.unwrap()),
+                old_balance.unwrap() + settled_amount
+            );
+        }
+    }
+
+    fn get_arrears_balance(&self) -> u64 {
+        let worker = TestWorkingGroup::worker_by_id(self.worker_id);
+        let relationship_id = worker.reward_relationship.unwrap();
+
+        recurringrewards::RewardArrearsByRelationshipId::<Test>::get(relationship_id).balance
+    }
+}
+
+pub struct UpdateWorkerRewardAccountFixture {
+    worker_id: u64,
+    new_reward_account_id: u64,
+    origin: RawOrigin<u64>,
+}
+
+impl UpdateWorkerRewardAccountFixture {
+    pub fn default_with_ids(worker_id: u64, new_reward_account_id: u64) -> Self {
+        Self {
+            worker_id,
+            new_reward_account_id,
+            origin: RawOrigin::Signed(1),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let actual_result = TestWorkingGroup::update_reward_account(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.new_reward_account_id,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            let worker = TestWorkingGroup::worker_by_id(self.worker_id);
+            let relationship_id = worker.reward_relationship.unwrap();
+
+            let relationship = recurringrewards::RewardRelationships::<Test>::get(relationship_id);
+
+            assert_eq!(relationship.account, self.new_reward_account_id);
+        }
+    }
+}
+
+pub struct UpdateWorkerRoleAccountFixture {
+    worker_id: u64,
+    new_role_account_id: u64,
+    origin: RawOrigin<u64>,
+}
+
+impl UpdateWorkerRoleAccountFixture {
+    pub fn default_with_ids(worker_id: u64, new_role_account_id: u64) -> Self {
+        Self {
+            worker_id,
+            new_role_account_id,
+            origin: RawOrigin::Signed(1),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let actual_result = TestWorkingGroup::update_role_account(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.new_role_account_id,
+        );
         assert_eq!(actual_result, expected_result);
 
         if actual_result.is_ok() {
@@ -271,7 +754,7 @@ pub fn set_mint_id(mint_id: u64) {
 }
 
 pub fn create_mint() -> u64 {
-    <minting::Module<Test>>::add_mint(100, None).unwrap()
+    <minting::Module<Test>>::add_mint(100, None, None).unwrap()
 }
 
 pub struct FillWorkerOpeningFixture {
@@ -415,6 +898,105 @@ impl TerminateApplicationFixture {
         assert_eq!(actual_result.clone(), expected_result);
     }
 }
+pub struct ShortlistApplicationFixture {
+    origin: RawOrigin<u64>,
+    worker_application_id: u64,
+}
+
+impl ShortlistApplicationFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+    pub fn default_for_application_id(application_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            worker_application_id: application_id,
+        }
+    }
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let actual_result = TestWorkingGroup::shortlist_application(
+            self.origin.clone().into(),
+            self.worker_application_id,
+        );
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            let application = TestWorkingGroup::application_by_id(self.worker_application_id);
+            let review =
+                <hiring::Module<Test>>::application_review_by_id(application.hiring_application_id);
+
+            assert!(review.shortlisted);
+        }
+    }
+}
+
+pub struct DeclineApplicationFixture {
+    origin: RawOrigin<u64>,
+    worker_application_id: u64,
+}
+
+impl DeclineApplicationFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+    pub fn default_for_application_id(application_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            worker_application_id: application_id,
+        }
+    }
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let actual_result = TestWorkingGroup::decline_application(
+            self.origin.clone().into(),
+            self.worker_application_id,
+        );
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            let application = TestWorkingGroup::application_by_id(self.worker_application_id);
+            let hiring_application =
+                <hiring::Module<Test>>::application_by_id(application.hiring_application_id);
+
+            assert_ne!(hiring_application.stage, hiring::ApplicationStage::Active);
+        }
+    }
+}
+
+pub struct SetApplicationReviewNoteFixture {
+    origin: RawOrigin<u64>,
+    worker_application_id: u64,
+    note_hash: H256,
+}
+
+impl SetApplicationReviewNoteFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+    pub fn default_for_application_id(application_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            worker_application_id: application_id,
+            note_hash: H256::repeat_byte(1),
+        }
+    }
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let actual_result = TestWorkingGroup::set_application_review_note(
+            self.origin.clone().into(),
+            self.worker_application_id,
+            self.note_hash,
+        );
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            let application = TestWorkingGroup::application_by_id(self.worker_application_id);
+            let review =
+                <hiring::Module<Test>>::application_review_by_id(application.hiring_application_id);
+
+            assert_eq!(review.note_hash, Some(self.note_hash));
+        }
+    }
+}
+
 pub struct WithdrawApplicationFixture {
     origin: RawOrigin<u64>,
     worker_application_id: u64,
@@ -482,6 +1064,7 @@ pub struct ApplyOnWorkerOpeningFixture {
     opt_role_stake_balance: Option<u64>,
     opt_application_stake_balance: Option<u64>,
     human_readable_text: Vec<u8>,
+    opt_sponsor_account_id: Option<u64>,
 }
 
 impl ApplyOnWorkerOpeningFixture {
@@ -514,6 +1097,13 @@ impl ApplyOnWorkerOpeningFixture {
         }
     }
 
+    pub fn with_sponsor(self, sponsor_account_id: u64) -> Self {
+        Self {
+            opt_sponsor_account_id: Some(sponsor_account_id),
+            ..self
+        }
+    }
+
     pub fn default_for_opening_id(opening_id: u64) -> Self {
         Self {
             origin: RawOrigin::Signed(1),
@@ -523,20 +1113,34 @@ impl ApplyOnWorkerOpeningFixture {
             opt_role_stake_balance: None,
             opt_application_stake_balance: None,
             human_readable_text: b"human_text".to_vec(),
+            opt_sponsor_account_id: None,
         }
     }
 
     pub fn call(&self) -> Result<u64, Error> {
         let saved_application_next_id = TestWorkingGroup::next_application_id();
-        TestWorkingGroup::apply_on_opening(
-            self.origin.clone().into(),
-            self.member_id,
-            self.worker_opening_id,
-            self.role_account_id,
-            self.opt_role_stake_balance,
-            self.opt_application_stake_balance,
-            self.human_readable_text.clone(),
-        )?;
+        if let Some(sponsor_account_id) = self.opt_sponsor_account_id {
+            TestWorkingGroup::apply_on_opening_with_sponsor(
+                self.origin.clone().into(),
+                self.member_id,
+                self.worker_opening_id,
+                self.role_account_id,
+                self.opt_role_stake_balance,
+                self.opt_application_stake_balance,
+                self.human_readable_text.clone(),
+                sponsor_account_id,
+            )?;
+        } else {
+            TestWorkingGroup::apply_on_opening(
+                self.origin.clone().into(),
+                self.member_id,
+                self.worker_opening_id,
+                self.role_account_id,
+                self.opt_role_stake_balance,
+                self.opt_application_stake_balance,
+                self.human_readable_text.clone(),
+            )?;
+        }
 
         Ok(saved_application_next_id)
     }
@@ -572,6 +1176,47 @@ impl ApplyOnWorkerOpeningFixture {
     }
 }
 
+pub struct SetStakeSponsorshipFixture {
+    origin: RawOrigin<u64>,
+    member_id: u64,
+    allowance: u64,
+}
+
+impl SetStakeSponsorshipFixture {
+    pub fn default_for_allowance(allowance: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(10),
+            member_id: 1,
+            allowance,
+        }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let actual_result = TestWorkingGroup::set_stake_sponsorship(
+            self.origin.clone().into(),
+            self.member_id,
+            self.allowance,
+        );
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            if let RawOrigin::Signed(sponsor_account_id) = self.origin {
+                assert_eq!(
+                    TestWorkingGroup::stake_sponsorship_allowance(
+                        sponsor_account_id,
+                        self.member_id
+                    ),
+                    self.allowance
+                );
+            }
+        }
+    }
+}
+
 pub struct AcceptWorkerApplicationsFixture {
     origin: RawOrigin<u64>,
     opening_id: u64,
@@ -767,79 +1412,267 @@ impl AddWorkerOpeningFixture {
     }
 }
 
-pub struct EventFixture;
-impl EventFixture {
-    pub fn assert_last_crate_event(
-        expected_raw_event: RawEvent<
-            u64,
-            u64,
-            u64,
-            u64,
-            u64,
-            std::collections::BTreeMap<u64, u64>,
-            Vec<u8>,
-            u64,
-            u64,
-            TestWorkingGroupInstance,
-        >,
-    ) {
-        let converted_event = TestEvent::working_group_TestWorkingGroupInstance(expected_raw_event);
-
-        Self::assert_last_global_event(converted_event)
-    }
-
-    pub fn assert_last_global_event(expected_event: TestEvent) {
-        let expected_event = EventRecord {
-            phase: Phase::ApplyExtrinsic(0),
-            event: expected_event,
-            topics: vec![],
-        };
-
-        assert_eq!(System::events().pop().unwrap(), expected_event);
-    }
-}
-
-pub struct DecreaseWorkerStakeFixture {
+pub struct AddOpeningTemplateFixture {
     origin: RawOrigin<u64>,
-    worker_id: u64,
-    balance: u64,
-    account_id: u64,
+    name: Vec<u8>,
+    opening_type: OpeningType,
+    commitment: OpeningPolicyCommitment<u64, u64>,
 }
 
-impl DecreaseWorkerStakeFixture {
-    pub fn default_for_worker_id(worker_id: u64) -> Self {
-        let account_id = 1;
-
-        let lead_account_id = get_current_lead_account_id();
-
+impl Default for AddOpeningTemplateFixture {
+    fn default() -> Self {
         Self {
-            origin: RawOrigin::Signed(lead_account_id),
-            worker_id,
-            balance: 10,
-            account_id,
+            origin: RawOrigin::Signed(1),
+            name: b"template".to_vec(),
+            opening_type: OpeningType::Worker,
+            commitment: <OpeningPolicyCommitment<u64, u64>>::default(),
         }
     }
+}
+
+impl AddOpeningTemplateFixture {
     pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
         Self { origin, ..self }
     }
 
-    pub fn with_balance(self, balance: u64) -> Self {
-        Self { balance, ..self }
+    pub fn with_name(self, name: Vec<u8>) -> Self {
+        Self { name, ..self }
     }
 
-    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
-        let stake_id = 0;
-        let old_balance = Balances::free_balance(&self.account_id);
-        let old_stake = <stake::Module<Test>>::stakes(stake_id);
-        let actual_result = TestWorkingGroup::decrease_stake(
-            self.origin.clone().into(),
-            self.worker_id,
-            self.balance,
-        );
-
-        assert_eq!(actual_result, expected_result);
-
-        if actual_result.is_ok() {
+    pub fn with_opening_type(self, opening_type: OpeningType) -> Self {
+        Self {
+            opening_type,
+            ..self
+        }
+    }
+
+    pub fn with_policy_commitment(self, commitment: OpeningPolicyCommitment<u64, u64>) -> Self {
+        Self { commitment, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) -> u64 {
+        let template_id = TestWorkingGroup::next_opening_template_id();
+
+        let actual_result = TestWorkingGroup::add_opening_template(
+            self.origin.clone().into(),
+            self.name.clone(),
+            self.opening_type,
+            self.commitment.clone(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::next_opening_template_id(),
+                template_id + 1
+            );
+
+            let expected_template = OpeningTemplate {
+                name: self.name.clone(),
+                version: 1,
+                opening_type: self.opening_type,
+                policy_commitment: self.commitment.clone(),
+            };
+
+            assert_eq!(
+                TestWorkingGroup::opening_template_by_id(template_id),
+                expected_template
+            );
+        } else {
+            assert!(
+                !<crate::OpeningTemplateById<Test, TestWorkingGroupInstance>>::exists(template_id)
+            );
+        }
+
+        template_id
+    }
+}
+
+pub struct UpdateOpeningTemplateFixture {
+    origin: RawOrigin<u64>,
+    template_id: u64,
+    commitment: OpeningPolicyCommitment<u64, u64>,
+}
+
+impl UpdateOpeningTemplateFixture {
+    pub fn default_for_template_id(template_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            template_id,
+            commitment: OpeningPolicyCommitment {
+                max_review_period_length: 10,
+                ..OpeningPolicyCommitment::default()
+            },
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_policy_commitment(self, commitment: OpeningPolicyCommitment<u64, u64>) -> Self {
+        Self { commitment, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_template = TestWorkingGroup::opening_template_by_id(self.template_id);
+
+        let actual_result = TestWorkingGroup::update_opening_template(
+            self.origin.clone().into(),
+            self.template_id,
+            self.commitment.clone(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        let new_template = TestWorkingGroup::opening_template_by_id(self.template_id);
+        if actual_result.is_ok() {
+            assert_eq!(new_template.version, old_template.version + 1);
+            assert_eq!(new_template.policy_commitment, self.commitment);
+            assert_eq!(new_template.name, old_template.name);
+        } else {
+            assert_eq!(new_template, old_template);
+        }
+    }
+}
+
+pub struct AddOpeningFromTemplateFixture {
+    origin: RawOrigin<u64>,
+    template_id: u64,
+    template_version: u32,
+    overrides: OpeningPolicyCommitmentOverrides<u64, u64>,
+    human_readable_text: Vec<u8>,
+}
+
+impl AddOpeningFromTemplateFixture {
+    pub fn default_for_template_id(template_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            template_id,
+            template_version: 1,
+            overrides: OpeningPolicyCommitmentOverrides::default(),
+            human_readable_text: b"human_text".to_vec(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_template_version(self, template_version: u32) -> Self {
+        Self {
+            template_version,
+            ..self
+        }
+    }
+
+    pub fn with_overrides(self, overrides: OpeningPolicyCommitmentOverrides<u64, u64>) -> Self {
+        Self { overrides, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) -> u64 {
+        let opening_id = TestWorkingGroup::next_opening_id();
+        let template = TestWorkingGroup::opening_template_by_id(self.template_id);
+
+        let actual_result = TestWorkingGroup::add_opening_from_template(
+            self.origin.clone().into(),
+            hiring::ActivateOpeningAt::CurrentBlock,
+            self.template_id,
+            self.template_version,
+            self.overrides.clone(),
+            self.human_readable_text.clone(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(TestWorkingGroup::next_opening_id(), opening_id + 1);
+
+            let opening = TestWorkingGroup::opening_by_id(opening_id);
+            assert_eq!(
+                opening.policy_commitment,
+                self.overrides.clone().apply(template.policy_commitment)
+            );
+            assert_eq!(opening.opening_type, template.opening_type);
+        } else {
+            assert_eq!(TestWorkingGroup::next_opening_id(), opening_id);
+        }
+
+        opening_id
+    }
+}
+
+pub struct EventFixture;
+impl EventFixture {
+    pub fn assert_last_crate_event(
+        expected_raw_event: RawEvent<
+            u64,
+            u64,
+            u64,
+            u64,
+            u64,
+            std::collections::BTreeMap<u64, u64>,
+            Vec<u8>,
+            u64,
+            u64,
+            TestWorkingGroupInstance,
+        >,
+    ) {
+        let converted_event = TestEvent::working_group_TestWorkingGroupInstance(expected_raw_event);
+
+        Self::assert_last_global_event(converted_event)
+    }
+
+    pub fn assert_last_global_event(expected_event: TestEvent) {
+        let expected_event = EventRecord {
+            phase: Phase::ApplyExtrinsic(0),
+            event: expected_event,
+            topics: vec![],
+        };
+
+        assert_eq!(System::events().pop().unwrap(), expected_event);
+    }
+}
+
+pub struct DecreaseWorkerStakeFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    balance: u64,
+    account_id: u64,
+}
+
+impl DecreaseWorkerStakeFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let account_id = 1;
+
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+            balance: 10,
+            account_id,
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_balance(self, balance: u64) -> Self {
+        Self { balance, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let stake_id = 0;
+        let old_balance = Balances::free_balance(&self.account_id);
+        let old_stake = <stake::Module<Test>>::stakes(stake_id);
+        let actual_result = TestWorkingGroup::decrease_stake(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.balance,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
             let new_stake = <stake::Module<Test>>::stakes(stake_id);
 
             // stake decreased
@@ -856,6 +1689,337 @@ impl DecreaseWorkerStakeFixture {
     }
 }
 
+pub struct RequestStakeDecreaseFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    balance: u64,
+}
+
+impl RequestStakeDecreaseFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            worker_id,
+            balance: 10,
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_balance(self, balance: u64) -> Self {
+        Self { balance, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_request = TestWorkingGroup::stake_decrease_request(self.worker_id);
+
+        let actual_result = TestWorkingGroup::request_stake_decrease(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.balance,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_request = TestWorkingGroup::stake_decrease_request(self.worker_id);
+        if actual_result.is_ok() {
+            let expected_request = StakeDecreaseRequest {
+                amount: self.balance,
+                requested_at: System::block_number(),
+                release_at: None,
+            };
+
+            assert_eq!(new_request, expected_request);
+        } else {
+            assert_eq!(new_request, old_request);
+        }
+    }
+}
+
+pub struct ApproveStakeDecreaseFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+}
+
+impl ApproveStakeDecreaseFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_request = TestWorkingGroup::stake_decrease_request(self.worker_id);
+
+        let actual_result =
+            TestWorkingGroup::approve_stake_decrease(self.origin.clone().into(), self.worker_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_request = TestWorkingGroup::stake_decrease_request(self.worker_id);
+        if actual_result.is_ok() {
+            let exit_unstaking_period = get_worker_by_id(self.worker_id)
+                .role_stake_profile
+                .unwrap()
+                .exit_unstaking_period
+                .unwrap_or(0);
+
+            assert_eq!(
+                new_request.release_at,
+                Some(System::block_number() + exit_unstaking_period)
+            );
+        } else {
+            assert_eq!(new_request, old_request);
+        }
+    }
+}
+
+pub struct RejectStakeDecreaseFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+}
+
+impl RejectStakeDecreaseFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let request_existed =
+            <crate::StakeDecreaseRequestByWorkerId<Test, TestWorkingGroupInstance>>::exists(
+                self.worker_id,
+            );
+
+        let actual_result =
+            TestWorkingGroup::reject_stake_decrease(self.origin.clone().into(), self.worker_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        let request_exists =
+            <crate::StakeDecreaseRequestByWorkerId<Test, TestWorkingGroupInstance>>::exists(
+                self.worker_id,
+            );
+        if actual_result.is_ok() {
+            assert!(!request_exists);
+        } else {
+            assert_eq!(request_exists, request_existed);
+        }
+    }
+}
+
+pub struct InitiateSlashingFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    balance: u64,
+    rationale: Vec<u8>,
+}
+
+impl InitiateSlashingFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+            balance: 10,
+            rationale: b"rationale".to_vec(),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_balance(self, balance: u64) -> Self {
+        Self { balance, ..self }
+    }
+
+    pub fn with_rationale(self, rationale: Vec<u8>) -> Self {
+        Self { rationale, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let stake_id = 0;
+        let old_stake = <stake::Module<Test>>::stakes(stake_id);
+        let old_pending_slashes_count = get_pending_slashes_count(self.worker_id);
+        let old_slash_count = get_worker_slash_count(self.worker_id);
+
+        let actual_result = TestWorkingGroup::initiate_slashing(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.balance,
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_pending_slashes_count = get_pending_slashes_count(self.worker_id);
+        if actual_result.is_ok() {
+            assert_eq!(new_pending_slashes_count, old_pending_slashes_count + 1);
+            assert_eq!(get_worker_slash_count(self.worker_id), old_slash_count + 1);
+
+            // stake is not slashed immediately
+            assert_eq!(
+                get_stake_balance(<stake::Module<Test>>::stakes(stake_id)),
+                get_stake_balance(old_stake)
+            );
+        } else {
+            assert_eq!(new_pending_slashes_count, old_pending_slashes_count);
+        }
+    }
+}
+
+pub struct AppealSlashingFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    slash_id: u64,
+    appeal_title: Vec<u8>,
+}
+
+impl AppealSlashingFixture {
+    pub fn default_for_ids(worker_id: u64, slash_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            worker_id,
+            slash_id,
+            appeal_title: b"appeal".to_vec(),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_appeal_title(self, appeal_title: Vec<u8>) -> Self {
+        Self {
+            appeal_title,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_pending_slash = TestWorkingGroup::pending_slash((self.worker_id, self.slash_id));
+
+        let actual_result = TestWorkingGroup::appeal_slashing(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.slash_id,
+            self.appeal_title.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_pending_slash = TestWorkingGroup::pending_slash((self.worker_id, self.slash_id));
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_pending_slash.appeal_thread_id,
+                Some(SLASHING_APPEAL_THREAD_ID)
+            );
+        } else {
+            assert_eq!(new_pending_slash, old_pending_slash);
+        }
+    }
+}
+
+pub struct CancelSlashingFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    slash_id: u64,
+}
+
+impl CancelSlashingFixture {
+    pub fn default_for_ids(worker_id: u64, slash_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            worker_id,
+            slash_id,
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_pending_slashes_count = get_pending_slashes_count(self.worker_id);
+        let old_slash_count = get_worker_slash_count(self.worker_id);
+
+        let actual_result = TestWorkingGroup::cancel_slashing(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.slash_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_pending_slashes_count = get_pending_slashes_count(self.worker_id);
+        if actual_result.is_ok() {
+            assert_eq!(new_pending_slashes_count, old_pending_slashes_count - 1);
+            assert_eq!(get_worker_slash_count(self.worker_id), old_slash_count - 1);
+        } else {
+            assert_eq!(new_pending_slashes_count, old_pending_slashes_count);
+        }
+    }
+}
+
+pub struct ResumeSlashingFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    slash_id: u64,
+}
+
+impl ResumeSlashingFixture {
+    pub fn default_for_ids(worker_id: u64, slash_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            worker_id,
+            slash_id,
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
+        let old_pending_slashes_count = get_pending_slashes_count(self.worker_id);
+
+        let actual_result = TestWorkingGroup::resume_slashing(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.slash_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        assert_eq!(
+            get_pending_slashes_count(self.worker_id),
+            old_pending_slashes_count
+        );
+    }
+}
+
+pub(crate) fn get_pending_slashes_count(worker_id: u64) -> usize {
+    <crate::PendingSlashes<Test, TestWorkingGroupInstance>>::enumerate()
+        .filter(|((slashed_worker_id, _), _)| *slashed_worker_id == worker_id)
+        .count()
+}
+
+pub(crate) fn get_worker_slash_count(worker_id: u64) -> u16 {
+    TestWorkingGroup::worker_slash_count(worker_id)
+}
+
 pub(crate) fn get_stake_balance(stake: stake::Stake<u64, u64, u64>) -> u64 {
     if let stake::StakingStatus::Staked(stake) = stake.staking_status {
         return stake.staked_amount;
@@ -880,19 +2044,19 @@ pub struct SlashWorkerStakeFixture {
     worker_id: u64,
     balance: u64,
     account_id: u64,
+    expected_slashed_balance: Option<u64>,
 }
 
 impl SlashWorkerStakeFixture {
     pub fn default_for_worker_id(worker_id: u64) -> Self {
         let account_id = 1;
 
-        let lead_account_id = get_current_lead_account_id();
-
         Self {
-            origin: RawOrigin::Signed(lead_account_id),
+            origin: RawOrigin::Root,
             worker_id,
             balance: 10,
             account_id,
+            expected_slashed_balance: None,
         }
     }
     pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
@@ -903,10 +2067,18 @@ impl SlashWorkerStakeFixture {
         Self { balance, ..self }
     }
 
+    pub fn with_expected_slashed_balance(self, expected_slashed_balance: u64) -> Self {
+        Self {
+            expected_slashed_balance: Some(expected_slashed_balance),
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: Result<(), Error>) {
         let stake_id = 0;
         let old_balance = Balances::free_balance(&self.account_id);
         let old_stake = <stake::Module<Test>>::stakes(stake_id);
+        let old_slash_count = get_worker_slash_count(self.worker_id);
         let actual_result =
             TestWorkingGroup::slash_stake(self.origin.clone().into(), self.worker_id, self.balance);
 
@@ -914,13 +2086,16 @@ impl SlashWorkerStakeFixture {
 
         if actual_result.is_ok() {
             let new_stake = <stake::Module<Test>>::stakes(stake_id);
+            let slashed_balance = self.expected_slashed_balance.unwrap_or(self.balance);
 
             // stake decreased
             assert_eq!(
                 get_stake_balance(new_stake),
-                get_stake_balance(old_stake) - self.balance
+                get_stake_balance(old_stake) - slashed_balance
             );
 
+            assert_eq!(get_worker_slash_count(self.worker_id), old_slash_count + 1);
+
             let new_balance = Balances::free_balance(&self.account_id);
 
             // worker balance unchanged
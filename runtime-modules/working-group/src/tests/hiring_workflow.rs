@@ -5,7 +5,7 @@ use crate::tests::fixtures::{
 };
 use crate::tests::mock::TestWorkingGroup;
 use crate::Error;
-use crate::{OpeningPolicyCommitment, OpeningType, RewardPolicy};
+use crate::{OpeningPolicyCommitment, OpeningType, RewardPolicy, SlashableTerms, SlashingTerms};
 use system::RawOrigin;
 
 #[derive(Clone)]
@@ -23,6 +23,7 @@ pub struct HiringWorkflow {
     applications: Vec<HiringWorkflowApplication>,
     setup_environment: bool,
     reward_policy: Option<RewardPolicy<u64, u64>>,
+    slashing_terms: SlashingTerms,
 }
 
 impl Default for HiringWorkflow {
@@ -34,6 +35,10 @@ impl Default for HiringWorkflow {
             applications: Vec::new(),
             setup_environment: true,
             reward_policy: None,
+            slashing_terms: SlashingTerms::Slashable(SlashableTerms {
+                max_count: 10,
+                max_percent_pts_per_time: 100,
+            }),
         }
     }
 }
@@ -78,6 +83,13 @@ impl HiringWorkflow {
         }
     }
 
+    pub fn with_slashing_terms(self, slashing_terms: SlashingTerms) -> Self {
+        Self {
+            slashing_terms,
+            ..self
+        }
+    }
+
     pub fn add_default_application(self) -> Self {
         let worker_handle = b"default worker handle".to_vec();
 
@@ -157,6 +169,7 @@ impl HiringWorkflow {
                         crowded_out_unstaking_period_length: None,
                         review_period_expired_unstaking_period_length: None,
                     }),
+                    role_slashing_terms: self.slashing_terms.clone(),
                     ..OpeningPolicyCommitment::default()
                 });
         }
@@ -74,15 +74,21 @@ impl hiring::Trait for Test {
     type StakeHandlerProvider = hiring::Module<Self>;
 }
 
+parameter_types! {
+    pub const MaxMintLedgerEntries: u32 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type MaxMintLedgerEntries = MaxMintLedgerEntries;
 }
 
 impl stake::Trait for Test {
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = StakingEventsHandler<Test>;
+    type StakingBackend = stake::PoolAccountBackend;
     type StakeId = u64;
     type SlashId = u64;
 }
@@ -119,7 +125,7 @@ impl balances::Trait for Test {
 }
 
 impl recurringrewards::Trait for Test {
-    type PayoutStatusHandler = ();
+    type PayoutStatusHandler = TestPayoutStatusHandler;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
 }
@@ -129,11 +135,51 @@ pub type System = system::Module<Test>;
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 3;
+    pub const MaxWorkerHistoryLength: u32 = 3;
+    pub const MaxWorkerSuspensionPeriod: u64 = 10;
+    pub const SlashingDisputePeriod: u64 = 5;
+    pub const ExtraRewardsBudgetPeriod: u64 = 100;
 }
 
 impl Trait<TestWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxWorkerHistoryLength = MaxWorkerHistoryLength;
+    type MaxWorkerSuspensionPeriod = MaxWorkerSuspensionPeriod;
+    type SlashingDisputePeriod = SlashingDisputePeriod;
+    type ExtraRewardsBudgetPeriod = ExtraRewardsBudgetPeriod;
+    type SlashingAppealThreadId = u64;
+    type SlashingAppealDiscussion = TestSlashingAppealDiscussion;
+}
+
+pub(crate) const SLASHING_APPEAL_THREAD_ID: u64 = 7;
+
+pub struct TestSlashingAppealDiscussion;
+impl crate::SlashingAppealDiscussion<u64, u64> for TestSlashingAppealDiscussion {
+    fn create_appeal_thread(_: u64, title: Vec<u8>) -> Result<u64, &'static str> {
+        if title.is_empty() {
+            return Err("Empty appeal thread title.");
+        }
+
+        Ok(SLASHING_APPEAL_THREAD_ID)
+    }
+}
+
+pub struct TestPayoutStatusHandler;
+impl recurringrewards::PayoutStatusHandler<Test> for TestPayoutStatusHandler {
+    fn payout_succeeded(id: u64, _: &u64, amount: u64) {
+        TestWorkingGroup::on_reward_payout_succeeded(id, amount);
+    }
+
+    fn payout_failed(id: u64, _: &u64, amount: u64) {
+        TestWorkingGroup::on_reward_payout_failed(id, amount);
+    }
+
+    fn arrears_settled(id: u64, _: &u64, settled_amount: u64, _: u64) {
+        TestWorkingGroup::on_reward_payout_succeeded(id, settled_amount);
+    }
+
+    fn relationship_completed(_: u64, _: &u64, _: u64) {}
 }
 
 pub type Membership = membership::members::Module<Test>;
@@ -204,14 +250,18 @@ impl<T: stake::Trait + crate::Trait<TestWorkingGroupInstance>> stake::StakingEve
         remaining_imbalance
     }
 
-    /// Empty handler for slashing
+    /// Removes the executed delayed slashing from the working group pending slashes.
     fn slashed(
-        _: &<T as stake::Trait>::StakeId,
-        _: Option<<T as stake::Trait>::SlashId>,
+        stake_id: &<T as stake::Trait>::StakeId,
+        slash_id: Option<<T as stake::Trait>::SlashId>,
         _: BalanceOf<T>,
         _: BalanceOf<T>,
         remaining_imbalance: NegativeImbalance<T>,
     ) -> NegativeImbalance<T> {
+        if let Some(slash_id) = slash_id {
+            <crate::Module<T, TestWorkingGroupInstance>>::on_stake_slashed(*stake_id, slash_id);
+        }
+
         remaining_imbalance
     }
 }
@@ -2,14 +2,20 @@ mod fixtures;
 mod hiring_workflow;
 mod mock;
 
-use crate::types::{OpeningPolicyCommitment, OpeningType, RewardPolicy};
+use crate::types::{
+    OpeningPolicyCommitment, OpeningPolicyCommitmentOverrides, OpeningType, RewardPolicy,
+    SlashableTerms, SlashingTerms,
+};
 use crate::{Error, RawEvent, Worker};
 use common::constraints::InputValidationLengthConstraint;
 use mock::{
-    build_test_externalities, Test, TestWorkingGroup, TestWorkingGroupInstance,
-    WORKING_GROUP_CONSTRAINT_DIFF, WORKING_GROUP_CONSTRAINT_MIN, WORKING_GROUP_MINT_CAPACITY,
+    build_test_externalities, Balances, Membership, System, Test, TestWorkingGroup,
+    TestWorkingGroupInstance, WORKING_GROUP_CONSTRAINT_DIFF, WORKING_GROUP_CONSTRAINT_MIN,
+    WORKING_GROUP_MINT_CAPACITY,
 };
-use srml_support::{StorageLinkedMap, StorageValue};
+use sr_primitives::traits::OnFinalize;
+use srml_support::traits::Get;
+use srml_support::{StorageLinkedMap, StorageMap, StorageValue};
 use std::collections::BTreeMap;
 use system::RawOrigin;
 
@@ -169,6 +175,7 @@ fn add_opening_fails_with_incorrect_unstaking_periods() {
             AddWorkerOpeningFixture::default().with_policy_commitment(OpeningPolicyCommitment {
                 application_rationing_policy: Some(hiring::ApplicationRationingPolicy {
                     max_active_applicants: 0,
+                    applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
                 }),
                 ..OpeningPolicyCommitment::default()
             });
@@ -178,6 +185,24 @@ fn add_opening_fails_with_incorrect_unstaking_periods() {
     });
 }
 
+#[test]
+fn add_opening_fails_with_invalid_role_slashing_terms() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture =
+            AddWorkerOpeningFixture::default().with_policy_commitment(OpeningPolicyCommitment {
+                role_slashing_terms: SlashingTerms::Slashable(SlashableTerms {
+                    max_count: 1,
+                    max_percent_pts_per_time: 101,
+                }),
+                ..OpeningPolicyCommitment::default()
+            });
+        add_opening_fixture
+            .call_and_assert(Err(Error::RoleSlashingTermsMaxPercentPtsPerTimeTooHigh));
+    });
+}
+
 #[test]
 fn add_opening_succeeds() {
     build_test_externalities().execute_with(|| {
@@ -501,6 +526,134 @@ fn apply_on_opening_fails_with_invalid_text() {
     });
 }
 
+#[test]
+fn set_stake_sponsorship_succeeds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let sponsor_account_id = 10;
+        let member_id = 1;
+        let allowance = 100;
+
+        SetStakeSponsorshipFixture::default_for_allowance(allowance).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeSponsorshipSet(
+            sponsor_account_id,
+            member_id,
+            allowance,
+        ));
+
+        SetStakeSponsorshipFixture::default_for_allowance(0).call_and_assert(Ok(()));
+
+        assert!(!<crate::StakeSponsorshipAllowance<
+            Test,
+            TestWorkingGroupInstance,
+        >>::exists(sponsor_account_id, member_id));
+    });
+}
+
+#[test]
+fn set_stake_sponsorship_fails_with_invalid_member_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_member_id = 55;
+
+        SetStakeSponsorshipFixture::default_for_allowance(100)
+            .with_member_id(invalid_member_id)
+            .call_and_assert(Err(Error::MembershipInvalidMemberId));
+    });
+}
+
+#[test]
+fn apply_on_opening_with_sponsor_succeeds_and_refunds_the_sponsor() {
+    build_test_externalities().execute_with(|| {
+        let total_balance = 500000;
+        let stake = 100;
+        let allowance = 150;
+        let sponsor_account_id = 10;
+        increase_total_balance_issuance_using_account_id(1, total_balance);
+        increase_total_balance_issuance_using_account_id(sponsor_account_id, total_balance);
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture =
+            AddWorkerOpeningFixture::default().with_policy_commitment(OpeningPolicyCommitment {
+                application_staking_policy: Some(hiring::StakingPolicy {
+                    amount: stake,
+                    ..hiring::StakingPolicy::default()
+                }),
+                ..OpeningPolicyCommitment::default()
+            });
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        SetStakeSponsorshipFixture::default_for_allowance(allowance).call_and_assert(Ok(()));
+
+        let application_id = ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_application_stake(stake)
+            .with_sponsor(sponsor_account_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(get_balance(1), total_balance);
+        assert_eq!(get_balance(sponsor_account_id), total_balance - stake);
+        assert_eq!(
+            TestWorkingGroup::stake_sponsorship_allowance(sponsor_account_id, 1),
+            allowance - stake
+        );
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        DeclineApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(get_balance(1), total_balance);
+        assert_eq!(get_balance(sponsor_account_id), total_balance);
+    });
+}
+
+#[test]
+fn apply_on_opening_with_sponsor_fails_with_insufficient_allowance() {
+    build_test_externalities().execute_with(|| {
+        let stake = 100;
+        let sponsor_account_id = 10;
+        increase_total_balance_issuance_using_account_id(sponsor_account_id, 500000);
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture =
+            AddWorkerOpeningFixture::default().with_policy_commitment(OpeningPolicyCommitment {
+                application_staking_policy: Some(hiring::StakingPolicy {
+                    amount: stake,
+                    ..hiring::StakingPolicy::default()
+                }),
+                ..OpeningPolicyCommitment::default()
+            });
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        SetStakeSponsorshipFixture::default_for_allowance(stake - 1).call_and_assert(Ok(()));
+
+        ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_application_stake(stake)
+            .with_sponsor(sponsor_account_id)
+            .call_and_assert(Err(Error::InsufficientStakeSponsorshipAllowance));
+    });
+}
+
+#[test]
+fn apply_on_opening_fails_with_suspended_member() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddWorkerOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        Membership::suspend_member(RawOrigin::Root.into(), 1, b"spam".to_vec(), None).unwrap();
+
+        let apply_on_opening_fixture =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id);
+        apply_on_opening_fixture.call_and_assert(Err(Error::SuspendedMemberCannotApply));
+    });
+}
+
 #[test]
 fn apply_on_opening_fails_with_already_active_application() {
     build_test_externalities().execute_with(|| {
@@ -777,6 +930,202 @@ fn begin_review_worker_applications_with_hiring_error() {
     });
 }
 
+fn add_application_in_review_period() -> u64 {
+    let add_opening_fixture = AddWorkerOpeningFixture::default();
+    let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+    let apply_on_opening_fixture = ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id);
+    let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+    BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+        .call_and_assert(Ok(()));
+
+    application_id
+}
+
+#[test]
+fn shortlist_application_succeeds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let application_id = add_application_in_review_period();
+
+        ShortlistApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationShortlisted(application_id));
+    });
+}
+
+#[test]
+fn shortlist_application_fails_with_invalid_application_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_application_id = 6;
+
+        ShortlistApplicationFixture::default_for_application_id(invalid_application_id)
+            .call_and_assert(Err(Error::WorkerApplicationDoesNotExist));
+    });
+}
+
+#[test]
+fn shortlist_application_fails_with_not_a_lead() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let application_id = add_application_in_review_period();
+
+        ShortlistApplicationFixture::default_for_application_id(application_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn shortlist_application_fails_with_invalid_origin_for_opening_type() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddWorkerOpeningFixture::default()
+            .with_origin(RawOrigin::Root)
+            .with_opening_type(OpeningType::Leader);
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        ShortlistApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Err(Error::RequireRootOrigin));
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        ShortlistApplicationFixture::default_for_application_id(application_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn shortlist_application_fails_with_opening_not_in_review_period() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddWorkerOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        ShortlistApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Err(Error::ReviewApplicationOpeningNotInReviewPeriodStage));
+    });
+}
+
+#[test]
+fn decline_application_succeeds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let application_id = add_application_in_review_period();
+
+        ShortlistApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Ok(()));
+
+        let decline_application_fixture =
+            DeclineApplicationFixture::default_for_application_id(application_id);
+        decline_application_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationDeclined(application_id));
+
+        decline_application_fixture
+            .call_and_assert(Err(Error::ReviewApplicationApplicationNotActive));
+    });
+}
+
+#[test]
+fn decline_application_succeeds_and_releases_application_stake() {
+    build_test_externalities().execute_with(|| {
+        let total_balance = 500000;
+        let stake = 100;
+        increase_total_balance_issuance_using_account_id(1, total_balance);
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture =
+            AddWorkerOpeningFixture::default().with_policy_commitment(OpeningPolicyCommitment {
+                application_staking_policy: Some(hiring::StakingPolicy {
+                    amount: stake,
+                    ..hiring::StakingPolicy::default()
+                }),
+                ..OpeningPolicyCommitment::default()
+            });
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+                .with_application_stake(stake);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        assert_eq!(get_balance(1), total_balance - stake);
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        DeclineApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationDeclined(application_id));
+
+        assert_eq!(get_balance(1), total_balance);
+    });
+}
+
+#[test]
+fn decline_application_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let application_id = add_application_in_review_period();
+
+        DeclineApplicationFixture::default_for_application_id(application_id)
+            .with_origin(RawOrigin::None)
+            .call_and_assert(Err(Error::RequireSignedOrigin));
+    });
+}
+
+#[test]
+fn set_application_review_note_succeeds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let application_id = add_application_in_review_period();
+
+        SetApplicationReviewNoteFixture::default_for_application_id(application_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationReviewNoteSet(application_id));
+    });
+}
+
+#[test]
+fn set_application_review_note_fails_with_declined_application() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let application_id = add_application_in_review_period();
+
+        DeclineApplicationFixture::default_for_application_id(application_id)
+            .call_and_assert(Ok(()));
+
+        SetApplicationReviewNoteFixture::default_for_application_id(application_id)
+            .call_and_assert(Err(Error::ReviewApplicationApplicationNotActive));
+    });
+}
+
 #[test]
 fn begin_review_worker_applications_fails_with_invalid_origin() {
     build_test_externalities().execute_with(|| {
@@ -1014,6 +1363,39 @@ fn update_worker_role_account_succeeds() {
     });
 }
 
+#[test]
+fn worker_role_account_index_is_maintained() {
+    build_test_externalities().execute_with(|| {
+        let new_account_id = 10;
+        let worker_id = fill_default_worker_position();
+        let old_account_id = TestWorkingGroup::worker_by_id(worker_id).role_account_id;
+
+        assert!(
+            TestWorkingGroup::worker_ids_by_role_account_id(old_account_id).contains(&worker_id)
+        );
+
+        UpdateWorkerRoleAccountFixture::default_with_ids(worker_id, new_account_id)
+            .call_and_assert(Ok(()));
+
+        assert!(
+            !TestWorkingGroup::worker_ids_by_role_account_id(old_account_id).contains(&worker_id)
+        );
+        assert_eq!(
+            TestWorkingGroup::worker_ids_by_role_account_id(new_account_id),
+            vec![worker_id]
+        );
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(new_account_id))
+            .call_and_assert(Ok(()));
+
+        assert!(!<crate::WorkerIdsByRoleAccountId<
+            Test,
+            TestWorkingGroupInstance,
+        >>::exists(new_account_id));
+    });
+}
+
 #[test]
 fn update_worker_role_account_by_leader_succeeds() {
     build_test_externalities().execute_with(|| {
@@ -1242,13 +1624,136 @@ fn update_worker_reward_amount_fails_with_no_recurring_reward() {
     });
 }
 
-fn fill_default_worker_position() -> u64 {
-    fill_worker_position(
-        Some(RewardPolicy {
-            amount_per_payout: 1000,
-            next_payment_at_block: 20,
-            payout_interval: None,
-        }),
+#[test]
+fn set_reward_arrears_policy_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SetRewardArrearsPolicyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerRewardArrearsPolicySet(worker_id));
+
+        SetRewardArrearsPolicyFixture::default_for_worker_id(worker_id)
+            .with_policy(None)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn set_reward_arrears_policy_succeeds_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default()
+            .with_reward_policy(RewardPolicy {
+                amount_per_payout: 1000,
+                next_payment_at_block: 20,
+                payout_interval: None,
+            })
+            .hire_lead();
+
+        SetRewardArrearsPolicyFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn set_reward_arrears_policy_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SetRewardArrearsPolicyFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn set_reward_arrears_policy_fails_with_no_recurring_reward() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_no_reward();
+
+        SetRewardArrearsPolicyFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::WorkerHasNoReward));
+    });
+}
+
+#[test]
+fn claim_reward_arrears_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SetRewardArrearsPolicyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        // Missed payout is kept in arrears.
+        assert_eq!(
+            TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), 0),
+            Ok(())
+        );
+        <recurringrewards::Module<Test> as OnFinalize<u64>>::on_finalize(20);
+
+        assert_eq!(
+            TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), 40000),
+            Ok(())
+        );
+
+        ClaimRewardArrearsFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerRewardArrearsClaimed(
+            worker_id, 1000,
+        ));
+    });
+}
+
+#[test]
+fn claim_reward_arrears_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        ClaimRewardArrearsFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::SignerIsNotWorkerRoleAccount));
+    });
+}
+
+#[test]
+fn claim_reward_arrears_fails_with_no_recurring_reward() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_no_reward();
+
+        ClaimRewardArrearsFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::WorkerHasNoReward));
+    });
+}
+
+#[test]
+fn claim_reward_arrears_fails_with_arrears_policy_not_set() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        ClaimRewardArrearsFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::RecurringRewardsArrearsPolicyNotSet));
+    });
+}
+
+#[test]
+fn claim_reward_arrears_fails_with_no_arrears_to_settle() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SetRewardArrearsPolicyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        ClaimRewardArrearsFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::RecurringRewardsNoArrearsToSettle));
+    });
+}
+
+fn fill_default_worker_position() -> u64 {
+    fill_worker_position(
+        Some(RewardPolicy {
+            amount_per_payout: 1000,
+            next_payment_at_block: 20,
+            payout_interval: None,
+        }),
         None,
         true,
         OpeningType::Worker,
@@ -1274,6 +1779,15 @@ fn fill_worker_position_with_stake(stake: u64) -> u64 {
     )
 }
 
+fn fill_worker_position_with_slashing_terms(slashing_terms: SlashingTerms) -> u64 {
+    HiringWorkflow::default()
+        .with_role_stake(Some(100))
+        .with_slashing_terms(slashing_terms)
+        .add_default_application()
+        .execute()
+        .unwrap()
+}
+
 fn fill_worker_position(
     reward_policy: Option<RewardPolicy<u64, u64>>,
     role_stake: Option<u64>,
@@ -1453,7 +1967,7 @@ fn terminate_worker_role_succeeds_with_stakes() {
 }
 
 #[test]
-fn terminate_worker_role_succeeds_with_slashing() {
+fn terminate_leader_role_succeeds_with_slashing() {
     build_test_externalities().execute_with(|| {
         let total_balance = 10000;
         let stake_balance = 100;
@@ -1464,13 +1978,11 @@ fn terminate_worker_role_succeeds_with_slashing() {
 
         assert_eq!(get_balance(worker_account_id), total_balance);
 
-        HireLeadFixture::default().hire_lead();
-
         let worker_id = HiringWorkflow::default()
-            .disable_setup_environment()
             .with_role_stake(Some(stake_balance))
+            .with_opening_type(OpeningType::Leader)
             .add_application_with_origin(
-                b"worker_handle".to_vec(),
+                b"leader_handle".to_vec(),
                 RawOrigin::Signed(worker_account_id),
                 worker_member_id,
             )
@@ -1492,7 +2004,9 @@ fn terminate_worker_role_succeeds_with_slashing() {
         // Terminate with slashing.
 
         let terminate_worker_role_fixture =
-            TerminateWorkerRoleFixture::default_for_worker_id(worker_id).with_slashing();
+            TerminateWorkerRoleFixture::default_for_worker_id(worker_id)
+                .with_origin(RawOrigin::Root)
+                .with_slashing();
 
         terminate_worker_role_fixture.call_and_assert(Ok(()));
 
@@ -1817,270 +2331,2303 @@ fn decrease_worker_stake_fails_with_not_set_lead() {
 }
 
 #[test]
-fn slash_worker_stake_succeeds() {
+fn request_stake_decrease_succeeds() {
     build_test_externalities().execute_with(|| {
         let worker_id = fill_worker_position_with_stake(100);
 
-        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id);
+        let request_stake_decrease_fixture =
+            RequestStakeDecreaseFixture::default_for_worker_id(worker_id);
 
-        slash_stake_fixture.call_and_assert(Ok(()));
+        request_stake_decrease_fixture.call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::StakeSlashed(worker_id));
+        EventFixture::assert_last_crate_event(RawEvent::StakeDecreaseRequested(worker_id));
     });
 }
 
 #[test]
-fn slash_leader_stake_succeeds() {
+fn request_stake_decrease_fails_with_invalid_origin() {
     build_test_externalities().execute_with(|| {
-        let leader_worker_id = HiringWorkflow::default()
-            .with_role_stake(Some(100))
-            .with_opening_type(OpeningType::Leader)
-            .add_default_application()
-            .execute()
-            .unwrap();
+        let worker_id = fill_worker_position_with_stake(100);
 
-        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(leader_worker_id)
-            .with_origin(RawOrigin::Root);
+        let request_stake_decrease_fixture =
+            RequestStakeDecreaseFixture::default_for_worker_id(worker_id)
+                .with_origin(RawOrigin::Signed(2));
 
-        slash_stake_fixture.call_and_assert(Ok(()));
+        request_stake_decrease_fixture.call_and_assert(Err(Error::SignerIsNotWorkerRoleAccount));
+    });
+}
 
-        EventFixture::assert_last_crate_event(RawEvent::StakeSlashed(leader_worker_id));
+#[test]
+fn request_stake_decrease_fails_with_zero_balance() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let request_stake_decrease_fixture =
+            RequestStakeDecreaseFixture::default_for_worker_id(worker_id).with_balance(0);
+
+        request_stake_decrease_fixture.call_and_assert(Err(Error::StakeBalanceCannotBeZero));
     });
 }
 
 #[test]
-fn slash_worker_stake_fails_with_invalid_origin() {
+fn request_stake_decrease_fails_with_invalid_balance() {
     build_test_externalities().execute_with(|| {
-        HireLeadFixture::default().hire_lead();
+        let worker_id = fill_worker_position_with_stake(100);
 
-        let invalid_worker_id = 22;
-        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(invalid_worker_id)
-            .with_origin(RawOrigin::None);
+        let request_stake_decrease_fixture =
+            RequestStakeDecreaseFixture::default_for_worker_id(worker_id).with_balance(100000000);
 
-        slash_stake_fixture.call_and_assert(Err(Error::RequireSignedOrigin));
+        request_stake_decrease_fixture.call_and_assert(Err(Error::StakingErrorInsufficientStake));
     });
 }
 
 #[test]
-fn slash_leader_stake_fails_with_invalid_origin() {
+fn request_stake_decrease_fails_with_no_stake_profile() {
     build_test_externalities().execute_with(|| {
-        let worker_id = HireLeadFixture::default().hire_lead();
+        let worker_id = fill_default_worker_position();
 
-        let slash_stake_fixture =
-            SlashWorkerStakeFixture::default_for_worker_id(worker_id).with_origin(RawOrigin::None);
+        let request_stake_decrease_fixture =
+            RequestStakeDecreaseFixture::default_for_worker_id(worker_id);
 
-        slash_stake_fixture.call_and_assert(Err(Error::RequireRootOrigin));
+        request_stake_decrease_fixture.call_and_assert(Err(Error::NoWorkerStakeProfile));
     });
 }
 
 #[test]
-fn slash_worker_stake_fails_with_zero_balance() {
+fn request_stake_decrease_fails_with_existing_request() {
     build_test_externalities().execute_with(|| {
         let worker_id = fill_worker_position_with_stake(100);
 
-        let slash_stake_fixture =
-            SlashWorkerStakeFixture::default_for_worker_id(worker_id).with_balance(0);
+        let request_stake_decrease_fixture =
+            RequestStakeDecreaseFixture::default_for_worker_id(worker_id);
 
-        slash_stake_fixture.call_and_assert(Err(Error::StakeBalanceCannotBeZero));
+        request_stake_decrease_fixture.call_and_assert(Ok(()));
+        request_stake_decrease_fixture
+            .call_and_assert(Err(Error::StakeDecreaseRequestAlreadyExists));
     });
 }
 
 #[test]
-fn slash_worker_stake_fails_with_invalid_worker_id() {
+fn approve_stake_decrease_succeeds_and_releases_stake_on_finalize() {
     build_test_externalities().execute_with(|| {
-        HireLeadFixture::default().hire_lead();
-        let invalid_worker_id = 11;
+        let worker_id = fill_worker_position_with_stake(100);
+        let account_id = 1;
+        let stake_id = 0;
 
-        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(invalid_worker_id);
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id)
+            .with_balance(30)
+            .call_and_assert(Ok(()));
 
-        slash_stake_fixture.call_and_assert(Err(Error::WorkerDoesNotExist));
+        let approve_stake_decrease_fixture =
+            ApproveStakeDecreaseFixture::default_for_worker_id(worker_id);
+
+        approve_stake_decrease_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeDecreaseApproved(worker_id));
+
+        let old_balance = Balances::free_balance(&account_id);
+
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(System::block_number());
+
+        assert_eq!(Balances::free_balance(&account_id), old_balance + 30);
+        assert_eq!(
+            get_stake_balance(<stake::Module<Test>>::stakes(stake_id)),
+            70
+        );
+        assert!(!<crate::StakeDecreaseRequestByWorkerId<
+            Test,
+            TestWorkingGroupInstance,
+        >>::exists(worker_id));
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeDecreased(worker_id));
     });
 }
 
 #[test]
-fn slash_worker_stake_fails_with_no_stake_profile() {
+fn approved_stake_decrease_respects_exit_unstaking_period() {
     build_test_externalities().execute_with(|| {
-        let worker_id = fill_default_worker_position();
+        let worker_id = fill_worker_position_with_stake(100);
+        let account_id = 1;
+        let exit_unstaking_period = 3;
 
-        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id);
+        <crate::WorkerById<Test, TestWorkingGroupInstance>>::mutate(worker_id, |worker| {
+            if let Some(ref mut stake_profile) = worker.role_stake_profile {
+                stake_profile.exit_unstaking_period = Some(exit_unstaking_period);
+            }
+        });
 
-        slash_stake_fixture.call_and_assert(Err(Error::NoWorkerStakeProfile));
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+        ApproveStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let release_at = System::block_number() + exit_unstaking_period;
+        let old_balance = Balances::free_balance(&account_id);
+
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(release_at - 1);
+        assert_eq!(Balances::free_balance(&account_id), old_balance);
+
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(release_at);
+        assert_eq!(Balances::free_balance(&account_id), old_balance + 10);
     });
 }
 
 #[test]
-fn slash_worker_stake_fails_with_not_set_lead() {
+fn approve_stake_decrease_fails_with_insufficient_stake() {
     build_test_externalities().execute_with(|| {
-        let invalid_worker_id = 11;
+        let worker_id = fill_worker_position_with_stake(100);
 
-        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(invalid_worker_id);
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id)
+            .with_balance(80)
+            .call_and_assert(Ok(()));
+
+        DecreaseWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_balance(50)
+            .call_and_assert(Ok(()));
 
-        slash_stake_fixture.call_and_assert(Err(Error::CurrentLeadNotSet));
+        ApproveStakeDecreaseFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::StakingErrorInsufficientStake));
     });
 }
 
 #[test]
-fn get_all_worker_ids_succeeds() {
+fn approved_stake_decrease_release_failure_emits_event() {
     build_test_externalities().execute_with(|| {
-        let worker_ids = TestWorkingGroup::get_regular_worker_ids();
-        assert_eq!(worker_ids, Vec::new());
+        let worker_id = fill_worker_position_with_stake(100);
+        let account_id = 1;
 
-        let leader_worker_id = HireLeadFixture::default().hire_lead();
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id)
+            .with_balance(80)
+            .call_and_assert(Ok(()));
+        ApproveStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
 
-        let worker_id1 = fill_worker_position(None, None, false, OpeningType::Worker, None);
-        let worker_id2 = fill_worker_position(None, None, false, OpeningType::Worker, None);
+        DecreaseWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_balance(50)
+            .call_and_assert(Ok(()));
 
-        let mut expected_ids = vec![worker_id1, worker_id2];
-        expected_ids.sort();
+        let old_balance = Balances::free_balance(&account_id);
 
-        let mut worker_ids = TestWorkingGroup::get_regular_worker_ids();
-        worker_ids.sort();
-        assert_eq!(worker_ids, expected_ids);
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(System::block_number());
 
-        assert!(!expected_ids.contains(&leader_worker_id));
+        assert_eq!(Balances::free_balance(&account_id), old_balance);
+        assert!(!<crate::StakeDecreaseRequestByWorkerId<
+            Test,
+            TestWorkingGroupInstance,
+        >>::exists(worker_id));
 
-        <crate::WorkerById<Test, TestWorkingGroupInstance>>::remove(worker_id1);
-        let worker_ids = TestWorkingGroup::get_regular_worker_ids();
-        assert_eq!(worker_ids, vec![worker_id2]);
+        EventFixture::assert_last_crate_event(RawEvent::StakeDecreaseReleaseFailed(worker_id));
     });
 }
 
 #[test]
-fn set_working_group_mint_capacity_succeeds() {
+fn approve_stake_decrease_succeeds_for_leader() {
     build_test_externalities().execute_with(|| {
-        let mint_id = <minting::Module<Test>>::add_mint(0, None).unwrap();
-        <crate::Mint<Test, TestWorkingGroupInstance>>::put(mint_id);
+        let worker_id = HireLeadFixture::default().with_stake(100).hire_lead();
 
-        let capacity = 15000;
-        let result = TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), capacity);
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
 
-        assert_eq!(result, Ok(()));
+        let approve_stake_decrease_fixture =
+            ApproveStakeDecreaseFixture::default_for_worker_id(worker_id)
+                .with_origin(RawOrigin::Root);
 
-        let mint = <minting::Module<Test>>::mints(mint_id);
-        assert_eq!(mint.capacity(), capacity);
+        approve_stake_decrease_fixture.call_and_assert(Ok(()));
     });
 }
 
 #[test]
-fn set_working_group_mint_capacity_fails_with_mint_not_found() {
+fn approve_stake_decrease_fails_with_invalid_origin() {
     build_test_externalities().execute_with(|| {
-        let capacity = 15000;
+        let worker_id = fill_worker_position_with_stake(100);
 
-        <crate::Mint<Test, TestWorkingGroupInstance>>::put(5); // random mint id
-        let result = TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), capacity);
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
 
-        assert_eq!(result, Err(Error::CannotFindMint));
+        let approve_stake_decrease_fixture =
+            ApproveStakeDecreaseFixture::default_for_worker_id(worker_id)
+                .with_origin(RawOrigin::Signed(2));
+
+        approve_stake_decrease_fixture.call_and_assert(Err(Error::IsNotLeadAccount));
     });
 }
 
 #[test]
-fn set_working_group_mint_capacity_fails_with_invalid_origin() {
+fn approve_stake_decrease_fails_with_no_request() {
     build_test_externalities().execute_with(|| {
-        let capacity = 15000;
-        let result = TestWorkingGroup::set_mint_capacity(RawOrigin::None.into(), capacity);
+        let worker_id = fill_worker_position_with_stake(100);
 
-        assert_eq!(result, Err(Error::RequireRootOrigin));
+        let approve_stake_decrease_fixture =
+            ApproveStakeDecreaseFixture::default_for_worker_id(worker_id);
+
+        approve_stake_decrease_fixture
+            .call_and_assert(Err(Error::StakeDecreaseRequestDoesNotExist));
     });
 }
 
 #[test]
-fn ensure_setting_genesis_working_group_mint_succeeds() {
+fn approve_stake_decrease_fails_with_already_approved_request() {
     build_test_externalities().execute_with(|| {
-        let mint_id = TestWorkingGroup::mint();
+        let worker_id = fill_worker_position_with_stake(100);
 
-        assert!(minting::Mints::<Test>::exists(mint_id));
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
 
-        let mint = <minting::Module<Test>>::mints(mint_id);
-        assert_eq!(mint.capacity(), WORKING_GROUP_MINT_CAPACITY);
+        let approve_stake_decrease_fixture =
+            ApproveStakeDecreaseFixture::default_for_worker_id(worker_id);
+
+        approve_stake_decrease_fixture.call_and_assert(Ok(()));
+        approve_stake_decrease_fixture
+            .call_and_assert(Err(Error::StakeDecreaseRequestIsAlreadyApproved));
     });
 }
 
 #[test]
-fn ensure_setting_genesis_constraints_succeeds() {
+fn reject_stake_decrease_succeeds() {
     build_test_externalities().execute_with(|| {
-        let default_constraint = common::constraints::InputValidationLengthConstraint::new(
-            WORKING_GROUP_CONSTRAINT_MIN,
-            WORKING_GROUP_CONSTRAINT_DIFF,
-        );
-        let opening_text_constraint = TestWorkingGroup::opening_human_readable_text();
-        let worker_text_constraint = TestWorkingGroup::application_human_readable_text();
-        let worker_exit_text_constraint = TestWorkingGroup::worker_exit_rationale_text();
+        let worker_id = fill_worker_position_with_stake(100);
 
-        assert_eq!(opening_text_constraint, default_constraint);
-        assert_eq!(worker_text_constraint, default_constraint);
-        assert_eq!(worker_exit_text_constraint, default_constraint);
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let reject_stake_decrease_fixture =
+            RejectStakeDecreaseFixture::default_for_worker_id(worker_id);
+
+        reject_stake_decrease_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeDecreaseRejected(worker_id));
+
+        // The worker can request the stake decrease again.
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
     });
 }
 
 #[test]
-fn active_worker_counter_works_successfully() {
+fn reject_stake_decrease_fails_with_invalid_origin() {
     build_test_externalities().execute_with(|| {
-        assert_eq!(TestWorkingGroup::active_worker_count(), 0);
+        let worker_id = fill_worker_position_with_stake(100);
 
-        let leader_id = HireLeadFixture::default().hire_lead();
-        assert_eq!(TestWorkingGroup::active_worker_count(), 1);
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
 
-        let worker_id1 = fill_worker_position(
-            None,
-            None,
-            false,
-            OpeningType::Worker,
-            Some(b"worker1".to_vec()),
-        );
-        assert_eq!(TestWorkingGroup::active_worker_count(), 2);
+        let reject_stake_decrease_fixture =
+            RejectStakeDecreaseFixture::default_for_worker_id(worker_id)
+                .with_origin(RawOrigin::Signed(2));
 
-        let worker_id2 = fill_worker_position(
-            None,
-            None,
-            false,
-            OpeningType::Worker,
-            Some(b"worker1".to_vec()),
+        reject_stake_decrease_fixture.call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn reject_stake_decrease_fails_with_already_approved_request() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+        ApproveStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let reject_stake_decrease_fixture =
+            RejectStakeDecreaseFixture::default_for_worker_id(worker_id);
+
+        reject_stake_decrease_fixture
+            .call_and_assert(Err(Error::StakeDecreaseRequestIsAlreadyApproved));
+    });
+}
+
+#[test]
+fn stake_decrease_request_is_removed_on_leaving_role() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        RequestStakeDecreaseFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!<crate::StakeDecreaseRequestByWorkerId<
+            Test,
+            TestWorkingGroupInstance,
+        >>::exists(worker_id));
+    });
+}
+
+#[test]
+fn slash_worker_stake_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id);
+
+        slash_stake_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeSlashed(worker_id));
+    });
+}
+
+#[test]
+fn slash_leader_stake_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let leader_worker_id = HiringWorkflow::default()
+            .with_role_stake(Some(100))
+            .with_opening_type(OpeningType::Leader)
+            .add_default_application()
+            .execute()
+            .unwrap();
+
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(leader_worker_id)
+            .with_origin(RawOrigin::Root);
+
+        slash_stake_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeSlashed(leader_worker_id));
+    });
+}
+
+#[test]
+fn slash_worker_stake_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_worker_id = 22;
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(invalid_worker_id)
+            .with_origin(RawOrigin::None);
+
+        slash_stake_fixture.call_and_assert(Err(Error::RequireRootOrigin));
+    });
+}
+
+#[test]
+fn slash_leader_stake_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default().hire_lead();
+
+        let slash_stake_fixture =
+            SlashWorkerStakeFixture::default_for_worker_id(worker_id).with_origin(RawOrigin::None);
+
+        slash_stake_fixture.call_and_assert(Err(Error::RequireRootOrigin));
+    });
+}
+
+#[test]
+fn slash_worker_stake_fails_with_zero_balance() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let slash_stake_fixture =
+            SlashWorkerStakeFixture::default_for_worker_id(worker_id).with_balance(0);
+
+        slash_stake_fixture.call_and_assert(Err(Error::StakeBalanceCannotBeZero));
+    });
+}
+
+#[test]
+fn slash_worker_stake_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+        let invalid_worker_id = 11;
+
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(invalid_worker_id);
+
+        slash_stake_fixture.call_and_assert(Err(Error::WorkerDoesNotExist));
+    });
+}
+
+#[test]
+fn slash_worker_stake_fails_with_no_stake_profile() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id);
+
+        slash_stake_fixture.call_and_assert(Err(Error::NoWorkerStakeProfile));
+    });
+}
+
+#[test]
+fn slash_worker_stake_fails_with_lead_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(1));
+
+        slash_stake_fixture.call_and_assert(Err(Error::RequireRootOrigin));
+    });
+}
+
+#[test]
+fn slash_worker_stake_fails_with_unslashable_role() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_slashing_terms(SlashingTerms::Unslashable);
+
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id);
+
+        slash_stake_fixture.call_and_assert(Err(Error::WorkerRoleIsUnslashable));
+    });
+}
+
+#[test]
+fn slash_worker_stake_fails_with_max_slash_count_reached() {
+    build_test_externalities().execute_with(|| {
+        let worker_id =
+            fill_worker_position_with_slashing_terms(SlashingTerms::Slashable(SlashableTerms {
+                max_count: 1,
+                max_percent_pts_per_time: 100,
+            }));
+
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id);
+
+        slash_stake_fixture.call_and_assert(Ok(()));
+        slash_stake_fixture.call_and_assert(Err(Error::WorkerMaxSlashCountReached));
+    });
+}
+
+#[test]
+fn slash_worker_stake_is_capped_by_max_percent_of_stake() {
+    build_test_externalities().execute_with(|| {
+        let worker_id =
+            fill_worker_position_with_slashing_terms(SlashingTerms::Slashable(SlashableTerms {
+                max_count: 10,
+                max_percent_pts_per_time: 20,
+            }));
+
+        // 20% of the 100 stake.
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_balance(50)
+            .with_expected_slashed_balance(20);
+
+        slash_stake_fixture.call_and_assert(Ok(()));
+
+        // 20% of the remaining 80 stake.
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_balance(50)
+            .with_expected_slashed_balance(16);
+
+        slash_stake_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn terminate_leader_role_with_slashing_fails_with_unslashable_role() {
+    build_test_externalities().execute_with(|| {
+        let leader_worker_id = HiringWorkflow::default()
+            .with_role_stake(Some(100))
+            .with_slashing_terms(SlashingTerms::Unslashable)
+            .with_opening_type(OpeningType::Leader)
+            .add_default_application()
+            .execute()
+            .unwrap();
+
+        let terminate_worker_role_fixture =
+            TerminateWorkerRoleFixture::default_for_worker_id(leader_worker_id)
+                .with_origin(RawOrigin::Root)
+                .with_slashing();
+
+        terminate_worker_role_fixture.call_and_assert(Err(Error::WorkerRoleIsUnslashable));
+
+        assert!(<crate::WorkerById<Test, TestWorkingGroupInstance>>::exists(
+            leader_worker_id
+        ));
+    });
+}
+
+#[test]
+fn terminate_worker_role_with_slashing_fails_with_lead_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let terminate_worker_role_fixture =
+            TerminateWorkerRoleFixture::default_for_worker_id(worker_id).with_slashing();
+
+        terminate_worker_role_fixture.call_and_assert(Err(Error::RequireRootOrigin));
+
+        assert!(<crate::WorkerById<Test, TestWorkingGroupInstance>>::exists(
+            worker_id
+        ));
+    });
+}
+
+#[test]
+fn initiate_slashing_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let initiate_slashing_fixture = InitiateSlashingFixture::default_for_worker_id(worker_id);
+
+        initiate_slashing_fixture.call_and_assert(Ok(()));
+
+        let slash_id = 0;
+        EventFixture::assert_last_crate_event(RawEvent::StakeSlashingInitiated(
+            worker_id, slash_id,
+        ));
+    });
+}
+
+#[test]
+fn initiate_slashing_succeeds_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default().with_stake(100).hire_lead();
+
+        let initiate_slashing_fixture =
+            InitiateSlashingFixture::default_for_worker_id(worker_id).with_origin(RawOrigin::Root);
+
+        initiate_slashing_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn initiate_slashing_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let initiate_slashing_fixture = InitiateSlashingFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2));
+
+        initiate_slashing_fixture.call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn initiate_slashing_fails_with_zero_balance() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let initiate_slashing_fixture =
+            InitiateSlashingFixture::default_for_worker_id(worker_id).with_balance(0);
+
+        initiate_slashing_fixture.call_and_assert(Err(Error::StakeBalanceCannotBeZero));
+    });
+}
+
+#[test]
+fn initiate_slashing_fails_with_no_stake_profile() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let initiate_slashing_fixture = InitiateSlashingFixture::default_for_worker_id(worker_id);
+
+        initiate_slashing_fixture.call_and_assert(Err(Error::NoWorkerStakeProfile));
+    });
+}
+
+#[test]
+fn initiate_slashing_fails_with_invalid_rationale() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let initiate_slashing_fixture =
+            InitiateSlashingFixture::default_for_worker_id(worker_id).with_rationale(Vec::new());
+
+        initiate_slashing_fixture.call_and_assert(Err(Error::WorkerExitRationaleTextTooShort));
+    });
+}
+
+#[test]
+fn initiate_slashing_fails_with_unslashable_role() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_slashing_terms(SlashingTerms::Unslashable);
+
+        let initiate_slashing_fixture = InitiateSlashingFixture::default_for_worker_id(worker_id);
+
+        initiate_slashing_fixture.call_and_assert(Err(Error::WorkerRoleIsUnslashable));
+    });
+}
+
+#[test]
+fn initiate_slashing_fails_with_max_slash_count_reached() {
+    build_test_externalities().execute_with(|| {
+        let worker_id =
+            fill_worker_position_with_slashing_terms(SlashingTerms::Slashable(SlashableTerms {
+                max_count: 1,
+                max_percent_pts_per_time: 100,
+            }));
+
+        let initiate_slashing_fixture = InitiateSlashingFixture::default_for_worker_id(worker_id);
+
+        initiate_slashing_fixture.call_and_assert(Ok(()));
+        initiate_slashing_fixture.call_and_assert(Err(Error::WorkerMaxSlashCountReached));
+    });
+}
+
+#[test]
+fn initiate_slashing_is_capped_by_max_percent_of_stake() {
+    build_test_externalities().execute_with(|| {
+        let worker_id =
+            fill_worker_position_with_slashing_terms(SlashingTerms::Slashable(SlashableTerms {
+                max_count: 10,
+                max_percent_pts_per_time: 20,
+            }));
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id)
+            .with_balance(50)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::pending_slash((worker_id, slash_id)).amount,
+            20
+        );
+    });
+}
+
+#[test]
+fn initiated_slashing_is_executed_after_dispute_period() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let stake_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id)
+            .with_balance(30)
+            .call_and_assert(Ok(()));
+
+        // SlashingDisputePeriod = 5 in the mock.
+        for block_number in 1..5 {
+            <stake::Module<Test> as OnFinalize<u64>>::on_finalize(block_number);
+        }
+        assert_eq!(
+            get_stake_balance(<stake::Module<Test>>::stakes(stake_id)),
+            100
+        );
+        assert_eq!(get_pending_slashes_count(worker_id), 1);
+
+        <stake::Module<Test> as OnFinalize<u64>>::on_finalize(5);
+
+        assert_eq!(
+            get_stake_balance(<stake::Module<Test>>::stakes(stake_id)),
+            70
+        );
+        assert_eq!(get_pending_slashes_count(worker_id), 0);
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeSlashed(worker_id));
+    });
+}
+
+#[test]
+fn appeal_slashing_succeeds_and_pauses_slashing() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let stake_id = 0;
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let appeal_slashing_fixture = AppealSlashingFixture::default_for_ids(worker_id, slash_id);
+
+        appeal_slashing_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeSlashingAppealed(worker_id, slash_id));
+
+        for block_number in 1..10 {
+            <stake::Module<Test> as OnFinalize<u64>>::on_finalize(block_number);
+        }
+
+        assert_eq!(
+            get_stake_balance(<stake::Module<Test>>::stakes(stake_id)),
+            100
+        );
+        assert_eq!(get_pending_slashes_count(worker_id), 1);
+    });
+}
+
+#[test]
+fn appeal_slashing_succeeds_for_suspended_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+        SuspendWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let appeal_slashing_fixture = AppealSlashingFixture::default_for_ids(worker_id, slash_id);
+
+        appeal_slashing_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn appeal_slashing_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let appeal_slashing_fixture = AppealSlashingFixture::default_for_ids(worker_id, slash_id)
+            .with_origin(RawOrigin::Signed(2));
+
+        appeal_slashing_fixture.call_and_assert(Err(Error::SignerIsNotWorkerRoleAccount));
+    });
+}
+
+#[test]
+fn appeal_slashing_fails_with_invalid_slash_id() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let invalid_slash_id = 11;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let appeal_slashing_fixture =
+            AppealSlashingFixture::default_for_ids(worker_id, invalid_slash_id);
+
+        appeal_slashing_fixture.call_and_assert(Err(Error::PendingSlashDoesNotExist));
+    });
+}
+
+#[test]
+fn appeal_slashing_fails_with_already_appealed_slashing() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let appeal_slashing_fixture = AppealSlashingFixture::default_for_ids(worker_id, slash_id);
+
+        appeal_slashing_fixture.call_and_assert(Ok(()));
+        appeal_slashing_fixture.call_and_assert(Err(Error::SlashingIsAlreadyAppealed));
+    });
+}
+
+#[test]
+fn appeal_slashing_fails_with_invalid_appeal_discussion() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let appeal_slashing_fixture = AppealSlashingFixture::default_for_ids(worker_id, slash_id)
+            .with_appeal_title(Vec::new());
+
+        appeal_slashing_fixture.call_and_assert(Err(Error::Other("Empty appeal thread title.")));
+    });
+}
+
+#[test]
+fn cancel_slashing_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let stake_id = 0;
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+        AppealSlashingFixture::default_for_ids(worker_id, slash_id).call_and_assert(Ok(()));
+
+        let cancel_slashing_fixture = CancelSlashingFixture::default_for_ids(worker_id, slash_id);
+
+        cancel_slashing_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeSlashingCancelled(
+            worker_id, slash_id,
+        ));
+
+        for block_number in 1..10 {
+            <stake::Module<Test> as OnFinalize<u64>>::on_finalize(block_number);
+        }
+
+        assert_eq!(
+            get_stake_balance(<stake::Module<Test>>::stakes(stake_id)),
+            100
+        );
+    });
+}
+
+#[test]
+fn cancelled_slashing_does_not_count_against_slashing_terms() {
+    build_test_externalities().execute_with(|| {
+        let worker_id =
+            fill_worker_position_with_slashing_terms(SlashingTerms::Slashable(SlashableTerms {
+                max_count: 1,
+                max_percent_pts_per_time: 100,
+            }));
+        let slash_id = 0;
+
+        let initiate_slashing_fixture = InitiateSlashingFixture::default_for_worker_id(worker_id);
+
+        initiate_slashing_fixture.call_and_assert(Ok(()));
+
+        CancelSlashingFixture::default_for_ids(worker_id, slash_id).call_and_assert(Ok(()));
+
+        initiate_slashing_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn cancel_slashing_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let lead_account_id = 1;
+        let cancel_slashing_fixture = CancelSlashingFixture::default_for_ids(worker_id, slash_id)
+            .with_origin(RawOrigin::Signed(lead_account_id));
+
+        cancel_slashing_fixture.call_and_assert(Err(Error::RequireRootOrigin));
+    });
+}
+
+#[test]
+fn cancel_slashing_fails_with_invalid_slash_id() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let invalid_slash_id = 11;
+
+        let cancel_slashing_fixture =
+            CancelSlashingFixture::default_for_ids(worker_id, invalid_slash_id);
+
+        cancel_slashing_fixture.call_and_assert(Err(Error::PendingSlashDoesNotExist));
+    });
+}
+
+#[test]
+fn leave_worker_role_fails_with_pending_slashing() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::WorkerHasPendingSlashes));
+
+        assert_eq!(get_pending_slashes_count(worker_id), 1);
+    });
+}
+
+#[test]
+fn terminate_worker_role_fails_with_pending_slashing() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::WorkerHasPendingSlashes));
+
+        let relationship_id = get_worker_by_id(worker_id).reward_relationship.unwrap();
+        let relationship = recurringrewards::RewardRelationships::<Test>::get(relationship_id);
+        assert!(relationship.is_active());
+    });
+}
+
+#[test]
+fn resume_slashing_succeeds_and_executes_slashing() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let stake_id = 0;
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id)
+            .with_balance(30)
+            .call_and_assert(Ok(()));
+        AppealSlashingFixture::default_for_ids(worker_id, slash_id).call_and_assert(Ok(()));
+
+        let resume_slashing_fixture = ResumeSlashingFixture::default_for_ids(worker_id, slash_id);
+
+        resume_slashing_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StakeSlashingResumed(worker_id, slash_id));
+
+        for block_number in 1..10 {
+            <stake::Module<Test> as OnFinalize<u64>>::on_finalize(block_number);
+        }
+
+        assert_eq!(
+            get_stake_balance(<stake::Module<Test>>::stakes(stake_id)),
+            70
+        );
+        assert_eq!(get_pending_slashes_count(worker_id), 0);
+    });
+}
+
+#[test]
+fn resume_slashing_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+        AppealSlashingFixture::default_for_ids(worker_id, slash_id).call_and_assert(Ok(()));
+
+        let lead_account_id = 1;
+        let resume_slashing_fixture = ResumeSlashingFixture::default_for_ids(worker_id, slash_id)
+            .with_origin(RawOrigin::Signed(lead_account_id));
+
+        resume_slashing_fixture.call_and_assert(Err(Error::RequireRootOrigin));
+    });
+}
+
+#[test]
+fn resume_slashing_fails_with_not_appealed_slashing() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+        let slash_id = 0;
+
+        InitiateSlashingFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let resume_slashing_fixture = ResumeSlashingFixture::default_for_ids(worker_id, slash_id);
+
+        resume_slashing_fixture.call_and_assert(Err(Error::SlashingIsNotAppealed));
+    });
+}
+
+#[test]
+fn get_all_worker_ids_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_ids = TestWorkingGroup::get_regular_worker_ids();
+        assert_eq!(worker_ids, Vec::new());
+
+        let leader_worker_id = HireLeadFixture::default().hire_lead();
+
+        let worker_id1 = fill_worker_position(None, None, false, OpeningType::Worker, None);
+        let worker_id2 = fill_worker_position(None, None, false, OpeningType::Worker, None);
+
+        let mut expected_ids = vec![worker_id1, worker_id2];
+        expected_ids.sort();
+
+        let mut worker_ids = TestWorkingGroup::get_regular_worker_ids();
+        worker_ids.sort();
+        assert_eq!(worker_ids, expected_ids);
+
+        assert!(!expected_ids.contains(&leader_worker_id));
+
+        <crate::WorkerById<Test, TestWorkingGroupInstance>>::remove(worker_id1);
+        let worker_ids = TestWorkingGroup::get_regular_worker_ids();
+        assert_eq!(worker_ids, vec![worker_id2]);
+    });
+}
+
+#[test]
+fn set_working_group_mint_capacity_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let mint_id = <minting::Module<Test>>::add_mint(0, None, None).unwrap();
+        <crate::Mint<Test, TestWorkingGroupInstance>>::put(mint_id);
+
+        let capacity = 15000;
+        let result = TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), capacity);
+
+        assert_eq!(result, Ok(()));
+
+        let mint = <minting::Module<Test>>::mints(mint_id);
+        assert_eq!(mint.capacity(), capacity);
+    });
+}
+
+#[test]
+fn set_working_group_mint_capacity_fails_with_mint_not_found() {
+    build_test_externalities().execute_with(|| {
+        let capacity = 15000;
+
+        <crate::Mint<Test, TestWorkingGroupInstance>>::put(5); // random mint id
+        let result = TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), capacity);
+
+        assert_eq!(result, Err(Error::CannotFindMint));
+    });
+}
+
+#[test]
+fn set_working_group_mint_capacity_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let capacity = 15000;
+        let result = TestWorkingGroup::set_mint_capacity(RawOrigin::None.into(), capacity);
+
+        assert_eq!(result, Err(Error::RequireRootOrigin));
+    });
+}
+
+#[test]
+fn ensure_setting_genesis_working_group_mint_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let mint_id = TestWorkingGroup::mint();
+
+        assert!(minting::Mints::<Test>::exists(mint_id));
+
+        let mint = <minting::Module<Test>>::mints(mint_id);
+        assert_eq!(mint.capacity(), WORKING_GROUP_MINT_CAPACITY);
+    });
+}
+
+#[test]
+fn ensure_setting_genesis_constraints_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let default_constraint = common::constraints::InputValidationLengthConstraint::new(
+            WORKING_GROUP_CONSTRAINT_MIN,
+            WORKING_GROUP_CONSTRAINT_DIFF,
+        );
+        let opening_text_constraint = TestWorkingGroup::opening_human_readable_text();
+        let worker_text_constraint = TestWorkingGroup::application_human_readable_text();
+        let worker_exit_text_constraint = TestWorkingGroup::worker_exit_rationale_text();
+
+        assert_eq!(opening_text_constraint, default_constraint);
+        assert_eq!(worker_text_constraint, default_constraint);
+        assert_eq!(worker_exit_text_constraint, default_constraint);
+    });
+}
+
+#[test]
+fn active_worker_counter_works_successfully() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(TestWorkingGroup::active_worker_count(), 0);
+
+        let leader_id = HireLeadFixture::default().hire_lead();
+        assert_eq!(TestWorkingGroup::active_worker_count(), 1);
+
+        let worker_id1 = fill_worker_position(
+            None,
+            None,
+            false,
+            OpeningType::Worker,
+            Some(b"worker1".to_vec()),
+        );
+        assert_eq!(TestWorkingGroup::active_worker_count(), 2);
+
+        let worker_id2 = fill_worker_position(
+            None,
+            None,
+            false,
+            OpeningType::Worker,
+            Some(b"worker1".to_vec()),
+        );
+        assert_eq!(TestWorkingGroup::active_worker_count(), 3);
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id1).call_and_assert(Ok(()));
+        assert_eq!(TestWorkingGroup::active_worker_count(), 2);
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id2).call_and_assert(Ok(()));
+        assert_eq!(TestWorkingGroup::active_worker_count(), 1);
+
+        TerminateWorkerRoleFixture::default_for_worker_id(leader_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+        assert_eq!(TestWorkingGroup::active_worker_count(), 0);
+    });
+}
+
+#[test]
+fn adding_too_much_workers_fails_with_single_application_out_of_limit() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        fill_worker_position(None, None, false, OpeningType::Worker, None);
+        fill_worker_position(None, None, false, OpeningType::Worker, None);
+
+        let hiring_workflow = HiringWorkflow::default()
+            .disable_setup_environment()
+            .add_default_application()
+            .expect(Err(Error::MaxActiveWorkerNumberExceeded));
+
+        hiring_workflow.execute()
+    });
+}
+
+#[test]
+fn fill_opening_cannot_hire_more_workers_using_several_applicationst_han_allows_worker_limit() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        fill_worker_position(None, None, false, OpeningType::Worker, None);
+
+        let hiring_workflow = HiringWorkflow::default()
+            .disable_setup_environment()
+            .add_application_with_origin(b"Some1".to_vec(), RawOrigin::Signed(2), 2)
+            .add_application_with_origin(b"Some2".to_vec(), RawOrigin::Signed(3), 3)
+            .expect(Err(Error::MaxActiveWorkerNumberExceeded));
+
+        hiring_workflow.execute()
+    });
+}
+
+#[test]
+fn evaluate_worker_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let evaluate_worker_fixture = EvaluateWorkerFixture::default_for_worker_id(worker_id);
+
+        evaluate_worker_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerEvaluated(worker_id, 80));
+    });
+}
+
+#[test]
+fn evaluate_worker_succeeds_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default().hire_lead();
+
+        let evaluate_worker_fixture =
+            EvaluateWorkerFixture::default_for_worker_id(worker_id).with_origin(RawOrigin::Root);
+
+        evaluate_worker_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn evaluate_worker_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let evaluate_worker_fixture =
+            EvaluateWorkerFixture::default_for_worker_id(worker_id).with_origin(RawOrigin::None);
+
+        evaluate_worker_fixture.call_and_assert(Err(Error::RequireSignedOrigin));
+    });
+}
+
+#[test]
+fn evaluate_worker_fails_with_invalid_origin_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default().hire_lead();
+
+        let evaluate_worker_fixture =
+            EvaluateWorkerFixture::default_for_worker_id(worker_id).with_origin(RawOrigin::None);
+
+        evaluate_worker_fixture.call_and_assert(Err(Error::RequireRootOrigin));
+    });
+}
+
+#[test]
+fn evaluate_worker_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let evaluate_worker_fixture = EvaluateWorkerFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2));
+
+        evaluate_worker_fixture.call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn evaluate_worker_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        let invalid_worker_id = 12;
+        fill_default_worker_position();
+
+        let evaluate_worker_fixture =
+            EvaluateWorkerFixture::default_for_worker_id(invalid_worker_id);
+
+        evaluate_worker_fixture.call_and_assert(Err(Error::WorkerDoesNotExist));
+    });
+}
+
+#[test]
+fn evaluate_worker_fails_with_too_high_score() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let evaluate_worker_fixture = EvaluateWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(crate::MAX_WORKER_EVALUATION_SCORE + 1);
+
+        evaluate_worker_fixture.call_and_assert(Err(Error::WorkerEvaluationScoreTooHigh));
+    });
+}
+
+#[test]
+fn evaluate_worker_fails_with_invalid_rationale() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let evaluate_worker_fixture =
+            EvaluateWorkerFixture::default_for_worker_id(worker_id).with_rationale(Vec::new());
+
+        evaluate_worker_fixture.call_and_assert(Err(Error::WorkerEvaluationRationaleTooShort));
+
+        let evaluate_worker_fixture = EvaluateWorkerFixture::default_for_worker_id(worker_id)
+            .with_rationale(b"MSG".repeat(1024));
+
+        evaluate_worker_fixture.call_and_assert(Err(Error::WorkerEvaluationRationaleTooLong));
+    });
+}
+
+#[test]
+fn evaluate_worker_keeps_bounded_history() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        for score in 1..=5 {
+            EvaluateWorkerFixture::default_for_worker_id(worker_id)
+                .with_score(score)
+                .call_and_assert(Ok(()));
+        }
+
+        let scores = TestWorkingGroup::worker_evaluations(worker_id)
+            .iter()
+            .map(|evaluation| evaluation.score)
+            .collect::<Vec<_>>();
+
+        // MaxWorkerHistoryLength = 3 in the mock.
+        assert_eq!(scores, vec![3, 4, 5]);
+    });
+}
+
+#[test]
+fn submit_work_report_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let submit_work_report_fixture = SubmitWorkReportFixture::default_for_worker_id(worker_id);
+
+        submit_work_report_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkReportSubmitted(worker_id));
+    });
+}
+
+#[test]
+fn submit_work_report_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let submit_work_report_fixture = SubmitWorkReportFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2));
+
+        submit_work_report_fixture.call_and_assert(Err(Error::SignerIsNotWorkerRoleAccount));
+    });
+}
+
+#[test]
+fn submit_work_report_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        let invalid_worker_id = 12;
+        fill_default_worker_position();
+
+        let submit_work_report_fixture =
+            SubmitWorkReportFixture::default_for_worker_id(invalid_worker_id);
+
+        submit_work_report_fixture.call_and_assert(Err(Error::WorkerDoesNotExist));
+    });
+}
+
+#[test]
+fn submit_work_report_fails_with_invalid_content_hashes() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let submit_work_report_fixture = SubmitWorkReportFixture::default_for_worker_id(worker_id)
+            .with_content_hashes(Vec::new());
+
+        submit_work_report_fixture.call_and_assert(Err(Error::WorkReportContentHashesAreEmpty));
+
+        let submit_work_report_fixture = SubmitWorkReportFixture::default_for_worker_id(worker_id)
+            .with_content_hashes(vec![
+                primitives::H256::zero();
+                crate::MAX_WORK_REPORT_CONTENT_HASHES + 1
+            ]);
+
+        submit_work_report_fixture.call_and_assert(Err(Error::WorkReportTooManyContentHashes));
+    });
+}
+
+#[test]
+fn submit_work_report_fails_with_invalid_description() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let submit_work_report_fixture =
+            SubmitWorkReportFixture::default_for_worker_id(worker_id).with_description(Vec::new());
+
+        submit_work_report_fixture.call_and_assert(Err(Error::WorkReportDescriptionTooShort));
+
+        let submit_work_report_fixture = SubmitWorkReportFixture::default_for_worker_id(worker_id)
+            .with_description(b"MSG".repeat(1024));
+
+        submit_work_report_fixture.call_and_assert(Err(Error::WorkReportDescriptionTooLong));
+    });
+}
+
+#[test]
+fn submit_work_report_keeps_bounded_history() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        for idx in 1..=5 {
+            SubmitWorkReportFixture::default_for_worker_id(worker_id)
+                .with_content_hashes(vec![primitives::H256::repeat_byte(idx)])
+                .call_and_assert(Ok(()));
+        }
+
+        let content_hashes = TestWorkingGroup::work_reports(worker_id)
+            .into_iter()
+            .flat_map(|report| report.content_hashes)
+            .collect::<Vec<_>>();
+
+        // MaxWorkerHistoryLength = 3 in the mock.
+        assert_eq!(
+            content_hashes,
+            vec![
+                primitives::H256::repeat_byte(3),
+                primitives::H256::repeat_byte(4),
+                primitives::H256::repeat_byte(5)
+            ]
+        );
+    });
+}
+
+#[test]
+fn worker_evaluations_and_work_reports_are_kept_after_leaving_role() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        EvaluateWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+        SubmitWorkReportFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::worker_evaluations(worker_id).len(), 1);
+        assert_eq!(TestWorkingGroup::work_reports(worker_id).len(), 1);
+    });
+}
+
+#[test]
+fn suspend_worker_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let suspend_worker_fixture = SuspendWorkerFixture::default_for_worker_id(worker_id);
+
+        suspend_worker_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerSuspended(
+            worker_id,
+            b"rationale".to_vec(),
+        ));
+    });
+}
+
+#[test]
+fn suspend_worker_succeeds_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default().hire_lead();
+
+        let suspend_worker_fixture =
+            SuspendWorkerFixture::default_for_worker_id(worker_id).with_origin(RawOrigin::Root);
+
+        suspend_worker_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn suspend_worker_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let suspend_worker_fixture = SuspendWorkerFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2));
+
+        suspend_worker_fixture.call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn suspend_worker_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        let invalid_worker_id = 12;
+        fill_default_worker_position();
+
+        let suspend_worker_fixture = SuspendWorkerFixture::default_for_worker_id(invalid_worker_id);
+
+        suspend_worker_fixture.call_and_assert(Err(Error::WorkerDoesNotExist));
+    });
+}
+
+#[test]
+fn suspend_worker_fails_with_already_suspended_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let suspend_worker_fixture = SuspendWorkerFixture::default_for_worker_id(worker_id);
+
+        suspend_worker_fixture.call_and_assert(Ok(()));
+        suspend_worker_fixture.call_and_assert(Err(Error::WorkerIsAlreadySuspended));
+    });
+}
+
+#[test]
+fn suspend_worker_fails_with_invalid_suspension_period() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let suspend_worker_fixture =
+            SuspendWorkerFixture::default_for_worker_id(worker_id).with_suspension_period(0);
+
+        suspend_worker_fixture.call_and_assert(Err(Error::WorkerSuspensionPeriodIsZero));
+
+        // MaxWorkerSuspensionPeriod = 10 in the mock.
+        let suspend_worker_fixture =
+            SuspendWorkerFixture::default_for_worker_id(worker_id).with_suspension_period(11);
+
+        suspend_worker_fixture.call_and_assert(Err(Error::WorkerSuspensionPeriodTooLong));
+    });
+}
+
+#[test]
+fn suspend_worker_fails_with_invalid_rationale() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let suspend_worker_fixture =
+            SuspendWorkerFixture::default_for_worker_id(worker_id).with_rationale(Vec::new());
+
+        suspend_worker_fixture.call_and_assert(Err(Error::WorkerExitRationaleTextTooShort));
+
+        let suspend_worker_fixture = SuspendWorkerFixture::default_for_worker_id(worker_id)
+            .with_rationale(b"MSG".repeat(1024));
+
+        suspend_worker_fixture.call_and_assert(Err(Error::WorkerExitRationaleTextTooLong));
+    });
+}
+
+#[test]
+fn suspended_worker_cannot_act_in_the_role() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SuspendWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        SubmitWorkReportFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::WorkerIsSuspended));
+    });
+}
+
+#[test]
+fn suspended_worker_can_leave_role() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SuspendWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let ends_at = TestWorkingGroup::worker_suspension(worker_id).ends_at;
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!TestWorkingGroup::is_worker_suspended(&worker_id));
+        assert!(TestWorkingGroup::worker_ids_by_suspension_end(ends_at).is_empty());
+    });
+}
+
+#[test]
+fn suspended_leader_cannot_act_as_leader() {
+    build_test_externalities().execute_with(|| {
+        let leader_worker_id = HireLeadFixture::default().hire_lead();
+
+        SuspendWorkerFixture::default_for_worker_id(leader_worker_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        AddWorkerOpeningFixture::default().call_and_assert(Err(Error::LeaderIsSuspended));
+    });
+}
+
+#[test]
+fn resume_worker_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SuspendWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let resume_worker_fixture = ResumeWorkerFixture::default_for_worker_id(worker_id);
+
+        resume_worker_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerResumed(worker_id));
+
+        let relationship_id = get_worker_by_id(worker_id).reward_relationship.unwrap();
+        let relationship = recurringrewards::RewardRelationships::<Test>::get(relationship_id);
+        assert_eq!(relationship.next_payment_at_block(), Some(20));
+
+        SubmitWorkReportFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn resume_worker_fails_with_not_suspended_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let resume_worker_fixture = ResumeWorkerFixture::default_for_worker_id(worker_id);
+
+        resume_worker_fixture.call_and_assert(Err(Error::WorkerIsNotSuspended));
+    });
+}
+
+#[test]
+fn resume_worker_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SuspendWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let resume_worker_fixture =
+            ResumeWorkerFixture::default_for_worker_id(worker_id).with_origin(RawOrigin::Signed(2));
+
+        resume_worker_fixture.call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn worker_suspension_expires_on_finalize() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SuspendWorkerFixture::default_for_worker_id(worker_id)
+            .with_suspension_period(5)
+            .call_and_assert(Ok(()));
+
+        let ends_at = TestWorkingGroup::worker_suspension(worker_id).ends_at;
+
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(ends_at - 1);
+        assert!(TestWorkingGroup::is_worker_suspended(&worker_id));
+
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(ends_at);
+        assert!(!TestWorkingGroup::is_worker_suspended(&worker_id));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerResumed(worker_id));
+    });
+}
+
+#[test]
+fn worker_suspension_is_removed_on_termination() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SuspendWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!TestWorkingGroup::is_worker_suspended(&worker_id));
+    });
+}
+
+#[test]
+fn grant_bonus_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(30)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerBonusGranted(worker_id, 30));
+    });
+}
+
+#[test]
+fn grant_bonus_succeeds_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let leader_worker_id = HireLeadFixture::default().hire_lead();
+
+        GrantBonusFixture::default_for_worker_id(leader_worker_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn grant_bonus_pays_once_and_is_removed_on_finalize() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(30)
+            .call_and_assert(Ok(()));
+
+        let extra_reward = TestWorkingGroup::extra_rewards(worker_id)[0].clone();
+        let relationship_id = extra_reward.reward_relationship_id;
+        let reward_account =
+            recurringrewards::RewardRelationships::<Test>::get(relationship_id).account;
+        let old_balance = get_balance(reward_account);
+
+        let payment_at = extra_reward.ends_at;
+        <recurringrewards::Module<Test> as OnFinalize<u64>>::on_finalize(payment_at);
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(payment_at);
+
+        assert_eq!(get_balance(reward_account), old_balance + 30);
+        assert!(recurringrewards::RewardRelationships::<Test>::exists(
+            relationship_id
+        ));
+
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(payment_at + 1);
+
+        assert!(!<crate::ExtraRewardsByWorkerId<
+            Test,
+            TestWorkingGroupInstance,
+        >>::exists(worker_id));
+        assert!(!recurringrewards::RewardRelationships::<Test>::exists(
+            relationship_id
+        ));
+    });
+}
+
+#[test]
+fn grant_bonus_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn grant_bonus_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        fill_default_worker_position();
+
+        let invalid_worker_id = 10;
+        GrantBonusFixture::default_for_worker_id(invalid_worker_id)
+            .call_and_assert(Err(Error::WorkerDoesNotExist));
+    });
+}
+
+#[test]
+fn grant_bonus_fails_with_zero_amount() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(0)
+            .call_and_assert(Err(Error::ExtraRewardAmountIsZero));
+    });
+}
+
+#[test]
+fn grant_bonus_fails_with_suspended_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        SuspendWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::WorkerIsSuspended));
+    });
+}
+
+#[test]
+fn grant_bonus_fails_with_max_extra_rewards_reached() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        for _ in 0..crate::MAX_WORKER_EXTRA_REWARDS {
+            GrantBonusFixture::default_for_worker_id(worker_id)
+                .with_amount(1)
+                .call_and_assert(Ok(()));
+        }
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(1)
+            .call_and_assert(Err(Error::WorkerMaxExtraRewardsReached));
+    });
+}
+
+#[test]
+fn grant_bonus_fails_with_exceeded_budget() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let mint_capacity = <minting::Module<Test>>::mints(TestWorkingGroup::mint()).capacity();
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(mint_capacity + 1)
+            .call_and_assert(Err(Error::ExtraRewardsBudgetExceeded));
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(mint_capacity - 10)
+            .call_and_assert(Ok(()));
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(11)
+            .call_and_assert(Err(Error::ExtraRewardsBudgetExceeded));
+    });
+}
+
+#[test]
+fn extra_rewards_budget_is_renewed_after_budget_period() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let mint_capacity = <minting::Module<Test>>::mints(TestWorkingGroup::mint()).capacity();
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(mint_capacity)
+            .call_and_assert(Ok(()));
+
+        let budget_period: u64 =
+            <Test as crate::Trait<TestWorkingGroupInstance>>::ExtraRewardsBudgetPeriod::get();
+        let period_started_at = TestWorkingGroup::granted_extra_rewards().period_started_at;
+
+        System::set_block_number(period_started_at + budget_period - 1);
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(1)
+            .call_and_assert(Err(Error::ExtraRewardsBudgetExceeded));
+
+        System::set_block_number(period_started_at + budget_period);
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(1)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::granted_extra_rewards().amount, 1);
+    });
+}
+
+#[test]
+fn grant_extra_reward_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantExtraRewardFixture::default_for_worker_id(worker_id)
+            .with_amount_per_payout(10)
+            .with_payout_interval(5)
+            .with_duration(12)
+            .call_and_assert(Ok(()));
+
+        // Two payouts fit the duration.
+        EventFixture::assert_last_crate_event(RawEvent::WorkerExtraRewardGranted(worker_id, 20));
+        assert_eq!(TestWorkingGroup::granted_extra_rewards().amount, 20);
+    });
+}
+
+#[test]
+fn grant_extra_reward_fails_with_zero_amount() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantExtraRewardFixture::default_for_worker_id(worker_id)
+            .with_amount_per_payout(0)
+            .call_and_assert(Err(Error::ExtraRewardAmountIsZero));
+    });
+}
+
+#[test]
+fn grant_extra_reward_fails_with_zero_payout_interval() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantExtraRewardFixture::default_for_worker_id(worker_id)
+            .with_payout_interval(0)
+            .call_and_assert(Err(Error::ExtraRewardPayoutIntervalIsZero));
+    });
+}
+
+#[test]
+fn grant_extra_reward_fails_with_too_short_duration() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantExtraRewardFixture::default_for_worker_id(worker_id)
+            .with_payout_interval(5)
+            .with_duration(4)
+            .call_and_assert(Err(Error::ExtraRewardDurationTooShort));
+    });
+}
+
+#[test]
+fn grant_extra_reward_fails_with_exceeded_budget() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let mint_capacity = <minting::Module<Test>>::mints(TestWorkingGroup::mint()).capacity();
+
+        GrantExtraRewardFixture::default_for_worker_id(worker_id)
+            .with_amount_per_payout(mint_capacity / 2 + 1)
+            .with_payout_interval(5)
+            .with_duration(10)
+            .call_and_assert(Err(Error::ExtraRewardsBudgetExceeded));
+    });
+}
+
+#[test]
+fn extra_reward_is_removed_after_the_duration_on_finalize() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantExtraRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let extra_reward = TestWorkingGroup::extra_rewards(worker_id)[0].clone();
+
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(extra_reward.ends_at);
+        assert_eq!(
+            TestWorkingGroup::extra_rewards(worker_id),
+            vec![extra_reward.clone()]
+        );
+
+        <TestWorkingGroup as OnFinalize<u64>>::on_finalize(extra_reward.ends_at + 1);
+        assert!(TestWorkingGroup::extra_rewards(worker_id).is_empty());
+        assert!(!recurringrewards::RewardRelationships::<Test>::exists(
+            extra_reward.reward_relationship_id
+        ));
+    });
+}
+
+#[test]
+fn extra_rewards_are_removed_on_worker_exit() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantBonusFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+        GrantExtraRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let relationship_ids = TestWorkingGroup::extra_rewards(worker_id)
+            .iter()
+            .map(|extra_reward| extra_reward.reward_relationship_id)
+            .collect::<Vec<_>>();
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!<crate::ExtraRewardsByWorkerId<
+            Test,
+            TestWorkingGroupInstance,
+        >>::exists(worker_id));
+        for relationship_id in relationship_ids {
+            assert!(!recurringrewards::RewardRelationships::<Test>::exists(
+                relationship_id
+            ));
+        }
+    });
+}
+
+#[test]
+fn extra_rewards_are_paused_on_worker_suspension() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantExtraRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let extra_reward = TestWorkingGroup::extra_rewards(worker_id)[0].clone();
+        let relationship_id = extra_reward.reward_relationship_id;
+        let payment_at = recurringrewards::RewardRelationships::<Test>::get(relationship_id)
+            .next_payment_at_block();
+
+        SuspendWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!recurringrewards::RewardRelationships::<Test>::get(relationship_id).is_active());
+        assert_eq!(
+            TestWorkingGroup::extra_rewards(worker_id)[0].paused_payment_at,
+            payment_at
+        );
+
+        ResumeWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert_eq!(
+            recurringrewards::RewardRelationships::<Test>::get(relationship_id)
+                .next_payment_at_block(),
+            payment_at
         );
-        assert_eq!(TestWorkingGroup::active_worker_count(), 3);
+        assert_eq!(
+            TestWorkingGroup::extra_rewards(worker_id),
+            vec![extra_reward]
+        );
+    });
+}
 
-        TerminateWorkerRoleFixture::default_for_worker_id(worker_id1).call_and_assert(Ok(()));
-        assert_eq!(TestWorkingGroup::active_worker_count(), 2);
+#[test]
+fn suspend_worker_fails_with_missing_extra_reward_relationship() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
 
-        TerminateWorkerRoleFixture::default_for_worker_id(worker_id2).call_and_assert(Ok(()));
-        assert_eq!(TestWorkingGroup::active_worker_count(), 1);
+        GrantExtraRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
 
-        TerminateWorkerRoleFixture::default_for_worker_id(leader_id)
+        let relationship_id = TestWorkingGroup::extra_rewards(worker_id)[0].reward_relationship_id;
+        recurringrewards::Module::<Test>::remove_reward_relationship(relationship_id);
+
+        SuspendWorkerFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::RelationshipMustExist));
+
+        // The worker reward relationship wasn't paused.
+        let worker_relationship_id = TestWorkingGroup::worker_by_id(worker_id)
+            .reward_relationship
+            .unwrap();
+        assert!(
+            recurringrewards::RewardRelationships::<Test>::get(worker_relationship_id).is_active()
+        );
+    });
+}
+
+fn pay_rewards_at(block_number: u64) {
+    <recurringrewards::Module<Test> as OnFinalize<u64>>::on_finalize(block_number);
+}
+
+#[test]
+fn worker_reward_payout_is_accounted_in_spending_report() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position(
+            Some(RewardPolicy {
+                amount_per_payout: 10,
+                next_payment_at_block: 20,
+                payout_interval: Some(5),
+            }),
+            None,
+            true,
+            OpeningType::Worker,
+            None,
+        );
+
+        pay_rewards_at(20);
+        pay_rewards_at(25);
+
+        let report = TestWorkingGroup::spending_report(0);
+        assert_eq!(report.worker_rewards, 20);
+        assert_eq!(report.leader_rewards, 0);
+        assert_eq!(report.total_spending(), 20);
+        assert_eq!(report.spending_by_worker.get(&worker_id), Some(&20));
+    });
+}
+
+#[test]
+fn leader_reward_payout_is_accounted_in_spending_report() {
+    build_test_externalities().execute_with(|| {
+        let leader_worker_id = HireLeadFixture::default()
+            .with_reward_policy(RewardPolicy {
+                amount_per_payout: 10,
+                next_payment_at_block: 20,
+                payout_interval: None,
+            })
+            .hire_lead();
+
+        pay_rewards_at(20);
+
+        let report = TestWorkingGroup::spending_report(0);
+        assert_eq!(report.worker_rewards, 0);
+        assert_eq!(report.leader_rewards, 10);
+        assert_eq!(report.spending_by_worker.get(&leader_worker_id), Some(&10));
+    });
+}
+
+#[test]
+fn extra_reward_payout_is_accounted_in_spending_report() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        GrantBonusFixture::default_for_worker_id(worker_id)
+            .with_amount(30)
+            .call_and_assert(Ok(()));
+
+        pay_rewards_at(System::block_number() + 1);
+
+        let report = TestWorkingGroup::spending_report(0);
+        assert_eq!(report.extra_rewards, 30);
+        assert_eq!(report.spending_by_worker.get(&worker_id), Some(&30));
+    });
+}
+
+#[test]
+fn stake_refund_is_accounted_in_spending_report() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let report = TestWorkingGroup::spending_report(0);
+        assert_eq!(report.refunds, 100);
+        assert_eq!(report.total_spending(), 100);
+        assert_eq!(report.spending_by_worker.get(&worker_id), None);
+    });
+}
+
+#[test]
+fn missed_reward_payout_is_accounted_in_spending_report() {
+    build_test_externalities().execute_with(|| {
+        // Default reward exceeds the mint capacity.
+        let worker_id = fill_default_worker_position();
+
+        pay_rewards_at(20);
+
+        let report = TestWorkingGroup::spending_report(0);
+        assert_eq!(report.missed_rewards, 1000);
+        assert_eq!(report.total_spending(), 0);
+        assert_eq!(report.spending_by_worker.get(&worker_id), None);
+    });
+}
+
+#[test]
+fn reward_payout_is_accounted_in_current_council_term() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position(
+            Some(RewardPolicy {
+                amount_per_payout: 10,
+                next_payment_at_block: 20,
+                payout_interval: Some(5),
+            }),
+            None,
+            true,
+            OpeningType::Worker,
+            None,
+        );
+
+        pay_rewards_at(20);
+
+        TestWorkingGroup::start_council_term(3);
+        assert_eq!(TestWorkingGroup::current_council_term(), 3);
+
+        pay_rewards_at(25);
+        pay_rewards_at(30);
+
+        assert_eq!(TestWorkingGroup::spending_report(0).worker_rewards, 10);
+
+        let report = TestWorkingGroup::spending_report(3);
+        assert_eq!(report.worker_rewards, 20);
+        assert_eq!(report.spending_by_worker.get(&worker_id), Some(&20));
+    });
+}
+
+#[test]
+fn add_opening_template_succeeds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::OpeningTemplateAdded(template_id));
+    });
+}
+
+#[test]
+fn add_opening_template_succeeds_for_leader_openings() {
+    build_test_externalities().execute_with(|| {
+        AddOpeningTemplateFixture::default()
+            .with_opening_type(OpeningType::Leader)
             .with_origin(RawOrigin::Root)
             .call_and_assert(Ok(()));
-        assert_eq!(TestWorkingGroup::active_worker_count(), 0);
     });
 }
 
 #[test]
-fn adding_too_much_workers_fails_with_single_application_out_of_limit() {
+fn add_opening_template_fails_with_invalid_origin() {
     build_test_externalities().execute_with(|| {
         HireLeadFixture::default().hire_lead();
 
-        fill_worker_position(None, None, false, OpeningType::Worker, None);
-        fill_worker_position(None, None, false, OpeningType::Worker, None);
+        AddOpeningTemplateFixture::default()
+            .with_opening_type(OpeningType::Leader)
+            .call_and_assert(Err(Error::RequireRootOrigin));
 
-        let hiring_workflow = HiringWorkflow::default()
-            .disable_setup_environment()
-            .add_default_application()
-            .expect(Err(Error::MaxActiveWorkerNumberExceeded));
+        AddOpeningTemplateFixture::default()
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
 
-        hiring_workflow.execute()
+#[test]
+fn add_opening_template_fails_with_invalid_name() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        AddOpeningTemplateFixture::default()
+            .with_name(Vec::new())
+            .call_and_assert(Err(Error::OpeningTemplateNameTooShort));
+
+        AddOpeningTemplateFixture::default()
+            .with_name([0u8; 1025].to_vec())
+            .call_and_assert(Err(Error::OpeningTemplateNameTooLong));
     });
 }
 
 #[test]
-fn fill_opening_cannot_hire_more_workers_using_several_applicationst_han_allows_worker_limit() {
+fn add_opening_template_fails_with_not_unique_name() {
     build_test_externalities().execute_with(|| {
         HireLeadFixture::default().hire_lead();
 
-        fill_worker_position(None, None, false, OpeningType::Worker, None);
+        AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
 
-        let hiring_workflow = HiringWorkflow::default()
-            .disable_setup_environment()
-            .add_application_with_origin(b"Some1".to_vec(), RawOrigin::Signed(2), 2)
-            .add_application_with_origin(b"Some2".to_vec(), RawOrigin::Signed(3), 3)
-            .expect(Err(Error::MaxActiveWorkerNumberExceeded));
+        AddOpeningTemplateFixture::default()
+            .call_and_assert(Err(Error::OpeningTemplateNameIsNotUnique));
+    });
+}
 
-        hiring_workflow.execute()
+#[test]
+fn add_opening_template_fails_with_invalid_policy_commitment() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        AddOpeningTemplateFixture::default()
+            .with_policy_commitment(OpeningPolicyCommitment {
+                terminate_role_stake_unstaking_period: Some(0),
+                ..OpeningPolicyCommitment::default()
+            })
+            .call_and_assert(Err(Error::TerminateRoleStakeUnstakingPeriodIsZero));
+    });
+}
+
+#[test]
+fn update_opening_template_succeeds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
+
+        UpdateOpeningTemplateFixture::default_for_template_id(template_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::OpeningTemplateUpdated(template_id, 2));
+    });
+}
+
+#[test]
+fn update_opening_template_fails_with_invalid_template_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_template_id = 10;
+        UpdateOpeningTemplateFixture::default_for_template_id(invalid_template_id)
+            .call_and_assert(Err(Error::OpeningTemplateDoesNotExist));
+    });
+}
+
+#[test]
+fn update_opening_template_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default()
+            .with_opening_type(OpeningType::Leader)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        UpdateOpeningTemplateFixture::default_for_template_id(template_id)
+            .call_and_assert(Err(Error::RequireRootOrigin));
+    });
+}
+
+#[test]
+fn update_opening_template_fails_with_invalid_policy_commitment() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
+
+        UpdateOpeningTemplateFixture::default_for_template_id(template_id)
+            .with_policy_commitment(OpeningPolicyCommitment {
+                exit_role_stake_unstaking_period: Some(0),
+                ..OpeningPolicyCommitment::default()
+            })
+            .call_and_assert(Err(Error::ExitRoleStakeUnstakingPeriodIsZero));
+    });
+}
+
+#[test]
+fn remove_opening_template_succeeds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
+
+        let result =
+            TestWorkingGroup::remove_opening_template(RawOrigin::Signed(1).into(), template_id);
+        assert_eq!(result, Ok(()));
+
+        assert!(!<crate::OpeningTemplateById<Test, TestWorkingGroupInstance>>::exists(template_id));
+        EventFixture::assert_last_crate_event(RawEvent::OpeningTemplateRemoved(template_id));
+
+        // Template name can be reused.
+        AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn remove_opening_template_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
+
+        let result =
+            TestWorkingGroup::remove_opening_template(RawOrigin::Signed(2).into(), template_id);
+        assert_eq!(result, Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn add_opening_from_template_succeeds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default()
+            .with_policy_commitment(OpeningPolicyCommitment {
+                max_review_period_length: 10,
+                terminate_role_stake_unstaking_period: Some(5),
+                ..OpeningPolicyCommitment::default()
+            })
+            .call_and_assert(Ok(()));
+
+        let opening_id = AddOpeningFromTemplateFixture::default_for_template_id(template_id)
+            .with_overrides(OpeningPolicyCommitmentOverrides {
+                max_review_period_length: Some(20),
+                terminate_role_stake_unstaking_period: Some(None),
+                ..OpeningPolicyCommitmentOverrides::default()
+            })
+            .call_and_assert(Ok(()));
+
+        let commitment = TestWorkingGroup::opening_by_id(opening_id).policy_commitment;
+        assert_eq!(commitment.max_review_period_length, 20);
+        assert_eq!(commitment.terminate_role_stake_unstaking_period, None);
+
+        EventFixture::assert_last_crate_event(RawEvent::OpeningAdded(opening_id));
+    });
+}
+
+#[test]
+fn add_opening_from_template_succeeds_for_leader_openings() {
+    build_test_externalities().execute_with(|| {
+        let template_id = AddOpeningTemplateFixture::default()
+            .with_opening_type(OpeningType::Leader)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        AddOpeningFromTemplateFixture::default_for_template_id(template_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn add_opening_from_template_fails_with_invalid_template_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_template_id = 10;
+        AddOpeningFromTemplateFixture::default_for_template_id(invalid_template_id)
+            .call_and_assert(Err(Error::OpeningTemplateDoesNotExist));
+    });
+}
+
+#[test]
+fn add_opening_from_template_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
+
+        AddOpeningFromTemplateFixture::default_for_template_id(template_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::IsNotLeadAccount));
+    });
+}
+
+#[test]
+fn add_opening_from_template_fails_with_outdated_template_version() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
+
+        UpdateOpeningTemplateFixture::default_for_template_id(template_id).call_and_assert(Ok(()));
+
+        AddOpeningFromTemplateFixture::default_for_template_id(template_id)
+            .with_template_version(1)
+            .call_and_assert(Err(Error::OpeningTemplateVersionMismatch));
+
+        AddOpeningFromTemplateFixture::default_for_template_id(template_id)
+            .with_template_version(2)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn add_opening_from_template_fails_with_invalid_overrides() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let template_id = AddOpeningTemplateFixture::default().call_and_assert(Ok(()));
+
+        AddOpeningFromTemplateFixture::default_for_template_id(template_id)
+            .with_overrides(OpeningPolicyCommitmentOverrides {
+                exit_role_application_stake_unstaking_period: Some(Some(0)),
+                ..OpeningPolicyCommitmentOverrides::default()
+            })
+            .call_and_assert(Err(Error::ExitRoleApplicationStakeUnstakingPeriodIsZero));
     });
 }
@@ -1,7 +1,10 @@
 #![warn(missing_docs)]
 
 use codec::{Decode, Encode};
+use rstd::collections::btree_map::BTreeMap;
 use rstd::collections::btree_set::BTreeSet;
+use rstd::vec::Vec;
+use sr_primitives::traits::Saturating;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -79,6 +82,119 @@ pub struct OpeningPolicyCommitment<BlockNumber, Balance> {
     pub exit_role_stake_unstaking_period: Option<BlockNumber>,
 }
 
+/// Overrides of the opening policy commitment. Fields set to `None` keep the values
+/// of the overridden commitment.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpeningPolicyCommitmentOverrides<BlockNumber, Balance> {
+    /// Rationing to be used override.
+    pub application_rationing_policy: Option<Option<hiring::ApplicationRationingPolicy>>,
+
+    /// Maximum length of review period of applications override.
+    pub max_review_period_length: Option<BlockNumber>,
+
+    /// Staking policy for application override.
+    pub application_staking_policy: Option<Option<hiring::StakingPolicy<Balance, BlockNumber>>>,
+
+    /// Staking policy for role itself override.
+    pub role_staking_policy: Option<Option<hiring::StakingPolicy<Balance, BlockNumber>>>,
+
+    /// Slashing terms during role override.
+    pub role_slashing_terms: Option<SlashingTerms>,
+
+    /// When filling an opening: unstaking period for application stake of successful applicants override.
+    pub fill_opening_successful_applicant_application_stake_unstaking_period:
+        Option<Option<BlockNumber>>,
+
+    /// When filling an opening: unstaking period for the application stake of failed applicants override.
+    pub fill_opening_failed_applicant_application_stake_unstaking_period:
+        Option<Option<BlockNumber>>,
+
+    /// When filling an opening: unstaking period for the role stake of failed applicants override.
+    pub fill_opening_failed_applicant_role_stake_unstaking_period: Option<Option<BlockNumber>>,
+
+    /// When terminating a worker: unstaking period for application stake override.
+    pub terminate_application_stake_unstaking_period: Option<Option<BlockNumber>>,
+
+    /// When terminating a worker/lead: unstaking period for role stake override.
+    pub terminate_role_stake_unstaking_period: Option<Option<BlockNumber>>,
+
+    /// When a worker/lead exits: unstaking period for application stake override.
+    pub exit_role_application_stake_unstaking_period: Option<Option<BlockNumber>>,
+
+    /// When a worker/lead exits: unstaking period for role stake override.
+    pub exit_role_stake_unstaking_period: Option<Option<BlockNumber>>,
+}
+
+impl<BlockNumber, Balance> OpeningPolicyCommitmentOverrides<BlockNumber, Balance> {
+    /// Applies the overrides to the commitment.
+    pub fn apply(
+        self,
+        commitment: OpeningPolicyCommitment<BlockNumber, Balance>,
+    ) -> OpeningPolicyCommitment<BlockNumber, Balance> {
+        OpeningPolicyCommitment {
+            application_rationing_policy: self
+                .application_rationing_policy
+                .unwrap_or(commitment.application_rationing_policy),
+            max_review_period_length: self
+                .max_review_period_length
+                .unwrap_or(commitment.max_review_period_length),
+            application_staking_policy: self
+                .application_staking_policy
+                .unwrap_or(commitment.application_staking_policy),
+            role_staking_policy: self
+                .role_staking_policy
+                .unwrap_or(commitment.role_staking_policy),
+            role_slashing_terms: self
+                .role_slashing_terms
+                .unwrap_or(commitment.role_slashing_terms),
+            fill_opening_successful_applicant_application_stake_unstaking_period: self
+                .fill_opening_successful_applicant_application_stake_unstaking_period
+                .unwrap_or(
+                    commitment.fill_opening_successful_applicant_application_stake_unstaking_period,
+                ),
+            fill_opening_failed_applicant_application_stake_unstaking_period: self
+                .fill_opening_failed_applicant_application_stake_unstaking_period
+                .unwrap_or(
+                    commitment.fill_opening_failed_applicant_application_stake_unstaking_period,
+                ),
+            fill_opening_failed_applicant_role_stake_unstaking_period: self
+                .fill_opening_failed_applicant_role_stake_unstaking_period
+                .unwrap_or(commitment.fill_opening_failed_applicant_role_stake_unstaking_period),
+            terminate_application_stake_unstaking_period: self
+                .terminate_application_stake_unstaking_period
+                .unwrap_or(commitment.terminate_application_stake_unstaking_period),
+            terminate_role_stake_unstaking_period: self
+                .terminate_role_stake_unstaking_period
+                .unwrap_or(commitment.terminate_role_stake_unstaking_period),
+            exit_role_application_stake_unstaking_period: self
+                .exit_role_application_stake_unstaking_period
+                .unwrap_or(commitment.exit_role_application_stake_unstaking_period),
+            exit_role_stake_unstaking_period: self
+                .exit_role_stake_unstaking_period
+                .unwrap_or(commitment.exit_role_stake_unstaking_period),
+        }
+    }
+}
+
+/// Named opening policy template of the working group. The template version is increased
+/// on each template policy update.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpeningTemplate<BlockNumber, Balance> {
+    /// Template name, unique within the working group.
+    pub name: Vec<u8>,
+
+    /// Template version.
+    pub version: u32,
+
+    /// Type of the openings created from the template: Leader or worker.
+    pub opening_type: OpeningType,
+
+    /// Policy commitment of the openings created from the template.
+    pub policy_commitment: OpeningPolicyCommitment<BlockNumber, Balance>,
+}
+
 /// An opening for a worker or lead role.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
@@ -222,6 +338,211 @@ impl<
     }
 }
 
+/// Structured evaluation of the worker/lead performance filed by the leader
+/// (or by the council for the leader).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct WorkerEvaluation<BlockNumber> {
+    /// Evaluation score: from zero to the max evaluation score.
+    pub score: u8,
+
+    /// Evaluation rationale.
+    pub rationale: Vec<u8>,
+
+    /// Block number of the evaluation.
+    pub evaluated_at: BlockNumber,
+}
+
+/// Work report submitted by the worker/lead.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct WorkReport<BlockNumber, Hash> {
+    /// Hashes of the content produced or processed during the reported work.
+    pub content_hashes: Vec<Hash>,
+
+    /// Work description.
+    pub description: Vec<u8>,
+
+    /// Block number of the report submission.
+    pub submitted_at: BlockNumber,
+}
+
+/// Temporary suspension of the worker/lead role.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct WorkerSuspension<BlockNumber> {
+    /// Block number of the suspension.
+    pub suspended_at: BlockNumber,
+
+    /// Block number when the worker/lead is resumed automatically.
+    pub ends_at: BlockNumber,
+
+    /// Suspension rationale.
+    pub rationale: Vec<u8>,
+
+    /// Scheduled payout of the reward relationship paused by the suspension.
+    /// None if the suspension did not pause the reward relationship.
+    pub paused_reward_payment_at: Option<BlockNumber>,
+}
+
+/// Worker/lead request to decrease the role stake.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct StakeDecreaseRequest<Balance, BlockNumber> {
+    /// Requested stake decrease amount.
+    pub amount: Balance,
+
+    /// Block number of the request.
+    pub requested_at: BlockNumber,
+
+    /// Block number when the approved stake decrease is released to the role account.
+    /// None while the request awaits the lead decision.
+    pub release_at: Option<BlockNumber>,
+}
+
+impl<Balance, BlockNumber> StakeDecreaseRequest<Balance, BlockNumber> {
+    /// Checks whether the request was approved by the lead.
+    pub fn is_approved(&self) -> bool {
+        self.release_at.is_some()
+    }
+}
+
+/// Delayed slashing of the worker/lead stake. It can be appealed by the worker/lead within
+/// the dispute period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct PendingSlash<Balance, BlockNumber, ThreadId> {
+    /// Slashing amount.
+    pub amount: Balance,
+
+    /// Slashing rationale.
+    pub rationale: Vec<u8>,
+
+    /// Block number of the slashing initiation.
+    pub initiated_at: BlockNumber,
+
+    /// Discussion thread of the worker/lead appeal. The slashing is paused while appealed.
+    pub appeal_thread_id: Option<ThreadId>,
+}
+
+impl<Balance, BlockNumber, ThreadId> PendingSlash<Balance, BlockNumber, ThreadId> {
+    /// Checks whether the slashing was appealed by the worker/lead.
+    pub fn is_appealed(&self) -> bool {
+        self.appeal_thread_id.is_some()
+    }
+}
+
+/// Bonus or time-limited extra reward of the worker/lead. It is paid from the working group
+/// mint by the separate reward relationship in addition to the regular role reward.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct ExtraReward<RewardRelationshipId, BlockNumber> {
+    /// Reward relationship paying the extra reward.
+    pub reward_relationship_id: RewardRelationshipId,
+
+    /// Block number of the last possible payout. The reward relationship is removed afterwards.
+    pub ends_at: BlockNumber,
+
+    /// Scheduled payout of the reward relationship paused by the worker/lead suspension.
+    /// None if the reward relationship is not paused.
+    pub paused_payment_at: Option<BlockNumber>,
+}
+
+/// Total of the bonuses and extra rewards granted within the current budget period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct GrantedExtraRewards<Balance, BlockNumber> {
+    /// Block number of the budget period start.
+    pub period_started_at: BlockNumber,
+
+    /// Total amount granted within the budget period.
+    pub amount: Balance,
+}
+
+/// Category of the working group mint spending.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpendingCategory {
+    /// Regular rewards of the workers.
+    WorkerReward,
+
+    /// Regular rewards of the leader.
+    LeaderReward,
+
+    /// Bonuses and extra rewards of the workers/lead.
+    ExtraReward,
+
+    /// Stake refunds to the workers/lead and applicants.
+    Refund,
+}
+
+/// Working group mint spending within the council term.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct SpendingReport<Balance, WorkerId: Ord> {
+    /// Total of the regular rewards paid to the workers.
+    pub worker_rewards: Balance,
+
+    /// Total of the regular rewards paid to the leader.
+    pub leader_rewards: Balance,
+
+    /// Total of the bonuses and extra rewards paid to the workers/lead.
+    pub extra_rewards: Balance,
+
+    /// Total of the rewards missed because of the insufficient mint capacity.
+    pub missed_rewards: Balance,
+
+    /// Total of the stakes refunded to the workers/lead and applicants.
+    pub refunds: Balance,
+
+    /// Total paid to each worker/lead.
+    pub spending_by_worker: BTreeMap<WorkerId, Balance>,
+}
+
+impl<Balance: Saturating + Copy + Default, WorkerId: Ord> SpendingReport<Balance, WorkerId> {
+    /// Accounts the paid reward in the report.
+    pub fn add_spending(
+        &mut self,
+        worker_id: WorkerId,
+        category: SpendingCategory,
+        amount: Balance,
+    ) {
+        self.add_category_spending(category, amount);
+
+        let worker_total = self.spending_by_worker.entry(worker_id).or_default();
+        *worker_total = worker_total.saturating_add(amount);
+    }
+
+    /// Accounts the stake refund in the report. Refunds are not accounted per worker/lead,
+    /// because the application stakes are refunded to the applicants.
+    pub fn add_refund(&mut self, amount: Balance) {
+        self.add_category_spending(SpendingCategory::Refund, amount);
+    }
+
+    /// Accounts the missed reward in the report.
+    pub fn add_missed_reward(&mut self, amount: Balance) {
+        self.missed_rewards = self.missed_rewards.saturating_add(amount);
+    }
+
+    /// Total paid within the council term.
+    pub fn total_spending(&self) -> Balance {
+        self.worker_rewards
+            .saturating_add(self.leader_rewards)
+            .saturating_add(self.extra_rewards)
+            .saturating_add(self.refunds)
+    }
+
+    fn add_category_spending(&mut self, category: SpendingCategory, amount: Balance) {
+        let category_total = match category {
+            SpendingCategory::WorkerReward => &mut self.worker_rewards,
+            SpendingCategory::LeaderReward => &mut self.leader_rewards,
+            SpendingCategory::ExtraReward => &mut self.extra_rewards,
+            SpendingCategory::Refund => &mut self.refunds,
+        };
+        *category_total = category_total.saturating_add(amount);
+    }
+}
+
 /// Origin of exit initiation.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Clone, PartialEq)]
@@ -0,0 +1,13 @@
+use crate::{AccountId, ForumWorkingGroup};
+
+/// Authorizes the forum working group lead and workers to moderate forum threads and posts.
+/// Suspended workers cannot moderate.
+pub struct ForumWorkingGroupModeratorValidator;
+
+impl forum::ForumModeratorValidator<AccountId> for ForumWorkingGroupModeratorValidator {
+    fn is_forum_moderator(account_id: &AccountId) -> bool {
+        ForumWorkingGroup::worker_ids_by_role_account_id(account_id)
+            .iter()
+            .any(|worker_id| !ForumWorkingGroup::is_worker_suspended(worker_id))
+    }
+}
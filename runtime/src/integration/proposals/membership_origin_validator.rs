@@ -18,7 +18,7 @@ impl<T: crate::members::Trait>
     for MembershipOriginValidator<T>
 {
     /// Check for valid combination of origin and actor_id. Actor_id should be valid member_id of
-    /// the membership module
+    /// the membership module. Suspended members are rejected.
     fn ensure_actor_origin(
         origin: <T as system::Trait>::Origin,
         actor_id: MemberId<T>,
@@ -32,6 +32,10 @@ impl<T: crate::members::Trait>
         if let Ok(profile) = profile_result {
             // whether the account_id belongs to the actor
             if profile.controller_account == account_id {
+                if profile.suspended {
+                    return Err("Membership validation failed: member is suspended");
+                }
+
                 return Ok(account_id);
             } else {
                 return Err("Membership validation failed: given account doesn't match with profile accounts");
@@ -106,6 +110,46 @@ mod tests {
         });
     }
 
+    #[test]
+    fn membership_origin_validator_fails_with_suspended_member() {
+        initial_test_ext().execute_with(|| {
+            let account_id = AccountId32::default();
+            let origin = RawOrigin::Signed(account_id.clone());
+            let error = "Membership validation failed: member is suspended";
+            let authority_account_id = AccountId32::default();
+            Membership::set_screening_authority(
+                RawOrigin::Root.into(),
+                authority_account_id.clone(),
+            )
+            .unwrap();
+
+            Membership::add_screened_member(
+                RawOrigin::Signed(authority_account_id.clone()).into(),
+                account_id,
+                UserInfo {
+                    handle: Some(b"handle".to_vec()),
+                    avatar_uri: None,
+                    about: None,
+                },
+            )
+            .unwrap();
+            let member_id = 0; // newly created member_id
+
+            Membership::suspend_member(
+                RawOrigin::Signed(authority_account_id).into(),
+                member_id,
+                b"rationale".to_vec(),
+                None,
+            )
+            .unwrap();
+
+            let validation_result =
+                MembershipOriginValidator::<Runtime>::ensure_actor_origin(origin.into(), member_id);
+
+            assert_eq!(validation_result, Err(error));
+        });
+    }
+
     #[test]
     fn membership_origin_validator_fails_with_incompatible_account_id_and_member_id() {
         initial_test_ext().execute_with(|| {
@@ -19,6 +19,7 @@ macro_rules! wrap_working_group_call {
     ($working_group:expr, $working_group_instance_call:expr) => {{
         match $working_group {
             WorkingGroup::Storage => Call::StorageWorkingGroup($working_group_instance_call),
+            WorkingGroup::Forum => Call::ForumWorkingGroup($working_group_instance_call),
         }
     }};
 }
@@ -119,6 +120,53 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     Wg::terminate_role_call(terminate_role_params)
                 )
             }
+            ProposalDetails::CancelWorkingGroupSlashing(worker_id, slash_id, working_group) => {
+                wrap_working_group_call!(
+                    working_group,
+                    Wg::create_cancel_slashing_call(worker_id, slash_id)
+                )
+            }
+            ProposalDetails::ResumeWorkingGroupSlashing(worker_id, slash_id, working_group) => {
+                wrap_working_group_call!(
+                    working_group,
+                    Wg::create_resume_slashing_call(worker_id, slash_id)
+                )
+            }
+            ProposalDetails::SuspendMember(member_id, rationale, ends_at) => Call::Members(
+                crate::members::Call::suspend_member(member_id, rationale, ends_at),
+            ),
+            ProposalDetails::UnsuspendMember(member_id) => {
+                Call::Members(crate::members::Call::unsuspend_member(member_id))
+            }
+            ProposalDetails::AddWorkingGroupLeaderOpeningTemplate(add_opening_template_params) => {
+                wrap_working_group_call!(
+                    add_opening_template_params.working_group,
+                    Wg::create_add_opening_template_call(add_opening_template_params)
+                )
+            }
+            ProposalDetails::UpdateWorkingGroupLeaderOpeningTemplate(
+                template_id,
+                commitment,
+                working_group,
+            ) => wrap_working_group_call!(
+                working_group,
+                Wg::create_update_opening_template_call(template_id, commitment)
+            ),
+            ProposalDetails::RemoveWorkingGroupLeaderOpeningTemplate(
+                template_id,
+                working_group,
+            ) => {
+                wrap_working_group_call!(
+                    working_group,
+                    Wg::create_remove_opening_template_call(template_id)
+                )
+            }
+            ProposalDetails::AddWorkingGroupLeaderOpeningFromTemplate(
+                add_opening_from_template_params,
+            ) => wrap_working_group_call!(
+                add_opening_from_template_params.working_group,
+                Wg::create_add_opening_from_template_call(add_opening_from_template_params)
+            ),
         };
 
         call.encode()
@@ -218,4 +266,68 @@ where
             terminate_role_params.slash,
         )
     }
+
+    // Generic call constructor for the working group 'cancel slashing'.
+    fn create_cancel_slashing_call(
+        worker_id: working_group::WorkerId<T>,
+        slash_id: working_group::SlashId<T>,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::cancel_slashing(worker_id, slash_id)
+    }
+
+    // Generic call constructor for the working group 'resume slashing'.
+    fn create_resume_slashing_call(
+        worker_id: working_group::WorkerId<T>,
+        slash_id: working_group::SlashId<T>,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::resume_slashing(worker_id, slash_id)
+    }
+
+    // Generic call constructor for the add working group leader opening template.
+    fn create_add_opening_template_call(
+        add_opening_template_params: proposals_codex::AddOpeningTemplateParameters<
+            T::BlockNumber,
+            working_group::BalanceOf<T>,
+        >,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::add_opening_template(
+            add_opening_template_params.name,
+            OpeningType::Leader,
+            add_opening_template_params.commitment,
+        )
+    }
+
+    // Generic call constructor for the update working group leader opening template.
+    fn create_update_opening_template_call(
+        template_id: working_group::OpeningTemplateId,
+        commitment: working_group::OpeningPolicyCommitment<
+            T::BlockNumber,
+            working_group::BalanceOf<T>,
+        >,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::update_opening_template(template_id, commitment)
+    }
+
+    // Generic call constructor for the remove working group leader opening template.
+    fn create_remove_opening_template_call(
+        template_id: working_group::OpeningTemplateId,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::remove_opening_template(template_id)
+    }
+
+    // Generic call constructor for the add working group leader opening from the template.
+    fn create_add_opening_from_template_call(
+        add_opening_from_template_params: proposals_codex::AddOpeningFromTemplateParameters<
+            T::BlockNumber,
+            working_group::BalanceOf<T>,
+        >,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::add_opening_from_template(
+            add_opening_from_template_params.activate_at,
+            add_opening_from_template_params.template_id,
+            add_opening_from_template_params.template_version,
+            add_opening_from_template_params.overrides,
+            add_opening_from_template_params.human_readable_text,
+        )
+    }
 }
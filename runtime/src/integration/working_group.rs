@@ -1,15 +1,23 @@
 use rstd::marker::PhantomData;
+use rstd::vec::Vec;
 use srml_support::{StorageLinkedMap, StorageMap};
 
-use crate::StorageWorkingGroupInstance;
+use crate::{ForumWorkingGroupInstance, Runtime, StorageWorkingGroupInstance, ThreadId};
+use governance::election::CouncilElected;
 use stake::{BalanceOf, NegativeImbalance};
+use working_group::MemberId;
+
+type WorkingGroupModule<T, I> = working_group::Module<T, I>;
 
 pub struct StakingEventsHandler<T> {
     pub marker: PhantomData<T>,
 }
 
-impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
-    stake::StakingEventsHandler<T> for StakingEventsHandler<T>
+impl<
+        T: stake::Trait
+            + working_group::Trait<ForumWorkingGroupInstance>
+            + working_group::Trait<StorageWorkingGroupInstance>,
+    > stake::StakingEventsHandler<T> for StakingEventsHandler<T>
 {
     /// Unstake remaining sum back to the source_account_id
     fn unstaked(
@@ -24,26 +32,131 @@ impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
 
         let hiring_application_id = hiring::ApplicationIdByStakingId::<T>::get(*stake_id);
 
+        if working_group::MemberIdByHiringApplicationId::<T, ForumWorkingGroupInstance>::exists(
+            hiring_application_id,
+        ) {
+            return WorkingGroupModule::<T, ForumWorkingGroupInstance>::refund_working_group_stake(
+                *stake_id,
+                remaining_imbalance,
+            );
+        }
+
         if working_group::MemberIdByHiringApplicationId::<T, StorageWorkingGroupInstance>::exists(
             hiring_application_id,
         ) {
-            return <working_group::Module<T, StorageWorkingGroupInstance>>::refund_working_group_stake(
-				*stake_id,
-				remaining_imbalance,
-			);
+            return WorkingGroupModule::<T, StorageWorkingGroupInstance>::refund_working_group_stake(
+                *stake_id,
+                remaining_imbalance,
+            );
         }
 
         remaining_imbalance
     }
 
-    /// Empty handler for the slashing.
+    /// Removes the executed delayed slashing from the working groups pending slashes.
     fn slashed(
-        _: &<T as stake::Trait>::StakeId,
-        _: Option<<T as stake::Trait>::SlashId>,
+        stake_id: &<T as stake::Trait>::StakeId,
+        slash_id: Option<<T as stake::Trait>::SlashId>,
         _: BalanceOf<T>,
         _: BalanceOf<T>,
         remaining_imbalance: NegativeImbalance<T>,
     ) -> NegativeImbalance<T> {
+        // Immediate slashing is not tracked by the working groups.
+        if let Some(slash_id) = slash_id {
+            <working_group::Module<T, ForumWorkingGroupInstance>>::on_stake_slashed(
+                *stake_id, slash_id,
+            );
+            <working_group::Module<T, StorageWorkingGroupInstance>>::on_stake_slashed(
+                *stake_id, slash_id,
+            );
+        }
+
         remaining_imbalance
     }
 }
+
+/// Opens the working group slashing appeal discussions in the proposals discussion module.
+pub struct SlashingAppealDiscussion;
+
+impl working_group::SlashingAppealDiscussion<MemberId<Runtime>, ThreadId>
+    for SlashingAppealDiscussion
+{
+    fn create_appeal_thread(
+        author_id: MemberId<Runtime>,
+        title: Vec<u8>,
+    ) -> Result<ThreadId, &'static str> {
+        <proposals_discussion::Module<Runtime>>::create_thread(author_id, title)
+            .map_err(|err| err.into())
+    }
+}
+
+/// Accounts the working group rewards payouts in the working group spending reports.
+pub struct PayoutStatusHandler;
+
+impl recurringrewards::PayoutStatusHandler<Runtime> for PayoutStatusHandler {
+    fn payout_succeeded(
+        id: <Runtime as recurringrewards::Trait>::RewardRelationshipId,
+        _destination_account: &<Runtime as system::Trait>::AccountId,
+        amount: minting::BalanceOf<Runtime>,
+    ) {
+        <working_group::Module<Runtime, ForumWorkingGroupInstance>>::on_reward_payout_succeeded(
+            id, amount,
+        );
+        <working_group::Module<Runtime, StorageWorkingGroupInstance>>::on_reward_payout_succeeded(
+            id, amount,
+        );
+    }
+
+    fn payout_failed(
+        id: <Runtime as recurringrewards::Trait>::RewardRelationshipId,
+        _destination_account: &<Runtime as system::Trait>::AccountId,
+        amount: minting::BalanceOf<Runtime>,
+    ) {
+        <working_group::Module<Runtime, ForumWorkingGroupInstance>>::on_reward_payout_failed(
+            id, amount,
+        );
+        <working_group::Module<Runtime, StorageWorkingGroupInstance>>::on_reward_payout_failed(
+            id, amount,
+        );
+    }
+
+    fn arrears_settled(
+        id: <Runtime as recurringrewards::Trait>::RewardRelationshipId,
+        _destination_account: &<Runtime as system::Trait>::AccountId,
+        settled_amount: minting::BalanceOf<Runtime>,
+        _remaining_arrears: minting::BalanceOf<Runtime>,
+    ) {
+        <working_group::Module<Runtime, ForumWorkingGroupInstance>>::on_reward_payout_succeeded(
+            id,
+            settled_amount,
+        );
+        <working_group::Module<Runtime, StorageWorkingGroupInstance>>::on_reward_payout_succeeded(
+            id,
+            settled_amount,
+        );
+    }
+
+    fn relationship_completed(
+        _id: <Runtime as recurringrewards::Trait>::RewardRelationshipId,
+        _destination_account: &<Runtime as system::Trait>::AccountId,
+        _total_reward_received: minting::BalanceOf<Runtime>,
+    ) {
+    }
+}
+
+/// 'Council elected' event handler. Starts the new council term of the working groups
+/// spending reports.
+pub struct CouncilElectedHandler;
+
+impl<Elected, Term> CouncilElected<Elected, Term> for CouncilElectedHandler {
+    fn council_elected(_new_council: Elected, _term: Term) {
+        let council_term = <governance::election::Module<Runtime>>::round();
+
+        <working_group::Module<Runtime, ForumWorkingGroupInstance>>::start_council_term(
+            council_term,
+        );
+        <working_group::Module<Runtime, StorageWorkingGroupInstance>>::start_council_term(
+            council_term,
+        );
+    }
+}
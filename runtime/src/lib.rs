@@ -161,7 +161,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 6,
-    spec_version: 21,
+    spec_version: 22,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
 };
@@ -509,13 +509,18 @@ impl hiring::Trait for Runtime {
     type StakeHandlerProvider = hiring::Module<Self>;
 }
 
+parameter_types! {
+    pub const MaxMintLedgerEntries: u32 = 100;
+}
+
 impl minting::Trait for Runtime {
     type Currency = <Self as common::currency::GovernanceCurrency>::Currency;
     type MintId = u64;
+    type MaxMintLedgerEntries = MaxMintLedgerEntries;
 }
 
 impl recurringrewards::Trait for Runtime {
-    type PayoutStatusHandler = (); // TODO - deal with successful and failed payouts
+    type PayoutStatusHandler = integration::working_group::PayoutStatusHandler;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
 }
@@ -534,6 +539,7 @@ impl stake::Trait for Runtime {
             crate::integration::working_group::StakingEventsHandler<Self>,
         ),
     );
+    type StakingBackend = stake::PoolAccountBackend;
     type StakeId = u64;
     type SlashId = u64;
 }
@@ -548,7 +554,13 @@ impl common::currency::GovernanceCurrency for Runtime {
 
 impl governance::election::Trait for Runtime {
     type Event = Event;
-    type CouncilElected = (Council, integration::proposals::CouncilElectedHandler);
+    type CouncilElected = (
+        Council,
+        (
+            integration::proposals::CouncilElectedHandler,
+            integration::working_group::CouncilElectedHandler,
+        ),
+    );
 }
 
 impl governance::council::Trait for Runtime {
@@ -574,6 +586,7 @@ impl storage::data_directory::Trait for Runtime {
     type ContentId = ContentId;
     type StorageProviderHelper = integration::storage::StorageProviderHelper;
     type IsActiveDataObjectType = DataObjectTypeRegistry;
+    type DataObjectTypeConstraintsProvider = DataObjectTypeRegistry;
     type MemberOriginValidator = MembershipOriginValidator<Self>;
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
 }
@@ -609,7 +622,7 @@ pub struct ShimMembershipRegistry {}
 
 impl forum::ForumUserRegistry<AccountId> for ShimMembershipRegistry {
     fn get_forum_user(id: &AccountId) -> Option<forum::ForumUser<AccountId>> {
-        if members::Module::<Runtime>::is_member_account(id) {
+        if members::Module::<Runtime>::is_unsuspended_member_account(id) {
             // For now we don't retreive the members profile since it is not used for anything,
             // but in the future we may need it to read out more
             // information possibly required to construct a
@@ -626,6 +639,7 @@ impl forum::ForumUserRegistry<AccountId> for ShimMembershipRegistry {
 impl forum::Trait for Runtime {
     type Event = Event;
     type MembershipRegistry = ShimMembershipRegistry;
+    type ModeratorValidator = integration::forum::ForumWorkingGroupModeratorValidator;
     type ThreadId = ThreadId;
     type PostId = PostId;
 }
@@ -633,16 +647,40 @@ impl forum::Trait for Runtime {
 impl migration::Trait for Runtime {
     type Event = Event;
 }
+// The forum working group instance alias.
+pub type ForumWorkingGroupInstance = working_group::Instance1;
+
 // The storage working group instance alias.
 pub type StorageWorkingGroupInstance = working_group::Instance2;
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
+    pub const MaxWorkerHistoryLength: u32 = 50;
+    pub const MaxWorkerSuspensionPeriod: BlockNumber = 30 * DAYS;
+    pub const SlashingDisputePeriod: BlockNumber = 7 * DAYS;
+    pub const ExtraRewardsBudgetPeriod: BlockNumber = 30 * DAYS;
+}
+
+impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
+    type Event = Event;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxWorkerHistoryLength = MaxWorkerHistoryLength;
+    type MaxWorkerSuspensionPeriod = MaxWorkerSuspensionPeriod;
+    type SlashingDisputePeriod = SlashingDisputePeriod;
+    type ExtraRewardsBudgetPeriod = ExtraRewardsBudgetPeriod;
+    type SlashingAppealThreadId = ThreadId;
+    type SlashingAppealDiscussion = integration::working_group::SlashingAppealDiscussion;
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type MaxWorkerHistoryLength = MaxWorkerHistoryLength;
+    type MaxWorkerSuspensionPeriod = MaxWorkerSuspensionPeriod;
+    type SlashingDisputePeriod = SlashingDisputePeriod;
+    type ExtraRewardsBudgetPeriod = ExtraRewardsBudgetPeriod;
+    type SlashingAppealThreadId = ThreadId;
+    type SlashingAppealDiscussion = integration::working_group::SlashingAppealDiscussion;
 }
 
 impl service_discovery::Trait for Runtime {
@@ -754,7 +792,7 @@ construct_runtime!(
         ProposalsDiscussion: proposals_discussion::{Module, Call, Storage, Event<T>},
         ProposalsCodex: proposals_codex::{Module, Call, Storage, Error, Config<T>},
         // --- Working groups
-        // reserved for the future use: ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Event<T>},
+        ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Config<T>, Error, Event<T>},
         StorageWorkingGroup: working_group::<Instance2>::{Module, Call, Storage, Config<T>, Error, Event<T>},
     }
 );
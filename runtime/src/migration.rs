@@ -2,13 +2,21 @@
 #![allow(clippy::redundant_closure_call)] // disable it because of the substrate lib design
 
 use crate::VERSION;
+use codec::{Codec, Decode, Encode};
+use common::BlockAndTime;
 use proposals_codex::ProposalsConfigParameters;
+use rstd::collections::btree_set::BTreeSet;
 use rstd::prelude::*;
 use sr_primitives::{
     print,
     traits::{One, Zero},
 };
-use srml_support::{debug, decl_event, decl_module, decl_storage};
+use srml_support::storage::unhashed;
+use srml_support::{
+    debug, decl_event, decl_module, decl_storage, StorageLinkedMap, StorageMap, StorageValue,
+};
+use storage::data_directory::{DataObjectInternal, LiaisonJudgement};
+use storage::data_object_type_registry::{DataObjectType, DataObjectTypeOf};
 
 impl<T: Trait> Module<T> {
     /// This method is called from on_initialize() when a runtime upgrade is detected. This
@@ -23,21 +31,249 @@ impl<T: Trait> Module<T> {
         // have been initialized with config() or build() chainspec construction mechanism.
         // Other tasks like resetting values, migrating values etc.
 
-        Self::initialize_storage_working_group_mint();
-        Self::initialize_storage_working_group_text_constraints();
-        Self::clear_storage_data();
+        // The forum working group is initialized once, on the upgrade which introduced it.
+        if !<working_group::Mint<T, working_group::Instance1>>::exists() {
+            Self::initialize_forum_working_group_mint();
+            Self::initialize_forum_working_group_text_constraints();
+        }
+
+        Self::migrate_data_objects_chunking_metadata();
+        Self::migrate_data_object_types_constraints();
+
+        minting::Module::<T>::migrate_mints_lifetime_limit();
+        minting::Module::<T>::schedule_existing_adjustments();
+        // Openings must be migrated before the hiring module reads them.
+        Self::migrate_hiring_openings_applicant_ranking();
+        Self::migrate_working_group_openings_applicant_ranking::<working_group::Instance1>();
+        Self::migrate_working_group_openings_applicant_ranking::<working_group::Instance2>();
+        Self::migrate_curator_openings_applicant_ranking();
+        hiring::Module::<T>::schedule_existing_opening_transitions();
+        recurringrewards::Module::<T>::migrate_reward_relationships_end_conditions();
+        recurringrewards::Module::<T>::schedule_existing_payouts();
+        stake::Module::<T>::migrate_stakes_due_blocks();
+        working_group::Module::<T, working_group::Instance1>::index_existing_reward_relationship_owners();
+        working_group::Module::<T, working_group::Instance2>::index_existing_reward_relationship_owners();
+        working_group::Module::<T, working_group::Instance2>::index_existing_worker_role_accounts();
 
         proposals_codex::Module::<T>::set_config_values(ProposalsConfigParameters::default());
     }
 }
 
+/// Data object layout before the content chunking metadata and MIME type were introduced.
+#[derive(Encode, Decode)]
+struct LegacyDataObject<T: Trait> {
+    owner: <T as membership::members::Trait>::MemberId,
+    added_at: BlockAndTime<T::BlockNumber, <T as timestamp::Trait>::Moment>,
+    type_id: <T as storage::data_object_type_registry::Trait>::DataObjectTypeId,
+    size: u64,
+    liaison: storage::StorageProviderId<T>,
+    liaison_judgement: LiaisonJudgement,
+    ipfs_content_id: Vec<u8>,
+}
+
+/// Data object type layout before the data object type constraints were introduced.
+#[derive(Encode, Decode)]
+struct LegacyDataObjectType {
+    description: Vec<u8>,
+    active: bool,
+}
+
+/// Linkage of the linked map values: it is saved next to the value itself.
+#[derive(Encode, Decode)]
+struct LegacyLinkage<Key> {
+    previous: Option<Key>,
+    next: Option<Key>,
+}
+
+/// Application rationing policy layout before the applicant ranking was introduced.
+#[derive(Encode, Decode)]
+struct LegacyApplicationRationingPolicy {
+    max_active_applicants: u32,
+}
+
+impl From<LegacyApplicationRationingPolicy> for hiring::ApplicationRationingPolicy {
+    fn from(legacy: LegacyApplicationRationingPolicy) -> Self {
+        hiring::ApplicationRationingPolicy {
+            max_active_applicants: legacy.max_active_applicants,
+            applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
+        }
+    }
+}
+
+/// Hiring opening layout before the applicant ranking was introduced.
+#[derive(Encode, Decode)]
+struct LegacyHiringOpening<Balance, BlockNumber, ApplicationId> {
+    created: BlockNumber,
+    stage: hiring::OpeningStage<BlockNumber, ApplicationId>,
+    max_review_period_length: BlockNumber,
+    application_rationing_policy: Option<LegacyApplicationRationingPolicy>,
+    application_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    role_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    human_readable_text: Vec<u8>,
+}
+
+impl<Balance, BlockNumber, ApplicationId>
+    From<LegacyHiringOpening<Balance, BlockNumber, ApplicationId>>
+    for hiring::Opening<Balance, BlockNumber, ApplicationId>
+{
+    fn from(legacy: LegacyHiringOpening<Balance, BlockNumber, ApplicationId>) -> Self {
+        hiring::Opening {
+            created: legacy.created,
+            stage: legacy.stage,
+            max_review_period_length: legacy.max_review_period_length,
+            application_rationing_policy: legacy.application_rationing_policy.map(Into::into),
+            application_staking_policy: legacy.application_staking_policy,
+            role_staking_policy: legacy.role_staking_policy,
+            human_readable_text: legacy.human_readable_text,
+        }
+    }
+}
+
+/// Working group opening policy commitment layout before the applicant ranking was introduced.
+#[derive(Encode, Decode)]
+struct LegacyWorkingGroupOpeningPolicyCommitment<BlockNumber, Balance> {
+    application_rationing_policy: Option<LegacyApplicationRationingPolicy>,
+    max_review_period_length: BlockNumber,
+    application_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    role_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    role_slashing_terms: working_group::SlashingTerms,
+    fill_opening_successful_applicant_application_stake_unstaking_period: Option<BlockNumber>,
+    fill_opening_failed_applicant_application_stake_unstaking_period: Option<BlockNumber>,
+    fill_opening_failed_applicant_role_stake_unstaking_period: Option<BlockNumber>,
+    terminate_application_stake_unstaking_period: Option<BlockNumber>,
+    terminate_role_stake_unstaking_period: Option<BlockNumber>,
+    exit_role_application_stake_unstaking_period: Option<BlockNumber>,
+    exit_role_stake_unstaking_period: Option<BlockNumber>,
+}
+
+impl<BlockNumber, Balance> From<LegacyWorkingGroupOpeningPolicyCommitment<BlockNumber, Balance>>
+    for working_group::OpeningPolicyCommitment<BlockNumber, Balance>
+{
+    fn from(legacy: LegacyWorkingGroupOpeningPolicyCommitment<BlockNumber, Balance>) -> Self {
+        working_group::OpeningPolicyCommitment {
+            application_rationing_policy: legacy.application_rationing_policy.map(Into::into),
+            max_review_period_length: legacy.max_review_period_length,
+            application_staking_policy: legacy.application_staking_policy,
+            role_staking_policy: legacy.role_staking_policy,
+            role_slashing_terms: legacy.role_slashing_terms,
+            fill_opening_successful_applicant_application_stake_unstaking_period: legacy
+                .fill_opening_successful_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_application_stake_unstaking_period: legacy
+                .fill_opening_failed_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_role_stake_unstaking_period: legacy
+                .fill_opening_failed_applicant_role_stake_unstaking_period,
+            terminate_application_stake_unstaking_period: legacy
+                .terminate_application_stake_unstaking_period,
+            terminate_role_stake_unstaking_period: legacy.terminate_role_stake_unstaking_period,
+            exit_role_application_stake_unstaking_period: legacy
+                .exit_role_application_stake_unstaking_period,
+            exit_role_stake_unstaking_period: legacy.exit_role_stake_unstaking_period,
+        }
+    }
+}
+
+/// Working group opening layout before the applicant ranking was introduced.
+#[derive(Encode, Decode)]
+struct LegacyWorkingGroupOpening<OpeningId, BlockNumber, Balance, ApplicationId: Ord> {
+    hiring_opening_id: OpeningId,
+    applications: BTreeSet<ApplicationId>,
+    policy_commitment: LegacyWorkingGroupOpeningPolicyCommitment<BlockNumber, Balance>,
+    opening_type: working_group::OpeningType,
+}
+
+impl<OpeningId, BlockNumber, Balance, ApplicationId: Ord>
+    From<LegacyWorkingGroupOpening<OpeningId, BlockNumber, Balance, ApplicationId>>
+    for working_group::Opening<OpeningId, BlockNumber, Balance, ApplicationId>
+{
+    fn from(
+        legacy: LegacyWorkingGroupOpening<OpeningId, BlockNumber, Balance, ApplicationId>,
+    ) -> Self {
+        working_group::Opening {
+            hiring_opening_id: legacy.hiring_opening_id,
+            applications: legacy.applications,
+            policy_commitment: legacy.policy_commitment.into(),
+            opening_type: legacy.opening_type,
+        }
+    }
+}
+
+/// Curator opening policy commitment layout before the applicant ranking was introduced.
+#[derive(Encode, Decode)]
+struct LegacyCuratorOpeningPolicyCommitment<BlockNumber, Balance> {
+    application_rationing_policy: Option<LegacyApplicationRationingPolicy>,
+    max_review_period_length: BlockNumber,
+    application_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    role_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    role_slashing_terms: content_working_group::SlashingTerms,
+    fill_opening_successful_applicant_application_stake_unstaking_period: Option<BlockNumber>,
+    fill_opening_failed_applicant_application_stake_unstaking_period: Option<BlockNumber>,
+    fill_opening_failed_applicant_role_stake_unstaking_period: Option<BlockNumber>,
+    terminate_curator_application_stake_unstaking_period: Option<BlockNumber>,
+    terminate_curator_role_stake_unstaking_period: Option<BlockNumber>,
+    exit_curator_role_application_stake_unstaking_period: Option<BlockNumber>,
+    exit_curator_role_stake_unstaking_period: Option<BlockNumber>,
+}
+
+impl<BlockNumber, Balance> From<LegacyCuratorOpeningPolicyCommitment<BlockNumber, Balance>>
+    for content_working_group::OpeningPolicyCommitment<BlockNumber, Balance>
+{
+    fn from(legacy: LegacyCuratorOpeningPolicyCommitment<BlockNumber, Balance>) -> Self {
+        content_working_group::OpeningPolicyCommitment {
+            application_rationing_policy: legacy.application_rationing_policy.map(Into::into),
+            max_review_period_length: legacy.max_review_period_length,
+            application_staking_policy: legacy.application_staking_policy,
+            role_staking_policy: legacy.role_staking_policy,
+            role_slashing_terms: legacy.role_slashing_terms,
+            fill_opening_successful_applicant_application_stake_unstaking_period: legacy
+                .fill_opening_successful_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_application_stake_unstaking_period: legacy
+                .fill_opening_failed_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_role_stake_unstaking_period: legacy
+                .fill_opening_failed_applicant_role_stake_unstaking_period,
+            terminate_curator_application_stake_unstaking_period: legacy
+                .terminate_curator_application_stake_unstaking_period,
+            terminate_curator_role_stake_unstaking_period: legacy
+                .terminate_curator_role_stake_unstaking_period,
+            exit_curator_role_application_stake_unstaking_period: legacy
+                .exit_curator_role_application_stake_unstaking_period,
+            exit_curator_role_stake_unstaking_period: legacy
+                .exit_curator_role_stake_unstaking_period,
+        }
+    }
+}
+
+/// Curator opening layout before the applicant ranking was introduced.
+#[derive(Encode, Decode)]
+struct LegacyCuratorOpening<OpeningId, BlockNumber, Balance, ApplicationId: Ord> {
+    opening_id: OpeningId,
+    curator_applications: BTreeSet<ApplicationId>,
+    policy_commitment: LegacyCuratorOpeningPolicyCommitment<BlockNumber, Balance>,
+}
+
+impl<OpeningId, BlockNumber, Balance, ApplicationId: Ord>
+    From<LegacyCuratorOpening<OpeningId, BlockNumber, Balance, ApplicationId>>
+    for content_working_group::CuratorOpening<OpeningId, BlockNumber, Balance, ApplicationId>
+{
+    fn from(legacy: LegacyCuratorOpening<OpeningId, BlockNumber, Balance, ApplicationId>) -> Self {
+        content_working_group::CuratorOpening {
+            opening_id: legacy.opening_id,
+            curator_applications: legacy.curator_applications,
+            policy_commitment: legacy.policy_commitment.into(),
+        }
+    }
+}
+
 pub trait Trait:
     system::Trait
     + minting::Trait
+    + hiring::Trait
+    + recurringrewards::Trait
+    + stake::Trait
     + proposals_codex::Trait
+    + working_group::Trait<working_group::Instance1>
     + working_group::Trait<working_group::Instance2>
+    + content_working_group::Trait
     + storage::data_directory::Trait
-    + storage::data_object_storage_registry::Trait
 {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 }
@@ -82,50 +318,191 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
-    fn initialize_storage_working_group_mint() {
-        let mint_id_result = <minting::Module<T>>::add_mint(<minting::BalanceOf<T>>::zero(), None);
+    fn initialize_forum_working_group_mint() {
+        let mint_id_result =
+            <minting::Module<T>>::add_mint(<minting::BalanceOf<T>>::zero(), None, None);
 
         if let Ok(mint_id) = mint_id_result {
-            <working_group::Mint<T, working_group::Instance2>>::put(mint_id);
+            <working_group::Mint<T, working_group::Instance1>>::put(mint_id);
         } else {
-            print("Failed to create a mint for the storage working group");
+            print("Failed to create a mint for the forum working group");
         }
     }
 
-    fn initialize_storage_working_group_text_constraints() {
-        <working_group::OpeningHumanReadableText<working_group::Instance2>>::put(
+    fn initialize_forum_working_group_text_constraints() {
+        <working_group::OpeningHumanReadableText<working_group::Instance1>>::put(
             working_group::default_text_constraint(),
         );
-        <working_group::WorkerApplicationHumanReadableText<working_group::Instance2>>::put(
+        <working_group::WorkerApplicationHumanReadableText<working_group::Instance1>>::put(
             working_group::default_text_constraint(),
         );
-        <working_group::WorkerExitRationaleText<working_group::Instance2>>::put(
+        <working_group::WorkerExitRationaleText<working_group::Instance1>>::put(
             working_group::default_text_constraint(),
         );
     }
 
-    fn clear_storage_data() {
-        // Clear storage data object registry data.
-        for id in <storage::data_directory::Module<T>>::known_content_ids() {
-            <storage::data_object_storage_registry::RelationshipsByContentId<T>>::remove(id);
+    // Decodes the value saved with the legacy layout. Values already saved with the new layout
+    // are longer than their legacy encoding and are skipped.
+    fn decode_legacy_value<V: Decode>(key: &[u8]) -> Option<V> {
+        let raw_value = unhashed::get_raw(key)?;
+        let mut input = &raw_value[..];
+
+        let value = V::decode(&mut input).ok()?;
+
+        if input.is_empty() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    // Converts data objects saved before the content chunking metadata and MIME type were
+    // introduced. Legacy objects are represented as a single chunk without the known Merkle root,
+    // content hash and MIME type.
+    fn migrate_data_objects_chunking_metadata() {
+        for content_id in <storage::data_directory::Module<T>>::known_content_ids() {
+            let key =
+                <storage::data_directory::DataObjectByContentId<T>>::hashed_key_for(content_id);
+
+            if let Some(legacy) = Self::decode_legacy_value::<LegacyDataObject<T>>(&key) {
+                let data_object = DataObjectInternal {
+                    owner: legacy.owner,
+                    added_at: legacy.added_at,
+                    type_id: legacy.type_id,
+                    size: legacy.size,
+                    mime_type: Vec::new(),
+                    liaison: legacy.liaison,
+                    liaison_judgement: legacy.liaison_judgement,
+                    ipfs_content_id: legacy.ipfs_content_id,
+                    content_merkle_root: T::Hash::default(),
+                    chunk_size: legacy.size,
+                    content_hash: None,
+                };
+
+                <storage::data_directory::DataObjectByContentId<T>>::insert(
+                    content_id,
+                    data_object,
+                );
+            }
         }
+    }
+
+    // Converts data object types saved before the constraints were introduced. Legacy types get
+    // permissive constraints.
+    fn migrate_data_object_types_constraints() {
+        let mut type_id =
+            <storage::data_object_type_registry::Module<T>>::first_data_object_type_id();
+        let next_type_id =
+            <storage::data_object_type_registry::Module<T>>::next_data_object_type_id();
 
-        let mut potential_id = <T as storage::data_object_storage_registry::Trait>::DataObjectStorageRelationshipId::zero();
-        while potential_id
-            < storage::data_object_storage_registry::Module::<T>::next_relationship_id()
+        while type_id < next_type_id {
+            let key =
+                <storage::data_object_type_registry::DataObjectTypes<T>>::hashed_key_for(type_id);
+
+            if let Some(legacy) = Self::decode_legacy_value::<LegacyDataObjectType>(&key) {
+                let data_object_type: DataObjectTypeOf<T> = DataObjectType {
+                    description: legacy.description,
+                    active: legacy.active,
+                    constraints: Default::default(),
+                };
+
+                <storage::data_object_type_registry::DataObjectTypes<T>>::insert(
+                    type_id,
+                    data_object_type,
+                );
+            }
+
+            type_id += One::one();
+        }
+    }
+
+    // Converts a linked map value saved with the legacy layout. The linkage saved next to
+    // the value is kept as is.
+    fn migrate_linked_map_value<Key: Codec, Legacy: Decode, Value: Encode + From<Legacy>>(
+        key: &[u8],
+    ) {
+        if let Some((legacy, linkage)) =
+            Self::decode_legacy_value::<(Legacy, LegacyLinkage<Key>)>(key)
         {
-            <storage::data_object_storage_registry::Relationships<T>>::remove(&potential_id);
+            unhashed::put(key, &(Value::from(legacy), linkage));
+        }
+    }
+
+    // Converts hiring openings saved before the applicant ranking was introduced. Legacy
+    // rationing policies rank the applicants by stake, as they did before.
+    fn migrate_hiring_openings_applicant_ranking() {
+        let mut opening_id = T::OpeningId::zero();
+        let next_opening_id = <hiring::Module<T>>::next_opening_id();
 
-            potential_id += <T as storage::data_object_storage_registry::Trait>::DataObjectStorageRelationshipId::one();
+        while opening_id < next_opening_id {
+            let key = <hiring::OpeningById<T>>::hashed_key_for(opening_id);
+
+            Self::migrate_linked_map_value::<
+                T::OpeningId,
+                LegacyHiringOpening<hiring::BalanceOf<T>, T::BlockNumber, T::ApplicationId>,
+                hiring::Opening<hiring::BalanceOf<T>, T::BlockNumber, T::ApplicationId>,
+            >(&key);
+
+            opening_id += One::one();
         }
+    }
+
+    // Converts working group openings saved before the applicant ranking was introduced.
+    fn migrate_working_group_openings_applicant_ranking<I: working_group::Instance>()
+    where
+        T: working_group::Trait<I>,
+    {
+        let mut opening_id = T::OpeningId::zero();
+        let next_opening_id = <working_group::Module<T, I>>::next_opening_id();
+
+        while opening_id < next_opening_id {
+            let key = <working_group::OpeningById<T, I>>::hashed_key_for(opening_id);
 
-        storage::data_object_storage_registry::NextRelationshipId::<T>::kill();
+            Self::migrate_linked_map_value::<
+                T::OpeningId,
+                LegacyWorkingGroupOpening<
+                    T::OpeningId,
+                    T::BlockNumber,
+                    working_group::BalanceOf<T>,
+                    T::ApplicationId,
+                >,
+                working_group::Opening<
+                    T::OpeningId,
+                    T::BlockNumber,
+                    working_group::BalanceOf<T>,
+                    T::ApplicationId,
+                >,
+            >(&key);
 
-        // Clear storage data directory data.
-        for id in <storage::data_directory::Module<T>>::known_content_ids() {
-            <storage::data_directory::DataObjectByContentId<T>>::remove(id);
+            opening_id += One::one();
         }
+    }
+
+    // Converts curator openings saved before the applicant ranking was introduced.
+    fn migrate_curator_openings_applicant_ranking() {
+        let mut opening_id = T::OpeningId::zero();
+        let next_opening_id = <content_working_group::Module<T>>::next_curator_opening_id();
+
+        while opening_id < next_opening_id {
+            let key = <content_working_group::CuratorOpeningById<T>>::hashed_key_for(opening_id);
 
-        <storage::data_directory::KnownContentIds<T>>::kill();
+            Self::migrate_linked_map_value::<
+                T::OpeningId,
+                LegacyCuratorOpening<
+                    T::OpeningId,
+                    T::BlockNumber,
+                    content_working_group::BalanceOf<T>,
+                    T::ApplicationId,
+                >,
+                content_working_group::CuratorOpening<
+                    T::OpeningId,
+                    T::BlockNumber,
+                    content_working_group::BalanceOf<T>,
+                    T::ApplicationId,
+                >,
+            >(&key);
+
+            opening_id += One::one();
+        }
     }
 }
@@ -0,0 +1,98 @@
+use super::initial_test_ext;
+use crate::integration::forum::ForumWorkingGroupModeratorValidator;
+use crate::{
+    AccountId, ForumWorkingGroup, ForumWorkingGroupInstance, Runtime, StorageWorkingGroup,
+};
+
+use forum::ForumModeratorValidator;
+use srml_support::{StorageLinkedMap, StorageValue};
+use working_group::{Worker, WorkerSuspension};
+
+#[test]
+fn forum_working_group_moderator_validator_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let lead_account_id: AccountId = [1; 32].into();
+        let worker_account_id: AccountId = [2; 32].into();
+        let not_worker_account_id: AccountId = [3; 32].into();
+
+        // Not a moderator - no workers.
+        assert!(!ForumWorkingGroupModeratorValidator::is_forum_moderator(
+            &lead_account_id
+        ));
+
+        let lead_worker_id = 1;
+        let worker_id = 7;
+
+        <working_group::WorkerById<Runtime, ForumWorkingGroupInstance>>::insert(
+            lead_worker_id,
+            Worker {
+                role_account_id: lead_account_id.clone(),
+                ..Worker::default()
+            },
+        );
+        <working_group::CurrentLead<Runtime, ForumWorkingGroupInstance>>::put(lead_worker_id);
+        <working_group::WorkerById<Runtime, ForumWorkingGroupInstance>>::insert(
+            worker_id,
+            Worker {
+                role_account_id: worker_account_id.clone(),
+                ..Worker::default()
+            },
+        );
+        ForumWorkingGroup::index_existing_worker_role_accounts();
+
+        assert!(ForumWorkingGroupModeratorValidator::is_forum_moderator(
+            &lead_account_id
+        ));
+        assert!(ForumWorkingGroupModeratorValidator::is_forum_moderator(
+            &worker_account_id
+        ));
+        assert!(!ForumWorkingGroupModeratorValidator::is_forum_moderator(
+            &not_worker_account_id
+        ));
+    });
+}
+
+#[test]
+fn storage_working_group_workers_are_not_forum_moderators() {
+    initial_test_ext().execute_with(|| {
+        let account_id: AccountId = [1; 32].into();
+
+        <working_group::WorkerById<Runtime, working_group::Instance2>>::insert(
+            1,
+            Worker {
+                role_account_id: account_id.clone(),
+                ..Worker::default()
+            },
+        );
+        StorageWorkingGroup::index_existing_worker_role_accounts();
+
+        assert!(!ForumWorkingGroupModeratorValidator::is_forum_moderator(
+            &account_id
+        ));
+    });
+}
+
+#[test]
+fn suspended_forum_workers_are_not_forum_moderators() {
+    initial_test_ext().execute_with(|| {
+        let account_id: AccountId = [1; 32].into();
+        let worker_id = 1;
+
+        <working_group::WorkerById<Runtime, ForumWorkingGroupInstance>>::insert(
+            worker_id,
+            Worker {
+                role_account_id: account_id.clone(),
+                ..Worker::default()
+            },
+        );
+        ForumWorkingGroup::index_existing_worker_role_accounts();
+        <working_group::WorkerSuspensionById<Runtime, ForumWorkingGroupInstance>>::insert(
+            worker_id,
+            WorkerSuspension::default(),
+        );
+
+        assert!(!ForumWorkingGroupModeratorValidator::is_forum_moderator(
+            &account_id
+        ));
+    });
+}
@@ -0,0 +1,415 @@
+use super::initial_test_ext;
+use crate::{
+    AccountId, Balance, BlockNumber, ContentId, DataDirectory, DataObjectTypeRegistry, Hash,
+    Hiring, Migration, Minting, Moment, RecurringRewards, Runtime, Stake, VERSION,
+};
+
+use codec::Encode;
+use common::BlockAndTime;
+use rstd::collections::btree_map::BTreeMap;
+use sr_primitives::traits::OnInitialize;
+use srml_support::storage::unhashed;
+use srml_support::{StorageLinkedMap, StorageMap, StorageValue};
+
+type MemberId = <Runtime as membership::members::Trait>::MemberId;
+type DataObjectTypeId = <Runtime as storage::data_object_type_registry::Trait>::DataObjectTypeId;
+type StorageProviderId = storage::StorageProviderId<Runtime>;
+type MintId = <Runtime as minting::Trait>::MintId;
+type RecipientId = <Runtime as recurringrewards::Trait>::RecipientId;
+type ApplicationId = <Runtime as hiring::Trait>::ApplicationId;
+type SlashId = <Runtime as stake::Trait>::SlashId;
+
+/// Data object layout before the content chunking metadata and MIME type were introduced.
+#[derive(Encode)]
+struct LegacyDataObject {
+    owner: MemberId,
+    added_at: BlockAndTime<BlockNumber, Moment>,
+    type_id: DataObjectTypeId,
+    size: u64,
+    liaison: StorageProviderId,
+    liaison_judgement: storage::data_directory::LiaisonJudgement,
+    ipfs_content_id: Vec<u8>,
+}
+
+/// Data object type layout before the data object type constraints were introduced.
+#[derive(Encode)]
+struct LegacyDataObjectType {
+    description: Vec<u8>,
+    active: bool,
+}
+
+/// Mint layout before the lifetime limit of the total minted tokens was introduced.
+#[derive(Encode)]
+struct LegacyMint {
+    capacity: Balance,
+    next_adjustment: Option<minting::NextAdjustment<Balance, BlockNumber>>,
+    created_at: BlockNumber,
+    total_minted: Balance,
+}
+
+/// Reward relationship layout before the end block and maximum total payout were introduced.
+#[derive(Encode)]
+struct LegacyRewardRelationship {
+    recipient: RecipientId,
+    mint_id: MintId,
+    account: AccountId,
+    amount_per_payout: Balance,
+    next_payment_at_block: Option<BlockNumber>,
+    payout_interval: Option<BlockNumber>,
+    total_reward_received: Balance,
+    total_reward_missed: Balance,
+}
+
+/// Application rationing policy layout before the applicant ranking was introduced.
+#[derive(Encode)]
+struct LegacyApplicationRationingPolicy {
+    max_active_applicants: u32,
+}
+
+/// Hiring opening layout before the applicant ranking was introduced.
+#[derive(Encode)]
+struct LegacyHiringOpening {
+    created: BlockNumber,
+    stage: hiring::OpeningStage<BlockNumber, ApplicationId>,
+    max_review_period_length: BlockNumber,
+    application_rationing_policy: Option<LegacyApplicationRationingPolicy>,
+    application_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    role_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    human_readable_text: Vec<u8>,
+}
+
+/// Unstaking state layout before the unstaking due block was introduced.
+#[derive(Encode)]
+struct LegacyUnstakingState {
+    started_at_block: BlockNumber,
+    is_active: bool,
+    blocks_remaining_in_active_period_for_unstaking: BlockNumber,
+}
+
+#[derive(Encode)]
+enum LegacyStakedStatus {
+    Normal,
+    Unstaking(LegacyUnstakingState),
+}
+
+/// Slash layout before the slashing due block was introduced.
+#[derive(Encode)]
+struct LegacySlash {
+    started_at_block: BlockNumber,
+    is_active: bool,
+    blocks_remaining_in_active_period_for_slashing: BlockNumber,
+    slash_amount: Balance,
+}
+
+#[derive(Encode)]
+struct LegacyStakedState {
+    staked_amount: Balance,
+    staked_status: LegacyStakedStatus,
+    next_slash_id: SlashId,
+    ongoing_slashes: BTreeMap<SlashId, LegacySlash>,
+}
+
+#[derive(Encode)]
+#[allow(dead_code)]
+enum LegacyStakingStatus {
+    NotStaked,
+    Staked(LegacyStakedState),
+}
+
+/// Stake layout before the slashing and unstaking due blocks were introduced.
+#[derive(Encode)]
+struct LegacyStake {
+    created: BlockNumber,
+    staking_status: LegacyStakingStatus,
+}
+
+// Replaces the linked map value saved under the key with the legacy encoded value, keeping the
+// linkage saved next to the value.
+fn put_legacy_linked_map_value<Value: Encode, Legacy: Encode>(
+    key: &[u8],
+    value: &Value,
+    legacy: &Legacy,
+) {
+    let raw_value = unhashed::get_raw(key).unwrap();
+    let linkage = &raw_value[value.encode().len()..];
+
+    let mut legacy_value = legacy.encode();
+    legacy_value.extend_from_slice(linkage);
+
+    unhashed::put_raw(key, &legacy_value);
+}
+
+// Runs the runtime upgrade from the previous runtime version.
+fn run_runtime_upgrade() {
+    crate::migration::SpecVersion::put(VERSION.spec_version - 1);
+
+    <Migration as OnInitialize<BlockNumber>>::on_initialize(1);
+
+    assert_eq!(Migration::spec_version(), Some(VERSION.spec_version));
+}
+
+#[test]
+fn runtime_upgrade_migrates_legacy_storage() {
+    initial_test_ext().execute_with(|| {
+        let account_id: AccountId = [1; 32].into();
+
+        // Data object type
+        let data_object_type_id = 0;
+        <storage::data_object_type_registry::NextDataObjectTypeId<Runtime>>::put(1);
+        unhashed::put(
+            &<storage::data_object_type_registry::DataObjectTypes<Runtime>>::hashed_key_for(
+                data_object_type_id,
+            ),
+            &LegacyDataObjectType {
+                description: b"legacy type".to_vec(),
+                active: true,
+            },
+        );
+
+        // Data object
+        let content_id = ContentId::from_low_u64_be(1);
+        let added_at = BlockAndTime { block: 0, time: 0 };
+        <storage::data_directory::KnownContentIds<Runtime>>::put(vec![content_id]);
+        unhashed::put(
+            &<storage::data_directory::DataObjectByContentId<Runtime>>::hashed_key_for(content_id),
+            &LegacyDataObject {
+                owner: 1,
+                added_at: added_at.clone(),
+                type_id: data_object_type_id,
+                size: 100,
+                liaison: 2,
+                liaison_judgement: storage::data_directory::LiaisonJudgement::Accepted,
+                ipfs_content_id: b"legacy content".to_vec(),
+            },
+        );
+
+        // Mint
+        let mint_id = Minting::add_mint(1000, None, None).unwrap();
+        let next_adjustment = minting::NextAdjustment {
+            adjustment: minting::AdjustOnInterval {
+                block_interval: 10,
+                adjustment_type: minting::AdjustCapacityBy::Adding(100),
+            },
+            at_block: 10,
+        };
+        put_legacy_linked_map_value(
+            &<minting::Mints<Runtime>>::hashed_key_for(mint_id),
+            &Minting::mints(mint_id),
+            &LegacyMint {
+                capacity: 1000,
+                next_adjustment: Some(next_adjustment),
+                created_at: 0,
+                total_minted: 0,
+            },
+        );
+
+        // Reward relationship
+        let recipient_id = RecurringRewards::add_recipient();
+        let relationship_id = RecurringRewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            account_id.clone(),
+            10,
+            20,
+            Some(20),
+            None,
+            None,
+        )
+        .unwrap();
+        put_legacy_linked_map_value(
+            &<recurringrewards::RewardRelationships<Runtime>>::hashed_key_for(relationship_id),
+            &RecurringRewards::reward_relationships(relationship_id),
+            &LegacyRewardRelationship {
+                recipient: recipient_id,
+                mint_id,
+                account: account_id,
+                amount_per_payout: 10,
+                next_payment_at_block: Some(20),
+                payout_interval: Some(20),
+                total_reward_received: 0,
+                total_reward_missed: 0,
+            },
+        );
+        // The legacy payouts were not scheduled.
+        <recurringrewards::RewardRelationshipsByPayoutBlock<Runtime>>::remove(20);
+
+        // Hiring opening
+        let opening_id = 0;
+        <hiring::OpeningById<Runtime>>::insert(opening_id, hiring::Opening::default());
+        <hiring::NextOpeningId<Runtime>>::put(opening_id + 1);
+        put_legacy_linked_map_value(
+            &<hiring::OpeningById<Runtime>>::hashed_key_for(opening_id),
+            &Hiring::opening_by_id(opening_id),
+            &LegacyHiringOpening {
+                created: 0,
+                stage: hiring::OpeningStage::WaitingToBegin {
+                    begins_at_block: 30,
+                },
+                max_review_period_length: 100,
+                application_rationing_policy: Some(LegacyApplicationRationingPolicy {
+                    max_active_applicants: 5,
+                }),
+                application_staking_policy: None,
+                role_staking_policy: None,
+                human_readable_text: b"legacy opening".to_vec(),
+            },
+        );
+
+        // Stakes
+        let unstaking_stake_id = Stake::create_stake();
+        put_legacy_linked_map_value(
+            &<stake::Stakes<Runtime>>::hashed_key_for(unstaking_stake_id),
+            &Stake::stakes(unstaking_stake_id),
+            &LegacyStake {
+                created: 0,
+                staking_status: LegacyStakingStatus::Staked(LegacyStakedState {
+                    staked_amount: 100,
+                    staked_status: LegacyStakedStatus::Unstaking(LegacyUnstakingState {
+                        started_at_block: 0,
+                        is_active: true,
+                        blocks_remaining_in_active_period_for_unstaking: 5,
+                    }),
+                    next_slash_id: 0,
+                    ongoing_slashes: BTreeMap::new(),
+                }),
+            },
+        );
+
+        let slashed_stake_id = Stake::create_stake();
+        let mut legacy_slashes = BTreeMap::new();
+        legacy_slashes.insert(
+            0,
+            LegacySlash {
+                started_at_block: 0,
+                is_active: true,
+                blocks_remaining_in_active_period_for_slashing: 3,
+                slash_amount: 10,
+            },
+        );
+        put_legacy_linked_map_value(
+            &<stake::Stakes<Runtime>>::hashed_key_for(slashed_stake_id),
+            &Stake::stakes(slashed_stake_id),
+            &LegacyStake {
+                created: 0,
+                staking_status: LegacyStakingStatus::Staked(LegacyStakedState {
+                    staked_amount: 100,
+                    staked_status: LegacyStakedStatus::Normal,
+                    next_slash_id: 1,
+                    ongoing_slashes: legacy_slashes,
+                }),
+            },
+        );
+
+        run_runtime_upgrade();
+
+        assert_eq!(
+            DataObjectTypeRegistry::data_object_types(data_object_type_id),
+            Some(storage::data_object_type_registry::DataObjectType {
+                description: b"legacy type".to_vec(),
+                active: true,
+                constraints: Default::default(),
+            })
+        );
+
+        assert_eq!(
+            DataDirectory::data_object_by_content_id(content_id),
+            Some(storage::data_directory::DataObjectInternal {
+                owner: 1,
+                added_at,
+                type_id: data_object_type_id,
+                size: 100,
+                mime_type: Vec::new(),
+                liaison: 2,
+                liaison_judgement: storage::data_directory::LiaisonJudgement::Accepted,
+                ipfs_content_id: b"legacy content".to_vec(),
+                content_merkle_root: Hash::default(),
+                chunk_size: 100,
+                content_hash: None,
+            })
+        );
+
+        assert_eq!(Minting::get_mint_capacity(mint_id), Ok(1000));
+        assert_eq!(
+            Minting::get_mint_next_adjustment(mint_id),
+            Ok(Some(next_adjustment))
+        );
+        assert_eq!(Minting::mint_ids_by_adjustment_block(10), vec![mint_id]);
+
+        let relationship = RecurringRewards::reward_relationships(relationship_id);
+        assert_eq!(relationship.next_payment_at_block(), Some(20));
+        assert_eq!(relationship.ends_at_block(), None);
+        assert_eq!(relationship.max_total_payout(), None);
+        assert_eq!(
+            RecurringRewards::reward_relationships_by_payout_block(20),
+            vec![relationship_id]
+        );
+
+        let opening = Hiring::opening_by_id(opening_id);
+        assert_eq!(
+            opening.application_rationing_policy,
+            Some(hiring::ApplicationRationingPolicy {
+                max_active_applicants: 5,
+                applicant_ranking: hiring::ApplicationRankingPolicy::Stake,
+            })
+        );
+        assert_eq!(opening.human_readable_text, b"legacy opening".to_vec());
+        assert_eq!(
+            Hiring::opening_ids_by_activation_block(30),
+            vec![opening_id]
+        );
+
+        assert_eq!(
+            Stake::stakes(unstaking_stake_id).staking_status,
+            stake::StakingStatus::Staked(stake::StakedState {
+                staked_amount: 100,
+                staked_status: stake::StakedStatus::Unstaking(stake::UnstakingState {
+                    started_at_block: 0,
+                    is_active: true,
+                    unstake_at_block: 4,
+                }),
+                next_slash_id: 0,
+                ongoing_slashes: BTreeMap::new(),
+            })
+        );
+        assert_eq!(Stake::stake_ids_by_due_block(4), vec![unstaking_stake_id]);
+
+        let mut expected_slashes = BTreeMap::new();
+        expected_slashes.insert(
+            0,
+            stake::Slash {
+                started_at_block: 0,
+                is_active: true,
+                slash_at_block: 2,
+                slash_amount: 10,
+            },
+        );
+        assert_eq!(
+            Stake::stakes(slashed_stake_id).staking_status,
+            stake::StakingStatus::Staked(stake::StakedState {
+                staked_amount: 100,
+                staked_status: stake::StakedStatus::Normal,
+                next_slash_id: 1,
+                ongoing_slashes: expected_slashes,
+            })
+        );
+        assert_eq!(Stake::stake_ids_by_due_block(2), vec![slashed_stake_id]);
+    });
+}
+
+#[test]
+fn runtime_upgrade_initializes_the_forum_working_group_once() {
+    initial_test_ext().execute_with(|| {
+        run_runtime_upgrade();
+
+        let mint_id = <working_group::Mint<Runtime, working_group::Instance1>>::get();
+        let mints_created = Minting::mints_created();
+
+        run_runtime_upgrade();
+
+        assert_eq!(
+            <working_group::Mint<Runtime, working_group::Instance1>>::get(),
+            mint_id
+        );
+        assert_eq!(Minting::mints_created(), mints_created);
+    });
+}
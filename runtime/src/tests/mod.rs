@@ -2,6 +2,8 @@
 
 #![cfg(test)]
 
+mod forum_integration;
+mod migration_integration;
 mod proposals_integration;
 mod storage_integration;
 
@@ -641,8 +641,8 @@ fn set_content_working_group_mint_capacity_execution_succeeds() {
         let account_id: [u8; 32] = [member_id; 32];
         let new_balance = <BalanceOf<Runtime>>::from(55u32);
 
-        let mint_id =
-            Mint::add_mint(0, None).expect("Failed to create a mint for the content working group");
+        let mint_id = Mint::add_mint(0, None, None)
+            .expect("Failed to create a mint for the content working group");
         <content_working_group::Mint<Runtime>>::put(mint_id);
 
         assert_eq!(Mint::get_mint_capacity(mint_id), Ok(0));
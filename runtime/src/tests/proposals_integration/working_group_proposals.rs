@@ -6,31 +6,34 @@ use system::RawOrigin;
 use common::working_group::WorkingGroup;
 use hiring::ActivateOpeningAt;
 use proposals_codex::AddOpeningParameters;
-use working_group::{OpeningPolicyCommitment, RewardPolicy};
+use working_group::{OpeningPolicyCommitment, RewardPolicy, SlashableTerms, SlashingTerms};
 
-use crate::{Balance, BlockNumber, StorageWorkingGroupInstance};
+use crate::{Balance, BlockNumber, ForumWorkingGroupInstance, StorageWorkingGroupInstance};
 use rstd::collections::btree_set::BTreeSet;
 
-type StorageWorkingGroup = working_group::Module<Runtime, StorageWorkingGroupInstance>;
+type WorkingGroupModule<I> = working_group::Module<Runtime, I>;
 
 type Hiring = hiring::Module<Runtime>;
 
-fn add_opening(
+fn add_opening<I: working_group::Instance>(
+    working_group: WorkingGroup,
     member_id: u8,
     account_id: [u8; 32],
     activate_at: hiring::ActivateOpeningAt<BlockNumber>,
     opening_policy_commitment: Option<OpeningPolicyCommitment<BlockNumber, u128>>,
     sequence_number: u32, // action sequence number to align with other actions
-) -> u64 {
+) -> u64
+where
+    Runtime: working_group::Trait<I>,
+{
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number + 1;
 
-    let opening_id = StorageWorkingGroup::next_opening_id();
+    let opening_id = WorkingGroupModule::<I>::next_opening_id();
 
-    assert!(!<working_group::OpeningById<
-        Runtime,
-        StorageWorkingGroupInstance,
-    >>::exists(opening_id));
+    assert!(!<working_group::OpeningById<Runtime, I>>::exists(
+        opening_id
+    ));
 
     let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
         ProposalCodex::create_add_working_group_leader_opening_proposal(
@@ -45,7 +48,7 @@ fn add_opening(
                     .clone()
                     .unwrap_or(OpeningPolicyCommitment::default()),
                 human_readable_text: Vec::new(),
-                working_group: WorkingGroup::Storage,
+                working_group,
             },
         )
     })
@@ -58,6 +61,7 @@ fn add_opening(
 }
 
 fn begin_review_applications(
+    working_group: WorkingGroup,
     member_id: u8,
     account_id: [u8; 32],
     opening_id: u64,
@@ -74,7 +78,7 @@ fn begin_review_applications(
             b"body".to_vec(),
             Some(<BalanceOf<Runtime>>::from(25_000_u32)),
             opening_id,
-            WorkingGroup::Storage,
+            working_group,
         )
     })
     .disable_setup_enviroment()
@@ -85,6 +89,7 @@ fn begin_review_applications(
 }
 
 fn fill_opening(
+    working_group: WorkingGroup,
     member_id: u8,
     account_id: [u8; 32],
     opening_id: u64,
@@ -106,7 +111,7 @@ fn fill_opening(
                 opening_id,
                 successful_application_id,
                 reward_policy: reward_policy.clone(),
-                working_group: WorkingGroup::Storage,
+                working_group,
             },
         )
     })
@@ -126,6 +131,7 @@ fn get_stake_balance(stake: stake::Stake<BlockNumber, Balance, u64>) -> Balance
 }
 
 fn decrease_stake(
+    working_group: WorkingGroup,
     member_id: u8,
     account_id: [u8; 32],
     leader_worker_id: u64,
@@ -144,7 +150,7 @@ fn decrease_stake(
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             leader_worker_id,
             stake_amount,
-            WorkingGroup::Storage,
+            working_group,
         )
     })
     .disable_setup_enviroment()
@@ -155,6 +161,7 @@ fn decrease_stake(
 }
 
 fn slash_stake(
+    working_group: WorkingGroup,
     member_id: u8,
     account_id: [u8; 32],
     leader_worker_id: u64,
@@ -173,7 +180,37 @@ fn slash_stake(
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             leader_worker_id,
             stake_amount,
-            WorkingGroup::Storage,
+            working_group,
+        )
+    })
+    .disable_setup_enviroment()
+    .with_expected_proposal_id(expected_proposal_id)
+    .with_run_to_block(run_to_block);
+
+    codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+}
+
+fn cancel_slashing(
+    working_group: WorkingGroup,
+    member_id: u8,
+    account_id: [u8; 32],
+    leader_worker_id: u64,
+    slash_id: u64,
+    sequence_number: u32, // action sequence number to align with other actions
+) {
+    let expected_proposal_id = sequence_number;
+    let run_to_block = sequence_number + 1;
+
+    let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+        ProposalCodex::create_cancel_working_group_slashing_proposal(
+            RawOrigin::Signed(account_id.clone().into()).into(),
+            member_id as u64,
+            b"title".to_vec(),
+            b"body".to_vec(),
+            Some(<BalanceOf<Runtime>>::from(50_000_u32)),
+            leader_worker_id,
+            slash_id,
+            working_group,
         )
     })
     .disable_setup_enviroment()
@@ -184,6 +221,7 @@ fn slash_stake(
 }
 
 fn set_reward(
+    working_group: WorkingGroup,
     member_id: u8,
     account_id: [u8; 32],
     leader_worker_id: u64,
@@ -202,7 +240,7 @@ fn set_reward(
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             leader_worker_id,
             reward_amount,
-            WorkingGroup::Storage,
+            working_group,
         )
     })
     .disable_setup_enviroment()
@@ -212,20 +250,23 @@ fn set_reward(
     codex_extrinsic_test_fixture.call_extrinsic_and_assert();
 }
 
-fn set_mint_capacity(
+fn set_mint_capacity<I: working_group::Instance>(
+    working_group: WorkingGroup,
     member_id: u8,
     account_id: [u8; 32],
     mint_capacity: Balance,
     sequence_number: u32, // action sequence number to align with other actions
     setup_environment: bool,
-) {
+) where
+    Runtime: working_group::Trait<I>,
+{
     let expected_proposal_id = sequence_number;
     let run_to_block = sequence_number + 1;
 
-    let mint_id_result = <minting::Module<Runtime>>::add_mint(0, None);
+    let mint_id_result = <minting::Module<Runtime>>::add_mint(0, None, None);
 
     if let Ok(mint_id) = mint_id_result {
-        <working_group::Mint<Runtime, StorageWorkingGroupInstance>>::put(mint_id);
+        <working_group::Mint<Runtime, I>>::put(mint_id);
     }
 
     let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
@@ -236,7 +277,7 @@ fn set_mint_capacity(
             b"body".to_vec(),
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             mint_capacity,
-            WorkingGroup::Storage,
+            working_group,
         )
     })
     .with_setup_enviroment(setup_environment)
@@ -247,6 +288,7 @@ fn set_mint_capacity(
 }
 
 fn terminate_role(
+    working_group: WorkingGroup,
     member_id: u8,
     account_id: [u8; 32],
     leader_worker_id: u64,
@@ -267,7 +309,7 @@ fn terminate_role(
                 worker_id: leader_worker_id,
                 rationale: Vec::new(),
                 slash,
-                working_group: WorkingGroup::Storage,
+                working_group,
             },
         )
     })
@@ -280,18 +322,33 @@ fn terminate_role(
 
 #[test]
 fn create_add_working_group_leader_opening_proposal_execution_succeeds() {
+    run_create_add_working_group_leader_opening_proposal_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_add_working_group_leader_opening_proposal_execution_succeeds::<
+        ForumWorkingGroupInstance,
+    >(WorkingGroup::Forum);
+}
+
+fn run_create_add_working_group_leader_opening_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
 
-        let next_opening_id = StorageWorkingGroup::next_opening_id();
+        let next_opening_id = WorkingGroupModule::<I>::next_opening_id();
 
-        assert!(!<working_group::OpeningById<
-            Runtime,
-            StorageWorkingGroupInstance,
-        >>::exists(next_opening_id));
+        assert!(!<working_group::OpeningById<Runtime, I>>::exists(
+            next_opening_id
+        ));
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
+            working_group,
             member_id,
             account_id,
             ActivateOpeningAt::CurrentBlock,
@@ -303,20 +360,33 @@ fn create_add_working_group_leader_opening_proposal_execution_succeeds() {
         assert_eq!(opening_id, next_opening_id);
 
         // Check for the new opening creation.
-        assert!(<working_group::OpeningById<
-            Runtime,
-            StorageWorkingGroupInstance,
-        >>::exists(opening_id));
+        assert!(<working_group::OpeningById<Runtime, I>>::exists(opening_id));
     });
 }
 
 #[test]
 fn create_begin_review_working_group_leader_applications_proposal_execution_succeeds() {
+    run_create_begin_review_working_group_leader_applications_proposal_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_begin_review_working_group_leader_applications_proposal_execution_succeeds::<
+        ForumWorkingGroupInstance,
+    >(WorkingGroup::Forum);
+}
+
+fn run_create_begin_review_working_group_leader_applications_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
+            working_group,
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
@@ -324,7 +394,7 @@ fn create_begin_review_working_group_leader_applications_proposal_execution_succ
             1,
         );
 
-        let opening = StorageWorkingGroup::opening_by_id(opening_id);
+        let opening = WorkingGroupModule::<I>::opening_by_id(opening_id);
 
         let hiring_opening = Hiring::opening_by_id(opening.hiring_opening_id);
         assert_eq!(
@@ -340,7 +410,7 @@ fn create_begin_review_working_group_leader_applications_proposal_execution_succ
             }
         );
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(working_group, member_id, account_id, opening_id, 2);
 
         let hiring_opening = Hiring::opening_by_id(opening.hiring_opening_id);
         assert_eq!(
@@ -361,11 +431,27 @@ fn create_begin_review_working_group_leader_applications_proposal_execution_succ
 
 #[test]
 fn create_fill_working_group_leader_opening_proposal_execution_succeeds() {
+    run_create_fill_working_group_leader_opening_proposal_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_fill_working_group_leader_opening_proposal_execution_succeeds::<
+        ForumWorkingGroupInstance,
+    >(WorkingGroup::Forum);
+}
+
+fn run_create_fill_working_group_leader_opening_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
+            working_group,
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
@@ -373,7 +459,7 @@ fn create_fill_working_group_leader_opening_proposal_execution_succeeds() {
             1,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupModule::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -387,12 +473,13 @@ fn create_fill_working_group_leader_opening_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(working_group, member_id, account_id, opening_id, 2);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupModule::<I>::current_lead();
         assert!(lead.is_none());
 
         fill_opening(
+            working_group,
             member_id,
             account_id,
             opening_id,
@@ -401,13 +488,26 @@ fn create_fill_working_group_leader_opening_proposal_execution_succeeds() {
             3,
         );
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupModule::<I>::current_lead();
         assert!(lead.is_some());
     });
 }
 
 #[test]
 fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
+    run_create_decrease_group_leader_stake_proposal_execution_succeeds::<StorageWorkingGroupInstance>(
+        WorkingGroup::Storage,
+    );
+    run_create_decrease_group_leader_stake_proposal_execution_succeeds::<ForumWorkingGroupInstance>(
+        WorkingGroup::Forum,
+    );
+}
+
+fn run_create_decrease_group_leader_stake_proposal_execution_succeeds<I: working_group::Instance>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -423,7 +523,8 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
+            working_group,
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
@@ -431,7 +532,7 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
             1,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupModule::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -445,12 +546,13 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(working_group, member_id, account_id, opening_id, 2);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupModule::<I>::current_lead();
         assert!(lead.is_none());
 
         fill_opening(
+            working_group,
             member_id,
             account_id,
             opening_id,
@@ -459,7 +561,7 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
             3,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupModule::<I>::current_lead().unwrap();
 
         let stake_id = 1;
         let old_balance = Balances::free_balance::<&AccountId32>(&account_id.into());
@@ -469,6 +571,7 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
 
         let decreasing_stake_amount = 30;
         decrease_stake(
+            working_group,
             member_id,
             account_id,
             leader_worker_id,
@@ -489,6 +592,19 @@ fn create_decrease_group_leader_stake_proposal_execution_succeeds() {
 
 #[test]
 fn create_slash_group_leader_stake_proposal_execution_succeeds() {
+    run_create_slash_group_leader_stake_proposal_execution_succeeds::<StorageWorkingGroupInstance>(
+        WorkingGroup::Storage,
+    );
+    run_create_slash_group_leader_stake_proposal_execution_succeeds::<ForumWorkingGroupInstance>(
+        WorkingGroup::Forum,
+    );
+}
+
+fn run_create_slash_group_leader_stake_proposal_execution_succeeds<I: working_group::Instance>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -501,10 +617,15 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
                 crowded_out_unstaking_period_length: None,
                 review_period_expired_unstaking_period_length: None,
             }),
+            role_slashing_terms: SlashingTerms::Slashable(SlashableTerms {
+                max_count: 1,
+                max_percent_pts_per_time: 100,
+            }),
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
+            working_group,
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
@@ -512,7 +633,7 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
             1,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupModule::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -526,12 +647,13 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(working_group, member_id, account_id, opening_id, 2);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupModule::<I>::current_lead();
         assert!(lead.is_none());
 
         fill_opening(
+            working_group,
             member_id,
             account_id,
             opening_id,
@@ -540,7 +662,7 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
             3,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupModule::<I>::current_lead().unwrap();
 
         let stake_id = 1;
         let old_balance = Balances::free_balance::<&AccountId32>(&account_id.into());
@@ -550,6 +672,7 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
 
         let slashing_stake_amount = 30;
         slash_stake(
+            working_group,
             member_id,
             account_id,
             leader_worker_id,
@@ -570,16 +693,31 @@ fn create_slash_group_leader_stake_proposal_execution_succeeds() {
 
 #[test]
 fn create_set_working_group_mint_capacity_proposal_execution_succeeds() {
+    run_create_set_working_group_mint_capacity_proposal_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_set_working_group_mint_capacity_proposal_execution_succeeds::<
+        ForumWorkingGroupInstance,
+    >(WorkingGroup::Forum);
+}
+
+fn run_create_set_working_group_mint_capacity_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
 
-        assert_eq!(StorageWorkingGroup::mint(), 0);
+        assert_eq!(WorkingGroupModule::<I>::mint(), 0);
 
         let mint_capacity = 999999;
-        set_mint_capacity(member_id, account_id, mint_capacity, 1, true);
+        set_mint_capacity::<I>(working_group, member_id, account_id, mint_capacity, 1, true);
 
-        let mint_id = StorageWorkingGroup::mint();
+        let mint_id = WorkingGroupModule::<I>::mint();
         let mint = <minting::Module<Runtime>>::mints(mint_id);
 
         assert_eq!(mint.capacity(), mint_capacity);
@@ -588,6 +726,19 @@ fn create_set_working_group_mint_capacity_proposal_execution_succeeds() {
 
 #[test]
 fn create_set_group_leader_reward_proposal_execution_succeeds() {
+    run_create_set_group_leader_reward_proposal_execution_succeeds::<StorageWorkingGroupInstance>(
+        WorkingGroup::Storage,
+    );
+    run_create_set_group_leader_reward_proposal_execution_succeeds::<ForumWorkingGroupInstance>(
+        WorkingGroup::Forum,
+    );
+}
+
+fn run_create_set_group_leader_reward_proposal_execution_succeeds<I: working_group::Instance>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -603,7 +754,8 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
+            working_group,
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
@@ -611,7 +763,7 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
             1,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupModule::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -625,9 +777,9 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(working_group, member_id, account_id, opening_id, 2);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupModule::<I>::current_lead();
         assert!(lead.is_none());
 
         let old_reward_amount = 100;
@@ -637,9 +789,10 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
             payout_interval: None,
         });
 
-        set_mint_capacity(member_id, account_id, 999999, 3, false);
+        set_mint_capacity::<I>(working_group, member_id, account_id, 999999, 3, false);
 
         fill_opening(
+            working_group,
             member_id,
             account_id,
             opening_id,
@@ -648,9 +801,9 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
             4,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupModule::<I>::current_lead().unwrap();
 
-        let worker = StorageWorkingGroup::worker_by_id(leader_worker_id);
+        let worker = WorkingGroupModule::<I>::worker_by_id(leader_worker_id);
         let relationship_id = worker.reward_relationship.unwrap();
 
         let relationship = recurringrewards::RewardRelationships::<Runtime>::get(relationship_id);
@@ -658,6 +811,7 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
 
         let new_reward_amount = 999;
         set_reward(
+            working_group,
             member_id,
             account_id,
             leader_worker_id,
@@ -672,6 +826,19 @@ fn create_set_group_leader_reward_proposal_execution_succeeds() {
 
 #[test]
 fn create_terminate_group_leader_role_proposal_execution_succeeds() {
+    run_create_terminate_group_leader_role_proposal_execution_succeeds::<StorageWorkingGroupInstance>(
+        WorkingGroup::Storage,
+    );
+    run_create_terminate_group_leader_role_proposal_execution_succeeds::<ForumWorkingGroupInstance>(
+        WorkingGroup::Forum,
+    );
+}
+
+fn run_create_terminate_group_leader_role_proposal_execution_succeeds<I: working_group::Instance>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -687,7 +854,8 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
+            working_group,
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
@@ -695,7 +863,7 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
             1,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupModule::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -709,9 +877,9 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(working_group, member_id, account_id, opening_id, 2);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupModule::<I>::current_lead();
         assert!(lead.is_none());
 
         let old_reward_amount = 100;
@@ -721,9 +889,10 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
             payout_interval: None,
         });
 
-        set_mint_capacity(member_id, account_id, 999999, 3, false);
+        set_mint_capacity::<I>(working_group, member_id, account_id, 999999, 3, false);
 
         fill_opening(
+            working_group,
             member_id,
             account_id,
             opening_id,
@@ -732,7 +901,7 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
             4,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupModule::<I>::current_lead().unwrap();
 
         let stake_id = 1;
         let old_balance = Balances::free_balance::<&AccountId32>(&account_id.into());
@@ -740,9 +909,16 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
 
         assert_eq!(get_stake_balance(old_stake), stake_amount);
 
-        terminate_role(member_id, account_id, leader_worker_id, false, 5);
+        terminate_role(
+            working_group,
+            member_id,
+            account_id,
+            leader_worker_id,
+            false,
+            5,
+        );
 
-        assert!(StorageWorkingGroup::current_lead().is_none());
+        assert!(WorkingGroupModule::<I>::current_lead().is_none());
 
         let new_balance = Balances::free_balance::<&AccountId32>(&account_id.into());
         let new_stake = <stake::Module<Runtime>>::stakes(stake_id);
@@ -754,6 +930,21 @@ fn create_terminate_group_leader_role_proposal_execution_succeeds() {
 
 #[test]
 fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds() {
+    run_create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds::<
+        ForumWorkingGroupInstance,
+    >(WorkingGroup::Forum);
+}
+
+fn run_create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
     initial_test_ext().execute_with(|| {
         let member_id = 1;
         let account_id: [u8; 32] = [member_id; 32];
@@ -766,10 +957,15 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
                 crowded_out_unstaking_period_length: None,
                 review_period_expired_unstaking_period_length: None,
             }),
+            role_slashing_terms: SlashingTerms::Slashable(SlashableTerms {
+                max_count: 1,
+                max_percent_pts_per_time: 100,
+            }),
             ..OpeningPolicyCommitment::default()
         };
 
-        let opening_id = add_opening(
+        let opening_id = add_opening::<I>(
+            working_group,
             member_id,
             account_id.clone(),
             ActivateOpeningAt::CurrentBlock,
@@ -777,7 +973,7 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
             1,
         );
 
-        let apply_result = StorageWorkingGroup::apply_on_opening(
+        let apply_result = WorkingGroupModule::<I>::apply_on_opening(
             RawOrigin::Signed(account_id.clone().into()).into(),
             member_id as u64,
             opening_id,
@@ -791,9 +987,9 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
 
         let expected_application_id = 0;
 
-        begin_review_applications(member_id, account_id, opening_id, 2);
+        begin_review_applications(working_group, member_id, account_id, opening_id, 2);
 
-        let lead = StorageWorkingGroup::current_lead();
+        let lead = WorkingGroupModule::<I>::current_lead();
         assert!(lead.is_none());
 
         let old_reward_amount = 100;
@@ -803,9 +999,10 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
             payout_interval: None,
         });
 
-        set_mint_capacity(member_id, account_id, 999999, 3, false);
+        set_mint_capacity::<I>(working_group, member_id, account_id, 999999, 3, false);
 
         fill_opening(
+            working_group,
             member_id,
             account_id,
             opening_id,
@@ -814,7 +1011,7 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
             4,
         );
 
-        let leader_worker_id = StorageWorkingGroup::current_lead().unwrap();
+        let leader_worker_id = WorkingGroupModule::<I>::current_lead().unwrap();
 
         let stake_id = 1;
         let old_balance = Balances::free_balance::<&AccountId32>(&account_id.into());
@@ -822,9 +1019,16 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
 
         assert_eq!(get_stake_balance(old_stake), stake_amount);
 
-        terminate_role(member_id, account_id, leader_worker_id, true, 5);
+        terminate_role(
+            working_group,
+            member_id,
+            account_id,
+            leader_worker_id,
+            true,
+            5,
+        );
 
-        assert!(StorageWorkingGroup::current_lead().is_none());
+        assert!(WorkingGroupModule::<I>::current_lead().is_none());
 
         let new_balance = Balances::free_balance::<&AccountId32>(&account_id.into());
         let new_stake = <stake::Module<Runtime>>::stakes(stake_id);
@@ -833,3 +1037,112 @@ fn create_terminate_group_leader_role_proposal_with_slashing_execution_succeeds(
         assert_eq!(new_balance, old_balance);
     });
 }
+
+#[test]
+fn create_cancel_working_group_slashing_proposal_execution_succeeds() {
+    run_create_cancel_working_group_slashing_proposal_execution_succeeds::<
+        StorageWorkingGroupInstance,
+    >(WorkingGroup::Storage);
+    run_create_cancel_working_group_slashing_proposal_execution_succeeds::<ForumWorkingGroupInstance>(
+        WorkingGroup::Forum,
+    );
+}
+
+fn run_create_cancel_working_group_slashing_proposal_execution_succeeds<
+    I: working_group::Instance,
+>(
+    working_group: WorkingGroup,
+) where
+    Runtime: working_group::Trait<I>,
+{
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+        let stake_amount = 100;
+
+        let opening_policy_commitment = OpeningPolicyCommitment {
+            role_staking_policy: Some(hiring::StakingPolicy {
+                amount: 100,
+                amount_mode: hiring::StakingAmountLimitMode::AtLeast,
+                crowded_out_unstaking_period_length: None,
+                review_period_expired_unstaking_period_length: None,
+            }),
+            role_slashing_terms: SlashingTerms::Slashable(SlashableTerms {
+                max_count: 1,
+                max_percent_pts_per_time: 100,
+            }),
+            ..OpeningPolicyCommitment::default()
+        };
+
+        let opening_id = add_opening::<I>(
+            working_group,
+            member_id,
+            account_id.clone(),
+            ActivateOpeningAt::CurrentBlock,
+            Some(opening_policy_commitment),
+            1,
+        );
+
+        let apply_result = WorkingGroupModule::<I>::apply_on_opening(
+            RawOrigin::Signed(account_id.clone().into()).into(),
+            member_id as u64,
+            opening_id,
+            account_id.clone().into(),
+            Some(stake_amount),
+            None,
+            Vec::new(),
+        );
+
+        assert_eq!(apply_result, Ok(()));
+
+        let expected_application_id = 0;
+
+        begin_review_applications(working_group, member_id, account_id, opening_id, 2);
+
+        fill_opening(
+            working_group,
+            member_id,
+            account_id,
+            opening_id,
+            expected_application_id,
+            None,
+            3,
+        );
+
+        let leader_worker_id = WorkingGroupModule::<I>::current_lead().unwrap();
+
+        let stake_id = 1;
+        let slash_id = 0;
+        let slashing_stake_amount = 30;
+
+        let initiate_slashing_result = WorkingGroupModule::<I>::initiate_slashing(
+            RawOrigin::Root.into(),
+            leader_worker_id,
+            slashing_stake_amount,
+            Vec::new(),
+        );
+
+        assert_eq!(initiate_slashing_result, Ok(()));
+        assert!(<working_group::PendingSlashes<Runtime, I>>::exists((
+            leader_worker_id,
+            slash_id
+        )));
+
+        cancel_slashing(
+            working_group,
+            member_id,
+            account_id,
+            leader_worker_id,
+            slash_id,
+            4,
+        );
+
+        let stake = <stake::Module<Runtime>>::stakes(stake_id);
+
+        assert!(!<working_group::PendingSlashes<Runtime, I>>::exists((
+            leader_worker_id,
+            slash_id
+        )));
+        assert_eq!(get_stake_balance(stake), stake_amount);
+    });
+}
@@ -212,7 +212,10 @@ export class WorkingGroupOpening {
 
   public getCommitment() {
     return {
-      'application_rationing_policy': { 'max_active_applicants': this.maxActiveApplicants },
+      'application_rationing_policy': {
+        'max_active_applicants': this.maxActiveApplicants,
+        'applicant_ranking': 'Stake',
+      },
       'max_review_period_length': this.maxReviewPeriodLength,
       'application_staking_policy': {
         'amount': this.applicationStakingPolicyAmount,
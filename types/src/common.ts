@@ -124,6 +124,7 @@ export class InputValidationLengthConstraint extends JoyStruct<InputValidationLe
 
 export const WorkingGroupDef = {
   Storage: Null,
+  Forum: Null,
 } as const
 export type WorkingGroupKey = keyof typeof WorkingGroupDef
 export class WorkingGroup extends JoyEnum(WorkingGroupDef) {}
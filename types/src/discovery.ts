@@ -1,9 +1,11 @@
 import { Struct } from '@polkadot/types/codec'
-import { getTypeRegistry, Text, u32 } from '@polkadot/types'
+import { getTypeRegistry, Text, u32, Option, Vec } from '@polkadot/types'
 import { BlockNumber } from '@polkadot/types/interfaces'
 
 export class IPNSIdentity extends Text {}
 export class Url extends Text {}
+export class Multiaddr extends Text {}
+export class ApiVersion extends u32 {}
 
 export class AccountInfo extends Struct {
   constructor(value?: any) {
@@ -25,6 +27,56 @@ export class AccountInfo extends Struct {
   }
 }
 
+export class ServiceEndpoints extends Struct {
+  constructor(value?: any) {
+    super(
+      {
+        urls: Vec.with(Url),
+        multiaddrs: Vec.with(Multiaddr),
+        api_versions: Vec.with(ApiVersion),
+        region: Option.with(Text),
+      },
+      value
+    )
+  }
+
+  get urls(): Vec<Url> {
+    return this.get('urls') as Vec<Url>
+  }
+
+  get multiaddrs(): Vec<Multiaddr> {
+    return this.get('multiaddrs') as Vec<Multiaddr>
+  }
+
+  get api_versions(): Vec<ApiVersion> {
+    return this.get('api_versions') as Vec<ApiVersion>
+  }
+
+  get region(): Option<Text> {
+    return this.get('region') as Option<Text>
+  }
+}
+
+export class ServiceEndpointsRecord extends Struct {
+  constructor(value?: any) {
+    super(
+      {
+        endpoints: ServiceEndpoints,
+        expires_at: u32, // BlockNumber
+      },
+      value
+    )
+  }
+
+  get endpoints(): ServiceEndpoints {
+    return this.get('endpoints') as ServiceEndpoints
+  }
+
+  get expires_at(): BlockNumber {
+    return this.get('expires_at') as BlockNumber
+  }
+}
+
 export function registerDiscoveryTypes() {
   try {
     const typeRegistry = getTypeRegistry()
@@ -32,6 +84,10 @@ export function registerDiscoveryTypes() {
       Url,
       IPNSIdentity,
       AccountInfo,
+      Multiaddr,
+      ApiVersion,
+      ServiceEndpoints,
+      ServiceEndpointsRecord,
     })
   } catch (err) {
     console.error('Failed to register custom types of discovery module', err)
@@ -1,6 +1,6 @@
-import { getTypeRegistry, Null, u128, u64, u32, Vec, Option, Text } from '@polkadot/types'
+import { getTypeRegistry, Null, u128, u64, u32, Vec, Option, Text, bool } from '@polkadot/types'
 import { Enum } from '@polkadot/types/codec'
-import { BlockNumber, Balance } from '@polkadot/types/interfaces'
+import { BlockNumber, Balance, Hash } from '@polkadot/types/interfaces'
 import { JoyStruct, JoyEnum } from '../common'
 import { StakeId } from '../stake'
 
@@ -36,6 +36,7 @@ export enum ApplicationDeactivationCauseKeys {
   OpeningCancelled = 'OpeningCancelled',
   ReviewPeriodExpired = 'ReviewPeriodExpired',
   OpeningFilled = 'OpeningFilled',
+  Declined = 'Declined',
 }
 
 export class ApplicationDeactivationCause extends Enum {
@@ -49,6 +50,7 @@ export class ApplicationDeactivationCause extends Enum {
         ApplicationDeactivationCauseKeys.OpeningCancelled,
         ApplicationDeactivationCauseKeys.ReviewPeriodExpired,
         ApplicationDeactivationCauseKeys.OpeningFilled,
+        ApplicationDeactivationCauseKeys.Declined,
       ],
       value,
       index
@@ -112,14 +114,49 @@ export class ApplicationStage extends JoyEnum({
   Inactive: InactiveApplicationStage,
 } as const) {}
 
+export type IStakeWeightedWithTenure = {
+  tenure_weight_per_block: u32
+  max_tenure_blocks: u32
+}
+export class StakeWeightedWithTenure extends JoyStruct<IStakeWeightedWithTenure> {
+  constructor(value?: IStakeWeightedWithTenure) {
+    super(
+      {
+        tenure_weight_per_block: u32,
+        max_tenure_blocks: u32,
+      },
+      value
+    )
+  }
+
+  get tenure_weight_per_block(): u32 {
+    return this.getField<u32>('tenure_weight_per_block')
+  }
+
+  get max_tenure_blocks(): u32 {
+    return this.getField<u32>('max_tenure_blocks')
+  }
+}
+
+class ApplicationRankingPolicy_Stake extends Null {}
+class ApplicationRankingPolicy_FirstComeFirstServed extends Null {}
+
+export class ApplicationRankingPolicy extends JoyEnum({
+  Stake: ApplicationRankingPolicy_Stake,
+  FirstComeFirstServed: ApplicationRankingPolicy_FirstComeFirstServed,
+  StakeWeightedWithTenure: StakeWeightedWithTenure,
+} as const) {}
+
 export type IApplicationRationingPolicy = {
   max_active_applicants: u32
+  applicant_ranking: ApplicationRankingPolicy
 }
 export class ApplicationRationingPolicy extends JoyStruct<IApplicationRationingPolicy> {
   constructor(value?: IApplicationRationingPolicy) {
     super(
       {
         max_active_applicants: u32,
+        applicant_ranking: ApplicationRankingPolicy,
       },
       value
     )
@@ -128,6 +165,10 @@ export class ApplicationRationingPolicy extends JoyStruct<IApplicationRationingP
   get max_active_applicants(): u32 {
     return this.getField<u32>('max_active_applicants')
   }
+
+  get applicant_ranking(): ApplicationRankingPolicy {
+    return this.getField<ApplicationRankingPolicy>('applicant_ranking')
+  }
 }
 
 export type WaitingToBeingOpeningStageVariantType = {
@@ -513,6 +554,31 @@ export class Application extends JoyStruct<IApplication> {
   }
 }
 
+export type IApplicationReview = {
+  shortlisted: bool
+  note_hash: Option<Hash>
+}
+
+export class ApplicationReview extends JoyStruct<IApplicationReview> {
+  constructor(value?: IApplicationReview) {
+    super(
+      {
+        shortlisted: bool,
+        note_hash: Option.with('Hash'),
+      },
+      value
+    )
+  }
+
+  get shortlisted(): bool {
+    return this.getField<bool>('shortlisted')
+  }
+
+  get note_hash(): Option<Hash> {
+    return this.getField<Option<Hash>>('note_hash')
+  }
+}
+
 export function registerHiringTypes() {
   try {
     getTypeRegistry().register({
@@ -522,10 +588,12 @@ export function registerHiringTypes() {
       ApplicationStage,
       // why the prefix? is there some other identically named type?
       'hiring::ActivateOpeningAt': ActivateOpeningAt,
+      ApplicationRankingPolicy,
       ApplicationRationingPolicy,
       OpeningStage,
       StakingPolicy,
       Opening,
+      ApplicationReview,
     })
   } catch (err) {
     console.error('Failed to register custom types of hiring module', err)
@@ -1,8 +1,9 @@
-import { Enum, Struct, Option, Vec as Vector, H256, BTreeMap, getTypeRegistry, u64, bool, Text } from '@polkadot/types'
+import { Enum, Struct, Option, Vec as Vector, H256, BTreeMap, getTypeRegistry, u64, bool, Text, Bytes } from '@polkadot/types'
 import { BlockAndTime } from './common'
 import { MemberId } from './members'
 import { StorageProviderId } from './working-group' // this should be in discovery really
 
+import { Balance } from '@polkadot/types/interfaces'
 import { randomAsU8a } from '@polkadot/util-crypto'
 import { encodeAddress, decodeAddress } from '@polkadot/keyring'
 // import { u8aToString, stringToU8a } from '@polkadot/util';
@@ -40,6 +41,9 @@ export class LiaisonJudgement extends Enum {
   }
 }
 
+export class ContentHash extends Bytes {}
+export class OptionContentHash extends Option.with(ContentHash) {}
+
 export class DataObject extends Struct {
   constructor(value?: any) {
     super(
@@ -48,9 +52,13 @@ export class DataObject extends Struct {
         added_at: BlockAndTime,
         type_id: DataObjectTypeId,
         size: u64,
+        mime_type: Text,
         liaison: StorageProviderId,
         liaison_judgement: LiaisonJudgement,
         ipfs_content_id: Text,
+        content_merkle_root: H256,
+        chunk_size: u64,
+        content_hash: OptionContentHash,
       },
       value
     )
@@ -73,6 +81,10 @@ export class DataObject extends Struct {
     return this.get('size') as u64
   }
 
+  get mime_type(): Text {
+    return this.get('mime_type') as Text
+  }
+
   get liaison(): StorageProviderId {
     return this.get('liaison') as StorageProviderId
   }
@@ -84,6 +96,18 @@ export class DataObject extends Struct {
   get ipfs_content_id(): Text {
     return this.get('ipfs_content_id') as Text
   }
+
+  get content_merkle_root(): H256 {
+    return this.get('content_merkle_root') as H256
+  }
+
+  get chunk_size(): u64 {
+    return this.get('chunk_size') as u64
+  }
+
+  get content_hash(): OptionContentHash {
+    return this.get('content_hash') as OptionContentHash
+  }
 }
 
 export class DataObjectStorageRelationship extends Struct {
@@ -111,12 +135,38 @@ export class DataObjectStorageRelationship extends Struct {
   }
 }
 
+export class DataObjectTypeConstraints extends Struct {
+  constructor(value?: any) {
+    super(
+      {
+        max_object_size: 'Option<u64>',
+        allowed_mime_types: 'Vec<Text>',
+        fee_per_byte: 'Option<Balance>',
+      },
+      value
+    )
+  }
+
+  get max_object_size(): Option<u64> {
+    return this.get('max_object_size') as Option<u64>
+  }
+
+  get allowed_mime_types(): Vector<Text> {
+    return this.get('allowed_mime_types') as Vector<Text>
+  }
+
+  get fee_per_byte(): Option<Balance> {
+    return this.get('fee_per_byte') as Option<Balance>
+  }
+}
+
 export class DataObjectType extends Struct {
   constructor(value?: any) {
     super(
       {
         description: Text,
         active: bool,
+        constraints: DataObjectTypeConstraints,
       },
       value
     )
@@ -129,6 +179,10 @@ export class DataObjectType extends Struct {
   get active(): bool {
     return this.get('active') as bool
   }
+
+  get constraints(): DataObjectTypeConstraints {
+    return this.get('constraints') as DataObjectTypeConstraints
+  }
 }
 
 export class DataObjectsMap extends BTreeMap.with(ContentId, DataObject) {}
@@ -138,10 +192,12 @@ export function registerMediaTypes() {
     getTypeRegistry().register({
       ContentId,
       LiaisonJudgement,
+      ContentHash,
       DataObject,
       DataObjectStorageRelationshipId,
       DataObjectStorageRelationship,
       DataObjectTypeId,
+      DataObjectTypeConstraints,
       DataObjectType,
       DataObjectsMap,
     })
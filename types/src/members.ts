@@ -215,6 +215,11 @@ export function registerMembershipTypes() {
         fee: 'BalanceOf',
         text: 'Text',
       },
+      MemberSuspension: {
+        rationale: 'Vec<u8>',
+        suspended_at: 'BlockNumber',
+        ends_at: 'Option<BlockNumber>',
+      },
       Role,
       ActorId,
       ActorInRole,
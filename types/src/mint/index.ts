@@ -1,4 +1,4 @@
-import { getTypeRegistry, u32, u64, u128, Option, Enum } from '@polkadot/types'
+import { getTypeRegistry, u16, u32, u64, u128, Option, Enum, Null, GenericAccountId } from '@polkadot/types'
 import { Balance, BlockNumber } from '@polkadot/types/interfaces'
 import { JoyStruct } from '../common'
 
@@ -7,6 +7,9 @@ export class MintId extends u64 {}
 export class Setting extends u128 {}
 export class Adding extends u128 {}
 export class Reducing extends u128 {}
+export class AddingPercentage extends u16 {}
+export class ReducingPercentage extends u16 {}
+export class ToppingUpTo extends u128 {}
 
 export class AdjustCapacityBy extends Enum {
   constructor(value?: any, index?: number) {
@@ -15,6 +18,9 @@ export class AdjustCapacityBy extends Enum {
         Setting,
         Adding,
         Reducing,
+        AddingPercentage,
+        ReducingPercentage,
+        ToppingUpTo,
       },
       value,
       index
@@ -59,6 +65,7 @@ export type IMint = {
   next_adjustment: Option<NextAdjustment>
   created_at: BlockNumber
   total_minted: Balance
+  max_total_minted: Option<Balance>
 }
 export class Mint extends JoyStruct<IMint> {
   constructor(value?: IMint) {
@@ -68,17 +75,81 @@ export class Mint extends JoyStruct<IMint> {
         next_adjustment: Option.with(NextAdjustment),
         created_at: u32,
         total_minted: u128,
+        max_total_minted: Option.with(u128),
       },
       value
     )
   }
 }
 
+class MintLedgerEntryKind_CapacityIncreased extends Null {}
+class MintLedgerEntryKind_CapacityDecreased extends Null {}
+class MintLedgerEntryKind_CapacityAdjustedUp extends Null {}
+class MintLedgerEntryKind_CapacityAdjustedDown extends Null {}
+class MintLedgerEntryKind_CapacityTransferredTo extends MintId {}
+class MintLedgerEntryKind_CapacityTransferredFrom extends MintId {}
+
+export class MintLedgerEntryKind extends Enum {
+  constructor(value?: any, index?: number) {
+    super(
+      {
+        CapacityIncreased: MintLedgerEntryKind_CapacityIncreased,
+        CapacityDecreased: MintLedgerEntryKind_CapacityDecreased,
+        CapacityAdjustedUp: MintLedgerEntryKind_CapacityAdjustedUp,
+        CapacityAdjustedDown: MintLedgerEntryKind_CapacityAdjustedDown,
+        CapacityTransferredTo: MintLedgerEntryKind_CapacityTransferredTo,
+        CapacityTransferredFrom: MintLedgerEntryKind_CapacityTransferredFrom,
+        TokensTransferred: GenericAccountId,
+      },
+      value,
+      index
+    )
+  }
+}
+
+export type IMintLedgerEntry = {
+  kind: MintLedgerEntryKind
+  amount: Balance
+  capacity: Balance
+  block: BlockNumber
+}
+export class MintLedgerEntry extends JoyStruct<IMintLedgerEntry> {
+  constructor(value?: IMintLedgerEntry) {
+    super(
+      {
+        kind: MintLedgerEntryKind,
+        amount: u128,
+        capacity: u128,
+        block: u32,
+      },
+      value
+    )
+  }
+
+  get kind(): MintLedgerEntryKind {
+    return this.getField<MintLedgerEntryKind>('kind')
+  }
+
+  get amount(): u128 {
+    return this.getField<u128>('amount')
+  }
+
+  get capacity(): u128 {
+    return this.getField<u128>('capacity')
+  }
+
+  get block(): u32 {
+    return this.getField<u32>('block')
+  }
+}
+
 export function registerMintTypes() {
   try {
     getTypeRegistry().register({
       MintId: 'u64',
       Mint,
+      MintLedgerEntryKind,
+      MintLedgerEntry,
       MintBalanceOf: 'Balance',
       BalanceOfMint: 'Balance',
       'minting::BalanceOf': 'Balance',
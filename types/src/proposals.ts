@@ -18,10 +18,16 @@ import AccountId from '@polkadot/types/primitive/Generic/AccountId'
 import { ThreadId, JoyStruct, WorkingGroup } from './common'
 import { MemberId } from './members'
 import { RoleParameters } from './roles'
-import { StakeId } from './stake'
+import { StakeId, SlashId } from './stake'
 import { ElectionParameters } from './council'
 import { ActivateOpeningAt, OpeningId, ApplicationId } from './hiring'
-import { WorkingGroupOpeningPolicyCommitment, WorkerId, RewardPolicy } from './working-group'
+import {
+  WorkingGroupOpeningPolicyCommitment,
+  WorkerId,
+  RewardPolicy,
+  OpeningTemplateId,
+  OpeningPolicyCommitmentOverrides,
+} from './working-group'
 
 export type IVotingResults = {
   abstensions: u32
@@ -476,6 +482,86 @@ export class AddOpeningParameters extends JoyStruct<IAddOpeningParameters> {
   }
 }
 
+export type IAddOpeningTemplateParameters = {
+  name: Bytes
+  commitment: WorkingGroupOpeningPolicyCommitment
+  working_group: WorkingGroup
+}
+
+export class AddOpeningTemplateParameters extends JoyStruct<IAddOpeningTemplateParameters> {
+  constructor(value?: IAddOpeningTemplateParameters) {
+    super(
+      {
+        name: Bytes,
+        commitment: WorkingGroupOpeningPolicyCommitment,
+        working_group: WorkingGroup,
+      },
+      value
+    )
+  }
+
+  get name(): Bytes {
+    return this.getField<Bytes>('name')
+  }
+
+  get commitment(): WorkingGroupOpeningPolicyCommitment {
+    return this.getField<WorkingGroupOpeningPolicyCommitment>('commitment')
+  }
+
+  get working_group(): WorkingGroup {
+    return this.getField<WorkingGroup>('working_group')
+  }
+}
+
+export type IAddOpeningFromTemplateParameters = {
+  activate_at: ActivateOpeningAt
+  template_id: OpeningTemplateId
+  template_version: u32
+  overrides: OpeningPolicyCommitmentOverrides
+  human_readable_text: Bytes
+  working_group: WorkingGroup
+}
+
+export class AddOpeningFromTemplateParameters extends JoyStruct<IAddOpeningFromTemplateParameters> {
+  constructor(value?: IAddOpeningFromTemplateParameters) {
+    super(
+      {
+        activate_at: ActivateOpeningAt,
+        template_id: OpeningTemplateId,
+        template_version: u32,
+        overrides: OpeningPolicyCommitmentOverrides,
+        human_readable_text: Bytes,
+        working_group: WorkingGroup,
+      },
+      value
+    )
+  }
+
+  get activate_at(): ActivateOpeningAt {
+    return this.getField<ActivateOpeningAt>('activate_at')
+  }
+
+  get template_id(): OpeningTemplateId {
+    return this.getField<OpeningTemplateId>('template_id')
+  }
+
+  get template_version(): u32 {
+    return this.getField<u32>('template_version')
+  }
+
+  get overrides(): OpeningPolicyCommitmentOverrides {
+    return this.getField<OpeningPolicyCommitmentOverrides>('overrides')
+  }
+
+  get human_readable_text(): Bytes {
+    return this.getField<Bytes>('human_readable_text')
+  }
+
+  get working_group(): WorkingGroup {
+    return this.getField<WorkingGroup>('working_group')
+  }
+}
+
 export type IFillOpeningParameters = {
   opening_id: OpeningId
   successful_application_id: ApplicationId
@@ -571,6 +657,18 @@ export class ProposalDetails extends Enum {
         SlashWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
         SetWorkingGroupLeaderReward: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
         TerminateWorkingGroupLeaderRole: TerminateRoleParameters,
+        CancelWorkingGroupSlashing: Tuple.with([WorkerId, SlashId, WorkingGroup]),
+        ResumeWorkingGroupSlashing: Tuple.with([WorkerId, SlashId, WorkingGroup]),
+        SuspendMember: Tuple.with([MemberId, 'Vec<u8>', 'Option<BlockNumber>']),
+        UnsuspendMember: MemberId,
+        AddWorkingGroupLeaderOpeningTemplate: AddOpeningTemplateParameters,
+        UpdateWorkingGroupLeaderOpeningTemplate: Tuple.with([
+          OpeningTemplateId,
+          WorkingGroupOpeningPolicyCommitment,
+          WorkingGroup,
+        ]),
+        RemoveWorkingGroupLeaderOpeningTemplate: Tuple.with([OpeningTemplateId, WorkingGroup]),
+        AddWorkingGroupLeaderOpeningFromTemplate: AddOpeningFromTemplateParameters,
       },
       value,
       index
@@ -594,6 +692,8 @@ export function registerProposalTypes() {
       DiscussionThread,
       DiscussionPost,
       AddOpeningParameters,
+      AddOpeningTemplateParameters,
+      AddOpeningFromTemplateParameters,
       FillOpeningParameters,
       TerminateRoleParameters,
     })
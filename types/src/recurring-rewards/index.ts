@@ -39,6 +39,8 @@ export type IRewardRelationship = {
   payout_interval: Option<BlockNumber>
   total_reward_received: Balance
   total_reward_missed: Balance
+  ends_at_block: Option<BlockNumber>
+  max_total_payout: Option<Balance>
 }
 export class RewardRelationship extends JoyStruct<IRewardRelationship> {
   constructor(value?: IRecipient) {
@@ -52,6 +54,8 @@ export class RewardRelationship extends JoyStruct<IRewardRelationship> {
         payout_interval: Option.with('BlockNumber'),
         total_reward_received: 'Balance',
         total_reward_missed: 'Balance',
+        ends_at_block: Option.with('BlockNumber'),
+        max_total_payout: Option.with('Balance'),
       },
       value
     )
@@ -80,6 +84,68 @@ export class RewardRelationship extends JoyStruct<IRewardRelationship> {
   get next_payment_at_block(): Option<BlockNumber> {
     return this.getField<Option<BlockNumber>>('next_payment_at_block')
   }
+
+  get ends_at_block(): Option<BlockNumber> {
+    return this.getField<Option<BlockNumber>>('ends_at_block')
+  }
+
+  get max_total_payout(): Option<Balance> {
+    return this.getField<Option<Balance>>('max_total_payout')
+  }
+}
+
+export type IArrearsPolicy = {
+  max_arrears: Balance
+  expiry_period: BlockNumber
+}
+export class ArrearsPolicy extends JoyStruct<IArrearsPolicy> {
+  constructor(value?: IArrearsPolicy) {
+    super(
+      {
+        max_arrears: 'Balance',
+        expiry_period: 'BlockNumber',
+      },
+      value
+    )
+  }
+
+  get max_arrears(): Balance {
+    return this.getField<Balance>('max_arrears')
+  }
+
+  get expiry_period(): BlockNumber {
+    return this.getField<BlockNumber>('expiry_period')
+  }
+}
+
+export type IRewardArrears = {
+  policy: ArrearsPolicy
+  balance: Balance
+  expires_at_block: Option<BlockNumber>
+}
+export class RewardArrears extends JoyStruct<IRewardArrears> {
+  constructor(value?: IRewardArrears) {
+    super(
+      {
+        policy: ArrearsPolicy,
+        balance: 'Balance',
+        expires_at_block: Option.with('BlockNumber'),
+      },
+      value
+    )
+  }
+
+  get policy(): ArrearsPolicy {
+    return this.getField<ArrearsPolicy>('policy')
+  }
+
+  get balance(): Balance {
+    return this.getField<Balance>('balance')
+  }
+
+  get expires_at_block(): Option<BlockNumber> {
+    return this.getField<Option<BlockNumber>>('expires_at_block')
+  }
 }
 
 export function registerRecurringRewardsTypes() {
@@ -89,6 +155,8 @@ export function registerRecurringRewardsTypes() {
       RewardRelationshipId: 'u64',
       Recipient,
       RewardRelationship,
+      ArrearsPolicy,
+      RewardArrears,
     })
   } catch (err) {
     console.error('Failed to register custom types of recurring rewards module', err)
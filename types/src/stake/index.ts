@@ -1,6 +1,6 @@
-import { getTypeRegistry, u32, u64, u128, Enum, Null, BTreeMap, bool } from '@polkadot/types'
+import { getTypeRegistry, u32, u64, u128, Enum, Null, BTreeMap, bool, GenericAccountId } from '@polkadot/types'
 import { JoyStruct } from '../common'
-import { BlockNumber, Balance } from '@polkadot/types/interfaces'
+import { AccountId, BlockNumber, Balance } from '@polkadot/types/interfaces'
 
 export class StakeId extends u64 {}
 export class SlashId extends u64 {}
@@ -8,7 +8,7 @@ export class SlashId extends u64 {}
 export type ISlash = {
   started_at_block: BlockNumber
   is_active: bool
-  blocks_remaining_in_active_period_for_slashing: BlockNumber
+  slash_at_block: BlockNumber
   slash_amount: Balance
 }
 export class Slash extends JoyStruct<ISlash> {
@@ -17,7 +17,7 @@ export class Slash extends JoyStruct<ISlash> {
       {
         started_at_block: u32,
         is_active: bool,
-        blocks_remaining_in_active_period_for_slashing: u32,
+        slash_at_block: u32,
         slash_amount: u128,
       },
       value
@@ -28,7 +28,7 @@ export class Slash extends JoyStruct<ISlash> {
 export type IUnstakingState = {
   started_at_block: BlockNumber
   is_active: bool
-  blocks_remaining_in_active_period_for_unstaking: BlockNumber
+  unstake_at_block: BlockNumber
 }
 export class UnstakingState extends JoyStruct<IUnstakingState> {
   constructor(value?: IUnstakingState) {
@@ -36,7 +36,7 @@ export class UnstakingState extends JoyStruct<IUnstakingState> {
       {
         started_at_block: u32,
         is_active: bool,
-        blocks_remaining_in_active_period_for_unstaking: u32,
+        unstake_at_block: u32,
       },
       value
     )
@@ -132,11 +132,47 @@ export class Stake extends JoyStruct<IStake> {
   }
 }
 
+export type IStakeFunding = {
+  funder: AccountId
+  share: Balance
+}
+
+export class StakeFunding extends JoyStruct<IStakeFunding> {
+  constructor(value?: IStakeFunding) {
+    super(
+      {
+        funder: GenericAccountId,
+        share: u128,
+      },
+      value
+    )
+  }
+}
+
+export type ILockedFunds = {
+  owner: AccountId
+  amount: Balance
+}
+
+export class LockedFunds extends JoyStruct<ILockedFunds> {
+  constructor(value?: ILockedFunds) {
+    super(
+      {
+        owner: GenericAccountId,
+        amount: u128,
+      },
+      value
+    )
+  }
+}
+
 export function registerStakeTypes() {
   try {
     getTypeRegistry().register({
       StakeId: 'u64',
       Stake,
+      StakeFunding,
+      LockedFunds,
     })
   } catch (err) {
     console.error('Failed to register custom types of stake module', err)
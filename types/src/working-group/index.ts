@@ -1,7 +1,7 @@
-import { getTypeRegistry, Bytes, BTreeMap, Option } from '@polkadot/types'
-import { u16, Null } from '@polkadot/types/primitive'
-import { AccountId, BlockNumber, Balance } from '@polkadot/types/interfaces'
-import { BTreeSet, JoyStruct } from '../common'
+import { getTypeRegistry, Bytes, BTreeMap, Option, Vec } from '@polkadot/types'
+import { u8, u16, u32, u64, Null } from '@polkadot/types/primitive'
+import { AccountId, BlockNumber, Balance, Hash } from '@polkadot/types/interfaces'
+import { BTreeSet, JoyStruct, ThreadId } from '../common'
 import { MemberId, ActorId } from '../members'
 import { RewardRelationshipId } from '../recurring-rewards'
 import { StakeId } from '../stake'
@@ -130,6 +130,289 @@ export class Worker extends JoyStruct<IWorker> {
   }
 }
 
+export type IWorkerEvaluation = {
+  score: u8
+  rationale: Bytes
+  evaluated_at: BlockNumber
+}
+
+export class WorkerEvaluation extends JoyStruct<IWorkerEvaluation> {
+  constructor(value?: IWorkerEvaluation) {
+    super(
+      {
+        score: u8,
+        rationale: Bytes,
+        evaluated_at: 'BlockNumber',
+      },
+      value
+    )
+  }
+
+  get score(): u8 {
+    return this.getField<u8>('score')
+  }
+
+  get rationale(): Bytes {
+    return this.getField<Bytes>('rationale')
+  }
+
+  get evaluated_at(): BlockNumber {
+    return this.getField<BlockNumber>('evaluated_at')
+  }
+}
+
+export type IWorkReport = {
+  content_hashes: Vec<Hash>
+  description: Bytes
+  submitted_at: BlockNumber
+}
+
+export class WorkReport extends JoyStruct<IWorkReport> {
+  constructor(value?: IWorkReport) {
+    super(
+      {
+        content_hashes: Vec.with('Hash'),
+        description: Bytes,
+        submitted_at: 'BlockNumber',
+      },
+      value
+    )
+  }
+
+  get content_hashes(): Vec<Hash> {
+    return this.getField<Vec<Hash>>('content_hashes')
+  }
+
+  get description(): Bytes {
+    return this.getField<Bytes>('description')
+  }
+
+  get submitted_at(): BlockNumber {
+    return this.getField<BlockNumber>('submitted_at')
+  }
+}
+
+export type IWorkerSuspension = {
+  suspended_at: BlockNumber
+  ends_at: BlockNumber
+  rationale: Bytes
+  paused_reward_payment_at: Option<BlockNumber>
+}
+
+export class WorkerSuspension extends JoyStruct<IWorkerSuspension> {
+  constructor(value?: IWorkerSuspension) {
+    super(
+      {
+        suspended_at: 'BlockNumber',
+        ends_at: 'BlockNumber',
+        rationale: Bytes,
+        paused_reward_payment_at: Option.with('BlockNumber'),
+      },
+      value
+    )
+  }
+
+  get suspended_at(): BlockNumber {
+    return this.getField<BlockNumber>('suspended_at')
+  }
+
+  get ends_at(): BlockNumber {
+    return this.getField<BlockNumber>('ends_at')
+  }
+
+  get rationale(): Bytes {
+    return this.getField<Bytes>('rationale')
+  }
+
+  get paused_reward_payment_at(): Option<BlockNumber> {
+    return this.getField<Option<BlockNumber>>('paused_reward_payment_at')
+  }
+}
+
+export type IStakeDecreaseRequest = {
+  amount: Balance
+  requested_at: BlockNumber
+  release_at: Option<BlockNumber>
+}
+
+export class StakeDecreaseRequest extends JoyStruct<IStakeDecreaseRequest> {
+  constructor(value?: IStakeDecreaseRequest) {
+    super(
+      {
+        amount: 'Balance',
+        requested_at: 'BlockNumber',
+        release_at: Option.with('BlockNumber'),
+      },
+      value
+    )
+  }
+
+  get amount(): Balance {
+    return this.getField<Balance>('amount')
+  }
+
+  get requested_at(): BlockNumber {
+    return this.getField<BlockNumber>('requested_at')
+  }
+
+  get release_at(): Option<BlockNumber> {
+    return this.getField<Option<BlockNumber>>('release_at')
+  }
+}
+
+export type IPendingSlash = {
+  amount: Balance
+  rationale: Bytes
+  initiated_at: BlockNumber
+  appeal_thread_id: Option<ThreadId>
+}
+
+export class PendingSlash extends JoyStruct<IPendingSlash> {
+  constructor(value?: IPendingSlash) {
+    super(
+      {
+        amount: 'Balance',
+        rationale: Bytes,
+        initiated_at: 'BlockNumber',
+        appeal_thread_id: Option.with(ThreadId),
+      },
+      value
+    )
+  }
+
+  get amount(): Balance {
+    return this.getField<Balance>('amount')
+  }
+
+  get rationale(): Bytes {
+    return this.getField<Bytes>('rationale')
+  }
+
+  get initiated_at(): BlockNumber {
+    return this.getField<BlockNumber>('initiated_at')
+  }
+
+  get appeal_thread_id(): Option<ThreadId> {
+    return this.getField<Option<ThreadId>>('appeal_thread_id')
+  }
+}
+
+export type IExtraReward = {
+  reward_relationship_id: RewardRelationshipId
+  ends_at: BlockNumber
+  paused_payment_at: Option<BlockNumber>
+}
+
+export class ExtraReward extends JoyStruct<IExtraReward> {
+  constructor(value?: IExtraReward) {
+    super(
+      {
+        reward_relationship_id: RewardRelationshipId,
+        ends_at: 'BlockNumber',
+        paused_payment_at: 'Option<BlockNumber>',
+      },
+      value
+    )
+  }
+
+  get reward_relationship_id(): RewardRelationshipId {
+    return this.getField<RewardRelationshipId>('reward_relationship_id')
+  }
+
+  get ends_at(): BlockNumber {
+    return this.getField<BlockNumber>('ends_at')
+  }
+
+  get paused_payment_at(): Option<BlockNumber> {
+    return this.getField<Option<BlockNumber>>('paused_payment_at')
+  }
+}
+
+export type IGrantedExtraRewards = {
+  period_started_at: BlockNumber
+  amount: Balance
+}
+
+export class GrantedExtraRewards extends JoyStruct<IGrantedExtraRewards> {
+  constructor(value?: IGrantedExtraRewards) {
+    super(
+      {
+        period_started_at: 'BlockNumber',
+        amount: 'Balance',
+      },
+      value
+    )
+  }
+
+  get period_started_at(): BlockNumber {
+    return this.getField<BlockNumber>('period_started_at')
+  }
+
+  get amount(): Balance {
+    return this.getField<Balance>('amount')
+  }
+}
+
+export class CouncilTerm extends u32 {}
+
+export class SpendingCategory extends JoyEnum({
+  WorkerReward: Null,
+  LeaderReward: Null,
+  ExtraReward: Null,
+  Refund: Null,
+} as const) {}
+
+export class SpendingByWorkerMap extends BTreeMap.with(WorkerId, 'Balance') {}
+
+export type ISpendingReport = {
+  worker_rewards: Balance
+  leader_rewards: Balance
+  extra_rewards: Balance
+  missed_rewards: Balance
+  refunds: Balance
+  spending_by_worker: SpendingByWorkerMap
+}
+
+export class SpendingReport extends JoyStruct<ISpendingReport> {
+  constructor(value?: ISpendingReport) {
+    super(
+      {
+        worker_rewards: 'Balance',
+        leader_rewards: 'Balance',
+        extra_rewards: 'Balance',
+        missed_rewards: 'Balance',
+        refunds: 'Balance',
+        spending_by_worker: SpendingByWorkerMap,
+      },
+      value
+    )
+  }
+
+  get worker_rewards(): Balance {
+    return this.getField<Balance>('worker_rewards')
+  }
+
+  get leader_rewards(): Balance {
+    return this.getField<Balance>('leader_rewards')
+  }
+
+  get extra_rewards(): Balance {
+    return this.getField<Balance>('extra_rewards')
+  }
+
+  get missed_rewards(): Balance {
+    return this.getField<Balance>('missed_rewards')
+  }
+
+  get refunds(): Balance {
+    return this.getField<Balance>('refunds')
+  }
+
+  get spending_by_worker(): SpendingByWorkerMap {
+    return this.getField<SpendingByWorkerMap>('spending_by_worker')
+  }
+}
+
 export type ISlashableTerms = {
   max_count: u16
   max_percent_pts_per_time: u16
@@ -254,6 +537,91 @@ export class WorkingGroupOpeningPolicyCommitment extends JoyStruct<IWorkingGroup
   }
 }
 
+export type IOpeningPolicyCommitmentOverrides = {
+  application_rationing_policy: Option<Option<ApplicationRationingPolicy>>
+  max_review_period_length: Option<BlockNumber>
+  application_staking_policy: Option<Option<StakingPolicy>>
+  role_staking_policy: Option<Option<StakingPolicy>>
+  role_slashing_terms: Option<SlashingTerms>
+  fill_opening_successful_applicant_application_stake_unstaking_period: Option<Option<BlockNumber>>
+  fill_opening_failed_applicant_application_stake_unstaking_period: Option<Option<BlockNumber>>
+  fill_opening_failed_applicant_role_stake_unstaking_period: Option<Option<BlockNumber>>
+  terminate_application_stake_unstaking_period: Option<Option<BlockNumber>>
+  terminate_role_stake_unstaking_period: Option<Option<BlockNumber>>
+  exit_role_application_stake_unstaking_period: Option<Option<BlockNumber>>
+  exit_role_stake_unstaking_period: Option<Option<BlockNumber>>
+}
+
+export class OpeningPolicyCommitmentOverrides extends JoyStruct<IOpeningPolicyCommitmentOverrides> {
+  constructor(value?: IOpeningPolicyCommitmentOverrides) {
+    super(
+      {
+        application_rationing_policy: Option.with(Option.with(ApplicationRationingPolicy)),
+        max_review_period_length: 'Option<BlockNumber>',
+        application_staking_policy: Option.with(Option.with(StakingPolicy)),
+        role_staking_policy: Option.with(Option.with(StakingPolicy)),
+        role_slashing_terms: Option.with(SlashingTerms),
+        fill_opening_successful_applicant_application_stake_unstaking_period: 'Option<Option<BlockNumber>>',
+        fill_opening_failed_applicant_application_stake_unstaking_period: 'Option<Option<BlockNumber>>',
+        fill_opening_failed_applicant_role_stake_unstaking_period: 'Option<Option<BlockNumber>>',
+        terminate_application_stake_unstaking_period: 'Option<Option<BlockNumber>>',
+        terminate_role_stake_unstaking_period: 'Option<Option<BlockNumber>>',
+        exit_role_application_stake_unstaking_period: 'Option<Option<BlockNumber>>',
+        exit_role_stake_unstaking_period: 'Option<Option<BlockNumber>>',
+      },
+      value
+    )
+  }
+
+  get application_rationing_policy(): Option<Option<ApplicationRationingPolicy>> {
+    return this.getField<Option<Option<ApplicationRationingPolicy>>>('application_rationing_policy')
+  }
+
+  get max_review_period_length(): Option<BlockNumber> {
+    return this.getField<Option<BlockNumber>>('max_review_period_length')
+  }
+
+  get application_staking_policy(): Option<Option<StakingPolicy>> {
+    return this.getField<Option<Option<StakingPolicy>>>('application_staking_policy')
+  }
+
+  get role_staking_policy(): Option<Option<StakingPolicy>> {
+    return this.getField<Option<Option<StakingPolicy>>>('role_staking_policy')
+  }
+
+  get role_slashing_terms(): Option<SlashingTerms> {
+    return this.getField<Option<SlashingTerms>>('role_slashing_terms')
+  }
+
+  get fill_opening_successful_applicant_application_stake_unstaking_period(): Option<Option<BlockNumber>> {
+    return this.getField<Option<Option<BlockNumber>>>('fill_opening_successful_applicant_application_stake_unstaking_period')
+  }
+
+  get fill_opening_failed_applicant_application_stake_unstaking_period(): Option<Option<BlockNumber>> {
+    return this.getField<Option<Option<BlockNumber>>>('fill_opening_failed_applicant_application_stake_unstaking_period')
+  }
+
+  get fill_opening_failed_applicant_role_stake_unstaking_period(): Option<Option<BlockNumber>> {
+    return this.getField<Option<Option<BlockNumber>>>('fill_opening_failed_applicant_role_stake_unstaking_period')
+  }
+
+  get terminate_application_stake_unstaking_period(): Option<Option<BlockNumber>> {
+    return this.getField<Option<Option<BlockNumber>>>('terminate_application_stake_unstaking_period')
+  }
+
+  get terminate_role_stake_unstaking_period(): Option<Option<BlockNumber>> {
+    return this.getField<Option<Option<BlockNumber>>>('terminate_role_stake_unstaking_period')
+  }
+
+  get exit_role_application_stake_unstaking_period(): Option<Option<BlockNumber>> {
+    return this.getField<Option<Option<BlockNumber>>>('exit_role_application_stake_unstaking_period')
+  }
+
+  get exit_role_stake_unstaking_period(): Option<Option<BlockNumber>> {
+    return this.getField<Option<Option<BlockNumber>>>('exit_role_stake_unstaking_period')
+  }
+}
+
 export class OpeningType_Leader extends Null {}
 export class OpeningType_Worker extends Null {}
 export const OpeningTypeDef = {
@@ -263,6 +631,45 @@ export const OpeningTypeDef = {
 export type OpeningTypeKey = keyof typeof OpeningTypeDef
 export class OpeningType extends JoyEnum(OpeningTypeDef) {}
 
+export class OpeningTemplateId extends u64 {}
+
+export type IOpeningTemplate = {
+  name: Bytes
+  version: u32
+  opening_type: OpeningType
+  policy_commitment: WorkingGroupOpeningPolicyCommitment
+}
+
+export class OpeningTemplate extends JoyStruct<IOpeningTemplate> {
+  constructor(value?: IOpeningTemplate) {
+    super(
+      {
+        name: Bytes,
+        version: u32,
+        opening_type: OpeningType,
+        policy_commitment: WorkingGroupOpeningPolicyCommitment,
+      },
+      value
+    )
+  }
+
+  get name(): Bytes {
+    return this.getField<Bytes>('name')
+  }
+
+  get version(): u32 {
+    return this.getField<u32>('version')
+  }
+
+  get opening_type(): OpeningType {
+    return this.getField<OpeningType>('opening_type')
+  }
+
+  get policy_commitment(): WorkingGroupOpeningPolicyCommitment {
+    return this.getField<WorkingGroupOpeningPolicyCommitment>('policy_commitment')
+  }
+}
+
 export type IOpening = {
   hiring_opening_id: OpeningId
   applications: BTreeSet<ApplicationId>
@@ -347,6 +754,19 @@ export function registerWorkingGroupTypes() {
       /// Alias used by the runtime working-group module
       HiringApplicationId: ApplicationId,
       RewardPolicy,
+      WorkerEvaluationOf: WorkerEvaluation,
+      WorkReportOf: WorkReport,
+      WorkerSuspensionOf: WorkerSuspension,
+      StakeDecreaseRequestOf: StakeDecreaseRequest,
+      PendingSlashOf: PendingSlash,
+      ExtraRewardOf: ExtraReward,
+      GrantedExtraRewardsOf: GrantedExtraRewards,
+      CouncilTerm,
+      SpendingCategory,
+      SpendingReportOf: SpendingReport,
+      OpeningTemplateId,
+      OpeningTemplateOf: OpeningTemplate,
+      OpeningPolicyCommitmentOverridesOf: OpeningPolicyCommitmentOverrides,
       'working_group::OpeningId': OpeningId,
       'working_group::WorkerId': WorkerId,
     })